@@ -1,6 +1,7 @@
 use radix_engine_interface::api::node_modules::auth::{
-    RoleAssignmentCreateInput, RoleAssignmentSetInput, RoleAssignmentSetOwnerInput,
-    ROLE_ASSIGNMENT_BLUEPRINT, ROLE_ASSIGNMENT_CREATE_IDENT, ROLE_ASSIGNMENT_SET_IDENT,
+    RoleAssignmentCreateInput, RoleAssignmentGetOwnerRoleInput, RoleAssignmentSetInput,
+    RoleAssignmentSetOwnerInput, ROLE_ASSIGNMENT_BLUEPRINT, ROLE_ASSIGNMENT_CREATE_IDENT,
+    ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT, ROLE_ASSIGNMENT_SET_IDENT,
     ROLE_ASSIGNMENT_SET_OWNER_IDENT,
 };
 use radix_engine_interface::api::object_api::ModuleId;
@@ -87,6 +88,28 @@ pub trait RoleAssignmentObject {
         Ok(())
     }
 
+    fn get_owner_role<Y: ClientApi<E>, E: Debug + ScryptoDecode>(
+        &self,
+        api: &mut Y,
+    ) -> Result<OwnerRoleEntry, E> {
+        let (node_id, module_id) = self.self_id();
+        let rtn = match module_id {
+            None => api.call_method(
+                node_id,
+                ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT,
+                scrypto_encode(&RoleAssignmentGetOwnerRoleInput {}).unwrap(),
+            )?,
+            Some(module_id) => api.call_module_method(
+                node_id,
+                module_id,
+                ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT,
+                scrypto_encode(&RoleAssignmentGetOwnerRoleInput {}).unwrap(),
+            )?,
+        };
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     fn set_role<
         Y: ClientApi<E>,
         E: Debug + ScryptoDecode,
@@ -93,6 +93,14 @@ pub trait NativeNonFungibleVault {
     where
         Y: ClientApi<E>;
 
+    fn contains_non_fungible<Y, E: Debug + ScryptoDecode>(
+        &self,
+        id: NonFungibleLocalId,
+        api: &mut Y,
+    ) -> Result<bool, E>
+    where
+        Y: ClientApi<E>;
+
     fn take_non_fungibles<Y, E: Debug + ScryptoDecode>(
         &mut self,
         non_fungible_local_ids: IndexSet<NonFungibleLocalId>,
@@ -374,4 +382,21 @@ impl NativeNonFungibleVault for Vault {
 
         Ok(scrypto_decode(&rtn).unwrap())
     }
+
+    fn contains_non_fungible<Y, E: Debug + ScryptoDecode>(
+        &self,
+        id: NonFungibleLocalId,
+        api: &mut Y,
+    ) -> Result<bool, E>
+    where
+        Y: ClientApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            NON_FUNGIBLE_VAULT_CONTAINS_NON_FUNGIBLE_IDENT,
+            scrypto_encode(&NonFungibleVaultContainsNonFungibleInput { id }).unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
 }
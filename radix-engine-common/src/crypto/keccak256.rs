@@ -0,0 +1,32 @@
+use crate::crypto::*;
+use sha3::{Digest, Keccak256};
+
+pub fn keccak256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+    Hash(Keccak256::digest(data).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::str::FromStr;
+
+    #[test]
+    fn test_keccak256_hash() {
+        let data = "Hello Radix";
+        let hash = keccak256_hash(data);
+        assert_eq!(
+            hash,
+            Hash::from_str("415942230ddb029416a4612818536de230d827cbac9646a0b26d9855a4c45587")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_keccak256_hash_of_empty_input() {
+        assert_eq!(
+            keccak256_hash([]),
+            Hash::from_str("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+                .unwrap()
+        );
+    }
+}
@@ -1,15 +1,25 @@
 mod blake2b;
 mod hash;
 mod hash_accumulator;
+mod keccak256;
 mod public_key;
+mod public_key_bls12381;
 mod public_key_ed25519;
 mod public_key_hash;
 mod public_key_secp256k1;
+mod signature_bls12381;
+mod signature_ed25519;
+mod signature_secp256k1;
 
 pub use self::blake2b::*;
 pub use self::hash::*;
 pub use self::hash_accumulator::*;
+pub use self::keccak256::*;
 pub use self::public_key::*;
+pub use self::public_key_bls12381::*;
 pub use self::public_key_ed25519::*;
 pub use self::public_key_hash::*;
 pub use self::public_key_secp256k1::*;
+pub use self::signature_bls12381::*;
+pub use self::signature_ed25519::*;
+pub use self::signature_secp256k1::*;
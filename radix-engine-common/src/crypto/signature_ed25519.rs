@@ -1,10 +1,4 @@
-use sbor::rust::borrow::ToOwned;
-use sbor::rust::fmt;
-use sbor::rust::str::FromStr;
-use sbor::rust::string::String;
-use sbor::rust::vec::Vec;
-use sbor::*;
-use utils::copy_u8_array;
+use crate::internal_prelude::*;
 
 /// Represents an ED25519 signature.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
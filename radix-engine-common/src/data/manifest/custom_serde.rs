@@ -87,12 +87,123 @@ impl SerializableCustomExtension for ManifestCustomExtension {
     }
 }
 
+/// The context needed to parse the custom values which were serialized with a
+/// [`ManifestValueDisplayContext`].
+///
+/// Note - the named buckets, proofs, address reservations and addresses are not resolved, so they
+/// have to be serialized with their ids (ie without any names in the display context).
+#[derive(Clone, Copy, Default)]
+pub struct ManifestValueDeserializationContext<'a> {
+    pub address_bech32_decoder: Option<&'a AddressBech32Decoder>,
+}
+
+impl<'a> ManifestValueDeserializationContext<'a> {
+    pub fn no_context() -> Self {
+        Self::default()
+    }
+
+    pub fn with_optional_bech32(address_bech32_decoder: Option<&'a AddressBech32Decoder>) -> Self {
+        Self {
+            address_bech32_decoder,
+        }
+    }
+}
+
+impl<'a> Into<ManifestValueDeserializationContext<'a>> for &'a AddressBech32Decoder {
+    fn into(self) -> ManifestValueDeserializationContext<'a> {
+        ManifestValueDeserializationContext::with_optional_bech32(Some(self))
+    }
+}
+
+impl DeserializableCustomExtension for ManifestCustomExtension {
+    type CustomValue = ManifestCustomValue;
+    type CustomDeserializationContext<'a> = ManifestValueDeserializationContext<'a>;
+
+    fn parse_custom_value(
+        context: &Self::CustomDeserializationContext<'_>,
+        custom_value_kind: Self::CustomValueKind,
+        content: &str,
+    ) -> Result<Self::CustomValue, String> {
+        let custom_value = match custom_value_kind {
+            ManifestCustomValueKind::Address => match content.parse::<u32>() {
+                Ok(address_id) => ManifestCustomValue::Address(ManifestAddress::Named(address_id)),
+                Err(_) => ManifestCustomValue::Address(ManifestAddress::Static(
+                    ScryptoValueDeserializationContext::with_optional_bech32(
+                        context.address_bech32_decoder,
+                    )
+                    .parse_node_id(content)?,
+                )),
+            },
+            ManifestCustomValueKind::Bucket => {
+                ManifestCustomValue::Bucket(ManifestBucket(parse_id(content)?))
+            }
+            ManifestCustomValueKind::Proof => {
+                ManifestCustomValue::Proof(ManifestProof(parse_id(content)?))
+            }
+            ManifestCustomValueKind::AddressReservation => ManifestCustomValue::AddressReservation(
+                ManifestAddressReservation(parse_id(content)?),
+            ),
+            ManifestCustomValueKind::Expression => {
+                let expression = match content {
+                    "ENTIRE_WORKTOP" => ManifestExpression::EntireWorktop,
+                    "ENTIRE_AUTH_ZONE" => ManifestExpression::EntireAuthZone,
+                    _ => return Err(format!("Unknown expression: {}", content)),
+                };
+                ManifestCustomValue::Expression(expression)
+            }
+            ManifestCustomValueKind::Blob => {
+                let hash = hex::decode(content)
+                    .map_err(|error| format!("{:?}", error))?
+                    .try_into()
+                    .map_err(|bytes: Vec<u8>| {
+                        format!("Invalid blob hash length: {}", bytes.len())
+                    })?;
+                ManifestCustomValue::Blob(ManifestBlobRef(hash))
+            }
+            ManifestCustomValueKind::Decimal => ManifestCustomValue::Decimal(from_decimal(
+                &Decimal::from_str(content).map_err(|error| format!("{:?}", error))?,
+            )),
+            ManifestCustomValueKind::PreciseDecimal => {
+                ManifestCustomValue::PreciseDecimal(from_precise_decimal(
+                    &PreciseDecimal::from_str(content).map_err(|error| format!("{:?}", error))?,
+                ))
+            }
+            ManifestCustomValueKind::NonFungibleLocalId => {
+                ManifestCustomValue::NonFungibleLocalId(from_non_fungible_local_id(
+                    NonFungibleLocalId::from_str(content)
+                        .map_err(|error| format!("{:?}", error))?,
+                ))
+            }
+        };
+        Ok(custom_value)
+    }
+
+    fn untagged_custom_value_kind(
+        custom_type_kind: &<Self::CustomSchema as CustomSchema>::CustomTypeKind<LocalTypeId>,
+    ) -> Option<Self::CustomValueKind> {
+        match custom_type_kind {
+            ScryptoCustomTypeKind::Reference => Some(ManifestCustomValueKind::Address),
+            // Can be either a bucket, a proof or an address reservation
+            ScryptoCustomTypeKind::Own => None,
+            ScryptoCustomTypeKind::Decimal => Some(ManifestCustomValueKind::Decimal),
+            ScryptoCustomTypeKind::PreciseDecimal => Some(ManifestCustomValueKind::PreciseDecimal),
+            ScryptoCustomTypeKind::NonFungibleLocalId => {
+                Some(ManifestCustomValueKind::NonFungibleLocalId)
+            }
+        }
+    }
+}
+
+fn parse_id(content: &str) -> Result<u32, String> {
+    content.parse().map_err(|error| format!("{:?}", error))
+}
+
 #[cfg(test)]
 #[cfg(feature = "serde")] // Ensures that VS Code runs this module with the features serde tag!
 mod tests {
     use super::*;
     use crate::address::test_addresses::*;
-    use crate::address::AddressBech32Encoder;
+    use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
     use crate::types::*;
     use sbor::rust::vec;
     use serde::Serialize;
@@ -285,6 +396,60 @@ mod tests {
         assert_programmatic_json_matches(&value, context, expected_programmatic);
     }
 
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_programmatic_json_deserializes_to_manifest_value() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let decoder = AddressBech32Decoder::for_simulator();
+        let value = ManifestValue::Tuple {
+            fields: vec![
+                ManifestCustomValue::Address(ManifestAddress::Static(
+                    FUNGIBLE_RESOURCE.as_node_id().clone(),
+                )),
+                ManifestCustomValue::Address(ManifestAddress::Named(3)),
+                ManifestCustomValue::Blob(ManifestBlobRef([7; 32])),
+                ManifestCustomValue::Bucket(ManifestBucket(1)),
+                ManifestCustomValue::Proof(ManifestProof(2)),
+                ManifestCustomValue::AddressReservation(ManifestAddressReservation(4)),
+                ManifestCustomValue::Decimal(from_decimal(&Decimal::ONE.checked_div(100).unwrap())),
+                ManifestCustomValue::PreciseDecimal(from_precise_decimal(&PreciseDecimal::ONE)),
+                ManifestCustomValue::NonFungibleLocalId(ManifestNonFungibleLocalId::ruid(
+                    [0x11; 32],
+                )),
+                ManifestCustomValue::Expression(ManifestExpression::EntireAuthZone),
+                ManifestCustomValue::Expression(ManifestExpression::EntireWorktop),
+            ]
+            .into_iter()
+            .map(|value| ManifestValue::Custom { value })
+            .collect(),
+        };
+        let payload = manifest_encode(&value).unwrap();
+        let json = to_value(
+            ManifestRawPayload::new_from_valid_slice(&payload).serializable(
+                SerializationParameters::Schemaless {
+                    mode: SerializationMode::Programmatic,
+                    custom_context: ManifestValueDisplayContext::with_optional_bech32(Some(
+                        &encoder,
+                    )),
+                    depth_limit: MANIFEST_SBOR_V1_MAX_DEPTH,
+                },
+            ),
+        )
+        .unwrap();
+
+        let deserialized = deserialize_value(
+            json,
+            &DeserializationParameters::<ManifestCustomExtension>::Schemaless {
+                mode: SerializationMode::Programmatic,
+                custom_context: (&decoder).into(),
+                depth_limit: MANIFEST_SBOR_V1_MAX_DEPTH,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(deserialized, value);
+    }
+
     fn assert_natural_json_matches<
         'a,
         T: ManifestEncode,
@@ -196,6 +196,33 @@ impl CustomSchema for ScryptoCustomSchema {
         }
     }
 
+    fn is_custom_type_validation_compatible(
+        old_validation: &Self::CustomTypeValidation,
+        new_validation: &Self::CustomTypeValidation,
+    ) -> bool {
+        if old_validation == new_validation {
+            return true;
+        }
+        match (old_validation, new_validation) {
+            // Any global reference validation is a special case of `IsGlobal`
+            (
+                ScryptoCustomTypeValidation::Reference(
+                    ReferenceValidation::IsGlobalPackage
+                    | ReferenceValidation::IsGlobalComponent
+                    | ReferenceValidation::IsGlobalResourceManager
+                    | ReferenceValidation::IsGlobalTyped(_, _),
+                ),
+                ScryptoCustomTypeValidation::Reference(ReferenceValidation::IsGlobal),
+            ) => true,
+            // Similarly, any typed internal reference validation is a special case of `IsInternal`
+            (
+                ScryptoCustomTypeValidation::Reference(ReferenceValidation::IsInternalTyped(_, _)),
+                ScryptoCustomTypeValidation::Reference(ReferenceValidation::IsInternal),
+            ) => true,
+            _ => false,
+        }
+    }
+
     fn empty_schema() -> &'static Schema<Self> {
         &EMPTY_SCHEMA
     }
@@ -40,12 +40,110 @@ impl SerializableCustomExtension for ScryptoCustomExtension {
     }
 }
 
+/// The context needed to parse the custom values which were serialized with a
+/// [`ScryptoValueDisplayContext`].
+#[derive(Clone, Copy, Default)]
+pub struct ScryptoValueDeserializationContext<'a> {
+    pub address_bech32_decoder: Option<&'a AddressBech32Decoder>,
+}
+
+impl<'a> ScryptoValueDeserializationContext<'a> {
+    pub fn no_context() -> Self {
+        Self::default()
+    }
+
+    pub fn with_optional_bech32(address_bech32_decoder: Option<&'a AddressBech32Decoder>) -> Self {
+        Self {
+            address_bech32_decoder,
+        }
+    }
+
+    /// Parses a node id from either its Bech32m address, its `NodeId(<hex>)` display form (as used
+    /// for the node ids without an address), or its raw hex.
+    pub fn parse_node_id(&self, content: &str) -> Result<NodeId, String> {
+        let hex = content
+            .strip_prefix("NodeId(")
+            .and_then(|content| content.strip_suffix(")"))
+            .unwrap_or(content);
+        let bytes = match hex::decode(hex) {
+            Ok(bytes) => bytes,
+            Err(_) => match self.address_bech32_decoder {
+                Some(decoder) => {
+                    let (_, bytes) = decoder
+                        .validate_and_decode(content)
+                        .map_err(|error| format!("{:?}", error))?;
+                    bytes
+                }
+                None => {
+                    return Err(format!(
+                        "Cannot decode {} without a Bech32m decoder",
+                        content
+                    ))
+                }
+            },
+        };
+        bytes
+            .try_into()
+            .map(NodeId)
+            .map_err(|bytes: Vec<u8>| format!("Invalid node id length: {}", bytes.len()))
+    }
+}
+
+impl<'a> Into<ScryptoValueDeserializationContext<'a>> for &'a AddressBech32Decoder {
+    fn into(self) -> ScryptoValueDeserializationContext<'a> {
+        ScryptoValueDeserializationContext::with_optional_bech32(Some(self))
+    }
+}
+
+impl DeserializableCustomExtension for ScryptoCustomExtension {
+    type CustomValue = ScryptoCustomValue;
+    type CustomDeserializationContext<'a> = ScryptoValueDeserializationContext<'a>;
+
+    fn parse_custom_value(
+        context: &Self::CustomDeserializationContext<'_>,
+        custom_value_kind: Self::CustomValueKind,
+        content: &str,
+    ) -> Result<Self::CustomValue, String> {
+        let custom_value = match custom_value_kind {
+            ScryptoCustomValueKind::Reference => {
+                ScryptoCustomValue::Reference(Reference(context.parse_node_id(content)?))
+            }
+            ScryptoCustomValueKind::Own => {
+                ScryptoCustomValue::Own(Own(context.parse_node_id(content)?))
+            }
+            ScryptoCustomValueKind::Decimal => ScryptoCustomValue::Decimal(
+                Decimal::from_str(content).map_err(|error| format!("{:?}", error))?,
+            ),
+            ScryptoCustomValueKind::PreciseDecimal => ScryptoCustomValue::PreciseDecimal(
+                PreciseDecimal::from_str(content).map_err(|error| format!("{:?}", error))?,
+            ),
+            ScryptoCustomValueKind::NonFungibleLocalId => ScryptoCustomValue::NonFungibleLocalId(
+                NonFungibleLocalId::from_str(content).map_err(|error| format!("{:?}", error))?,
+            ),
+        };
+        Ok(custom_value)
+    }
+
+    fn untagged_custom_value_kind(
+        custom_type_kind: &<Self::CustomSchema as CustomSchema>::CustomTypeKind<LocalTypeId>,
+    ) -> Option<Self::CustomValueKind> {
+        let custom_value_kind = match custom_type_kind {
+            ScryptoCustomTypeKind::Reference => ScryptoCustomValueKind::Reference,
+            ScryptoCustomTypeKind::Own => ScryptoCustomValueKind::Own,
+            ScryptoCustomTypeKind::Decimal => ScryptoCustomValueKind::Decimal,
+            ScryptoCustomTypeKind::PreciseDecimal => ScryptoCustomValueKind::PreciseDecimal,
+            ScryptoCustomTypeKind::NonFungibleLocalId => ScryptoCustomValueKind::NonFungibleLocalId,
+        };
+        Some(custom_value_kind)
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "serde")] // Ensures that VS Code runs this module with the features serde tag!
 mod tests {
     use super::*;
     use crate::address::test_addresses::*;
-    use crate::address::AddressBech32Encoder;
+    use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
     use crate::data::scrypto::model::*;
     use crate::data::scrypto::{scrypto_encode, ScryptoValue};
     use crate::math::*;
@@ -236,6 +334,99 @@ mod tests {
         assert_programmatic_json_matches(&value, context, expected_programmatic);
     }
 
+    #[derive(ScryptoSbor, Debug, PartialEq, Eq)]
+    pub struct DeserializationSample {
+        pub resource: ResourceAddress,
+        pub vault: Own,
+        pub amount: Decimal,
+        pub ids: Vec<NonFungibleLocalId>,
+        pub precise_amounts: IndexMap<String, PreciseDecimal>,
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_custom_values_deserialize_from_json() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let decoder = AddressBech32Decoder::for_simulator();
+        let value = DeserializationSample {
+            resource: FUNGIBLE_RESOURCE,
+            vault: Own(NodeId([0x58; NodeId::LENGTH])),
+            amount: Decimal::ONE.checked_div(100).unwrap(),
+            ids: vec![
+                NonFungibleLocalId::string("hello").unwrap(),
+                NonFungibleLocalId::integer(123),
+                NonFungibleLocalId::bytes(vec![0x23, 0x45]).unwrap(),
+                NonFungibleLocalId::ruid([0x11; 32]),
+            ],
+            precise_amounts: indexmap!(
+                "zero".to_string() => PreciseDecimal::ZERO,
+                "one".to_string() => PreciseDecimal::ONE,
+            ),
+        };
+        let payload = scrypto_encode(&value).unwrap();
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<DeserializationSample, ScryptoCustomSchema>();
+
+        for mode in [SerializationMode::Programmatic, SerializationMode::Natural] {
+            let json = to_value(
+                ScryptoRawPayload::new_from_valid_slice(&payload).serializable(
+                    SerializationParameters::WithSchema {
+                        mode,
+                        custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(
+                            &encoder,
+                        )),
+                        schema: schema.v1(),
+                        type_id,
+                        depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+                    },
+                ),
+            )
+            .unwrap();
+
+            let deserialized = deserialize_payload(
+                json,
+                &DeserializationParameters::<ScryptoCustomExtension>::WithSchema {
+                    mode,
+                    custom_context: (&decoder).into(),
+                    schema: schema.v1(),
+                    type_id,
+                    depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+                },
+            )
+            .unwrap();
+
+            assert_eq!(deserialized, payload);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_node_ids_deserialize_from_all_their_string_forms() {
+        let decoder = AddressBech32Decoder::for_simulator();
+        for content in [
+            FUNGIBLE_RESOURCE_SIM_ADDRESS,
+            FUNGIBLE_RESOURCE_NO_NETWORK_STRING,
+            FUNGIBLE_RESOURCE_HEX_STRING,
+        ] {
+            assert_eq!(
+                ScryptoCustomExtension::parse_custom_value(
+                    &(&decoder).into(),
+                    ScryptoCustomValueKind::Reference,
+                    content
+                ),
+                Ok(ScryptoCustomValue::Reference(Reference(
+                    FUNGIBLE_RESOURCE_NODE_ID
+                )))
+            );
+        }
+        assert!(ScryptoCustomExtension::parse_custom_value(
+            &ScryptoValueDeserializationContext::no_context(),
+            ScryptoCustomValueKind::Reference,
+            FUNGIBLE_RESOURCE_SIM_ADDRESS
+        )
+        .is_err());
+    }
+
     fn assert_natural_json_matches<
         'a,
         T: ScryptoEncode,
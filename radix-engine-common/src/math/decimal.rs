@@ -15,6 +15,7 @@ use crate::data::scrypto::*;
 use crate::math::bnum_integer::*;
 use crate::math::rounding_mode::*;
 use crate::math::traits::*;
+use crate::math::transcendental;
 use crate::math::PreciseDecimal;
 use crate::well_known_scrypto_custom_type;
 use crate::*;
@@ -264,6 +265,54 @@ impl Decimal {
             Some(Decimal(nth_root))
         }
     }
+
+    /// Natural logarithm of a Decimal.
+    ///
+    /// Returns `None` if the number is not positive. The result is within 1 ulp
+    /// (`10^-18`) of the exact value, and is deterministic across platforms.
+    pub fn checked_ln(&self) -> Option<Self> {
+        if !self.is_positive() {
+            return None;
+        }
+        let x = transcendental::to_working_scale(BigInt::from(self.0), Self::SCALE);
+        let ln = transcendental::from_working_scale(transcendental::ln(&x), Self::SCALE);
+        I192::try_from(ln).ok().map(Self)
+    }
+
+    /// Base 2 logarithm of a Decimal.
+    ///
+    /// Returns `None` if the number is not positive. The result is within 1 ulp
+    /// (`10^-18`) of the exact value, and is deterministic across platforms.
+    pub fn checked_log2(&self) -> Option<Self> {
+        if !self.is_positive() {
+            return None;
+        }
+        let x = transcendental::to_working_scale(BigInt::from(self.0), Self::SCALE);
+        let log2 = transcendental::from_working_scale(transcendental::log2(&x), Self::SCALE);
+        I192::try_from(log2).ok().map(Self)
+    }
+
+    /// Exponential function (`e^x`) of a Decimal.
+    ///
+    /// Returns `None` if the result overflows. The result is within 1 ulp (`10^-18`)
+    /// of the exact value, and is deterministic across platforms.
+    pub fn checked_exp(&self) -> Option<Self> {
+        let x = transcendental::to_working_scale(BigInt::from(self.0), Self::SCALE);
+        let exp = transcendental::exp_to_scale(&x, Self::SCALE)?;
+        I192::try_from(exp).ok().map(Self)
+    }
+
+    /// Raises a Decimal to the power of a Decimal exponent.
+    ///
+    /// Returns `None` if the result is undefined (zero to a negative power, or a negative number
+    /// to a non-integer power) or overflows. The result is computed as `e^(exp * ln(self))`
+    /// without intermediate rounding, and is within 1 ulp (`10^-18`) of the exact value.
+    /// For integer exponents, see also [`Self::checked_powi`].
+    pub fn checked_pow(&self, exp: Self) -> Option<Self> {
+        let pow =
+            transcendental::pow_to_scale(&BigInt::from(self.0), &BigInt::from(exp.0), Self::SCALE)?;
+        I192::try_from(pow).ok().map(Self)
+    }
 }
 
 macro_rules! from_primitive_type {
@@ -1927,6 +1976,124 @@ mod tests {
         assert_eq!(root_0, None);
     }
 
+    #[test]
+    fn test_ln() {
+        assert_eq!(
+            test_dec!(2).checked_ln().unwrap(),
+            test_dec!("0.693147180559945309")
+        );
+        assert_eq!(
+            test_dec!(10).checked_ln().unwrap(),
+            test_dec!("2.302585092994045684")
+        );
+        assert_eq!(
+            test_dec!("0.5").checked_ln().unwrap(),
+            test_dec!("-0.693147180559945309")
+        );
+        assert_eq!(
+            test_dec!("0.000000000000000001").checked_ln().unwrap(),
+            test_dec!("-41.446531673892822312")
+        );
+        assert_eq!(
+            Decimal::MAX.checked_ln().unwrap(),
+            test_dec!("90.944579813056731786")
+        );
+        assert_eq!(test_dec!(1).checked_ln().unwrap(), Decimal::ZERO);
+        assert_eq!(test_dec!(0).checked_ln(), None);
+        assert_eq!(test_dec!("-1").checked_ln(), None);
+    }
+
+    #[test]
+    fn test_log2() {
+        assert_eq!(test_dec!(1024).checked_log2().unwrap(), test_dec!(10));
+        assert_eq!(test_dec!("0.25").checked_log2().unwrap(), test_dec!("-2"));
+        assert_eq!(
+            test_dec!(10).checked_log2().unwrap(),
+            test_dec!("3.321928094887362348")
+        );
+        assert_eq!(
+            test_dec!(3).checked_log2().unwrap(),
+            test_dec!("1.584962500721156181")
+        );
+        assert_eq!(test_dec!(0).checked_log2(), None);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(test_dec!(0).checked_exp().unwrap(), Decimal::ONE);
+        assert_eq!(
+            test_dec!(1).checked_exp().unwrap(),
+            test_dec!("2.718281828459045235")
+        );
+        assert_eq!(
+            test_dec!("-1").checked_exp().unwrap(),
+            test_dec!("0.367879441171442322")
+        );
+        assert_eq!(
+            test_dec!(10).checked_exp().unwrap(),
+            test_dec!("22026.465794806716516958")
+        );
+        assert_eq!(
+            test_dec!(90).checked_exp().unwrap(),
+            test_dec!("1220403294317840802002710035136369753970.746421099767546244")
+        );
+        assert_eq!(
+            test_dec!("-40").checked_exp().unwrap(),
+            test_dec!("0.000000000000000004")
+        );
+        assert_eq!(
+            test_dec!("0.000000000000000001").checked_exp().unwrap(),
+            test_dec!("1.000000000000000001")
+        );
+        assert_eq!(test_dec!("-1000").checked_exp().unwrap(), Decimal::ZERO);
+        assert_eq!(Decimal::MIN.checked_exp().unwrap(), Decimal::ZERO);
+        assert_eq!(test_dec!(91).checked_exp(), None);
+        assert_eq!(Decimal::MAX.checked_exp(), None);
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(
+            test_dec!(2).checked_pow(test_dec!("0.5")).unwrap(),
+            test_dec!("1.414213562373095049")
+        );
+        assert_eq!(
+            test_dec!("1.05").checked_pow(test_dec!("2.5")).unwrap(),
+            test_dec!("1.129726321947045722")
+        );
+        assert_eq!(
+            test_dec!(10).checked_pow(test_dec!("-2.5")).unwrap(),
+            test_dec!("0.003162277660168379")
+        );
+        assert_eq!(
+            test_dec!(4).checked_pow(test_dec!("0.5")).unwrap(),
+            test_dec!(2)
+        );
+        assert_eq!(
+            test_dec!(1000000).checked_pow(test_dec!(3)).unwrap(),
+            test_dec!("1000000000000000000")
+        );
+        assert_eq!(
+            test_dec!("-2").checked_pow(test_dec!(3)).unwrap(),
+            test_dec!("-8")
+        );
+        assert_eq!(
+            test_dec!("-2").checked_pow(test_dec!("-2")).unwrap(),
+            test_dec!("0.25")
+        );
+        assert_eq!(test_dec!("-2").checked_pow(test_dec!("0.5")), None);
+        assert_eq!(
+            test_dec!(0).checked_pow(test_dec!(2)).unwrap(),
+            Decimal::ZERO
+        );
+        assert_eq!(test_dec!(0).checked_pow(test_dec!("-1")), None);
+        assert_eq!(
+            test_dec!(0).checked_pow(test_dec!(0)).unwrap(),
+            Decimal::ONE
+        );
+        assert_eq!(test_dec!(10).checked_pow(test_dec!(40)), None);
+    }
+
     #[test]
     fn no_panic_with_18_decimal_places() {
         // Arrange
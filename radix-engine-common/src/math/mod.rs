@@ -3,6 +3,7 @@ pub mod decimal;
 pub mod precise_decimal;
 pub mod rounding_mode;
 pub mod traits;
+mod transcendental;
 
 pub use bnum_integer::*;
 pub use decimal::*;
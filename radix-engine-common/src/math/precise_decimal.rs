@@ -19,6 +19,7 @@ use crate::math::bnum_integer::*;
 use crate::math::decimal::*;
 use crate::math::rounding_mode::*;
 use crate::math::traits::*;
+use crate::math::transcendental;
 use crate::well_known_scrypto_custom_type;
 use crate::*;
 
@@ -293,6 +294,54 @@ impl PreciseDecimal {
             Some(Self(nth_root))
         }
     }
+
+    /// Natural logarithm of a PreciseDecimal.
+    ///
+    /// Returns `None` if the number is not positive. The result is within 1 ulp
+    /// (`10^-36`) of the exact value, and is deterministic across platforms.
+    pub fn checked_ln(&self) -> Option<Self> {
+        if !self.is_positive() {
+            return None;
+        }
+        let x = transcendental::to_working_scale(BigInt::from(self.0), Self::SCALE);
+        let ln = transcendental::from_working_scale(transcendental::ln(&x), Self::SCALE);
+        I256::try_from(ln).ok().map(Self)
+    }
+
+    /// Base 2 logarithm of a PreciseDecimal.
+    ///
+    /// Returns `None` if the number is not positive. The result is within 1 ulp
+    /// (`10^-36`) of the exact value, and is deterministic across platforms.
+    pub fn checked_log2(&self) -> Option<Self> {
+        if !self.is_positive() {
+            return None;
+        }
+        let x = transcendental::to_working_scale(BigInt::from(self.0), Self::SCALE);
+        let log2 = transcendental::from_working_scale(transcendental::log2(&x), Self::SCALE);
+        I256::try_from(log2).ok().map(Self)
+    }
+
+    /// Exponential function (`e^x`) of a PreciseDecimal.
+    ///
+    /// Returns `None` if the result overflows. The result is within 1 ulp (`10^-36`)
+    /// of the exact value, and is deterministic across platforms.
+    pub fn checked_exp(&self) -> Option<Self> {
+        let x = transcendental::to_working_scale(BigInt::from(self.0), Self::SCALE);
+        let exp = transcendental::exp_to_scale(&x, Self::SCALE)?;
+        I256::try_from(exp).ok().map(Self)
+    }
+
+    /// Raises a PreciseDecimal to the power of a PreciseDecimal exponent.
+    ///
+    /// Returns `None` if the result is undefined (zero to a negative power, or a negative number
+    /// to a non-integer power) or overflows. The result is computed as `e^(exp * ln(self))`
+    /// without intermediate rounding, and is within 1 ulp (`10^-36`) of the exact value.
+    /// For integer exponents, see also [`Self::checked_powi`].
+    pub fn checked_pow(&self, exp: Self) -> Option<Self> {
+        let pow =
+            transcendental::pow_to_scale(&BigInt::from(self.0), &BigInt::from(exp.0), Self::SCALE)?;
+        I256::try_from(pow).ok().map(Self)
+    }
 }
 
 macro_rules! from_primitive_type {
@@ -2134,6 +2183,132 @@ mod tests {
         assert_eq!(root_0, None);
     }
 
+    #[test]
+    fn test_ln() {
+        assert_eq!(
+            test_pdec!(2).checked_ln().unwrap(),
+            test_pdec!("0.693147180559945309417232121458176568")
+        );
+        assert_eq!(
+            test_pdec!(10).checked_ln().unwrap(),
+            test_pdec!("2.302585092994045684017991454684364208")
+        );
+        assert_eq!(
+            test_pdec!("0.5").checked_ln().unwrap(),
+            test_pdec!("-0.693147180559945309417232121458176568")
+        );
+        assert_eq!(
+            test_pdec!("0.000000000000000001").checked_ln().unwrap(),
+            test_pdec!("-41.446531673892822312323846184318555737")
+        );
+        assert_eq!(
+            PreciseDecimal::MAX.checked_ln().unwrap(),
+            test_pdec!("93.859467695000409276746498603197913386")
+        );
+        assert_eq!(test_pdec!(1).checked_ln().unwrap(), PreciseDecimal::ZERO);
+        assert_eq!(test_pdec!(0).checked_ln(), None);
+        assert_eq!(test_pdec!("-1").checked_ln(), None);
+    }
+
+    #[test]
+    fn test_log2() {
+        assert_eq!(test_pdec!(1024).checked_log2().unwrap(), test_pdec!(10));
+        assert_eq!(test_pdec!("0.25").checked_log2().unwrap(), test_pdec!("-2"));
+        assert_eq!(
+            test_pdec!(10).checked_log2().unwrap(),
+            test_pdec!("3.321928094887362347870319429489390176")
+        );
+        assert_eq!(
+            test_pdec!(3).checked_log2().unwrap(),
+            test_pdec!("1.584962500721156181453738943947816509")
+        );
+        assert_eq!(test_pdec!(0).checked_log2(), None);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(test_pdec!(0).checked_exp().unwrap(), PreciseDecimal::ONE);
+        assert_eq!(
+            test_pdec!(1).checked_exp().unwrap(),
+            test_pdec!("2.718281828459045235360287471352662498")
+        );
+        assert_eq!(
+            test_pdec!("-1").checked_exp().unwrap(),
+            test_pdec!("0.367879441171442321595523770161460867")
+        );
+        assert_eq!(
+            test_pdec!(10).checked_exp().unwrap(),
+            test_pdec!("22026.465794806716516957900645284244366354")
+        );
+        assert_eq!(
+            test_pdec!(90).checked_exp().unwrap(),
+            test_pdec!(
+                "1220403294317840802002710035136369753970.746421099767546244343829824312727359"
+            )
+        );
+        assert_eq!(
+            test_pdec!("-40").checked_exp().unwrap(),
+            test_pdec!("0.000000000000000004248354255291588995")
+        );
+        assert_eq!(
+            test_pdec!("0.000000000000000001").checked_exp().unwrap(),
+            test_pdec!("1.000000000000000001000000000000000001")
+        );
+        assert_eq!(
+            test_pdec!("-1000").checked_exp().unwrap(),
+            PreciseDecimal::ZERO
+        );
+        assert_eq!(
+            PreciseDecimal::MIN.checked_exp().unwrap(),
+            PreciseDecimal::ZERO
+        );
+        assert_eq!(test_pdec!(94).checked_exp(), None);
+        assert_eq!(PreciseDecimal::MAX.checked_exp(), None);
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(
+            test_pdec!(2).checked_pow(test_pdec!("0.5")).unwrap(),
+            test_pdec!("1.414213562373095048801688724209698079")
+        );
+        assert_eq!(
+            test_pdec!("1.05").checked_pow(test_pdec!("2.5")).unwrap(),
+            test_pdec!("1.129726321947045721750119514527445982")
+        );
+        assert_eq!(
+            test_pdec!(10).checked_pow(test_pdec!("-2.5")).unwrap(),
+            test_pdec!("0.003162277660168379331998893544432719")
+        );
+        assert_eq!(
+            test_pdec!(4).checked_pow(test_pdec!("0.5")).unwrap(),
+            test_pdec!(2)
+        );
+        assert_eq!(
+            test_pdec!(1000000).checked_pow(test_pdec!(3)).unwrap(),
+            test_pdec!("1000000000000000000")
+        );
+        assert_eq!(
+            test_pdec!("-2").checked_pow(test_pdec!(3)).unwrap(),
+            test_pdec!("-8")
+        );
+        assert_eq!(
+            test_pdec!("-2").checked_pow(test_pdec!("-2")).unwrap(),
+            test_pdec!("0.25")
+        );
+        assert_eq!(test_pdec!("-2").checked_pow(test_pdec!("0.5")), None);
+        assert_eq!(
+            test_pdec!(0).checked_pow(test_pdec!(2)).unwrap(),
+            PreciseDecimal::ZERO
+        );
+        assert_eq!(test_pdec!(0).checked_pow(test_pdec!("-1")), None);
+        assert_eq!(
+            test_pdec!(0).checked_pow(test_pdec!(0)).unwrap(),
+            PreciseDecimal::ONE
+        );
+        assert_eq!(test_pdec!(10).checked_pow(test_pdec!(41)), None);
+    }
+
     #[test]
     fn no_panic_with_36_decimal_places() {
         // Arrange
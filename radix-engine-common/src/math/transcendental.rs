@@ -0,0 +1,306 @@
+//! Deterministic fixed-point implementations of the natural logarithm and the exponential
+//! function, shared by [`Decimal`](super::Decimal) and [`PreciseDecimal`](super::PreciseDecimal).
+//!
+//! All intermediate results are integers representing `m / 10^WORKING_SCALE`, and only integer
+//! arithmetic is used, so the results are identical on all platforms (including WASM).
+//!
+//! The working scale leaves more than 40 guard digits beyond the scale of `PreciseDecimal`, which
+//! covers the precision lost when a result is multiplied by up to `2^136` during range reduction.
+//! Thus the results of the public functions built on top of these are within 1 ulp of the exact
+//! value, and are in practice correctly rounded (half away from zero).
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+/// The number of decimal digits of the fraction of intermediate results.
+const WORKING_SCALE: u32 = 128;
+
+/// The digits of `ln(2)` at the working scale (truncated).
+const LN_2_DIGITS: &[u8] = b"69314718055994530941723212145817656807550013436025525412068000949339362196969471560586332699641868754200148102057068573368552023";
+
+/// Any argument of `exp` above this value overflows both decimal types, as `e^100 > 10^43`.
+const EXP_ARGUMENT_MAX: i64 = 100;
+
+/// `ln(10)` rounded up to 2 decimal places, used to detect arguments of `exp` whose result
+/// rounds to zero.
+const LN_10_CEIL_CENTS: i64 = 231;
+
+fn one() -> BigInt {
+    BigInt::from(10).pow(WORKING_SCALE)
+}
+
+fn ln_2() -> BigInt {
+    BigInt::parse_bytes(LN_2_DIGITS, 10).unwrap()
+}
+
+/// Converts a fixed-point number of the given scale to the working scale.
+pub(crate) fn to_working_scale(value: BigInt, scale: u32) -> BigInt {
+    value * BigInt::from(10).pow(WORKING_SCALE - scale)
+}
+
+/// Converts a number of the working scale to the given scale, rounding half away from zero.
+pub(crate) fn from_working_scale(value: BigInt, scale: u32) -> BigInt {
+    let divisor = BigInt::from(10).pow(WORKING_SCALE - scale);
+    let half = &divisor / 2;
+    if value.is_negative() {
+        -((-value + half) / divisor)
+    } else {
+        (value + half) / divisor
+    }
+}
+
+/// Returns `value * 2^exponent`, truncating towards zero.
+fn mul_pow2(value: &BigInt, exponent: i64) -> BigInt {
+    if exponent >= 0 {
+        value << exponent as usize
+    } else {
+        value / (BigInt::one() << exponent.unsigned_abs() as usize)
+    }
+}
+
+/// Natural logarithm of a positive number of the working scale.
+pub(crate) fn ln(x: &BigInt) -> BigInt {
+    debug_assert!(x.is_positive());
+    let one = one();
+
+    // Reduce `x = m * 2^k`, with `m` in `[1/sqrt(2), sqrt(2))`, so that the series below
+    // converges quickly.
+    let mut k = x.bits() as i64 - one.bits() as i64;
+    let mut m = mul_pow2(x, -k);
+    let one_squared = &one * &one;
+    while &m * &m * 2 < one_squared {
+        k -= 1;
+        m = mul_pow2(x, -k);
+    }
+    while &m * &m >= &one_squared * 2 {
+        k += 1;
+        m = mul_pow2(x, -k);
+    }
+
+    // ln(m) = 2 * atanh(z) = 2 * (z + z^3/3 + z^5/5 + ...), with `z = (m - 1) / (m + 1)`
+    let z = (&m - &one) * &one / (&m + &one);
+    let z_squared = &z * &z / &one;
+    let mut sum = BigInt::zero();
+    let mut term = z;
+    let mut n = 1u32;
+    while !term.is_zero() {
+        sum += &term / n;
+        term = term * &z_squared / &one;
+        n += 2;
+    }
+
+    sum * 2 + ln_2() * k
+}
+
+/// Exponential function of a number of the working scale, rounded to the given scale.
+///
+/// Returns `None` if the argument is so large that the result would not fit any decimal type.
+pub(crate) fn exp_to_scale(x: &BigInt, scale: u32) -> Option<BigInt> {
+    let one = one();
+    if x > &(&one * EXP_ARGUMENT_MAX) {
+        return None;
+    }
+    // e^x < 10^-(scale + 1) rounds to zero.
+    if x < &(&one * -(LN_10_CEIL_CENTS * (scale as i64 + 1)) / 100) {
+        return Some(BigInt::zero());
+    }
+
+    // Reduce `x = k * ln(2) + r`, with `|r| <= ln(2) / 2`.
+    let ln_2 = ln_2();
+    let k = (x + &ln_2 / 2).div_floor(&ln_2);
+    let r = x - &k * &ln_2;
+
+    // e^r = 1 + r + r^2/2! + r^3/3! + ...
+    let mut sum = BigInt::zero();
+    let mut term = one.clone();
+    let mut n = 1u32;
+    while !term.is_zero() {
+        sum += &term;
+        term = term * &r / (&one * n);
+        n += 1;
+    }
+
+    Some(from_working_scale(mul_pow2(&sum, k.to_i64()?), scale))
+}
+
+/// Base 2 logarithm of a positive number of the working scale.
+pub(crate) fn log2(x: &BigInt) -> BigInt {
+    ln(x) * one() / ln_2()
+}
+
+/// Raises `x` to the power of `y`, both being fixed-point numbers of the given scale, and rounds
+/// the result to that scale.
+///
+/// Returns `None` if the result is undefined (zero to a negative power, or a negative number to
+/// a non-integer power), or would not fit any decimal type.
+pub(crate) fn pow_to_scale(x: &BigInt, y: &BigInt, scale: u32) -> Option<BigInt> {
+    let unit = BigInt::from(10).pow(scale);
+    if y.is_zero() {
+        return Some(unit);
+    }
+    if x.is_zero() {
+        return if y.is_positive() {
+            Some(BigInt::zero())
+        } else {
+            None
+        };
+    }
+
+    // A negative base is only allowed with an integer exponent, the sign of the result then
+    // depending on the parity of the exponent.
+    let negate = if x.is_negative() {
+        let (integer, fraction) = y.div_rem(&unit);
+        if !fraction.is_zero() {
+            return None;
+        }
+        integer.is_odd()
+    } else {
+        false
+    };
+
+    // x^y = e^(y * ln(x))
+    let ln_x = ln(&to_working_scale(x.abs(), scale));
+    let result = exp_to_scale(&(ln_x * y / unit), scale)?;
+    Some(if negate { -result } else { result })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ln_2_constant_matches_series() {
+        // ln(2) = 2 * atanh(1/3)
+        let z = one() / 3;
+        let z_squared = &z * &z / one();
+        let mut sum = BigInt::zero();
+        let mut term = z;
+        let mut n = 1u32;
+        while !term.is_zero() {
+            sum += &term / n;
+            term = term * &z_squared / one();
+            n += 2;
+        }
+        assert!((sum * 2 - ln_2()).abs() < BigInt::from(1000));
+        assert_eq!(ln(&(one() * 2)), ln_2());
+    }
+
+    #[test]
+    fn test_exp_of_ln_is_identity() {
+        for value in [1u64, 2, 3, 7, 10, 1000, 123456789] {
+            let x = one() * value;
+            let result = exp_to_scale(&ln(&x), 36).unwrap();
+            assert_eq!(result, BigInt::from(value) * BigInt::from(10).pow(36));
+        }
+    }
+
+    #[test]
+    fn test_exp_bounds() {
+        assert_eq!(exp_to_scale(&(one() * 101), 36), None);
+        assert_eq!(exp_to_scale(&(one() * -100), 36), Some(BigInt::zero()));
+        assert_eq!(
+            exp_to_scale(&BigInt::zero(), 18),
+            Some(BigInt::from(10).pow(18))
+        );
+    }
+
+    /// Compares the results against a reference big-float (MPFR) implementation for random inputs.
+    #[cfg(feature = "full_math_tests")]
+    mod reference {
+        use crate::math::*;
+        use rug::ops::Pow;
+        use rug::Float;
+
+        const PRECISION: u32 = 512;
+        const ITERATIONS: usize = 2000;
+
+        struct XorShift(u64);
+
+        impl XorShift {
+            fn next(&mut self) -> u64 {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                self.0
+            }
+
+            fn decimal(&mut self) -> Decimal {
+                let subunits = I192::from_digits([self.next(), self.next(), self.next() >> 1]);
+                Decimal(subunits >> (self.next() % 191) as u32)
+            }
+
+            fn precise_decimal(&mut self) -> PreciseDecimal {
+                let subunits =
+                    I256::from_digits([self.next(), self.next(), self.next(), self.next() >> 1]);
+                PreciseDecimal(subunits >> (self.next() % 255) as u32)
+            }
+
+            fn exponent(&mut self, max: i64) -> Decimal {
+                let integer = (self.next() % (2 * max as u64)) as i64 - max;
+                Decimal::from(integer) + Decimal(I192::from(self.next() % 10u64.pow(18)))
+            }
+        }
+
+        fn float(value: impl ToString) -> Float {
+            Float::with_val(PRECISION, Float::parse(value.to_string()).unwrap())
+        }
+
+        fn assert_within_ulp(result: impl ToString, reference: Float, ulp: &str, input: &str) {
+            let difference = Float::with_val(PRECISION, float(result) - &reference).abs();
+            assert!(
+                difference <= float(ulp),
+                "{}: expected {}, difference {}",
+                input,
+                reference,
+                difference
+            );
+        }
+
+        macro_rules! test_against_reference {
+            ($name:ident, $type:ident, $random:ident, $ulp:literal) => {
+                #[test]
+                fn $name() {
+                    let mut rng = XorShift(0x2545F4914F6CDD1D);
+                    for _ in 0..ITERATIONS {
+                        let x = rng.$random().checked_abs().unwrap();
+                        if x.is_positive() {
+                            let input = format!("ln/log2({})", x);
+                            assert_within_ulp(x.checked_ln().unwrap(), float(x).ln(), $ulp, &input);
+                            assert_within_ulp(
+                                x.checked_log2().unwrap(),
+                                float(x).log2(),
+                                $ulp,
+                                &input,
+                            );
+                        }
+
+                        let y = $type::from(rng.exponent(88));
+                        let input = format!("exp({})", y);
+                        assert_within_ulp(y.checked_exp().unwrap(), float(y).exp(), $ulp, &input);
+
+                        let y = $type::from(rng.exponent(4));
+                        let input = format!("pow({}, {})", x, y);
+                        let reference = float(x).pow(float(y));
+                        match x.checked_pow(y) {
+                            Some(result) => assert_within_ulp(result, reference, $ulp, &input),
+                            None => assert!(
+                                x.is_zero() || reference > float($type::MAX),
+                                "{}: unexpected overflow",
+                                input
+                            ),
+                        }
+                    }
+                }
+            };
+        }
+
+        test_against_reference!(test_decimal_against_reference, Decimal, decimal, "1e-18");
+        test_against_reference!(
+            test_precise_decimal_against_reference,
+            PreciseDecimal,
+            precise_decimal,
+            "1e-36"
+        );
+    }
+}
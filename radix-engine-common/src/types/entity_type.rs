@@ -56,6 +56,12 @@ pub enum EntityType {
     /// A global native pool entity (197 in decimal). Gives Bech32 prefix: `c` followed by one of `c`, `e`, `6` or `m`.
     GlobalMultiResourcePool = 0b11000110, //--------- 11000 => c, 110xx => ce6m (101 = pool)
 
+    //=========================================================================
+    // Account Lockers (start with char e for escrow)
+    //=========================================================================
+    /// A global native account locker entity (200 in decimal). Gives Bech32 prefix: `e` followed by one of `q`, `p`, `z` or `r`.
+    GlobalAccountLocker = 0b11001000, //------------- 11001 => e, 000xx => qpzr
+
     //=========================================================================
     // Secp256k1 Virtual Global Components (start with char 6 for Secp256k1)
     //=========================================================================
@@ -126,6 +132,7 @@ impl EntityType {
             | EntityType::GlobalOneResourcePool
             | EntityType::GlobalTwoResourcePool
             | EntityType::GlobalMultiResourcePool
+            | EntityType::GlobalAccountLocker
             | EntityType::GlobalTransactionTracker => true,
             EntityType::InternalFungibleVault
             | EntityType::InternalNonFungibleVault
@@ -153,6 +160,7 @@ impl EntityType {
             | EntityType::GlobalOneResourcePool
             | EntityType::GlobalTwoResourcePool
             | EntityType::GlobalMultiResourcePool
+            | EntityType::GlobalAccountLocker
             | EntityType::GlobalTransactionTracker => true,
             EntityType::GlobalPackage
             | EntityType::GlobalFungibleResourceManager
@@ -192,6 +200,15 @@ impl EntityType {
         matches!(self, EntityType::GlobalNonFungibleResourceManager)
     }
 
+    pub const fn is_global_account(&self) -> bool {
+        matches!(
+            self,
+            EntityType::GlobalAccount
+                | EntityType::GlobalVirtualSecp256k1Account
+                | EntityType::GlobalVirtualEd25519Account
+        )
+    }
+
     pub const fn is_global_virtual(&self) -> bool {
         match self {
             EntityType::GlobalVirtualSecp256k1Account
@@ -263,3 +263,38 @@ impl SubstateKey {
 pub type FieldKey = u8;
 pub type MapKey = Vec<u8>;
 pub type SortedKey = ([u8; 2], Vec<u8>);
+
+/// A range of [`SortedKey`]s by their sort prefix, from `start` (inclusive) up to `end` (exclusive),
+/// or up to the end of the partition if `end` is `None`.
+///
+/// Note that only the sort prefix is ordered: entries sharing a sort prefix are returned in an
+/// unspecified (but deterministic) order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sbor)]
+pub struct SortedKeyRange {
+    pub start: [u8; 2],
+    pub end: Option<[u8; 2]>,
+}
+
+impl SortedKeyRange {
+    pub fn all() -> Self {
+        Self {
+            start: [0u8; 2],
+            end: None,
+        }
+    }
+
+    pub fn new(start: u16, end: Option<u16>) -> Self {
+        Self {
+            start: start.to_be_bytes(),
+            end: end.map(|end| end.to_be_bytes()),
+        }
+    }
+
+    pub fn is_all(&self) -> bool {
+        self.start == [0u8; 2] && self.end.is_none()
+    }
+
+    pub fn contains(&self, sort_prefix: &[u8; 2]) -> bool {
+        self.start <= *sort_prefix && self.end.map_or(true, |end| *sort_prefix < end)
+    }
+}
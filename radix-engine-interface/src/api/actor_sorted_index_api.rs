@@ -2,7 +2,7 @@ use crate::api::ActorStateHandle;
 use radix_engine_common::data::scrypto::{
     scrypto_decode, scrypto_encode, ScryptoDecode, ScryptoEncode,
 };
-use radix_engine_common::types::SortedKey;
+use radix_engine_common::types::{SortedKey, SortedKeyRange};
 use radix_engine_interface::api::CollectionIndex;
 use sbor::rust::prelude::*;
 use sbor::rust::vec::Vec;
@@ -63,6 +63,22 @@ pub trait ClientActorSortedIndexApi<E> {
         object_handle: ActorStateHandle,
         collection_index: CollectionIndex,
         count: u32,
+    ) -> Result<Vec<(SortedKey, Vec<u8>)>, E> {
+        self.actor_sorted_index_scan_range(
+            object_handle,
+            collection_index,
+            SortedKeyRange::all(),
+            count,
+        )
+    }
+
+    /// Scans the first elements of count within a range of sort prefixes from a sorted index
+    fn actor_sorted_index_scan_range(
+        &mut self,
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        range: SortedKeyRange,
+        count: u32,
     ) -> Result<Vec<(SortedKey, Vec<u8>)>, E>;
 
     /// Scans the first elements of count from a sorted index
@@ -84,4 +100,25 @@ pub trait ClientActorSortedIndexApi<E> {
 
         Ok(entries)
     }
+
+    /// Scans the first elements of count within a range of sort prefixes from a sorted index
+    fn actor_sorted_index_scan_range_typed<K: ScryptoDecode, V: ScryptoDecode>(
+        &mut self,
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        range: SortedKeyRange,
+        count: u32,
+    ) -> Result<Vec<(K, V)>, E> {
+        let entries = self
+            .actor_sorted_index_scan_range(object_handle, collection_index, range, count)?
+            .into_iter()
+            .map(|(key, buf)| {
+                let typed_key: K = scrypto_decode(&key.1).unwrap();
+                let typed_value: V = scrypto_decode(&buf).unwrap();
+                (typed_key, typed_value)
+            })
+            .collect();
+
+        Ok(entries)
+    }
 }
@@ -0,0 +1,38 @@
+use crate::sbor::rust::prelude::*;
+use radix_engine_common::crypto::{
+    Bls12381G1PublicKey, Bls12381G2Signature, Ed25519PublicKey, Ed25519Signature, Hash,
+    Secp256k1PublicKey, Secp256k1Signature,
+};
+
+pub trait ClientCryptoUtilsApi<E> {
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Hash, E>;
+
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Hash, E>;
+
+    fn ed25519_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Ed25519PublicKey,
+        signature: Ed25519Signature,
+    ) -> Result<bool, E>;
+
+    fn secp256k1_verify(
+        &mut self,
+        hash: Hash,
+        public_key: Secp256k1PublicKey,
+        signature: Secp256k1Signature,
+    ) -> Result<bool, E>;
+
+    fn bls12381_v1_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Bls12381G1PublicKey,
+        signature: Bls12381G2Signature,
+    ) -> Result<bool, E>;
+
+    fn bls12381_v1_aggregate_verify(
+        &mut self,
+        pub_keys_and_msgs: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Bls12381G2Signature,
+    ) -> Result<bool, E>;
+}
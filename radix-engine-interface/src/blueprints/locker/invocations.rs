@@ -0,0 +1,199 @@
+use crate::blueprints::resource::*;
+use crate::*;
+#[cfg(feature = "radix_engine_fuzzing")]
+use arbitrary::Arbitrary;
+use radix_engine_common::prelude::*;
+use radix_engine_interface::math::Decimal;
+use sbor::rust::collections::{IndexMap, IndexSet};
+use sbor::rust::fmt::Debug;
+
+pub const ACCOUNT_LOCKER_BLUEPRINT: &str = "AccountLocker";
+
+define_type_info_marker!(Some(LOCKER_PACKAGE), AccountLocker);
+
+pub const ACCOUNT_LOCKER_STORER_ROLE: &str = "storer";
+pub const ACCOUNT_LOCKER_STORER_UPDATER_ROLE: &str = "storer_updater";
+pub const ACCOUNT_LOCKER_RECOVERER_ROLE: &str = "recoverer";
+pub const ACCOUNT_LOCKER_RECOVERER_UPDATER_ROLE: &str = "recoverer_updater";
+
+/// Specifies the resources stored for, recovered from or claimed by a single claimant.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub enum ResourceSpecifier {
+    Fungible(Decimal),
+    NonFungible(IndexSet<NonFungibleLocalId>),
+}
+
+//=============================
+// Account Locker Instantiate
+//=============================
+
+pub const ACCOUNT_LOCKER_INSTANTIATE_IDENT: &str = "instantiate";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountLockerInstantiateInput {
+    pub owner_role: OwnerRole,
+    pub storer_role: AccessRule,
+    pub storer_updater_role: AccessRule,
+    pub recoverer_role: AccessRule,
+    pub recoverer_updater_role: AccessRule,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct AccountLockerInstantiateManifestInput {
+    pub owner_role: OwnerRole,
+    pub storer_role: AccessRule,
+    pub storer_updater_role: AccessRule,
+    pub recoverer_role: AccessRule,
+    pub recoverer_updater_role: AccessRule,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+pub type AccountLockerInstantiateOutput = Global<AccountLockerObjectTypeInfo>;
+
+//====================================
+// Account Locker Instantiate Simple
+//====================================
+
+pub const ACCOUNT_LOCKER_INSTANTIATE_SIMPLE_IDENT: &str = "instantiate_simple";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockerInstantiateSimpleInput {
+    pub allow_recover: bool,
+}
+
+pub type AccountLockerInstantiateSimpleOutput = (Global<AccountLockerObjectTypeInfo>, Bucket);
+
+//=======================
+// Account Locker Store
+//=======================
+
+pub const ACCOUNT_LOCKER_STORE_IDENT: &str = "store";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountLockerStoreInput {
+    pub claimant: ComponentAddress,
+    pub bucket: Bucket,
+    pub try_direct_send: bool,
+}
+
+#[derive(Debug, Eq, PartialEq, ManifestSbor)]
+pub struct AccountLockerStoreManifestInput {
+    pub claimant: ComponentAddress,
+    pub bucket: ManifestBucket,
+    pub try_direct_send: bool,
+}
+
+pub type AccountLockerStoreOutput = ();
+
+//=========================
+// Account Locker Airdrop
+//=========================
+
+pub const ACCOUNT_LOCKER_AIRDROP_IDENT: &str = "airdrop";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct AccountLockerAirdropInput {
+    pub claimants: IndexMap<ComponentAddress, ResourceSpecifier>,
+    pub bucket: Bucket,
+    pub try_direct_send: bool,
+}
+
+#[derive(Debug, Eq, PartialEq, ManifestSbor)]
+pub struct AccountLockerAirdropManifestInput {
+    pub claimants: IndexMap<ComponentAddress, ResourceSpecifier>,
+    pub bucket: ManifestBucket,
+    pub try_direct_send: bool,
+}
+
+pub type AccountLockerAirdropOutput = Option<Bucket>;
+
+//=========================
+// Account Locker Recover
+//=========================
+
+pub const ACCOUNT_LOCKER_RECOVER_IDENT: &str = "recover";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockerRecoverInput {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+pub type AccountLockerRecoverOutput = Bucket;
+
+//========================================
+// Account Locker Recover Non Fungibles
+//========================================
+
+pub const ACCOUNT_LOCKER_RECOVER_NON_FUNGIBLES_IDENT: &str = "recover_non_fungibles";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockerRecoverNonFungiblesInput {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub ids: IndexSet<NonFungibleLocalId>,
+}
+
+pub type AccountLockerRecoverNonFungiblesOutput = Bucket;
+
+//=======================
+// Account Locker Claim
+//=======================
+
+pub const ACCOUNT_LOCKER_CLAIM_IDENT: &str = "claim";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockerClaimInput {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+pub type AccountLockerClaimOutput = Bucket;
+
+//======================================
+// Account Locker Claim Non Fungibles
+//======================================
+
+pub const ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT: &str = "claim_non_fungibles";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockerClaimNonFungiblesInput {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub ids: IndexSet<NonFungibleLocalId>,
+}
+
+pub type AccountLockerClaimNonFungiblesOutput = Bucket;
+
+//============================
+// Account Locker Get Amount
+//============================
+
+pub const ACCOUNT_LOCKER_GET_AMOUNT_IDENT: &str = "get_amount";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockerGetAmountInput {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+}
+
+pub type AccountLockerGetAmountOutput = Decimal;
+
+//==========================================
+// Account Locker Get Non Fungible Local Ids
+//==========================================
+
+pub const ACCOUNT_LOCKER_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT: &str = "get_non_fungible_local_ids";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockerGetNonFungibleLocalIdsInput {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub limit: u32,
+}
+
+pub type AccountLockerGetNonFungibleLocalIdsOutput = IndexSet<NonFungibleLocalId>;
@@ -54,6 +54,24 @@ pub struct PackagePublishWasmAdvancedManifestInput {
 
 pub type PackagePublishWasmAdvancedOutput = PackageAddress;
 
+pub const PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT: &str = "publish_wasm_upgradeable";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct PackagePublishWasmUpgradeableInput {
+    pub definition: PackageDefinition,
+    pub code: Vec<u8>,
+    pub metadata: MetadataInit,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct PackagePublishWasmUpgradeableManifestInput {
+    pub definition: PackageDefinition,
+    pub code: ManifestBlobRef,
+    pub metadata: MetadataInit,
+}
+
+pub type PackagePublishWasmUpgradeableOutput = (PackageAddress, Bucket);
+
 pub const PACKAGE_PUBLISH_NATIVE_IDENT: &str = "publish_native";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -83,6 +101,31 @@ pub struct PackageClaimRoyaltiesInput {}
 
 pub type PackageClaimRoyaltiesOutput = Bucket;
 
+pub const PACKAGE_UPGRADE_IDENT: &str = "upgrade";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct PackageUpgradeInput {
+    pub definition: PackageDefinition,
+    pub code: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct PackageUpgradeManifestInput {
+    pub definition: PackageDefinition,
+    pub code: ManifestBlobRef,
+}
+
+pub type PackageUpgradeOutput = ();
+
+pub const PACKAGE_LOCK_UPGRADES_IDENT: &str = "lock_upgrades";
+
+#[derive(
+    Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestCategorize, ManifestEncode, ManifestDecode,
+)]
+pub struct PackageLockUpgradesInput {}
+
+pub type PackageLockUpgradesOutput = ();
+
 #[derive(Debug, Clone, Eq, PartialEq, Default, ScryptoSbor, ManifestSbor)]
 pub struct PackageDefinition {
     pub blueprints: IndexMap<String, BlueprintDefinitionInit>,
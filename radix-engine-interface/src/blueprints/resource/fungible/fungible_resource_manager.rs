@@ -107,6 +107,37 @@ pub struct FungibleResourceManagerCreateWithInitialSupplyManifestInput {
 
 pub type FungibleResourceManagerCreateWithInitialSupplyOutput = (ResourceAddress, Bucket);
 
+pub const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT: &str =
+    "create_with_supply_limits";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleResourceManagerCreateWithSupplyLimitsInput {
+    pub owner_role: OwnerRole,
+    pub divisibility: u8,
+    pub initial_supply: Decimal,
+    pub supply_limits: ResourceSupplyLimits,
+    pub lock_supply_limits: bool,
+    pub resource_roles: FungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct FungibleResourceManagerCreateWithSupplyLimitsManifestInput {
+    pub owner_role: OwnerRole,
+    pub divisibility: u8,
+    pub initial_supply: Decimal,
+    pub supply_limits: ResourceSupplyLimits,
+    pub lock_supply_limits: bool,
+    pub resource_roles: FungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+pub type FungibleResourceManagerCreateWithSupplyLimitsOutput = (ResourceAddress, Bucket);
+
 pub const FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT: &str = "mint";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
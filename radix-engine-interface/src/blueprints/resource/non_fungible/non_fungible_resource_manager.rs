@@ -196,6 +196,54 @@ pub struct NonFungibleResourceManagerCreateRuidWithInitialSupplyGenericInput<S,
 
 pub type NonFungibleResourceManagerCreateRuidWithInitialSupplyOutput = (ResourceAddress, Bucket);
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT: &str =
+    "create_with_supply_limits";
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateWithSupplyLimitsInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
+    pub supply_limits: ResourceSupplyLimits,
+    pub lock_supply_limits: bool,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+/// For manifest
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerCreateWithSupplyLimitsManifestInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub entries: IndexMap<NonFungibleLocalId, (ManifestValue,)>,
+    pub supply_limits: ResourceSupplyLimits,
+    pub lock_supply_limits: bool,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+/// For typed value, to skip any codec
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateWithSupplyLimitsGenericInput<S, T> {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: S,
+    pub entries: IndexMap<NonFungibleLocalId, (T,)>,
+    pub supply_limits: ResourceSupplyLimits,
+    pub lock_supply_limits: bool,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+pub type NonFungibleResourceManagerCreateWithSupplyLimitsOutput = (ResourceAddress, Bucket);
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT: &str = "update_non_fungible_data";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -92,3 +92,43 @@ pub struct ResourceManagerGetAmountForWithdrawalInput {
 }
 
 pub type ResourceManagerGetAmountForWithdrawalOutput = Decimal;
+
+/// Limits on the supply of a resource, enforced by the resource manager on every mint.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Default, Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ResourceSupplyLimits {
+    /// The maximum total supply of the resource. Burning resources makes room for new mints.
+    pub max_supply: Option<Decimal>,
+    /// The maximum amount which can be minted within a period of epochs.
+    pub mint_allowance: Option<MintAllowance>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct MintAllowance {
+    pub amount: Decimal,
+    pub period_in_epochs: u64,
+}
+
+pub const RESOURCE_MANAGER_GET_SUPPLY_LIMITS_IDENT: &str = "get_supply_limits";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ResourceManagerGetSupplyLimitsInput {}
+
+pub type ResourceManagerGetSupplyLimitsOutput = Option<ResourceSupplyLimits>;
+
+pub const RESOURCE_MANAGER_SET_SUPPLY_LIMITS_IDENT: &str = "set_supply_limits";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ResourceManagerSetSupplyLimitsInput {
+    pub supply_limits: ResourceSupplyLimits,
+}
+
+pub type ResourceManagerSetSupplyLimitsOutput = ();
+
+pub const RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_IDENT: &str = "lock_supply_limits";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ResourceManagerLockSupplyLimitsInput {}
+
+pub type ResourceManagerLockSupplyLimitsOutput = ();
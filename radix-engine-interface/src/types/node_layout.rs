@@ -43,6 +43,16 @@ pub const ROLE_ASSIGNMENT_ROLE_DEF_PARTITION: PartitionNumber = PartitionNumber(
 pub const ROLE_ASSIGNMENT_ROLE_DEF_PARTITION_OFFSET: PartitionOffset = PartitionOffset(1u8);
 pub const ROLE_ASSIGNMENT_MUTABILITY_PARTITION_OFFSET: PartitionOffset = PartitionOffset(2u8);
 
+/// A partition of the transaction tracker node, outside of its blueprint state, which records
+/// the protocol updates which have been applied to the ledger.
+pub const PROTOCOL_UPDATE_STATUS_PARTITION: PartitionNumber = PartitionNumber(32u8);
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
+pub enum ProtocolUpdateStatusField {
+    AppliedProtocolUpdates,
+}
+
 //=============================
 // Blueprint partition - common
 //=============================
@@ -342,6 +352,7 @@ substate_key!(TypeInfoField);
 substate_key!(RoyaltyField);
 substate_key!(ComponentField);
 substate_key!(TransactionTrackerField);
+substate_key!(ProtocolUpdateStatusField);
 
 // Transient
 substate_key!(FungibleBucketField);
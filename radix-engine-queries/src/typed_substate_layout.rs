@@ -5,6 +5,12 @@ pub use radix_engine::blueprints::access_controller::*;
 pub use radix_engine::blueprints::account::{AccountBlueprint, AccountError, AccountNativePackage};
 use radix_engine::blueprints::account::{AccountTypedSubstateKey, AccountTypedSubstateValue};
 pub use radix_engine::blueprints::consensus_manager::*;
+pub use radix_engine::blueprints::locker::{
+    AccountLockerBlueprint, AccountLockerError, LockerNativePackage,
+};
+use radix_engine::blueprints::locker::{
+    AccountLockerPartitionOffset, AccountLockerTypedSubstateKey, AccountLockerTypedSubstateValue,
+};
 pub use radix_engine::blueprints::package::*;
 pub use radix_engine::blueprints::pool::multi_resource_pool;
 use radix_engine::blueprints::pool::multi_resource_pool::{
@@ -23,8 +29,12 @@ pub use radix_engine::blueprints::transaction_tracker::*;
 pub use radix_engine::system::attached_modules::metadata::*;
 pub use radix_engine::system::attached_modules::role_assignment::*;
 pub use radix_engine::system::attached_modules::royalty::*;
+pub use radix_engine::system::protocol_updates::{
+    AppliedProtocolUpdate, AppliedProtocolUpdatesSubstate,
+};
 use radix_engine::system::system_substates::FieldSubstate;
 use radix_engine::system::system_substates::KeyValueEntrySubstate;
+use radix_engine::system::system_substates::{IndexEntrySubstate, SortedIndexEntrySubstate};
 pub use radix_engine::system::type_info::*;
 pub use radix_engine_interface::api::node_modules::royalty::*;
 use transaction::prelude::IntentHash;
@@ -83,6 +93,7 @@ pub enum TypedSubstateKey {
     RoyaltyModule(TypedRoyaltyModuleSubstateKey),
     MetadataModule(TypedMetadataModuleSubstateKey),
     MainModule(TypedMainModuleSubstateKey),
+    ProtocolUpdateStatus(ProtocolUpdateStatusField),
 }
 
 impl TypedSubstateKey {
@@ -146,10 +157,13 @@ pub enum TypedMainModuleSubstateKey {
     OneResourcePool(OneResourcePoolTypedSubstateKey),
     TwoResourcePool(TwoResourcePoolTypedSubstateKey),
     MultiResourcePool(MultiResourcePoolTypedSubstateKey),
+    AccountLocker(AccountLockerTypedSubstateKey),
     TransactionTrackerField(TransactionTrackerField),
     TransactionTrackerCollectionEntry(IntentHash),
     // Objects - Generic Scrypto Components
     GenericScryptoComponentField(ComponentField),
+    GenericScryptoComponentIndexEntry(CollectionIndex, MapKey),
+    GenericScryptoComponentSortedIndexEntry(CollectionIndex, SortedKey),
     // KVStores - Generic KV Stores
     GenericKeyValueStoreKey(MapKey),
 }
@@ -214,6 +228,12 @@ pub fn to_typed_substate_key(
                 scrypto_decode(&key).map_err(|_| error("Access Rules key"))?,
             ))
         }
+        PROTOCOL_UPDATE_STATUS_PARTITION if entity_type == EntityType::GlobalTransactionTracker => {
+            TypedSubstateKey::ProtocolUpdateStatus(
+                ProtocolUpdateStatusField::try_from(substate_key)
+                    .map_err(|_| error("ProtocolUpdateStatusField"))?,
+            )
+        }
         partition_num @ _ if partition_num >= MAIN_BASE_PARTITION => {
             TypedSubstateKey::MainModule(to_typed_object_module_substate_key(
                 entity_type,
@@ -251,9 +271,26 @@ fn to_typed_object_substate_key_internal(
 ) -> Result<TypedMainModuleSubstateKey, ()> {
     let substate_type = match entity_type {
         EntityType::InternalGenericComponent | EntityType::GlobalGenericComponent => {
-            TypedMainModuleSubstateKey::GenericScryptoComponentField(ComponentField::try_from(
-                substate_key,
-            )?)
+            // Scrypto blueprints have a single field partition, followed by one partition for
+            // each of their collections, in order of collection index.
+            match (partition_offset.0.checked_sub(1), substate_key) {
+                (None, substate_key) => TypedMainModuleSubstateKey::GenericScryptoComponentField(
+                    ComponentField::try_from(substate_key)?,
+                ),
+                (Some(collection_index), SubstateKey::Map(key)) => {
+                    TypedMainModuleSubstateKey::GenericScryptoComponentIndexEntry(
+                        collection_index,
+                        key.clone(),
+                    )
+                }
+                (Some(collection_index), SubstateKey::Sorted(key)) => {
+                    TypedMainModuleSubstateKey::GenericScryptoComponentSortedIndexEntry(
+                        collection_index,
+                        key.clone(),
+                    )
+                }
+                (Some(_), SubstateKey::Field(_)) => return Err(()),
+            }
         }
         EntityType::GlobalPackage => TypedMainModuleSubstateKey::Package(
             PackageTypedSubstateKey::for_key_at_partition_offset(partition_offset, substate_key)?,
@@ -330,6 +367,12 @@ fn to_typed_object_substate_key_internal(
                 substate_key,
             )?,
         ),
+        EntityType::GlobalAccountLocker => TypedMainModuleSubstateKey::AccountLocker(
+            AccountLockerTypedSubstateKey::for_key_in_partition(
+                &AccountLockerPartitionOffset::try_from(partition_offset)?,
+                substate_key,
+            )?,
+        ),
         EntityType::GlobalTransactionTracker => {
             if partition_offset == PartitionOffset(0) {
                 TypedMainModuleSubstateKey::TransactionTrackerField(
@@ -362,6 +405,7 @@ pub enum TypedSubstateValue {
     RoyaltyModule(TypedRoyaltyModuleSubstateValue),
     MetadataModule(TypedMetadataModuleSubstateValue),
     MainModule(TypedMainModuleSubstateValue),
+    ProtocolUpdateStatus(FieldSubstate<AppliedProtocolUpdatesSubstate>),
 }
 
 #[derive(Debug)]
@@ -402,10 +446,13 @@ pub enum TypedMainModuleSubstateValue {
     OneResourcePool(OneResourcePoolTypedSubstateValue),
     TwoResourcePool(TwoResourcePoolTypedSubstateValue),
     MultiResourcePool(MultiResourcePoolTypedSubstateValue),
+    AccountLocker(AccountLockerTypedSubstateValue),
     TransactionTracker(TypedTransactionTrackerFieldValue),
     TransactionTrackerCollectionEntry(KeyValueEntrySubstate<TransactionStatusSubstateContents>),
     // Generic Scrypto Components and KV Stores
     GenericScryptoComponent(GenericScryptoComponentFieldValue),
+    GenericScryptoComponentIndexEntry(IndexEntrySubstate<ScryptoOwnedRawValue>),
+    GenericScryptoComponentSortedIndexEntry(SortedIndexEntrySubstate<ScryptoOwnedRawValue>),
     GenericKeyValueStoreEntry(KeyValueEntrySubstate<ScryptoOwnedRawValue>),
 }
 
@@ -476,6 +523,9 @@ fn to_typed_substate_value_internal(
         TypedSubstateKey::MainModule(object_substate_key) => TypedSubstateValue::MainModule(
             to_typed_object_substate_value(object_substate_key, data)?,
         ),
+        TypedSubstateKey::ProtocolUpdateStatus(
+            ProtocolUpdateStatusField::AppliedProtocolUpdates,
+        ) => TypedSubstateValue::ProtocolUpdateStatus(scrypto_decode(data)?),
     };
     Ok(substate_value)
 }
@@ -531,6 +581,14 @@ fn to_typed_object_substate_value(
                 }
             })
         }
+        TypedMainModuleSubstateKey::GenericScryptoComponentIndexEntry(..) => {
+            TypedMainModuleSubstateValue::GenericScryptoComponentIndexEntry(scrypto_decode(data)?)
+        }
+        TypedMainModuleSubstateKey::GenericScryptoComponentSortedIndexEntry(..) => {
+            TypedMainModuleSubstateValue::GenericScryptoComponentSortedIndexEntry(scrypto_decode(
+                data,
+            )?)
+        }
         TypedMainModuleSubstateKey::GenericKeyValueStoreKey(_) => {
             TypedMainModuleSubstateValue::GenericKeyValueStoreEntry(scrypto_decode(data)?)
         }
@@ -549,6 +607,11 @@ fn to_typed_object_substate_value(
                 MultiResourcePoolTypedSubstateValue::from_key_and_data(key, data)?,
             )
         }
+        TypedMainModuleSubstateKey::AccountLocker(key) => {
+            TypedMainModuleSubstateValue::AccountLocker(
+                AccountLockerTypedSubstateValue::from_key_and_data(key, data)?,
+            )
+        }
 
         TypedMainModuleSubstateKey::TransactionTrackerField(offset) => {
             TypedMainModuleSubstateValue::TransactionTracker(match offset {
@@ -68,6 +68,11 @@ pub trait DatabaseKeyMapper {
 
     fn sorted_to_db_sort_key(sorted_key: &SortedKey) -> DbSortKey;
     fn sorted_from_db_sort_key(db_sort_key: &DbSortKey) -> SortedKey;
+
+    /// Returns a [`DbSortKey`] which is lower than or equal to the mapped keys of all
+    /// [`SortedKey`]s having the given sort prefix, and greater than all mapped keys of any lower
+    /// sort prefix.
+    fn sort_prefix_to_db_sort_key(sort_prefix: &[u8; 2]) -> DbSortKey;
 }
 
 /// A [`DatabaseKeyMapper`] tailored for databases which cannot tolerate long common prefixes
@@ -132,6 +137,10 @@ impl DatabaseKeyMapper for SpreadPrefixKeyMapper {
             SpreadPrefixKeyMapper::from_hash_prefixed(&db_sort_key.0[2..]).to_vec(),
         )
     }
+
+    fn sort_prefix_to_db_sort_key(sort_prefix: &[u8; 2]) -> DbSortKey {
+        DbSortKey(sort_prefix.to_vec())
+    }
 }
 
 impl SpreadPrefixKeyMapper {
@@ -146,6 +146,29 @@ pub trait SubstateDatabase {
         &self,
         partition_key: &DbPartitionKey,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_>;
+
+    /// Iterates over the entries of the given partition starting from the given [`DbSortKey`]
+    /// (inclusive; or from the first entry, if [`Option::None`]), in a lexicographical order
+    /// (ascending) of the [`DbSortKey`]s.
+    ///
+    /// The default implementation skips the preceding entries of [`Self::list_entries()`];
+    /// databases capable of seeking should override it.
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        match from_sort_key {
+            Some(from_sort_key) => {
+                let from_sort_key = from_sort_key.clone();
+                Box::new(
+                    self.list_entries(partition_key)
+                        .skip_while(move |(sort_key, _)| *sort_key < from_sort_key),
+                )
+            }
+            None => self.list_entries(partition_key),
+        }
+    }
 }
 
 /// A write interface between Track and a database vendor.
@@ -159,3 +182,92 @@ pub trait ListableSubstateDatabase {
     /// Iterates over all partition keys, in an arbitrary order.
     fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_>;
 }
+
+/// A read interface of a database which retains its past states, i.e. which is capable of serving
+/// substates "as of" a specific (already committed) state version.
+pub trait VersionedSubstateDatabase {
+    /// Returns the state version of the most recent commit (or `0` for an empty database).
+    fn get_current_state_version(&self) -> u64;
+
+    /// Reads a substate value by its partition and sort key, as it was right after committing the
+    /// given state version, or [`Option::None`] if missing at that version.
+    ///
+    /// # Panics
+    /// Panics if the given state version is not available in the database.
+    fn get_substate_at_version(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        state_version: u64,
+    ) -> Option<DbSubstateValue>;
+
+    /// Iterates over all entries of the given partition, as it was right after committing the given
+    /// state version, in a lexicographical order (ascending) of the [`DbSortKey`]s.
+    ///
+    /// # Panics
+    /// Panics if the given state version is not available in the database.
+    fn list_entries_at_version(
+        &self,
+        partition_key: &DbPartitionKey,
+        state_version: u64,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_>;
+
+    /// Returns a read-only [`SubstateDatabase`] view of the state at the given version.
+    fn at_version(&self, state_version: u64) -> SubstateDatabaseAtVersion<'_, Self>
+    where
+        Self: Sized,
+    {
+        SubstateDatabaseAtVersion::new(self, state_version)
+    }
+}
+
+/// A [`SubstateDatabase`] which reads a fixed, past state of an underlying
+/// [`VersionedSubstateDatabase`].
+pub struct SubstateDatabaseAtVersion<'d, D> {
+    database: &'d D,
+    state_version: u64,
+}
+
+impl<'d, D: VersionedSubstateDatabase> SubstateDatabaseAtVersion<'d, D> {
+    /// Creates a view of the given database's state at the given version.
+    ///
+    /// # Panics
+    /// Panics if the given state version was not committed yet.
+    pub fn new(database: &'d D, state_version: u64) -> Self {
+        let current_state_version = database.get_current_state_version();
+        if state_version > current_state_version {
+            panic!(
+                "state version {} requested, but the current one is {}",
+                state_version, current_state_version
+            );
+        }
+        Self {
+            database,
+            state_version,
+        }
+    }
+
+    /// Returns the state version seen by this view.
+    pub fn state_version(&self) -> u64 {
+        self.state_version
+    }
+}
+
+impl<'d, D: VersionedSubstateDatabase> SubstateDatabase for SubstateDatabaseAtVersion<'d, D> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        self.database
+            .get_substate_at_version(partition_key, sort_key, self.state_version)
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.database
+            .list_entries_at_version(partition_key, self.state_version)
+    }
+}
@@ -0,0 +1,193 @@
+use crate::interface::*;
+use itertools::{EitherOrBoth, Itertools};
+use utils::rust::boxed::Box;
+use utils::rust::collections::{BTreeMap, BTreeSet};
+use utils::rust::ops::Bound;
+
+/// A [`SubstateDatabase`] which overlays uncommitted, in-memory changes over an underlying
+/// database.
+///
+/// Commits are only ever applied to the overlay, so the underlying database is never written to.
+/// This allows executing transactions (e.g. previews) against hypothetical states, and chaining
+/// them, without any effect on the real ledger state.
+pub struct SubstateDatabaseOverlay<'d, D> {
+    root: &'d D,
+    overlay: BTreeMap<DbPartitionKey, PartitionOverlay>,
+}
+
+/// The changes to a single partition, which are either relative to the underlying database
+/// (for [`PartitionDatabaseUpdates::Delta`]) or replace its contents entirely (for
+/// [`PartitionDatabaseUpdates::Reset`]).
+enum PartitionOverlay {
+    Delta(BTreeMap<DbSortKey, DatabaseUpdate>),
+    Reset(BTreeMap<DbSortKey, DbSubstateValue>),
+}
+
+impl<'d, D: SubstateDatabase> SubstateDatabaseOverlay<'d, D> {
+    /// Creates an overlay with no changes over the given database.
+    pub fn new(root: &'d D) -> Self {
+        Self {
+            root,
+            overlay: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the underlying database.
+    pub fn root(&self) -> &'d D {
+        self.root
+    }
+
+    /// Returns all changes committed to this overlay so far, as they would have to be committed
+    /// to the underlying database.
+    pub fn database_updates(&self) -> DatabaseUpdates {
+        let mut database_updates = DatabaseUpdates::default();
+        for (partition_key, partition_overlay) in &self.overlay {
+            let partition_updates = match partition_overlay {
+                PartitionOverlay::Delta(substate_updates) => PartitionDatabaseUpdates::Delta {
+                    substate_updates: substate_updates
+                        .iter()
+                        .map(|(sort_key, update)| (sort_key.clone(), update.clone()))
+                        .collect(),
+                },
+                PartitionOverlay::Reset(new_substate_values) => PartitionDatabaseUpdates::Reset {
+                    new_substate_values: new_substate_values
+                        .iter()
+                        .map(|(sort_key, value)| (sort_key.clone(), value.clone()))
+                        .collect(),
+                },
+            };
+            database_updates
+                .node_updates
+                .entry(partition_key.node_key.clone())
+                .or_default()
+                .partition_updates
+                .insert(partition_key.partition_num, partition_updates);
+        }
+        database_updates
+    }
+}
+
+impl<'d, D: SubstateDatabase> SubstateDatabase for SubstateDatabaseOverlay<'d, D> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        match self.overlay.get(partition_key) {
+            Some(PartitionOverlay::Delta(substate_updates)) => match substate_updates.get(sort_key)
+            {
+                Some(DatabaseUpdate::Set(value)) => Some(value.clone()),
+                Some(DatabaseUpdate::Delete) => None,
+                None => self.root.get_substate(partition_key, sort_key),
+            },
+            Some(PartitionOverlay::Reset(new_substate_values)) => {
+                new_substate_values.get(sort_key).cloned()
+            }
+            None => self.root.get_substate(partition_key, sort_key),
+        }
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.list_entries_from(partition_key, None)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let range = match from_sort_key {
+            Some(from_sort_key) => (Bound::Included(from_sort_key.clone()), Bound::Unbounded),
+            None => (Bound::Unbounded, Bound::Unbounded),
+        };
+        match self.overlay.get(partition_key) {
+            Some(PartitionOverlay::Delta(substate_updates)) => Box::new(
+                self.root
+                    .list_entries_from(partition_key, from_sort_key)
+                    .merge_join_by(
+                        substate_updates.range(range),
+                        |(root_key, _), (overlay_key, _)| root_key.cmp(overlay_key),
+                    )
+                    .filter_map(|entry| match entry {
+                        EitherOrBoth::Left(root_entry) => Some(root_entry),
+                        EitherOrBoth::Right((sort_key, update))
+                        | EitherOrBoth::Both(_, (sort_key, update)) => match update {
+                            DatabaseUpdate::Set(value) => Some((sort_key.clone(), value.clone())),
+                            DatabaseUpdate::Delete => None,
+                        },
+                    }),
+            ),
+            Some(PartitionOverlay::Reset(new_substate_values)) => Box::new(
+                new_substate_values
+                    .range(range)
+                    .map(|(sort_key, value)| (sort_key.clone(), value.clone())),
+            ),
+            None => self.root.list_entries_from(partition_key, from_sort_key),
+        }
+    }
+}
+
+impl<'d, D: SubstateDatabase> CommittableSubstateDatabase for SubstateDatabaseOverlay<'d, D> {
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        for (node_key, node_updates) in &database_updates.node_updates {
+            for (partition_num, partition_updates) in &node_updates.partition_updates {
+                let partition_key = DbPartitionKey {
+                    node_key: node_key.clone(),
+                    partition_num: *partition_num,
+                };
+                match partition_updates {
+                    PartitionDatabaseUpdates::Delta { substate_updates } => {
+                        match self
+                            .overlay
+                            .entry(partition_key)
+                            .or_insert_with(|| PartitionOverlay::Delta(BTreeMap::new()))
+                        {
+                            PartitionOverlay::Delta(existing_updates) => {
+                                for (sort_key, update) in substate_updates {
+                                    existing_updates.insert(sort_key.clone(), update.clone());
+                                }
+                            }
+                            PartitionOverlay::Reset(existing_values) => {
+                                for (sort_key, update) in substate_updates {
+                                    match update {
+                                        DatabaseUpdate::Set(value) => {
+                                            existing_values.insert(sort_key.clone(), value.clone())
+                                        }
+                                        DatabaseUpdate::Delete => existing_values.remove(sort_key),
+                                    };
+                                }
+                            }
+                        }
+                    }
+                    PartitionDatabaseUpdates::Reset {
+                        new_substate_values,
+                    } => {
+                        self.overlay.insert(
+                            partition_key,
+                            PartitionOverlay::Reset(
+                                new_substate_values
+                                    .iter()
+                                    .map(|(sort_key, value)| (sort_key.clone(), value.clone()))
+                                    .collect(),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'d, D: ListableSubstateDatabase> ListableSubstateDatabase for SubstateDatabaseOverlay<'d, D> {
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        let partition_keys: BTreeSet<DbPartitionKey> = self
+            .root
+            .list_partition_keys()
+            .chain(self.overlay.keys().cloned())
+            .collect();
+        Box::new(partition_keys.into_iter())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::hash_tree::tree_store::StaleTreePart;
 use crate::hash_tree::types::{LeafKey, LeafNode, SPARSE_MERKLE_PLACEHOLDER_HASH};
 use jellyfish::JellyfishMerkleTree;
+use proof::{SubstateProof, TierProof};
 use radix_engine_common::crypto::{hash, Hash};
 use radix_engine_store_interface::interface::{
     DatabaseUpdate, DatabaseUpdates, DbNodeKey, DbPartitionKey, DbPartitionNum, DbSortKey,
@@ -14,11 +15,12 @@ use utils::rust::collections::{index_map_new, IndexMap};
 use utils::rust::vec::Vec;
 
 pub mod hash_tree_facade;
+pub mod proof;
 pub mod tree_store;
 
-// The sources copied from Aptos (the `jellyfish` and `types` modules) contain support for
-// generating proofs, which we plan to use in near future. Hence, we do not delete that code, but
-// suppress warnings.
+// The sources copied from Aptos (the `jellyfish` and `types` modules) contain more features than
+// we currently use (e.g. range proofs), which we plan to use in near future. Hence, we do not
+// delete that code, but suppress warnings.
 
 #[allow(dead_code)]
 mod jellyfish;
@@ -107,8 +109,176 @@ pub fn list_substate_hashes_at_version<S: ReadableTreeStore>(
     by_db_partition
 }
 
+/// Returns the hash of the given substate's value, together with the version at which this value
+/// was most recently set, as seen in the "3-Tier JMT" at the given `node_root_version`.
+/// Returns `None` if the substate did not exist at that version.
+///
+/// # Panics
+/// Panics if a root node for `node_root_version` does not exist.
+pub fn get_substate_hash_at_version<S: ReadableTreeStore>(
+    node_tier_store: &S,
+    node_root_version: Version,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> Option<(Hash, Version)> {
+    let mut node_tier_store = node_tier_store;
+    let partition_root_version = get_lower_tier_root_version(
+        &node_tier_store,
+        Some(node_root_version),
+        &partition_key.node_key,
+    )?;
+    let mut partition_tier_store =
+        NestedTreeStore::new(&mut node_tier_store, partition_key.node_key.clone());
+    let substate_root_version = get_lower_tier_root_version(
+        &partition_tier_store,
+        Some(partition_root_version),
+        &[partition_key.partition_num],
+    )?;
+    let substate_tier_store =
+        NestedTreeStore::new(&mut partition_tier_store, vec![partition_key.partition_num]);
+    JellyfishMerkleTree::new(&substate_tier_store)
+        .get_with_proof(&LeafKey::new(&sort_key.0), substate_root_version)
+        .unwrap()
+        .0
+        .map(|(value_hash, last_hash_change_version, _version)| {
+            (value_hash, last_hash_change_version)
+        })
+}
+
+/// Lists the hashes of all substates of the given partition (in a lexicographical order of their
+/// [`DbSortKey`]s), each together with the version at which its value was most recently set, as
+/// seen in the "3-Tier JMT" at the given `node_root_version`.
+/// Returns an empty map if the partition did not exist at that version.
+///
+/// # Panics
+/// Panics if a root node for `node_root_version` does not exist.
+pub fn list_partition_substate_hashes_at_version<S: ReadableTreeStore>(
+    node_tier_store: &S,
+    node_root_version: Version,
+    partition_key: &DbPartitionKey,
+) -> IndexMap<DbSortKey, (Hash, Version)> {
+    let mut node_tier_store = node_tier_store;
+    let Some(partition_root_version) = get_lower_tier_root_version(
+        &node_tier_store,
+        Some(node_root_version),
+        &partition_key.node_key,
+    ) else {
+        return index_map_new();
+    };
+    let mut partition_tier_store =
+        NestedTreeStore::new(&mut node_tier_store, partition_key.node_key.clone());
+    let Some(substate_root_version) = get_lower_tier_root_version(
+        &partition_tier_store,
+        Some(partition_root_version),
+        &[partition_key.partition_num],
+    ) else {
+        return index_map_new();
+    };
+    let mut substate_tier_store =
+        NestedTreeStore::new(&mut partition_tier_store, vec![partition_key.partition_num]);
+    list_leaves(&mut substate_tier_store, substate_root_version)
+        .into_iter()
+        .map(|substate_tier_leaf| {
+            (
+                DbSortKey(substate_tier_leaf.leaf_key().bytes.clone()),
+                (
+                    substate_tier_leaf.value_hash(),
+                    substate_tier_leaf.payload().clone(),
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Returns the root hash of the "3-Tier JMT" at the given `node_root_version` (i.e. the same hash
+/// which was returned by the [`put_at_next_version()`] call that created this version).
+///
+/// # Panics
+/// Panics if a root node for `node_root_version` does not exist.
+pub fn get_root_hash_at_version<S: ReadableTreeStore>(
+    node_tier_store: &S,
+    node_root_version: Version,
+) -> Hash {
+    JellyfishMerkleTree::<_, Version>::new(node_tier_store)
+        .get_root_hash(node_root_version)
+        .unwrap()
+}
+
+/// Generates a proof of the given substate's inclusion in (or exclusion from) the "3-Tier JMT" at
+/// the given `node_root_version`, verifiable against the root hash of that version (see
+/// [`SubstateProof::verify()`]).
+///
+/// # Panics
+/// Panics if a root node for `node_root_version` does not exist.
+pub fn get_substate_proof_at_version<S: ReadableTreeStore>(
+    node_tier_store: &S,
+    node_root_version: Version,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> SubstateProof {
+    let mut tier_proofs = Vec::new();
+    let mut node_tier_store = node_tier_store;
+    let Some(partition_root_version) = prove_tier_leaf(
+        &node_tier_store,
+        node_root_version,
+        &partition_key.node_key,
+        &mut tier_proofs,
+    ) else {
+        return SubstateProof { tier_proofs };
+    };
+    let mut partition_tier_store =
+        NestedTreeStore::new(&mut node_tier_store, partition_key.node_key.clone());
+    let Some(substate_root_version) = prove_tier_leaf(
+        &partition_tier_store,
+        partition_root_version,
+        &[partition_key.partition_num],
+        &mut tier_proofs,
+    ) else {
+        return SubstateProof { tier_proofs };
+    };
+    let substate_tier_store =
+        NestedTreeStore::new(&mut partition_tier_store, vec![partition_key.partition_num]);
+    prove_tier_leaf(
+        &substate_tier_store,
+        substate_root_version,
+        &sort_key.0,
+        &mut tier_proofs,
+    );
+    SubstateProof { tier_proofs }
+}
+
+/// Lists the keys of all individual nodes constituting the given stale tree part (i.e. the keys of
+/// nodes which can be deleted once no version referencing that part needs to be readable anymore).
+///
+/// # Panics
+/// Panics if any of the nodes belonging to a [`StaleTreePart::Subtree`] does not exist.
+pub fn list_stale_node_keys<S: ReadableTreeStore>(
+    tree_store: &S,
+    stale_tree_part: StaleTreePart,
+) -> Vec<NodeKey> {
+    match stale_tree_part {
+        StaleTreePart::Node(key) => vec![key],
+        StaleTreePart::Subtree(key) => JellyfishMerkleTree::<_, Version>::new(tree_store)
+            .get_all_nodes_referenced(key)
+            .unwrap(),
+    }
+}
+
 // only internals below
 
+fn prove_tier_leaf<S: ReadableTreeStore>(
+    store: &S,
+    version: Version,
+    leaf_bytes: &[u8],
+    tier_proofs: &mut Vec<TierProof>,
+) -> Option<Version> {
+    let (leaf_node_data, proof) = JellyfishMerkleTree::new(store)
+        .get_with_proof(&LeafKey::new(leaf_bytes), version)
+        .unwrap();
+    tier_proofs.push(TierProof::from(proof));
+    leaf_node_data.map(|(_hash, last_hash_change_version, _version)| last_hash_change_version)
+}
+
 fn list_leaves<S: ReadableTreeStore>(
     tree_store: &mut S,
     version: Version,
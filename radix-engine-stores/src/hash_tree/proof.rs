@@ -0,0 +1,191 @@
+use crate::hash_tree::types::{
+    IteratedLeafKey, LeafKey, SparseMerkleInternalNode, SparseMerkleLeafNode, SparseMerkleProof,
+    SPARSE_MERKLE_PLACEHOLDER_HASH,
+};
+use radix_engine_common::crypto::{hash, Hash};
+use radix_engine_common::Sbor;
+use radix_engine_store_interface::interface::{DbPartitionKey, DbSortKey};
+use utils::prelude::vec;
+use utils::rust::vec::Vec;
+
+/// A proof of a substate's inclusion in (or exclusion from) the "3-Tier JMT" at a specific version.
+/// It can be verified against the root hash of that version alone, i.e. without any access to the
+/// tree's storage (see [`SubstateProof::verify()`]).
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct SubstateProof {
+    /// Proofs of the consecutive tiers traversed while looking up the substate: ReNode-Tier,
+    /// Partition-Tier and Substate-Tier.
+    /// There are less than 3 of them only if an exclusion was already proven at a higher tier
+    /// (e.g. when the entire partition did not exist).
+    pub tier_proofs: Vec<TierProof>,
+}
+
+/// A proof of a single key's inclusion in (or exclusion from) a single tier's JMT.
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct TierProof {
+    /// The leaf found at the end of the looked-up key's path:
+    /// - If its key equals the looked-up key, this is an inclusion proof.
+    /// - If its key differs, this is an exclusion proof (since that leaf is the only one existing
+    ///   in the subtree which would contain the looked-up key).
+    /// - If this is `None`, this is an exclusion proof (since the subtree is empty).
+    pub leaf: Option<TierProofLeaf>,
+    /// Hashes of all siblings on the path, ordered from the bottom level to the root level.
+    pub siblings: Vec<Hash>,
+}
+
+/// A leaf node's data included in the [`TierProof`].
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct TierProofLeaf {
+    /// The leaf's complete key.
+    pub key: Vec<u8>,
+    /// The leaf's value hash (i.e. a hash of a substate value, or a root hash of a lower tier).
+    pub value_hash: Hash,
+}
+
+/// An error of a [`SubstateProof`] verification.
+/// The `tier` fields hold an index of the affected tier (i.e. `0` for the ReNode-Tier, `1` for the
+/// Partition-Tier and `2` for the Substate-Tier).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubstateProofError {
+    /// The proven inclusion of a higher tier's leaf requires a proof of the given tier.
+    MissingTierProof { tier: usize },
+    /// The proven exclusion at a higher tier does not allow for a proof of the given tier.
+    UnexpectedTierProof { tier: usize },
+    /// The tier proof has more siblings than the length of the looked-up key allows for.
+    TooManySiblings { tier: usize },
+    /// The tier proof's leaf does not lie on the looked-up key's path.
+    LeafNotOnPath { tier: usize },
+    /// The tier proof does not lead to the expected root hash.
+    RootHashMismatch {
+        tier: usize,
+        expected: Hash,
+        computed: Hash,
+    },
+    /// The proof is valid, but it proves a different value than expected.
+    ValueHashMismatch {
+        expected: Option<Hash>,
+        proven: Option<Hash>,
+    },
+}
+
+impl SubstateProof {
+    /// Verifies this proof against the given (trusted) root hash, and returns the proven hash of
+    /// the substate's value (or `None`, if the proof shows that the substate does not exist).
+    pub fn verify(
+        &self,
+        root_hash: &Hash,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Result<Option<Hash>, SubstateProofError> {
+        let tier_keys = [
+            partition_key.node_key.clone(),
+            vec![partition_key.partition_num],
+            sort_key.0.clone(),
+        ];
+        let mut tier_root_hash = *root_hash;
+        for (tier, tier_key) in tier_keys.iter().enumerate() {
+            let tier_proof = self
+                .tier_proofs
+                .get(tier)
+                .ok_or(SubstateProofError::MissingTierProof { tier })?;
+            match tier_proof.verify(tier, &tier_root_hash, tier_key)? {
+                Some(value_hash) => tier_root_hash = value_hash,
+                None => return self.ensure_tier_count(tier + 1).map(|_| None),
+            }
+        }
+        self.ensure_tier_count(tier_keys.len())
+            .map(|_| Some(tier_root_hash))
+    }
+
+    /// Verifies this proof against the given (trusted) root hash, and checks that it proves the
+    /// given value of the substate (or its absence, in case of `None`).
+    pub fn verify_value(
+        &self,
+        root_hash: &Hash,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        value: Option<&[u8]>,
+    ) -> Result<(), SubstateProofError> {
+        let proven = self.verify(root_hash, partition_key, sort_key)?;
+        let expected = value.map(hash);
+        if proven != expected {
+            return Err(SubstateProofError::ValueHashMismatch { expected, proven });
+        }
+        Ok(())
+    }
+
+    fn ensure_tier_count(&self, tier_count: usize) -> Result<(), SubstateProofError> {
+        if self.tier_proofs.len() > tier_count {
+            return Err(SubstateProofError::UnexpectedTierProof { tier: tier_count });
+        }
+        Ok(())
+    }
+}
+
+impl TierProof {
+    /// Verifies this proof against the given root hash of the tier (with the given index), and
+    /// returns the proven leaf's value hash (or `None`, if the proof shows that the key does not
+    /// exist).
+    fn verify(
+        &self,
+        tier: usize,
+        root_hash: &Hash,
+        key: &[u8],
+    ) -> Result<Option<Hash>, SubstateProofError> {
+        let key = LeafKey::new(key);
+        if self.siblings.len() > key.bytes.len() * 8 {
+            return Err(SubstateProofError::TooManySiblings { tier });
+        }
+        let (proven_value_hash, bottom_hash) = match &self.leaf {
+            Some(leaf) => {
+                let leaf_key = LeafKey::new(&leaf.key);
+                let leaf_hash = SparseMerkleLeafNode::new(leaf_key.clone(), leaf.value_hash).hash();
+                if leaf_key == key {
+                    (Some(leaf.value_hash), leaf_hash)
+                } else {
+                    let common_prefix_bits = key
+                        .iter_bits()
+                        .zip(leaf_key.iter_bits())
+                        .take_while(|(bit, leaf_bit)| bit == leaf_bit)
+                        .count();
+                    if common_prefix_bits < self.siblings.len() {
+                        return Err(SubstateProofError::LeafNotOnPath { tier });
+                    }
+                    (None, leaf_hash)
+                }
+            }
+            None => (None, SPARSE_MERKLE_PLACEHOLDER_HASH),
+        };
+        let computed = self
+            .siblings
+            .iter()
+            .zip(key.iter_bits().take(self.siblings.len()).rev())
+            .fold(bottom_hash, |node_hash, (sibling_hash, bit)| {
+                if bit {
+                    SparseMerkleInternalNode::new(*sibling_hash, node_hash).hash()
+                } else {
+                    SparseMerkleInternalNode::new(node_hash, *sibling_hash).hash()
+                }
+            });
+        if computed != *root_hash {
+            return Err(SubstateProofError::RootHashMismatch {
+                tier,
+                expected: *root_hash,
+                computed,
+            });
+        }
+        Ok(proven_value_hash)
+    }
+}
+
+impl From<SparseMerkleProof> for TierProof {
+    fn from(proof: SparseMerkleProof) -> Self {
+        Self {
+            leaf: proof.leaf().map(|leaf| TierProofLeaf {
+                key: leaf.key().bytes.clone(),
+                value_hash: *leaf.value_hash(),
+            }),
+            siblings: proof.siblings().to_vec(),
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use super::types::{Nibble, NibblePath, Version, SPARSE_MERKLE_PLACEHOLDER_HASH};
 use crate::hash_tree::jellyfish::JellyfishMerkleTree;
-use crate::hash_tree::put_at_next_version;
+use crate::hash_tree::proof::{SubstateProof, SubstateProofError};
 use crate::hash_tree::tree_store::{
     SerializedInMemoryTreeStore, StaleTreePart, TreeChildEntry, TreeInternalNode, TreeLeafNode,
     TreeNode, TreeStore, TypedInMemoryTreeStore,
 };
 use crate::hash_tree::types::{LeafKey, NodeKey};
+use crate::hash_tree::{
+    get_substate_hash_at_version, get_substate_proof_at_version,
+    list_partition_substate_hashes_at_version, put_at_next_version,
+};
 use itertools::Itertools;
 use radix_engine_common::crypto::{hash, Hash};
 use radix_engine_common::data::scrypto::{scrypto_decode, scrypto_encode};
@@ -349,6 +353,176 @@ fn records_stale_subtree_root_key_when_partition_removed() {
     );
 }
 
+#[test]
+fn reads_substate_hashes_at_historical_versions() {
+    let mut tester = HashTreeTester::new_empty();
+    tester.put_substate_changes(vec![change(1, 6, 2, Some(30)), change(1, 6, 3, Some(40))]);
+    tester.put_substate_changes(vec![change(1, 6, 2, Some(70))]);
+    tester.put_substate_changes(vec![change(1, 6, 2, None)]);
+    let partition_key = DbPartitionKey {
+        node_key: from_seed(1),
+        partition_num: 6,
+    };
+    let read_at = |version: Version, sort_key_seed: u8| {
+        get_substate_hash_at_version(
+            &tester.tree_store,
+            version,
+            &partition_key,
+            &DbSortKey(from_seed(sort_key_seed)),
+        )
+    };
+    assert_eq!(read_at(1, 2), Some((hash(from_seed(30)), 1)));
+    assert_eq!(read_at(2, 2), Some((hash(from_seed(70)), 2)));
+    assert_eq!(read_at(3, 2), None);
+    assert_eq!(read_at(3, 3), Some((hash(from_seed(40)), 1)));
+    assert_eq!(read_at(3, 4), None);
+}
+
+#[test]
+fn lists_partition_substate_hashes_at_historical_versions() {
+    let mut tester = HashTreeTester::new_empty();
+    tester.put_substate_changes(vec![
+        change(4, 7, 6, Some(36)),
+        change(4, 7, 7, Some(37)),
+        change(4, 8, 1, Some(41)),
+    ]);
+    tester.reset_partition(
+        from_seed(4),
+        7,
+        vec![(DbSortKey(from_seed(8)), from_seed(38))],
+    );
+    tester.put_substate_changes(vec![change(4, 7, 8, None)]);
+    let partition_key = DbPartitionKey {
+        node_key: from_seed(4),
+        partition_num: 7,
+    };
+    assert_eq!(
+        list_partition_substate_hashes_at_version(&tester.tree_store, 1, &partition_key),
+        indexmap!(
+            DbSortKey(from_seed(6)) => (hash(from_seed(36)), 1),
+            DbSortKey(from_seed(7)) => (hash(from_seed(37)), 1),
+        )
+    );
+    assert_eq!(
+        list_partition_substate_hashes_at_version(&tester.tree_store, 2, &partition_key),
+        indexmap!(
+            DbSortKey(from_seed(8)) => (hash(from_seed(38)), 2),
+        )
+    );
+    assert!(
+        list_partition_substate_hashes_at_version(&tester.tree_store, 3, &partition_key).is_empty()
+    );
+}
+
+#[test]
+fn substate_proof_proves_inclusion_against_root_hash() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_v1 = tester.put_substate_changes(vec![
+        change(1, 6, 2, Some(30)),
+        change(1, 6, 3, Some(40)),
+        change(2, 6, 2, Some(50)),
+    ]);
+    let root_v2 = tester.put_substate_changes(vec![change(1, 6, 2, Some(70))]);
+    let (partition_key, sort_key) = substate_key(1, 6, 2);
+
+    let proof_v1 = get_substate_proof_at_version(&tester.tree_store, 1, &partition_key, &sort_key);
+    assert_eq!(proof_v1.tier_proofs.len(), 3);
+    assert_eq!(
+        proof_v1.verify(&root_v1, &partition_key, &sort_key),
+        Ok(Some(hash(from_seed(30))))
+    );
+
+    let proof_v2 = get_substate_proof_at_version(&tester.tree_store, 2, &partition_key, &sort_key);
+    assert_eq!(
+        proof_v2.verify_value(&root_v2, &partition_key, &sort_key, Some(&from_seed(70))),
+        Ok(())
+    );
+}
+
+#[test]
+fn substate_proof_proves_exclusion_at_each_tier() {
+    let mut tester = HashTreeTester::new_empty();
+    let root = tester.put_substate_changes(vec![
+        change(1, 6, 2, Some(30)),
+        change(1, 6, 3, Some(40)),
+        change(2, 6, 2, Some(50)),
+    ]);
+    for ((partition_key, sort_key), expected_tier_count) in [
+        // missing RE Node
+        (substate_key(3, 6, 2), 1),
+        // missing partition within an existing RE Node
+        (substate_key(1, 7, 2), 2),
+        // missing substate within an existing partition
+        (substate_key(1, 6, 4), 3),
+    ] {
+        let proof = get_substate_proof_at_version(&tester.tree_store, 1, &partition_key, &sort_key);
+        assert_eq!(proof.tier_proofs.len(), expected_tier_count);
+        assert_eq!(proof.verify(&root, &partition_key, &sort_key), Ok(None));
+        assert_eq!(
+            proof.verify_value(&root, &partition_key, &sort_key, None),
+            Ok(())
+        );
+    }
+}
+
+#[test]
+fn substate_proof_verification_fails_for_wrong_root_value_or_key() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_v1 =
+        tester.put_substate_changes(vec![change(1, 6, 2, Some(30)), change(1, 6, 3, Some(40))]);
+    let root_v2 = tester.put_substate_changes(vec![change(1, 6, 2, Some(70))]);
+    let (partition_key, sort_key) = substate_key(1, 6, 2);
+    let proof = get_substate_proof_at_version(&tester.tree_store, 2, &partition_key, &sort_key);
+
+    assert_eq!(
+        proof.verify(&root_v1, &partition_key, &sort_key),
+        Err(SubstateProofError::RootHashMismatch {
+            tier: 0,
+            expected: root_v1,
+            computed: root_v2,
+        })
+    );
+    assert_eq!(
+        proof.verify_value(&root_v2, &partition_key, &sort_key, Some(&from_seed(30))),
+        Err(SubstateProofError::ValueHashMismatch {
+            expected: Some(hash(from_seed(30))),
+            proven: Some(hash(from_seed(70))),
+        })
+    );
+    let (_, other_sort_key) = substate_key(1, 6, 3);
+    assert!(proof
+        .verify(&root_v2, &partition_key, &other_sort_key)
+        .is_err());
+
+    let mut tampered_proof = proof.clone();
+    tampered_proof.tier_proofs[2]
+        .leaf
+        .as_mut()
+        .unwrap()
+        .value_hash = hash(from_seed(30));
+    assert!(tampered_proof
+        .verify(&root_v2, &partition_key, &sort_key)
+        .is_err());
+
+    let mut truncated_proof = proof;
+    truncated_proof.tier_proofs.pop();
+    assert_eq!(
+        truncated_proof.verify(&root_v2, &partition_key, &sort_key),
+        Err(SubstateProofError::MissingTierProof { tier: 2 })
+    );
+}
+
+#[test]
+fn substate_proof_sbor_decodes_what_was_encoded() {
+    let mut tester = HashTreeTester::new_empty();
+    tester.put_substate_changes(vec![change(1, 6, 2, Some(30)), change(1, 6, 3, Some(40))]);
+    let (partition_key, sort_key) = substate_key(1, 6, 2);
+    let proof = get_substate_proof_at_version(&tester.tree_store, 1, &partition_key, &sort_key);
+    let encoded = scrypto_encode(&proof).unwrap();
+    let decoded = scrypto_decode::<SubstateProof>(&encoded).unwrap();
+    assert_eq!(proof, decoded);
+}
+
 #[test]
 fn sbor_uses_custom_direct_codecs_for_nibbles() {
     let nibbles = nibbles("a1a2a3");
@@ -454,6 +628,16 @@ pub fn change_exact(
     )
 }
 
+fn substate_key(node_key_seed: u8, partition_num: u8, sort_key_seed: u8) -> DbSubstateKey {
+    (
+        DbPartitionKey {
+            node_key: from_seed(node_key_seed),
+            partition_num,
+        },
+        DbSortKey(from_seed(sort_key_seed)),
+    )
+}
+
 fn from_seed(node_key_seed: u8) -> Vec<u8> {
     vec![node_key_seed; node_key_seed as usize]
 }
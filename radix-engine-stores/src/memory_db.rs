@@ -1,4 +1,5 @@
 use radix_engine_store_interface::interface::*;
+use sbor::rust::ops::Bound;
 use sbor::rust::prelude::*;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -39,6 +40,25 @@ impl SubstateDatabase for InMemorySubstateDatabase {
 
         Box::new(iter)
     }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let range = match from_sort_key {
+            Some(from_sort_key) => (Bound::Included(from_sort_key.clone()), Bound::Unbounded),
+            None => (Bound::Unbounded, Bound::Unbounded),
+        };
+        let iter = self
+            .partitions
+            .get(partition_key)
+            .into_iter()
+            .flat_map(move |partition| partition.range(range.clone()))
+            .map(|(key, substate)| (key.clone(), substate.clone()));
+
+        Box::new(iter)
+    }
 }
 
 impl CommittableSubstateDatabase for InMemorySubstateDatabase {
@@ -54,9 +54,19 @@ impl SubstateDatabase for RocksdbSubstateStore {
     fn list_entries(
         &self,
         partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.list_entries_from(partition_key, None)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         let partition_key = partition_key.clone();
-        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, &DbSortKey(vec![]));
+        let empty_sort_key = DbSortKey(vec![]);
+        let start_key_bytes =
+            encode_to_rocksdb_bytes(&partition_key, from_sort_key.unwrap_or(&empty_sort_key));
         let iter = self
             .db
             .iterator_cf(
@@ -1,7 +1,13 @@
+use crate::hash_tree::proof::SubstateProof;
 use crate::hash_tree::tree_store::{
-    encode_key, NodeKey, ReadableTreeStore, TreeNode, VersionedTreeNode,
+    encode_key, NodeKey, ReadableTreeStore, StaleTreePart, TreeNode, VersionedTreeNode,
+};
+use crate::hash_tree::{
+    get_root_hash_at_version, get_substate_hash_at_version, get_substate_proof_at_version,
+    list_partition_substate_hashes_at_version, list_stale_node_keys,
 };
 use itertools::Itertools;
+use radix_engine_common::crypto::Hash;
 use radix_engine_common::data::scrypto::{scrypto_decode, scrypto_encode};
 use radix_engine_derive::ScryptoSbor;
 use radix_engine_store_interface::interface::*;
@@ -12,8 +18,11 @@ use rocksdb::{
 };
 use sbor::prelude::*;
 use std::path::PathBuf;
+use utils::copy_u8_array;
 
 mod state_tree;
+#[cfg(test)]
+mod test;
 use crate::rocks_db::{decode_from_rocksdb_bytes, encode_to_rocksdb_bytes};
 use state_tree::*;
 
@@ -21,6 +30,11 @@ const META_CF: &str = "meta";
 const SUBSTATES_CF: &str = "substates";
 const MERKLE_NODES_CF: &str = "merkle_nodes";
 const STALE_MERKLE_TREE_PARTS_CF: &str = "stale_merkle_tree_parts";
+const HISTORICAL_SUBSTATES_CF: &str = "historical_substates";
+const STALE_HISTORICAL_SUBSTATES_CF: &str = "stale_historical_substates";
+
+const METADATA_KEY: &[u8] = &[];
+const PRUNING_METADATA_KEY: &[u8] = b"pruning";
 
 pub struct RocksDBWithMerkleTreeSubstateStore {
     db: DBWithThreadMode<SingleThreaded>,
@@ -52,6 +66,8 @@ impl RocksDBWithMerkleTreeSubstateStore {
                 SUBSTATES_CF,
                 MERKLE_NODES_CF,
                 STALE_MERKLE_TREE_PARTS_CF,
+                HISTORICAL_SUBSTATES_CF,
+                STALE_HISTORICAL_SUBSTATES_CF,
             ]
             .into_iter()
             .map(|name| ColumnFamilyDescriptor::new(name, Options::default()))
@@ -61,9 +77,156 @@ impl RocksDBWithMerkleTreeSubstateStore {
         Self { db }
     }
 
+    /// Returns the root hash of the state tree at the given (already committed) state version.
+    pub fn get_state_hash_at_version(&self, state_version: u64) -> Hash {
+        self.assert_state_version_available(state_version);
+        get_root_hash_at_version(self, state_version)
+    }
+
+    /// Generates a proof of the given substate's inclusion in (or exclusion from) the state tree
+    /// at the given (already committed) state version.
+    /// The proof can be verified against the [`Self::get_state_hash_at_version()`] alone.
+    pub fn get_substate_proof_at_version(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        state_version: u64,
+    ) -> SubstateProof {
+        self.assert_state_version_available(state_version);
+        get_substate_proof_at_version(self, state_version, partition_key, sort_key)
+    }
+
+    /// Deletes all parts of the state tree (together with the historical substate values) which
+    /// are only reachable from the state versions older than the given number of most recent ones.
+    /// After this operation, only the states in the range
+    /// `[current_state_version - retained_state_versions + 1, current_state_version]` remain
+    /// readable (see [`VersionedSubstateDatabase`]).
+    ///
+    /// Note: this operation is never triggered automatically - a long-running ledger should call
+    /// it periodically, to keep its storage from growing without bounds.
+    ///
+    /// # Panics
+    /// Panics if the `retained_state_versions` is 0 (i.e. the current state must always be kept).
+    pub fn prune_state_history(&mut self, retained_state_versions: u64) {
+        if retained_state_versions == 0 {
+            panic!("at least the current state version must be retained");
+        }
+        let current_state_version = self.read_metadata().current_state_version;
+        let first_retained_state_version =
+            (current_state_version + 1).saturating_sub(retained_state_versions);
+        if first_retained_state_version <= self.read_pruning_metadata().first_readable_state_version
+        {
+            return;
+        }
+
+        // a part which became stale at version `V` is only reachable from versions lower than `V`
+        let mut batch = WriteBatch::default();
+        for (record_key, record_value) in
+            self.list_stale_records(STALE_MERKLE_TREE_PARTS_CF, first_retained_state_version)
+        {
+            for stale_tree_part in scrypto_decode::<Vec<StaleTreePart>>(&record_value).unwrap() {
+                for node_key in list_stale_node_keys(self, stale_tree_part) {
+                    batch.delete_cf(self.cf(MERKLE_NODES_CF), encode_key(&node_key));
+                }
+            }
+            batch.delete_cf(self.cf(STALE_MERKLE_TREE_PARTS_CF), record_key);
+        }
+        for (record_key, record_value) in
+            self.list_stale_records(STALE_HISTORICAL_SUBSTATES_CF, first_retained_state_version)
+        {
+            for historical_substate_key in scrypto_decode::<Vec<Vec<u8>>>(&record_value).unwrap() {
+                batch.delete_cf(self.cf(HISTORICAL_SUBSTATES_CF), historical_substate_key);
+            }
+            batch.delete_cf(self.cf(STALE_HISTORICAL_SUBSTATES_CF), record_key);
+        }
+        batch.put_cf(
+            self.cf(META_CF),
+            PRUNING_METADATA_KEY,
+            scrypto_encode(&PruningMetadata {
+                first_readable_state_version: first_retained_state_version,
+            })
+            .unwrap(),
+        );
+        self.db.write(batch).unwrap();
+    }
+
     fn cf(&self, cf: &str) -> &ColumnFamily {
         self.db.cf_handle(cf).unwrap()
     }
+
+    fn read_metadata(&self) -> Metadata {
+        self.db
+            .get_cf(self.cf(META_CF), METADATA_KEY)
+            .unwrap()
+            .map(|bytes| scrypto_decode::<Metadata>(&bytes).unwrap())
+            .unwrap_or_else(|| Metadata {
+                current_state_version: 0,
+            })
+    }
+
+    fn read_pruning_metadata(&self) -> PruningMetadata {
+        self.db
+            .get_cf(self.cf(META_CF), PRUNING_METADATA_KEY)
+            .unwrap()
+            .map(|bytes| scrypto_decode::<PruningMetadata>(&bytes).unwrap())
+            .unwrap_or_else(|| PruningMetadata {
+                first_readable_state_version: 0,
+            })
+    }
+
+    /// Lists the records (of the given "stale parts" column family) which became stale at state
+    /// versions lower than or equal to the given one.
+    fn list_stale_records(
+        &self,
+        cf: &str,
+        max_stale_since_state_version: u64,
+    ) -> Vec<(Box<[u8]>, Box<[u8]>)> {
+        self.db
+            .iterator_cf(self.cf(cf), IteratorMode::Start)
+            .map(|kv| kv.expect("IO Error"))
+            .take_while(|(key, _)| {
+                u64::from_be_bytes(copy_u8_array(key)) <= max_stale_since_state_version
+            })
+            .collect()
+    }
+
+    fn get_historical_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        value_state_version: u64,
+    ) -> DbSubstateValue {
+        self.db
+            .get_cf(
+                self.cf(HISTORICAL_SUBSTATES_CF),
+                encode_historical_substate_key(value_state_version, partition_key, sort_key),
+            )
+            .expect("IO Error")
+            .unwrap_or_else(|| {
+                panic!(
+                    "substate {:?}:{:?} set at version {} referenced by the state tree, but not found",
+                    partition_key, sort_key, value_state_version
+                )
+            })
+    }
+
+    fn assert_state_version_available(&self, state_version: u64) {
+        let current_state_version = self.read_metadata().current_state_version;
+        if state_version > current_state_version {
+            panic!(
+                "state version {} requested, but the current one is {}",
+                state_version, current_state_version
+            );
+        }
+        let first_readable_state_version =
+            self.read_pruning_metadata().first_readable_state_version;
+        if state_version < first_readable_state_version {
+            panic!(
+                "state version {} requested, but it was pruned (the first readable one is {})",
+                state_version, first_readable_state_version
+            );
+        }
+    }
 }
 
 impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
@@ -81,9 +244,19 @@ impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
     fn list_entries(
         &self,
         partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.list_entries_from(partition_key, None)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         let partition_key = partition_key.clone();
-        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, &DbSortKey(vec![]));
+        let empty_sort_key = DbSortKey(vec![]);
+        let start_key_bytes =
+            encode_to_rocksdb_bytes(&partition_key, from_sort_key.unwrap_or(&empty_sort_key));
         let iter = self
             .db
             .iterator_cf(
@@ -105,20 +278,16 @@ impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
 impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
     fn commit(&mut self, database_updates: &DatabaseUpdates) {
         // read required info about current database state (here I fake it a bit)
-        let metadata = self
-            .db
-            .get_cf(self.cf(META_CF), [])
-            .unwrap()
-            .map(|bytes| scrypto_decode::<Metadata>(&bytes).unwrap())
-            .unwrap_or_else(|| Metadata {
-                current_state_version: 0,
-            });
+        let metadata = self.read_metadata();
         let parent_state_version = metadata.current_state_version;
         let next_state_version = parent_state_version + 1;
 
         // prepare a batch write (we use the same approach in the actual Node)
         let mut batch = WriteBatch::default();
 
+        // collect references to the previous values of all affected substates, which become stale
+        let mut stale_historical_substate_keys = Vec::new();
+
         // put regular substate changes
         for (node_key, node_updates) in &database_updates.node_updates {
             for (partition_num, partition_updates) in &node_updates.partition_updates {
@@ -129,9 +298,36 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
                 match partition_updates {
                     PartitionDatabaseUpdates::Delta { substate_updates } => {
                         for (sort_key, update) in substate_updates {
+                            if parent_state_version > 0 {
+                                if let Some((_value_hash, value_state_version)) =
+                                    get_substate_hash_at_version(
+                                        self,
+                                        parent_state_version,
+                                        &partition_key,
+                                        sort_key,
+                                    )
+                                {
+                                    stale_historical_substate_keys.push(
+                                        encode_historical_substate_key(
+                                            value_state_version,
+                                            &partition_key,
+                                            sort_key,
+                                        ),
+                                    );
+                                }
+                            }
                             let key_bytes = encode_to_rocksdb_bytes(&partition_key, sort_key);
                             match update {
                                 DatabaseUpdate::Set(value_bytes) => {
+                                    batch.put_cf(
+                                        self.cf(HISTORICAL_SUBSTATES_CF),
+                                        encode_historical_substate_key(
+                                            next_state_version,
+                                            &partition_key,
+                                            sort_key,
+                                        ),
+                                        value_bytes,
+                                    );
                                     self.db
                                         .put_cf(self.cf(SUBSTATES_CF), key_bytes, value_bytes)
                                 }
@@ -145,6 +341,23 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
                     PartitionDatabaseUpdates::Reset {
                         new_substate_values,
                     } => {
+                        if parent_state_version > 0 {
+                            for (sort_key, (_value_hash, value_state_version)) in
+                                list_partition_substate_hashes_at_version(
+                                    self,
+                                    parent_state_version,
+                                    &partition_key,
+                                )
+                            {
+                                stale_historical_substate_keys.push(
+                                    encode_historical_substate_key(
+                                        value_state_version,
+                                        &partition_key,
+                                        &sort_key,
+                                    ),
+                                );
+                            }
+                        }
                         // Note: a plain `delete_range()` is missing from rocksdb's API, and
                         // (at the moment of writing) this is the only reason of having CF.
                         self.db
@@ -156,6 +369,15 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
                             .expect("IO error");
                         for (sort_key, value_bytes) in new_substate_values {
                             let key_bytes = encode_to_rocksdb_bytes(&partition_key, sort_key);
+                            batch.put_cf(
+                                self.cf(HISTORICAL_SUBSTATES_CF),
+                                encode_historical_substate_key(
+                                    next_state_version,
+                                    &partition_key,
+                                    sort_key,
+                                ),
+                                value_bytes,
+                            );
                             self.db
                                 .put_cf(self.cf(SUBSTATES_CF), key_bytes, value_bytes)
                                 .expect("IO error");
@@ -165,7 +387,7 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
             }
         }
 
-        // derive and put new JMT nodes (also record references to stale parts, for later amortized background GC [not implemented here!])
+        // derive and put new JMT nodes (also record references to stale parts, for later pruning - see `prune_state_history()`)
         let state_hash_tree_update =
             compute_state_tree_update(self, parent_state_version, database_updates);
         for (key, node) in state_hash_tree_update.new_nodes {
@@ -180,11 +402,16 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
             next_state_version.to_be_bytes(),
             scrypto_encode(&state_hash_tree_update.stale_tree_parts).unwrap(),
         );
+        batch.put_cf(
+            self.cf(STALE_HISTORICAL_SUBSTATES_CF),
+            next_state_version.to_be_bytes(),
+            scrypto_encode(&stale_historical_substate_keys).unwrap(),
+        );
 
         // update the metadata
         batch.put_cf(
             self.cf(META_CF),
-            [],
+            METADATA_KEY,
             scrypto_encode(&Metadata {
                 current_state_version: next_state_version,
             })
@@ -223,7 +450,72 @@ impl ReadableTreeStore for RocksDBWithMerkleTreeSubstateStore {
     }
 }
 
+impl VersionedSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
+    fn get_current_state_version(&self) -> u64 {
+        self.read_metadata().current_state_version
+    }
+
+    fn get_substate_at_version(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        state_version: u64,
+    ) -> Option<DbSubstateValue> {
+        self.assert_state_version_available(state_version);
+        if state_version == 0 {
+            // the initial, empty state
+            return None;
+        }
+        get_substate_hash_at_version(self, state_version, partition_key, sort_key).map(
+            |(_value_hash, value_state_version)| {
+                self.get_historical_substate(partition_key, sort_key, value_state_version)
+            },
+        )
+    }
+
+    fn list_entries_at_version(
+        &self,
+        partition_key: &DbPartitionKey,
+        state_version: u64,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.assert_state_version_available(state_version);
+        if state_version == 0 {
+            // the initial, empty state
+            return Box::new(std::iter::empty());
+        }
+        let partition_key = partition_key.clone();
+        let substate_hashes =
+            list_partition_substate_hashes_at_version(self, state_version, &partition_key);
+        Box::new(substate_hashes.into_iter().map(
+            move |(sort_key, (_value_hash, value_state_version))| {
+                let value =
+                    self.get_historical_substate(&partition_key, &sort_key, value_state_version);
+                (sort_key, value)
+            },
+        ))
+    }
+}
+
+/// Encodes a key of a substate value set at the given state version, for a column family holding
+/// all values which are (potentially) reachable from the historical versions of the state tree.
+fn encode_historical_substate_key(
+    state_version: u64,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> Vec<u8> {
+    [
+        state_version.to_be_bytes().to_vec(),
+        encode_to_rocksdb_bytes(partition_key, sort_key),
+    ]
+    .concat()
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, ScryptoSbor)]
 struct Metadata {
     current_state_version: u64,
 }
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, ScryptoSbor)]
+struct PruningMetadata {
+    first_readable_state_version: u64,
+}
@@ -0,0 +1,193 @@
+use super::RocksDBWithMerkleTreeSubstateStore;
+use crate::hash_tree::tree_store::{NodeKey, ReadableTreeStore};
+use radix_engine_common::crypto::Hash;
+use radix_engine_store_interface::interface::{
+    CommittableSubstateDatabase, DatabaseUpdate, DatabaseUpdates, DbPartitionKey, DbSortKey,
+    DbSubstateValue, NodeDatabaseUpdates, PartitionDatabaseUpdates, SubstateDatabase,
+    VersionedSubstateDatabase,
+};
+use sbor::prelude::*;
+
+#[test]
+fn reads_substates_at_historical_versions() {
+    let mut tester = StoreTester::new("reads_substates_at_historical_versions");
+    tester.put(1, 6, 2, Some(30));
+    tester.put(1, 6, 3, Some(40));
+    tester.put(1, 6, 2, None);
+    tester.reset(1, 6, vec![(4, 50)]);
+
+    let store = &tester.store;
+    let (partition_key, sort_key) = substate_key(1, 6, 2);
+    assert_eq!(store.get_current_state_version(), 4);
+    assert_eq!(
+        store.get_substate_at_version(&partition_key, &sort_key, 0),
+        None
+    );
+    assert_eq!(
+        store.get_substate_at_version(&partition_key, &sort_key, 1),
+        Some(from_seed(30))
+    );
+    assert_eq!(
+        store.get_substate_at_version(&partition_key, &sort_key, 2),
+        Some(from_seed(30))
+    );
+    assert_eq!(
+        store.get_substate_at_version(&partition_key, &sort_key, 3),
+        None
+    );
+    assert_eq!(
+        store
+            .list_entries_at_version(&partition_key, 3)
+            .collect::<Vec<_>>(),
+        vec![(DbSortKey(from_seed(3)), from_seed(40))]
+    );
+    assert_eq!(
+        store
+            .at_version(4)
+            .list_entries(&partition_key)
+            .collect::<Vec<_>>(),
+        store.list_entries(&partition_key).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn pruning_keeps_current_and_retained_versions_readable() {
+    let mut tester = StoreTester::new("pruning_keeps_current_and_retained_versions_readable");
+    tester.put(1, 6, 2, Some(30));
+    tester.put(1, 6, 3, Some(40));
+    tester.put(1, 6, 2, Some(70));
+    tester.reset(1, 6, vec![(2, 80), (4, 90)]);
+    tester.put(2, 6, 2, Some(10));
+    let expected_states = (3..=5)
+        .map(|state_version| (state_version, tester.read_state_at(state_version)))
+        .collect::<Vec<_>>();
+
+    tester.store.prune_state_history(3);
+
+    for (state_version, expected_state) in expected_states {
+        assert_eq!(tester.read_state_at(state_version), expected_state);
+    }
+    let (partition_key, sort_key) = substate_key(1, 6, 4);
+    assert_eq!(
+        tester.store.get_substate(&partition_key, &sort_key),
+        Some(from_seed(90))
+    );
+
+    // the tree must also remain updatable after pruning:
+    tester.put(1, 6, 4, Some(20));
+    assert_eq!(
+        tester
+            .store
+            .get_substate_at_version(&partition_key, &sort_key, 6),
+        Some(from_seed(20))
+    );
+}
+
+#[test]
+fn pruning_deletes_nodes_only_reachable_from_old_versions() {
+    let mut tester = StoreTester::new("pruning_deletes_nodes_only_reachable_from_old_versions");
+    tester.put(1, 6, 2, Some(30));
+    tester.put(1, 6, 2, Some(40));
+    tester.put(1, 6, 2, Some(50));
+    let root_key = |state_version| NodeKey::new_empty_path(state_version);
+    assert!(tester.store.get_node(&root_key(1)).is_some());
+
+    tester.store.prune_state_history(2);
+
+    assert!(tester.store.get_node(&root_key(1)).is_none());
+    assert!(tester.store.get_node(&root_key(2)).is_some());
+    assert!(tester.store.get_node(&root_key(3)).is_some());
+}
+
+#[test]
+#[should_panic]
+fn reading_pruned_version_panics() {
+    let mut tester = StoreTester::new("reading_pruned_version_panics");
+    tester.put(1, 6, 2, Some(30));
+    tester.put(1, 6, 2, Some(40));
+    tester.store.prune_state_history(1);
+    let (partition_key, sort_key) = substate_key(1, 6, 2);
+    tester
+        .store
+        .get_substate_at_version(&partition_key, &sort_key, 1);
+}
+
+struct StoreTester {
+    store: RocksDBWithMerkleTreeSubstateStore,
+}
+
+impl StoreTester {
+    fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("radix_engine_stores_{}", name));
+        Self {
+            store: RocksDBWithMerkleTreeSubstateStore::clear(root),
+        }
+    }
+
+    fn put(&mut self, node_key_seed: u8, partition_num: u8, sort_key_seed: u8, value: Option<u8>) {
+        let (partition_key, sort_key) = substate_key(node_key_seed, partition_num, sort_key_seed);
+        let update = value
+            .map(|value_seed| DatabaseUpdate::Set(from_seed(value_seed)))
+            .unwrap_or(DatabaseUpdate::Delete);
+        self.store
+            .commit(&DatabaseUpdates::from_delta_maps(indexmap!(
+                partition_key => indexmap!(sort_key => update)
+            )));
+    }
+
+    fn reset(&mut self, node_key_seed: u8, partition_num: u8, values: Vec<(u8, u8)>) {
+        self.store.commit(&DatabaseUpdates {
+            node_updates: indexmap!(
+                from_seed(node_key_seed) => NodeDatabaseUpdates {
+                    partition_updates: indexmap!(
+                        partition_num => PartitionDatabaseUpdates::Reset {
+                            new_substate_values: values
+                                .into_iter()
+                                .map(|(sort_key_seed, value_seed)| {
+                                    (DbSortKey(from_seed(sort_key_seed)), from_seed(value_seed))
+                                })
+                                .collect()
+                        }
+                    )
+                }
+            ),
+        });
+    }
+
+    fn read_state_at(&self, state_version: u64) -> (Hash, Vec<Vec<(DbSortKey, DbSubstateValue)>>) {
+        let partition_entries = [1, 2]
+            .into_iter()
+            .map(|node_key_seed| {
+                self.store
+                    .list_entries_at_version(&partition_key(node_key_seed, 6), state_version)
+                    .collect()
+            })
+            .collect();
+        (
+            self.store.get_state_hash_at_version(state_version),
+            partition_entries,
+        )
+    }
+}
+
+fn substate_key(
+    node_key_seed: u8,
+    partition_num: u8,
+    sort_key_seed: u8,
+) -> (DbPartitionKey, DbSortKey) {
+    (
+        partition_key(node_key_seed, partition_num),
+        DbSortKey(from_seed(sort_key_seed)),
+    )
+}
+
+fn partition_key(node_key_seed: u8, partition_num: u8) -> DbPartitionKey {
+    DbPartitionKey {
+        node_key: from_seed(node_key_seed),
+        partition_num,
+    }
+}
+
+fn from_seed(seed: u8) -> Vec<u8> {
+    vec![seed; seed as usize]
+}
@@ -129,6 +129,7 @@ fn transfer_test(c: &mut Criterion) {
     let mut scrypto_interpreter = ScryptoInterpreter {
         wasm_engine: DefaultWasmEngine::default(),
         wasm_validator_config: WasmValidatorConfig::new(),
+        wasm_disk_cache: None,
     };
     let mut substate_db = InMemorySubstateDatabase::standard();
     let _ = Bootstrapper::new(&mut substate_db, &scrypto_interpreter)
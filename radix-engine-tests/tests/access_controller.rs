@@ -1,12 +1,19 @@
-use radix_engine::blueprints::access_controller::AccessControllerError;
+use radix_engine::blueprints::access_controller::{
+    AccessControllerError, AccessControllerField, AccessControllerStateFieldPayload,
+    AccessControllerStateV1, AccessControllerStateV2, VersionedAccessControllerState,
+};
 use radix_engine::blueprints::resource::FungibleResourceManagerError;
 use radix_engine::errors::ApplicationError;
 use radix_engine::errors::RuntimeError;
 use radix_engine::errors::SystemModuleError;
 use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::system::system_substates::FieldSubstate;
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::access_controller::*;
+use radix_engine_store_interface::db_key_mapper::{
+    MappedCommittableSubstateDatabase, MappedSubstateDatabase, SpreadPrefixKeyMapper,
+};
 use scrypto_unit::{CustomGenesis, DefaultTestRunner, TestRunnerBuilder};
 use transaction::prelude::*;
 
@@ -500,6 +507,221 @@ pub fn minting_of_recovery_badges_fails_for_confirmation_role() {
     receipt.expect_specific_failure(is_auth_unauthorized_error);
 }
 
+#[test]
+pub fn anybody_can_contribute_to_the_recovery_fee_vault() {
+    // Arrange
+    let mut test_runner = AccessControllerTestRunner::new(Some(100));
+
+    // Act
+    let receipt = test_runner.contribute_recovery_fee(dec!(100));
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .test_runner
+            .get_component_balance(test_runner.access_controller_address, XRD),
+        dec!(100)
+    );
+}
+
+#[test]
+pub fn recovery_role_can_pay_for_recovery_from_the_recovery_fee_vault() {
+    // Arrange
+    let mut test_runner = AccessControllerTestRunner::new(Some(100));
+    test_runner
+        .contribute_recovery_fee(dec!(100))
+        .expect_commit_success();
+    let account_balance = test_runner
+        .test_runner
+        .get_component_balance(test_runner.account.0, XRD);
+
+    // Act
+    let manifest = test_runner
+        .manifest_builder(Role::Recovery)
+        .call_method(
+            test_runner.access_controller_address,
+            ACCESS_CONTROLLER_LOCK_RECOVERY_FEE_IDENT,
+            AccessControllerLockRecoveryFeeInput { amount: dec!(50) },
+        )
+        .call_method(
+            test_runner.access_controller_address,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            AccessControllerInitiateRecoveryAsRecoveryInput {
+                rule_set: RuleSet {
+                    primary_role: rule!(require(test_runner.recovery_role_badge)),
+                    recovery_role: rule!(require(test_runner.recovery_role_badge)),
+                    confirmation_role: rule!(require(test_runner.recovery_role_badge)),
+                },
+                timed_recovery_delay_in_minutes: None,
+            },
+        )
+        .build();
+    let receipt = test_runner.test_runner.execute_manifest(
+        manifest,
+        [NonFungibleGlobalId::from_public_key(&test_runner.account.1)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .test_runner
+            .get_component_balance(test_runner.account.0, XRD),
+        account_balance
+    );
+    assert!(
+        test_runner
+            .test_runner
+            .get_component_balance(test_runner.access_controller_address, XRD)
+            < dec!(100)
+    );
+}
+
+#[test]
+pub fn locking_a_recovery_fee_without_contributions_fails() {
+    // Arrange
+    let mut test_runner = AccessControllerTestRunner::new(Some(100));
+
+    // Act
+    let receipt = test_runner.lock_recovery_fee(Role::Recovery, dec!(10));
+
+    // Assert
+    receipt.expect_specific_failure(is_no_xrd_fee_vault_error);
+}
+
+#[test]
+pub fn confirmation_role_can_lock_a_recovery_fee() {
+    // Arrange
+    let mut test_runner = AccessControllerTestRunner::new(Some(100));
+    test_runner
+        .contribute_recovery_fee(dec!(100))
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.lock_recovery_fee(Role::Confirmation, dec!(10));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+pub fn primary_role_can_withdraw_the_recovery_fee() {
+    // Arrange
+    let mut test_runner = AccessControllerTestRunner::new(Some(100));
+    test_runner
+        .contribute_recovery_fee(dec!(100))
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.withdraw_recovery_fee(Role::Primary, dec!(40));
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .test_runner
+            .get_component_balance(test_runner.access_controller_address, XRD),
+        dec!(60)
+    );
+}
+
+#[test]
+pub fn recovery_role_cant_withdraw_the_recovery_fee() {
+    // Arrange
+    let mut test_runner = AccessControllerTestRunner::new(Some(100));
+    test_runner
+        .contribute_recovery_fee(dec!(100))
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.withdraw_recovery_fee(Role::Recovery, dec!(40));
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_unauthorized_error);
+}
+
+#[test]
+pub fn primary_role_cant_withdraw_the_recovery_fee_when_locked() {
+    // Arrange
+    let mut test_runner = AccessControllerTestRunner::new(Some(100));
+    test_runner
+        .contribute_recovery_fee(dec!(100))
+        .expect_commit_success();
+    test_runner
+        .lock_primary_role(Role::Recovery)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.withdraw_recovery_fee(Role::Primary, dec!(40));
+
+    // Assert
+    receipt.expect_specific_failure(is_operation_requires_unlocked_primary_role_error);
+}
+
+#[test]
+pub fn access_controller_state_v1_is_updated_to_v2_when_read() {
+    // Arrange
+    let mut test_runner = AccessControllerTestRunner::new(Some(100));
+    let node_id = test_runner.access_controller_address.into_node_id();
+    let substate_key: SubstateKey = AccessControllerField::State.into();
+
+    // Overwrite the state with its encoding from before the recovery XRD vault was introduced
+    let state = test_runner
+        .test_runner
+        .substate_db()
+        .get_mapped::<SpreadPrefixKeyMapper, FieldSubstate<AccessControllerStateFieldPayload>>(
+            &node_id,
+            MAIN_BASE_PARTITION,
+            &substate_key,
+        )
+        .unwrap()
+        .into_payload()
+        .into_latest();
+    let state_v1 = VersionedAccessControllerState::V1(AccessControllerStateV1 {
+        controlled_asset: state.controlled_asset,
+        timed_recovery_delay_in_minutes: state.timed_recovery_delay_in_minutes,
+        recovery_badge: state.recovery_badge,
+        state: state.state,
+    });
+    test_runner
+        .test_runner
+        .substate_db_mut()
+        .put_mapped::<SpreadPrefixKeyMapper, _>(
+            &node_id,
+            MAIN_BASE_PARTITION,
+            &substate_key,
+            &FieldSubstate::new_unlocked_field(state_v1),
+        );
+
+    // Act
+    let receipt = test_runner.contribute_recovery_fee(dec!(100));
+
+    // Assert
+    receipt.expect_commit_success();
+    let state = test_runner
+        .test_runner
+        .substate_db()
+        .get_mapped::<SpreadPrefixKeyMapper, FieldSubstate<AccessControllerStateFieldPayload>>(
+            &node_id,
+            MAIN_BASE_PARTITION,
+            &substate_key,
+        )
+        .unwrap()
+        .into_payload();
+    assert!(matches!(
+        state.as_latest_ref(),
+        Some(AccessControllerStateV2 {
+            timed_recovery_delay_in_minutes: Some(100),
+            recovery_xrd_vault: Some(..),
+            ..
+        })
+    ));
+    test_runner
+        .create_proof(Role::Primary)
+        .expect_commit_success();
+}
+
 //=============
 // State Tests
 //=============
@@ -1569,6 +1791,15 @@ fn is_operation_requires_unlocked_primary_role_error(error: &RuntimeError) -> bo
     )
 }
 
+fn is_no_xrd_fee_vault_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccessControllerError(
+            AccessControllerError::NoXrdFeeVault
+        ))
+    )
+}
+
 fn is_recovery_already_exists_for_proposer_error(error: &RuntimeError) -> bool {
     matches!(
         error,
@@ -1978,6 +2209,49 @@ impl AccessControllerTestRunner {
         self.execute_manifest(manifest)
     }
 
+    pub fn contribute_recovery_fee(&mut self, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(self.account.0, XRD, amount)
+            .take_all_from_worktop(XRD, "xrd")
+            .with_bucket("xrd", |builder, bucket| {
+                builder.call_method(
+                    self.access_controller_address,
+                    ACCESS_CONTROLLER_CONTRIBUTE_RECOVERY_FEE_IDENT,
+                    AccessControllerContributeRecoveryFeeManifestInput { bucket },
+                )
+            })
+            .build();
+        self.execute_manifest(manifest)
+    }
+
+    pub fn lock_recovery_fee(&mut self, as_role: Role, amount: Decimal) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_address,
+                ACCESS_CONTROLLER_LOCK_RECOVERY_FEE_IDENT,
+                AccessControllerLockRecoveryFeeInput { amount },
+            )
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            [NonFungibleGlobalId::from_public_key(&self.account.1)],
+        )
+    }
+
+    pub fn withdraw_recovery_fee(&mut self, as_role: Role, amount: Decimal) -> TransactionReceipt {
+        let manifest = self
+            .manifest_builder(as_role)
+            .call_method(
+                self.access_controller_address,
+                ACCESS_CONTROLLER_WITHDRAW_RECOVERY_FEE_IDENT,
+                AccessControllerWithdrawRecoveryFeeInput { amount },
+            )
+            .try_deposit_entire_worktop_or_abort(self.account.0, None)
+            .build();
+        self.execute_manifest(manifest)
+    }
+
     fn execute_manifest(&mut self, manifest: TransactionManifestV1) -> TransactionReceipt {
         self.test_runner.execute_manifest_ignoring_fee(
             manifest,
@@ -1,3 +1,4 @@
+use radix_engine::blueprints::account::AccountError;
 use radix_engine::blueprints::resource::{NonFungibleResourceManagerError, VaultError};
 use radix_engine::errors::{ApplicationError, RejectionReason, RuntimeError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
@@ -357,3 +358,120 @@ fn cannot_lock_contingent_fee_if_not_enough_funds() {
         ))
     ));
 }
+
+#[test]
+fn can_read_account_balances_without_auth() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_account(false);
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let (_, _, other_account) = test_runner.new_account(false);
+    let unknown_resource_address =
+        test_runner.create_fungible_resource(dec!(100), 18, other_account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            ACCOUNT_BALANCE_IDENT,
+            AccountBalanceInput { resource_address },
+        )
+        .call_method(
+            account,
+            ACCOUNT_BALANCE_IDENT,
+            AccountBalanceInput {
+                resource_address: unknown_resource_address,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(result.output::<AccountBalanceOutput>(1), dec!(100));
+    assert_eq!(result.output::<AccountBalanceOutput>(2), dec!(0));
+}
+
+#[test]
+fn can_list_and_check_account_non_fungibles_without_auth() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_account(false);
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            ACCOUNT_NON_FUNGIBLE_LOCAL_IDS_IDENT,
+            AccountNonFungibleLocalIdsInput {
+                resource_address,
+                limit: 10,
+            },
+        )
+        .call_method(
+            account,
+            ACCOUNT_HAS_NON_FUNGIBLE_IDENT,
+            AccountHasNonFungibleInput {
+                resource_address,
+                local_id: NonFungibleLocalId::integer(1),
+            },
+        )
+        .call_method(
+            account,
+            ACCOUNT_HAS_NON_FUNGIBLE_IDENT,
+            AccountHasNonFungibleInput {
+                resource_address,
+                local_id: NonFungibleLocalId::integer(4),
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let ids = result.output::<AccountNonFungibleLocalIdsOutput>(1);
+    assert_eq!(
+        ids,
+        indexset!(
+            NonFungibleLocalId::integer(1),
+            NonFungibleLocalId::integer(2),
+            NonFungibleLocalId::integer(3)
+        )
+    );
+    assert!(result.output::<AccountHasNonFungibleOutput>(2));
+    assert!(!result.output::<AccountHasNonFungibleOutput>(3));
+}
+
+#[test]
+fn cannot_list_non_fungible_local_ids_of_fungible_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_account(false);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            ACCOUNT_NON_FUNGIBLE_LOCAL_IDS_IDENT,
+            AccountNonFungibleLocalIdsInput {
+                resource_address: XRD,
+                limit: 10,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccountError(
+                AccountError::NotANonFungibleResource { .. }
+            ))
+        )
+    });
+}
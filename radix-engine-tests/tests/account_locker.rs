@@ -0,0 +1,522 @@
+use radix_engine::blueprints::locker::AccountLockerError;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::locker::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn can_store_and_claim_fungibles() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+    let (claimant_key, _, claimant_account) = test_runner.new_account(false);
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, true);
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, admin_account);
+    store(
+        &mut test_runner,
+        &locker,
+        claimant_account,
+        resource_address,
+        dec!(40),
+        false,
+    )
+    .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            locker.address,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            AccountLockerClaimInput {
+                claimant: claimant_account,
+                resource_address,
+                amount: dec!(15),
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(claimant_account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&claimant_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(claimant_account, resource_address),
+        dec!(15)
+    );
+    assert_eq!(
+        get_amount(
+            &mut test_runner,
+            locker.address,
+            claimant_account,
+            resource_address
+        ),
+        dec!(25)
+    );
+}
+
+#[test]
+fn locker_is_allocated_with_the_account_locker_entity_type() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+
+    // Act
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, false);
+
+    // Assert
+    assert_eq!(
+        locker.address.as_node_id().entity_type(),
+        Some(EntityType::GlobalAccountLocker)
+    );
+}
+
+#[test]
+fn cannot_claim_without_proof_of_account_ownership() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+    let (_, _, claimant_account) = test_runner.new_account(false);
+    let (other_key, _, other_account) = test_runner.new_account(false);
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, true);
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, admin_account);
+    store(
+        &mut test_runner,
+        &locker,
+        claimant_account,
+        resource_address,
+        dec!(40),
+        false,
+    )
+    .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            locker.address,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            AccountLockerClaimInput {
+                claimant: claimant_account,
+                resource_address,
+                amount: dec!(40),
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&other_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::AssertAccessRuleFailed)
+        )
+    });
+}
+
+#[test]
+fn direct_send_deposits_into_accounts_accepting_the_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+    let (_, _, claimant_account) = test_runner.new_account(false);
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, true);
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, admin_account);
+
+    // Act
+    let receipt = store(
+        &mut test_runner,
+        &locker,
+        claimant_account,
+        resource_address,
+        dec!(40),
+        true,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(claimant_account, resource_address),
+        dec!(40)
+    );
+    assert_eq!(
+        get_amount(
+            &mut test_runner,
+            locker.address,
+            claimant_account,
+            resource_address
+        ),
+        dec!(0)
+    );
+}
+
+#[test]
+fn direct_send_stores_resources_rejected_by_the_account_deposit_rules() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+    let (claimant_key, _, claimant_account) = test_runner.new_account(false);
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, true);
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, admin_account);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            claimant_account,
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountSetDefaultDepositRuleInput {
+                default: DefaultDepositRule::Reject,
+            },
+        )
+        .build();
+    test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&claimant_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = store(
+        &mut test_runner,
+        &locker,
+        claimant_account,
+        resource_address,
+        dec!(40),
+        true,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(claimant_account, resource_address),
+        dec!(0)
+    );
+    assert_eq!(
+        get_amount(
+            &mut test_runner,
+            locker.address,
+            claimant_account,
+            resource_address
+        ),
+        dec!(40)
+    );
+}
+
+#[test]
+fn can_airdrop_non_fungibles_and_claim_them() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+    let (claimant_key, _, claimant_account) = test_runner.new_account(false);
+    let (_, _, other_account) = test_runner.new_account(false);
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, true);
+    let resource_address = test_runner.create_non_fungible_resource(admin_account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(admin_account, locker.badge, dec!(1))
+        .withdraw_from_account(admin_account, resource_address, dec!(3))
+        .take_all_from_worktop(resource_address, "bucket")
+        .with_bucket("bucket", |builder, bucket| {
+            builder.call_method(
+                locker.address,
+                ACCOUNT_LOCKER_AIRDROP_IDENT,
+                AccountLockerAirdropManifestInput {
+                    claimants: indexmap!(
+                        claimant_account => ResourceSpecifier::NonFungible(indexset!(
+                            NonFungibleLocalId::integer(1),
+                            NonFungibleLocalId::integer(2)
+                        )),
+                        other_account => ResourceSpecifier::NonFungible(indexset!(
+                            NonFungibleLocalId::integer(3)
+                        )),
+                    ),
+                    bucket,
+                    try_direct_send: false,
+                },
+            )
+        })
+        .try_deposit_entire_worktop_or_abort(admin_account, None)
+        .call_method(
+            locker.address,
+            ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT,
+            AccountLockerClaimNonFungiblesInput {
+                claimant: claimant_account,
+                resource_address,
+                ids: indexset!(NonFungibleLocalId::integer(2)),
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(claimant_account, None)
+        .call_method(
+            locker.address,
+            ACCOUNT_LOCKER_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT,
+            AccountLockerGetNonFungibleLocalIdsInput {
+                claimant: claimant_account,
+                resource_address,
+                limit: 10,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![
+            NonFungibleGlobalId::from_public_key(&admin_key),
+            NonFungibleGlobalId::from_public_key(&claimant_key),
+        ],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(
+        result.output::<AccountLockerGetNonFungibleLocalIdsOutput>(8),
+        indexset!(NonFungibleLocalId::integer(1))
+    );
+    assert_eq!(
+        test_runner.get_component_balance(claimant_account, resource_address),
+        dec!(1)
+    );
+    assert_eq!(
+        get_amount(
+            &mut test_runner,
+            locker.address,
+            other_account,
+            resource_address
+        ),
+        dec!(1)
+    );
+}
+
+#[test]
+fn can_recover_stored_resources_when_allowed() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+    let (_, _, claimant_account) = test_runner.new_account(false);
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, true);
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, admin_account);
+    store(
+        &mut test_runner,
+        &locker,
+        claimant_account,
+        resource_address,
+        dec!(40),
+        false,
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = recover(
+        &mut test_runner,
+        &locker,
+        claimant_account,
+        resource_address,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(admin_account, resource_address),
+        dec!(70)
+    );
+    assert_eq!(
+        get_amount(
+            &mut test_runner,
+            locker.address,
+            claimant_account,
+            resource_address
+        ),
+        dec!(10)
+    );
+}
+
+#[test]
+fn cannot_recover_stored_resources_when_not_allowed() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+    let (_, _, claimant_account) = test_runner.new_account(false);
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, false);
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, admin_account);
+    store(
+        &mut test_runner,
+        &locker,
+        claimant_account,
+        resource_address,
+        dec!(40),
+        false,
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = recover(
+        &mut test_runner,
+        &locker,
+        claimant_account,
+        resource_address,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_store_for_a_claimant_that_is_not_an_account() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (admin_key, _, admin_account) = test_runner.new_account(false);
+    let locker = instantiate_locker(&mut test_runner, admin_key, admin_account, true);
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, admin_account);
+
+    // Act
+    let receipt = store(
+        &mut test_runner,
+        &locker,
+        locker.address,
+        resource_address,
+        dec!(40),
+        false,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccountLockerError(
+                AccountLockerError::ClaimantIsNotAnAccount { .. }
+            ))
+        )
+    });
+}
+
+/// A locker instantiated with a badge that is held by the admin account.
+struct TestLocker {
+    address: ComponentAddress,
+    badge: ResourceAddress,
+    admin_key: Secp256k1PublicKey,
+    admin_account: ComponentAddress,
+}
+
+fn instantiate_locker(
+    test_runner: &mut DefaultTestRunner,
+    admin_key: Secp256k1PublicKey,
+    admin_account: ComponentAddress,
+    allow_recover: bool,
+) -> TestLocker {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            LOCKER_PACKAGE,
+            ACCOUNT_LOCKER_BLUEPRINT,
+            ACCOUNT_LOCKER_INSTANTIATE_SIMPLE_IDENT,
+            AccountLockerInstantiateSimpleInput { allow_recover },
+        )
+        .try_deposit_entire_worktop_or_abort(admin_account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let result = receipt.expect_commit_success();
+    TestLocker {
+        address: result.new_component_addresses()[0],
+        badge: result.new_resource_addresses()[0],
+        admin_key,
+        admin_account,
+    }
+}
+
+fn store(
+    test_runner: &mut DefaultTestRunner,
+    locker: &TestLocker,
+    claimant: ComponentAddress,
+    resource_address: ResourceAddress,
+    amount: Decimal,
+    try_direct_send: bool,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(locker.admin_account, locker.badge, dec!(1))
+        .withdraw_from_account(locker.admin_account, resource_address, amount)
+        .take_all_from_worktop(resource_address, "bucket")
+        .with_bucket("bucket", |builder, bucket| {
+            builder.call_method(
+                locker.address,
+                ACCOUNT_LOCKER_STORE_IDENT,
+                AccountLockerStoreManifestInput {
+                    claimant,
+                    bucket,
+                    try_direct_send,
+                },
+            )
+        })
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&locker.admin_key)],
+    )
+}
+
+fn recover(
+    test_runner: &mut DefaultTestRunner,
+    locker: &TestLocker,
+    claimant: ComponentAddress,
+    resource_address: ResourceAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(locker.admin_account, locker.badge, dec!(1))
+        .call_method(
+            locker.address,
+            ACCOUNT_LOCKER_RECOVER_IDENT,
+            AccountLockerRecoverInput {
+                claimant,
+                resource_address,
+                amount: dec!(30),
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(locker.admin_account, None)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&locker.admin_key)],
+    )
+}
+
+fn get_amount(
+    test_runner: &mut DefaultTestRunner,
+    locker: ComponentAddress,
+    claimant: ComponentAddress,
+    resource_address: ResourceAddress,
+) -> Decimal {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            locker,
+            ACCOUNT_LOCKER_GET_AMOUNT_IDENT,
+            AccountLockerGetAmountInput {
+                claimant,
+                resource_address,
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt
+        .expect_commit_success()
+        .output::<AccountLockerGetAmountOutput>(1)
+}
@@ -0,0 +1,47 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod crypto_scrypto {
+    struct CryptoScrypto {}
+
+    impl CryptoScrypto {
+        pub fn keccak256_hash(data: Vec<u8>) -> Hash {
+            CryptoUtils::keccak256_hash(data)
+        }
+
+        pub fn blake2b_256_hash(data: Vec<u8>) -> Hash {
+            CryptoUtils::blake2b_256_hash(data)
+        }
+
+        pub fn ed25519_verify(
+            message: Vec<u8>,
+            public_key: Ed25519PublicKey,
+            signature: Ed25519Signature,
+        ) -> bool {
+            CryptoUtils::ed25519_verify(message, &public_key, &signature)
+        }
+
+        pub fn secp256k1_verify(
+            hash: Hash,
+            public_key: Secp256k1PublicKey,
+            signature: Secp256k1Signature,
+        ) -> bool {
+            CryptoUtils::secp256k1_verify(&hash, &public_key, &signature)
+        }
+
+        pub fn bls12381_v1_verify(
+            message: Vec<u8>,
+            public_key: Bls12381G1PublicKey,
+            signature: Bls12381G2Signature,
+        ) -> bool {
+            CryptoUtils::bls12381_v1_verify(message, &public_key, &signature)
+        }
+
+        pub fn bls12381_v1_aggregate_verify(
+            pub_keys_and_msgs: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+            signature: Bls12381G2Signature,
+        ) -> bool {
+            CryptoUtils::bls12381_v1_aggregate_verify(&pub_keys_and_msgs, &signature)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod index_collections {
+    struct IndexCollections {
+        names: Index<u32, String>,
+        orders: SortedIndex<String, Decimal>,
+    }
+
+    impl IndexCollections {
+        pub fn new() -> Global<IndexCollections> {
+            Self {
+                names: Index::new(),
+                orders: SortedIndex::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn insert_name(&mut self, id: u32, name: String) {
+            self.names.insert(id, name);
+        }
+
+        pub fn remove_name(&mut self, id: u32) -> Option<String> {
+            self.names.remove(&id)
+        }
+
+        pub fn scan_name_ids(&self, limit: u32) -> Vec<u32> {
+            self.names.scan_keys(limit)
+        }
+
+        pub fn drain_names(&mut self, limit: u32) -> Vec<(u32, String)> {
+            self.names.drain(limit)
+        }
+
+        pub fn insert_order(&mut self, price: u16, order_id: String, amount: Decimal) {
+            self.orders.insert(price, order_id, amount);
+        }
+
+        pub fn remove_order(&mut self, price: u16, order_id: String) -> Option<Decimal> {
+            self.orders.remove(price, &order_id)
+        }
+
+        pub fn scan_orders(&self, limit: u32) -> Vec<(u16, String, Decimal)> {
+            self.orders.scan(limit)
+        }
+
+        pub fn orders_in_range(
+            &self,
+            start: u16,
+            end: u16,
+            limit: u32,
+        ) -> Vec<(u16, String, Decimal)> {
+            self.orders.range(start..end, limit)
+        }
+    }
+}
@@ -0,0 +1,192 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::system::system_modules::costing::FeeTable;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn call_crypto_scrypto<T: ScryptoDecode>(
+    test_runner: &mut DefaultTestRunner,
+    package_address: PackageAddress,
+    function_name: &str,
+    args: ManifestArgs,
+) -> T {
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "CryptoScrypto", function_name, args)
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_success().output(1)
+}
+
+#[test]
+fn test_keccak256_and_blake2b_256_hash() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("crypto_scrypto"));
+    let data = b"Hello Radix".to_vec();
+
+    // Act
+    let keccak256: Hash = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "keccak256_hash",
+        manifest_args!(data.clone()),
+    );
+    let blake2b_256: Hash = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "blake2b_256_hash",
+        manifest_args!(data.clone()),
+    );
+
+    // Assert
+    assert_eq!(
+        keccak256,
+        Hash::from_str("415942230ddb029416a4612818536de230d827cbac9646a0b26d9855a4c45587").unwrap()
+    );
+    assert_eq!(blake2b_256, blake2b_256_hash(&data));
+}
+
+#[test]
+fn test_ed25519_verify() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("crypto_scrypto"));
+    let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let message_hash = hash("Test");
+    let signature = private_key.sign(&message_hash);
+
+    // Act
+    let valid: bool = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "ed25519_verify",
+        manifest_args!(message_hash.to_vec(), private_key.public_key(), signature),
+    );
+    let invalid: bool = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "ed25519_verify",
+        manifest_args!(hash("Other").to_vec(), private_key.public_key(), signature),
+    );
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+fn test_secp256k1_verify() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("crypto_scrypto"));
+    let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let message_hash = hash("Test");
+    let signature = private_key.sign(&message_hash);
+
+    // Act
+    let valid: bool = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "secp256k1_verify",
+        manifest_args!(message_hash, private_key.public_key(), signature),
+    );
+    let invalid: bool = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "secp256k1_verify",
+        manifest_args!(hash("Other"), private_key.public_key(), signature),
+    );
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+fn test_bls12381_v1_verify() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("crypto_scrypto"));
+    let private_key = Bls12381G1PrivateKey::from_u64(1).unwrap();
+    let message = b"Test".to_vec();
+    let signature = private_key.sign_v1(&message);
+
+    // Act
+    let valid: bool = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "bls12381_v1_verify",
+        manifest_args!(message, private_key.public_key(), signature),
+    );
+    let invalid: bool = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "bls12381_v1_verify",
+        manifest_args!(b"Other".to_vec(), private_key.public_key(), signature),
+    );
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+fn test_bls12381_v1_aggregate_verify() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("crypto_scrypto"));
+    let private_keys: Vec<Bls12381G1PrivateKey> = (1..=5)
+        .map(|i| Bls12381G1PrivateKey::from_u64(i).unwrap())
+        .collect();
+    let pub_keys_and_msgs: Vec<(Bls12381G1PublicKey, Vec<u8>)> = private_keys
+        .iter()
+        .enumerate()
+        .map(|(i, sk)| (sk.public_key(), vec![i as u8; 32]))
+        .collect();
+    let signatures: Vec<Bls12381G2Signature> = private_keys
+        .iter()
+        .zip(pub_keys_and_msgs.iter())
+        .map(|(sk, (_, msg))| sk.sign_v1(msg))
+        .collect();
+    let aggregate = aggregate_bls12381_signatures(&signatures).unwrap();
+
+    // Act
+    let valid: bool = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "bls12381_v1_aggregate_verify",
+        manifest_args!(pub_keys_and_msgs.clone(), aggregate),
+    );
+    let invalid: bool = call_crypto_scrypto(
+        &mut test_runner,
+        package_address,
+        "bls12381_v1_aggregate_verify",
+        manifest_args!(pub_keys_and_msgs[1..].to_vec(), aggregate),
+    );
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+fn test_bls12381_v1_aggregate_verify_cost_scales_with_number_of_keys() {
+    // Arrange
+    let fee_table = FeeTable::new();
+
+    // Act
+    let one_key = fee_table.bls12381_v1_aggregate_verify_cost(1, 32);
+    let ten_keys = fee_table.bls12381_v1_aggregate_verify_cost(10, 320);
+
+    // Assert
+    assert!(one_key >= fee_table.bls12381_v1_verify_cost(32));
+    assert_eq!(
+        ten_keys - one_key,
+        9 * fee_table.bls12381_v1_aggregate_verify_per_key_cost + 288 / 4
+    );
+}
@@ -5,7 +5,8 @@ use radix_engine::blueprints::resource::WorktopError;
 use radix_engine::errors::RuntimeError;
 use radix_engine::errors::{ApplicationError, CallFrameError, KernelError};
 use radix_engine::kernel::call_frame::OpenSubstateError;
-use radix_engine::transaction::{FeeLocks, TransactionReceipt};
+use radix_engine::system::system_modules::costing::{FeeTable, FeeTableError, VersionedFeeTable};
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, FeeLocks, TransactionReceipt};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -571,3 +572,77 @@ fn regular_and_contingent_fee_locks_are_correct_in_execution_trace() {
         }
     )
 }
+
+#[test]
+fn custom_fee_table_is_used_for_costing() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let mut execute_with_fee_table = |fee_table: FeeTable| {
+        let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+        let nonce = test_runner.next_transaction_nonce();
+        test_runner.execute_transaction(
+            TestTransaction::new_from_nonce(manifest, nonce)
+                .prepare()
+                .expect("expected transaction to be preparable")
+                .get_executable(btreeset!()),
+            CostingParameters::default(),
+            ExecutionConfig::for_test_transaction()
+                .with_fee_table(fee_table)
+                .unwrap(),
+        )
+    };
+    let default_fee_table = FeeTable::new();
+    let mut increased_fee_table = FeeTable::new();
+    increased_fee_table.validate_tx_payload_cost_per_byte *= 2;
+
+    // Act
+    let default_receipt = execute_with_fee_table(default_fee_table);
+    let increased_receipt = execute_with_fee_table(increased_fee_table);
+
+    // Assert
+    default_receipt.expect_commit_success();
+    increased_receipt.expect_commit_success();
+    assert!(
+        increased_receipt
+            .fee_summary
+            .total_execution_cost_units_consumed
+            > default_receipt
+                .fee_summary
+                .total_execution_cost_units_consumed
+    );
+}
+
+#[test]
+fn versioned_fee_table_can_be_loaded_from_sbor() {
+    // Arrange
+    let mut fee_table = FeeTable::new();
+    fee_table.system_call_base_cost = 1_000;
+    let encoded = scrypto_encode(&VersionedFeeTable::from(fee_table.clone())).unwrap();
+
+    // Act
+    let decoded = scrypto_decode::<VersionedFeeTable>(&encoded)
+        .unwrap()
+        .into_latest();
+
+    // Assert
+    assert_eq!(decoded, fee_table);
+    assert_ne!(decoded, FeeTable::new());
+}
+
+#[test]
+fn fee_table_with_zero_divisor_is_rejected() {
+    // Arrange
+    let mut fee_table = FeeTable::new();
+    fee_table.wasm_execution_units_per_cost_unit = 0;
+
+    // Act
+    let result = ExecutionConfig::for_test_transaction().with_fee_table(fee_table);
+
+    // Assert
+    assert_eq!(
+        result.err(),
+        Some(FeeTableError::ZeroDivisor(
+            "wasm_execution_units_per_cost_unit"
+        ))
+    );
+}
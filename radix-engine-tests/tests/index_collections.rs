@@ -0,0 +1,215 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn setup() -> (DefaultTestRunner, ComponentAddress) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("index_collections"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "IndexCollections", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+    (test_runner, component_address)
+}
+
+fn call_method<T: ScryptoDecode>(
+    test_runner: &mut DefaultTestRunner,
+    component_address: ComponentAddress,
+    method_name: &str,
+    args: ManifestArgs,
+) -> T {
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, method_name, args)
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_success().output(1)
+}
+
+#[test]
+fn can_insert_scan_and_remove_index_entries() {
+    // Arrange
+    let (mut test_runner, component_address) = setup();
+    for id in 0u32..5u32 {
+        call_method::<()>(
+            &mut test_runner,
+            component_address,
+            "insert_name",
+            manifest_args!(id, format!("name{}", id)),
+        );
+    }
+
+    // Act
+    let removed: Option<String> = call_method(
+        &mut test_runner,
+        component_address,
+        "remove_name",
+        manifest_args!(3u32),
+    );
+    let missing: Option<String> = call_method(
+        &mut test_runner,
+        component_address,
+        "remove_name",
+        manifest_args!(3u32),
+    );
+    let limited: Vec<u32> = call_method(
+        &mut test_runner,
+        component_address,
+        "scan_name_ids",
+        manifest_args!(2u32),
+    );
+    let mut ids: Vec<u32> = call_method(
+        &mut test_runner,
+        component_address,
+        "scan_name_ids",
+        manifest_args!(10u32),
+    );
+    ids.sort();
+
+    // Assert
+    assert_eq!(removed, Some("name3".to_string()));
+    assert_eq!(missing, None);
+    assert_eq!(limited.len(), 2);
+    assert_eq!(ids, vec![0, 1, 2, 4]);
+}
+
+#[test]
+fn can_drain_index_in_bounded_batches() {
+    // Arrange
+    let (mut test_runner, component_address) = setup();
+    for id in 0u32..5u32 {
+        call_method::<()>(
+            &mut test_runner,
+            component_address,
+            "insert_name",
+            manifest_args!(id, format!("name{}", id)),
+        );
+    }
+
+    // Act
+    let first: Vec<(u32, String)> = call_method(
+        &mut test_runner,
+        component_address,
+        "drain_names",
+        manifest_args!(3u32),
+    );
+    let second: Vec<(u32, String)> = call_method(
+        &mut test_runner,
+        component_address,
+        "drain_names",
+        manifest_args!(3u32),
+    );
+    let remaining: Vec<u32> = call_method(
+        &mut test_runner,
+        component_address,
+        "scan_name_ids",
+        manifest_args!(10u32),
+    );
+
+    // Assert
+    assert_eq!(first.len(), 3);
+    assert_eq!(second.len(), 2);
+    let mut drained: Vec<(u32, String)> = first.into_iter().chain(second).collect();
+    drained.sort();
+    assert_eq!(
+        drained,
+        (0u32..5u32)
+            .map(|id| (id, format!("name{}", id)))
+            .collect::<Vec<_>>()
+    );
+    assert!(remaining.is_empty());
+}
+
+#[test]
+fn sorted_index_scan_returns_entries_in_sort_prefix_order() {
+    // Arrange
+    let (mut test_runner, component_address) = setup();
+    for (price, order_id) in [(30u16, "c"), (10u16, "a"), (20u16, "b"), (40u16, "d")] {
+        call_method::<()>(
+            &mut test_runner,
+            component_address,
+            "insert_order",
+            manifest_args!(price, order_id.to_string(), Decimal::from(price)),
+        );
+    }
+
+    // Act
+    let all: Vec<(u16, String, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "scan_orders",
+        manifest_args!(10u32),
+    );
+    let first_two: Vec<(u16, String, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "scan_orders",
+        manifest_args!(2u32),
+    );
+
+    // Assert
+    assert_eq!(
+        all.iter().map(|(price, _, _)| *price).collect::<Vec<_>>(),
+        vec![10, 20, 30, 40]
+    );
+    assert_eq!(all[0], (10, "a".to_string(), dec!(10)));
+    assert_eq!(first_two, all[..2].to_vec());
+}
+
+#[test]
+fn sorted_index_range_is_bounded_by_sort_prefix_and_limit() {
+    // Arrange
+    let (mut test_runner, component_address) = setup();
+    for (price, order_id) in [(10u16, "a"), (20u16, "b"), (20u16, "c"), (30u16, "d")] {
+        call_method::<()>(
+            &mut test_runner,
+            component_address,
+            "insert_order",
+            manifest_args!(price, order_id.to_string(), dec!(1)),
+        );
+    }
+    let removed: Option<Decimal> = call_method(
+        &mut test_runner,
+        component_address,
+        "remove_order",
+        manifest_args!(30u16, "d".to_string()),
+    );
+
+    // Act
+    let in_range: Vec<(u16, String, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "orders_in_range",
+        manifest_args!(15u16, 40u16, 10u32),
+    );
+    let limited: Vec<(u16, String, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "orders_in_range",
+        manifest_args!(0u16, 40u16, 1u32),
+    );
+    let empty: Vec<(u16, String, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "orders_in_range",
+        manifest_args!(21u16, 30u16, 10u32),
+    );
+
+    // Assert
+    assert_eq!(removed, Some(dec!(1)));
+    let mut order_ids: Vec<String> = in_range.into_iter().map(|(_, id, _)| id).collect();
+    order_ids.sort();
+    assert_eq!(order_ids, vec!["b".to_string(), "c".to_string()]);
+    assert_eq!(limited, vec![(10, "a".to_string(), dec!(1))]);
+    assert!(empty.is_empty());
+}
@@ -1,4 +1,7 @@
 use radix_engine::types::*;
+use radix_engine_store_interface::interface::{
+    DbSortKey, ListableSubstateDatabase, SubstateDatabase,
+};
 use scrypto_unit::*;
 use transaction_scenarios::scenario::{NextAction, ScenarioCore};
 use transaction_scenarios::scenarios::get_builder_for_every_scenario;
@@ -36,3 +39,25 @@ fn substate_store_matches_hash_tree_after_each_scenario() {
         }
     }
 }
+
+#[test]
+fn substate_proofs_verify_against_state_hash() {
+    let mut test_runner = TestRunnerBuilder::new().with_state_hashing().build();
+    test_runner.new_allocated_account();
+    let state_hash = test_runner.get_state_hash();
+
+    let database = test_runner.substate_db();
+    for partition_key in database.list_partition_keys() {
+        for (sort_key, value) in database.list_entries(&partition_key) {
+            test_runner
+                .get_substate_proof(&partition_key, &sort_key)
+                .verify_value(&state_hash, &partition_key, &sort_key, Some(&value))
+                .unwrap();
+        }
+        let missing_sort_key = DbSortKey(vec![0xFF; 64]);
+        test_runner
+            .get_substate_proof(&partition_key, &missing_sort_key)
+            .verify_value(&state_hash, &partition_key, &missing_sort_key, None)
+            .unwrap();
+    }
+}
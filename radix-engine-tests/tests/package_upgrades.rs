@@ -0,0 +1,401 @@
+use radix_engine::blueprints::package::{PackageError, PackageUpgradedEvent};
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::package::CodeHash;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn basic_package_code() -> Vec<u8> {
+    wat2wasm(include_str!("wasm/basic_package.wat"))
+}
+
+/// All functions are mapped to the single `Test_f` export of the basic package.
+fn definition_with_functions(functions: Vec<&str>) -> PackageDefinition {
+    PackageDefinition::new_functions_only_test_definition(
+        "Test",
+        functions
+            .into_iter()
+            .map(|function| (function, "Test_f", false))
+            .collect(),
+    )
+}
+
+mod v1 {
+    use radix_engine::types::*;
+
+    #[derive(ScryptoSbor)]
+    pub enum Choice {
+        A,
+    }
+}
+
+mod v2 {
+    use radix_engine::types::*;
+
+    #[derive(ScryptoSbor)]
+    pub enum Choice {
+        A,
+        B,
+    }
+}
+
+/// A single function `f`, with the given input and output types.
+fn definition_with_function_types<I: ScryptoDescribe, O: ScryptoDescribe>() -> PackageDefinition {
+    let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+    let input = aggregator.add_child_type_and_descendents::<I>();
+    let output = aggregator.add_child_type_and_descendents::<O>();
+    let mut definition = definition_with_functions(vec!["f"]);
+    let blueprint = definition.blueprints.get_mut("Test").unwrap();
+    blueprint.schema.schema = generate_full_schema(aggregator);
+    let function = blueprint.schema.functions.functions.get_mut("f").unwrap();
+    function.input = TypeRef::Static(input);
+    function.output = TypeRef::Static(output);
+    definition
+}
+
+fn publish_upgradeable_package(
+    test_runner: &mut DefaultTestRunner,
+    definition: PackageDefinition,
+) -> (Secp256k1PublicKey, ComponentAddress, PackageAddress) {
+    let (public_key, _, account) = test_runner.new_account(false);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package_upgradeable(basic_package_code(), definition)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let package_address = receipt.expect_commit(true).new_package_addresses()[0];
+
+    (public_key, account, package_address)
+}
+
+fn owner_badge_of(package_address: PackageAddress) -> NonFungibleLocalId {
+    NonFungibleLocalId::bytes(package_address.as_node_id().0).unwrap()
+}
+
+fn upgrade_package(
+    test_runner: &mut DefaultTestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    package_address: PackageAddress,
+    definition: PackageDefinition,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            PACKAGE_OWNER_BADGE,
+            [owner_badge_of(package_address)],
+        )
+        .upgrade_package(package_address, basic_package_code(), definition)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    )
+}
+
+fn call_test_function(
+    test_runner: &mut DefaultTestRunner,
+    package_address: PackageAddress,
+    function: &str,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Test", function, manifest_args!())
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn is_package_error(error: &RuntimeError, f: impl Fn(&PackageError) -> bool) -> bool {
+    match error {
+        RuntimeError::ApplicationError(ApplicationError::PackageError(e)) => f(e),
+        _ => false,
+    }
+}
+
+#[test]
+fn owner_can_upgrade_package_with_compatible_definition() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, account, package_address) =
+        publish_upgradeable_package(&mut test_runner, definition_with_functions(vec!["f"]));
+    call_test_function(&mut test_runner, package_address, "g").expect_commit_failure();
+
+    // Act
+    let receipt = upgrade_package(
+        &mut test_runner,
+        public_key,
+        account,
+        package_address,
+        definition_with_functions(vec!["f", "g"]),
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let (_, event_data) = commit
+        .application_events
+        .iter()
+        .find(|(event_type_identifier, _)| {
+            test_runner.is_event_name_equal::<PackageUpgradedEvent>(event_type_identifier)
+        })
+        .expect("Upgrade should emit an event");
+    let event: PackageUpgradedEvent = scrypto_decode(event_data).unwrap();
+    assert_eq!(event.version, 1);
+    assert_eq!(
+        event.code_hash,
+        CodeHash::from_hash(hash(&basic_package_code()))
+    );
+
+    call_test_function(&mut test_runner, package_address, "f").expect_commit_success();
+    call_test_function(&mut test_runner, package_address, "g").expect_commit_success();
+}
+
+#[test]
+fn upgraded_blueprint_can_be_called_within_the_upgrade_transaction() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, account, package_address) =
+        publish_upgradeable_package(&mut test_runner, definition_with_functions(vec!["f"]));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Test", "f", manifest_args!())
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            PACKAGE_OWNER_BADGE,
+            [owner_badge_of(package_address)],
+        )
+        .upgrade_package(
+            package_address,
+            basic_package_code(),
+            definition_with_functions(vec!["f", "g"]),
+        )
+        .call_function(package_address, "Test", "g", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_upgrade_package_without_owner_badge() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, package_address) =
+        publish_upgradeable_package(&mut test_runner, definition_with_functions(vec!["f"]));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .upgrade_package(
+            package_address,
+            basic_package_code(),
+            definition_with_functions(vec!["f", "g"]),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_upgrade_package_published_without_upgrades() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package(basic_package_code(), definition_with_functions(vec!["f"]))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let package_address = receipt.expect_commit(true).new_package_addresses()[0];
+
+    // Act
+    let receipt = upgrade_package(
+        &mut test_runner,
+        public_key,
+        account,
+        package_address,
+        definition_with_functions(vec!["f", "g"]),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_package_error(e, |e| matches!(e, PackageError::UpgradesNotEnabled))
+    });
+}
+
+#[test]
+fn cannot_upgrade_package_by_removing_a_function() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, account, package_address) =
+        publish_upgradeable_package(&mut test_runner, definition_with_functions(vec!["f", "g"]));
+
+    // Act
+    let receipt = upgrade_package(
+        &mut test_runner,
+        public_key,
+        account,
+        package_address,
+        definition_with_functions(vec!["f"]),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_package_error(e, |e| {
+            matches!(e, PackageError::IncompatibleBlueprintUpgrade { blueprint, .. } if blueprint == "Test")
+        })
+    });
+    call_test_function(&mut test_runner, package_address, "g").expect_commit_success();
+}
+
+#[test]
+fn cannot_upgrade_package_by_adding_a_field() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, account, package_address) =
+        publish_upgradeable_package(&mut test_runner, definition_with_functions(vec!["f"]));
+
+    // Act
+    let receipt = upgrade_package(
+        &mut test_runner,
+        public_key,
+        account,
+        package_address,
+        PackageDefinition::new_with_field_test_definition("Test", vec![("f", "Test_f", false)]),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_package_error(e, |e| {
+            matches!(e, PackageError::IncompatibleBlueprintUpgrade { .. })
+        })
+    });
+}
+
+#[test]
+fn cannot_upgrade_package_by_removing_a_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, account, package_address) =
+        publish_upgradeable_package(&mut test_runner, definition_with_functions(vec!["f"]));
+
+    // Act
+    let receipt = upgrade_package(
+        &mut test_runner,
+        public_key,
+        account,
+        package_address,
+        PackageDefinition::new_single_function_test_definition("Other", "f"),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_package_error(e, |e| {
+            matches!(e, PackageError::UpgradeRemovesBlueprint(blueprint) if blueprint == "Test")
+        })
+    });
+}
+
+#[test]
+fn cannot_upgrade_package_after_upgrades_are_locked() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, account, package_address) =
+        publish_upgradeable_package(&mut test_runner, definition_with_functions(vec!["f"]));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            PACKAGE_OWNER_BADGE,
+            [owner_badge_of(package_address)],
+        )
+        .lock_package_upgrades(package_address)
+        .build();
+    test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = upgrade_package(
+        &mut test_runner,
+        public_key,
+        account,
+        package_address,
+        definition_with_functions(vec!["f", "g"]),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_package_error(e, |e| matches!(e, PackageError::UpgradesLocked))
+    });
+    call_test_function(&mut test_runner, package_address, "f").expect_commit_success();
+}
+
+#[test]
+fn owner_can_upgrade_package_by_extending_a_function_input() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, account, package_address) = publish_upgradeable_package(
+        &mut test_runner,
+        definition_with_function_types::<(v1::Choice,), ()>(),
+    );
+
+    // Act
+    let receipt = upgrade_package(
+        &mut test_runner,
+        public_key,
+        account,
+        package_address,
+        definition_with_function_types::<(v2::Choice,), ()>(),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cannot_upgrade_package_by_extending_a_function_output() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, account, package_address) = publish_upgradeable_package(
+        &mut test_runner,
+        definition_with_function_types::<(), v1::Choice>(),
+    );
+
+    // Act
+    let receipt = upgrade_package(
+        &mut test_runner,
+        public_key,
+        account,
+        package_address,
+        definition_with_function_types::<(), v2::Choice>(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_package_error(e, |e| {
+            matches!(e, PackageError::IncompatibleBlueprintUpgrade { blueprint, .. } if blueprint == "Test")
+        })
+    });
+}
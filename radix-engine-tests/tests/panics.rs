@@ -137,6 +137,7 @@ impl KernelSubstateApi<SystemLockData> for MockKernel {
         &mut self,
         _: &NodeId,
         _: PartitionNumber,
+        _: &SortedKeyRange,
         _: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         panic1!()
@@ -1,6 +1,12 @@
+use radix_engine::errors::{RejectionReason, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::{AuthError, SkippedAuthRoles};
 use radix_engine::system::system_modules::costing::FeeTable;
 use radix_engine::transaction::CostingParameters;
 use radix_engine::transaction::ExecutionConfig;
+use radix_engine::transaction::{
+    PreviewAssertion, PreviewAssertionError, PreviewBundleTransaction, PreviewError,
+    PreviewOverrides, StateOverride, StateOverrideError,
+};
 use radix_engine::types::*;
 use radix_engine_interface::rule;
 use scrypto_unit::*;
@@ -143,6 +149,365 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
     result.unwrap().expect_commit_success();
 }
 
+#[test]
+fn test_preview_with_skipped_owner_role_method_authorization() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+
+    let public_key = Secp256k1PrivateKey::from_u64(99).unwrap().public_key();
+    let withdraw_auth = rule!(require(NonFungibleGlobalId::from_public_key(&public_key)));
+    let account = test_runner.new_account_advanced(OwnerRole::Fixed(withdraw_auth));
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: false,
+        skip_epoch_check: false,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .withdraw_from_account(account, XRD, 1)
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &preview_flags,
+    );
+
+    // Act
+    let receipt = test_runner
+        .preview(preview_intent.clone(), &network)
+        .unwrap();
+    let receipt_with_skipped_role = test_runner
+        .preview_with_overrides(
+            preview_intent,
+            &network,
+            &PreviewOverrides::new().with_skipped_auth_roles(
+                SkippedAuthRoles::new().skip_entity_role(account, OWNER_ROLE),
+            ),
+        )
+        .unwrap();
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionReason::ErrorBeforeLoanAndDeferredCostsRepaid(
+                RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                    AuthError::Unauthorized(..)
+                ))
+            )
+        )
+    });
+    receipt_with_skipped_role.expect_commit_success();
+}
+
+#[test]
+fn test_preview_with_fungible_vault_balance_override() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let vault_id = test_runner.get_component_vaults(account, XRD)[0];
+    let balance_before = test_runner.get_component_balance(account, XRD);
+
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .withdraw_from_account(account, XRD, 1_000_000)
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &preview_flags,
+    );
+
+    // Act
+    let receipt = test_runner
+        .preview_with_overrides(
+            preview_intent,
+            &network,
+            &PreviewOverrides::new().with_state_override(StateOverride::FungibleVaultBalance {
+                vault_id: InternalAddress::new_or_panic(vault_id.0),
+                amount: dec!("2000000"),
+            }),
+        )
+        .unwrap();
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, XRD),
+        balance_before
+    );
+}
+
+#[test]
+fn test_preview_assertions_are_evaluated_against_the_resulting_state() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let vault_id =
+        InternalAddress::new_or_panic(test_runner.get_component_vaults(account, XRD)[0].0);
+
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1_000_000)
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &preview_flags,
+    );
+
+    // Act
+    let (receipt, assertion_results) = test_runner
+        .preview_with_assertions(
+            preview_intent,
+            &network,
+            &PreviewOverrides::new().with_state_override(StateOverride::FungibleVaultBalance {
+                vault_id,
+                amount: dec!("2000000"),
+            }),
+            &[
+                PreviewAssertion::FungibleVaultBalance {
+                    vault_id,
+                    amount: dec!("1000000"),
+                },
+                PreviewAssertion::FungibleVaultBalance {
+                    vault_id,
+                    amount: dec!("2000000"),
+                },
+            ],
+        )
+        .unwrap();
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        assertion_results,
+        vec![
+            Ok(()),
+            Err(PreviewAssertionError::FungibleVaultBalanceMismatch {
+                vault_id,
+                actual: dec!("1000000"),
+            })
+        ]
+    );
+}
+
+#[test]
+fn test_preview_with_fungible_vault_balance_override_of_non_fungible_vault_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &PreviewFlags::default(),
+    );
+    let non_fungible_resource = test_runner.create_non_fungible_resource(account);
+    let non_fungible_vault_id = test_runner.get_component_vaults(account, non_fungible_resource)[0];
+    let not_a_fungible_vault = InternalAddress::new_or_panic(non_fungible_vault_id.0);
+
+    // Act
+    let result = test_runner.preview_with_overrides(
+        preview_intent,
+        &network,
+        &PreviewOverrides::new().with_state_override(StateOverride::FungibleVaultBalance {
+            vault_id: not_a_fungible_vault,
+            amount: dec!("1"),
+        }),
+    );
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(PreviewError::StateOverrideError(
+            StateOverrideError::NotAFungibleVault(not_a_fungible_vault)
+        ))
+    );
+}
+
+#[test]
+fn test_preview_with_epoch_override() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+    let (_, preview_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &PreviewFlags::default(),
+    );
+    let epoch_before = test_runner.get_current_epoch();
+
+    // Act
+    let receipt = test_runner
+        .preview_with_overrides(
+            preview_intent,
+            &network,
+            &PreviewOverrides::new().with_state_override(StateOverride::Epoch(Epoch::of(100))),
+        )
+        .unwrap();
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(e, RejectionReason::TransactionEpochNoLongerValid { .. })
+    });
+    assert_eq!(test_runner.get_current_epoch(), epoch_before);
+}
+
+#[test]
+fn test_preview_bundle_transactions_see_previous_state_updates() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let balance_before = test_runner.get_component_balance(account, XRD);
+
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+    };
+    let (_, top_up_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        &preview_flags,
+    );
+    let (_, withdraw_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, balance_before.checked_add(1).unwrap())
+            .try_deposit_entire_worktop_or_abort(other_account, None)
+            .build(),
+        &preview_flags,
+    );
+
+    // Act
+    let single_receipt = test_runner
+        .preview(withdraw_intent.clone(), &network)
+        .unwrap();
+    let bundle_receipts = test_runner
+        .preview_bundle(vec![top_up_intent.into(), withdraw_intent.into()], &network)
+        .unwrap();
+
+    // Assert
+    single_receipt.expect_commit_failure();
+    assert_eq!(bundle_receipts.len(), 2);
+    bundle_receipts[0].expect_commit_success();
+    bundle_receipts[1].expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, XRD),
+        balance_before
+    );
+}
+
+#[test]
+fn test_preview_bundle_of_manifests_see_previous_state_updates() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let balance_before = test_runner.get_component_balance(account, XRD);
+
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+    };
+    let top_up = PreviewBundleTransaction::Manifest {
+        manifest: ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        signer_public_keys: vec![],
+        flags: preview_flags.clone(),
+    };
+    let withdraw = PreviewBundleTransaction::Manifest {
+        manifest: ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, balance_before.checked_add(1).unwrap())
+            .try_deposit_entire_worktop_or_abort(other_account, None)
+            .build(),
+        signer_public_keys: vec![],
+        flags: preview_flags,
+    };
+
+    // Act
+    let bundle_receipts = test_runner
+        .preview_bundle(vec![top_up, withdraw], &network)
+        .unwrap();
+
+    // Assert
+    assert_eq!(bundle_receipts.len(), 2);
+    bundle_receipts[0].expect_commit_success();
+    bundle_receipts[1].expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, XRD),
+        balance_before
+    );
+}
+
+#[test]
+fn test_preview_bundle_reports_index_of_invalid_transaction() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, valid_intent) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        ManifestBuilder::new().lock_fee_from_faucet().build(),
+        &PreviewFlags::default(),
+    );
+    let mut invalid_intent = valid_intent.clone();
+    invalid_intent.intent.header.network_id = NetworkDefinition::mainnet().id;
+
+    // Act
+    let result =
+        test_runner.preview_bundle(vec![valid_intent.into(), invalid_intent.into()], &network);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(PreviewError::BundleTransactionValidationError { index: 1, .. })
+    ));
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     test_runner: &mut DefaultTestRunner,
     network: &NetworkDefinition,
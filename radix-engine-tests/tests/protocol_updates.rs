@@ -0,0 +1,300 @@
+use radix_engine::blueprints::consensus_manager::*;
+use radix_engine::system::protocol_updates::*;
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::system::system_substates::{FieldSubstate, LockStatus};
+use radix_engine::types::*;
+use radix_engine_interface::api::ModuleId;
+use radix_engine_store_interface::interface::DatabaseUpdate;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn read_consensus_manager_config(test_runner: &DefaultTestRunner) -> ConsensusManagerConfig {
+    SystemDatabaseReader::new(test_runner.substate_db())
+        .read_typed_object_field::<ConsensusManagerConfigurationFieldPayload>(
+            CONSENSUS_MANAGER.as_node_id(),
+            ModuleId::Main,
+            ConsensusManagerField::Configuration.field_index(),
+        )
+        .unwrap()
+        .into_latest()
+        .config
+}
+
+/// An epoch change into the given epoch, where the whole current validator set signalled the
+/// readiness for the given protocol updates.
+fn epoch_change_signalling(
+    test_runner: &DefaultTestRunner,
+    epoch: Epoch,
+    protocol_update_names: &[&str],
+) -> EpochChangeEvent {
+    let validator_set = SystemDatabaseReader::new(test_runner.substate_db())
+        .read_typed_object_field::<ConsensusManagerCurrentValidatorSetFieldPayload>(
+            CONSENSUS_MANAGER.as_node_id(),
+            ModuleId::Main,
+            ConsensusManagerField::CurrentValidatorSet.field_index(),
+        )
+        .unwrap()
+        .into_latest()
+        .validator_set;
+    let total_stake = validator_set
+        .validators_by_stake_desc
+        .values()
+        .fold(Decimal::zero(), |total, validator| total + validator.stake);
+
+    EpochChangeEvent {
+        epoch,
+        validator_set,
+        significant_protocol_update_readiness: protocol_update_names
+            .iter()
+            .map(|name| (name.to_string(), total_stake))
+            .collect(),
+    }
+}
+
+fn max_validators_update(
+    config: ConsensusManagerConfig,
+    max_validators: u32,
+) -> IndexMap<(NodeId, PartitionNumber), IndexMap<SubstateKey, DatabaseUpdate>> {
+    let config = ConsensusManagerConfig {
+        max_validators,
+        ..config
+    };
+    let substate = FieldSubstate::new_field(
+        ConsensusManagerConfigurationFieldPayload::from_content_source(
+            ConsensusManagerConfigSubstate { config },
+        ),
+        LockStatus::Locked,
+    );
+
+    indexmap!(
+        (CONSENSUS_MANAGER.into_node_id(), MAIN_BASE_PARTITION) => indexmap!(
+            SubstateKey::Field(ConsensusManagerField::Configuration.field_index())
+                => DatabaseUpdate::Set(scrypto_encode(&substate).unwrap())
+        )
+    )
+}
+
+fn create_resource_transaction() -> SystemTransactionV1 {
+    let manifest = ManifestBuilder::new()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            18,
+            FungibleResourceRoles::default(),
+            metadata!(),
+            None,
+        )
+        .build();
+
+    SystemTransactionV1 {
+        instructions: InstructionsV1(manifest.instructions),
+        blobs: BlobsV1 { blobs: vec![] },
+        hash_for_execution: hash("Protocol update test resource"),
+        pre_allocated_addresses: vec![],
+    }
+}
+
+#[test]
+fn protocol_update_applies_flashes_and_system_transactions_in_order() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let epoch = test_runner.get_current_epoch();
+    let config = read_consensus_manager_config(&test_runner);
+    let protocol_update = ProtocolUpdate::new("v2")
+        .with_flash(
+            "increase_max_validators",
+            max_validators_update(config.clone(), config.max_validators + 10),
+        )
+        .with_system_transaction("create_resource", create_resource_transaction());
+
+    let epoch_change = epoch_change_signalling(&test_runner, epoch, &["v2"]);
+
+    // Act
+    let receipt = test_runner
+        .execute_protocol_update(&protocol_update, &epoch_change)
+        .unwrap();
+
+    // Assert
+    assert_eq!(receipt.protocol_update_name, "v2");
+    assert_eq!(receipt.epoch, epoch);
+    let action_names: Vec<&str> = receipt
+        .action_receipts
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(
+        action_names,
+        vec!["increase_max_validators", "create_resource"]
+    );
+
+    let flash_commit = receipt.action_receipts[0].1.expect_commit_success();
+    assert!(!flash_commit.state_updates.by_node.is_empty());
+    let transaction_commit = receipt.action_receipts[1].1.expect_commit_success();
+    assert_eq!(transaction_commit.new_resource_addresses().len(), 1);
+
+    assert_eq!(
+        read_consensus_manager_config(&test_runner).max_validators,
+        config.max_validators + 10
+    );
+    assert_eq!(
+        read_applied_protocol_updates(test_runner.substate_db()).applied,
+        vec![AppliedProtocolUpdate {
+            name: "v2".to_string(),
+            epoch,
+        }]
+    );
+}
+
+#[test]
+fn protocol_update_at_wrong_epoch_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let epoch = test_runner.get_current_epoch();
+    let config = read_consensus_manager_config(&test_runner);
+    let protocol_update = ProtocolUpdate::new("v2").with_flash(
+        "increase_max_validators",
+        max_validators_update(config.clone(), config.max_validators + 10),
+    );
+
+    let epoch_change = epoch_change_signalling(&test_runner, epoch.next().unwrap(), &["v2"]);
+
+    // Act
+    let result = test_runner.execute_protocol_update(&protocol_update, &epoch_change);
+
+    // Assert
+    assert_eq!(
+        result.unwrap_err(),
+        ProtocolUpdateError::NotAtEpochBoundary {
+            expected_epoch: epoch.next().unwrap(),
+            actual_epoch: epoch,
+            actual_round: Round::zero(),
+        }
+    );
+    assert_eq!(read_consensus_manager_config(&test_runner), config);
+}
+
+#[test]
+fn protocol_update_after_the_first_round_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let epoch = test_runner.get_current_epoch();
+    let config = read_consensus_manager_config(&test_runner);
+    test_runner
+        .advance_to_round(Round::of(1))
+        .expect_commit_success();
+    let protocol_update = ProtocolUpdate::new("v2").with_flash(
+        "increase_max_validators",
+        max_validators_update(config.clone(), config.max_validators + 10),
+    );
+
+    let epoch_change = epoch_change_signalling(&test_runner, epoch, &["v2"]);
+
+    // Act
+    let result = test_runner.execute_protocol_update(&protocol_update, &epoch_change);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ProtocolUpdateError::NotAtEpochBoundary { .. })
+    ));
+    assert_eq!(read_consensus_manager_config(&test_runner), config);
+}
+
+#[test]
+fn protocol_update_without_readiness_signal_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let epoch = test_runner.get_current_epoch();
+    let config = read_consensus_manager_config(&test_runner);
+    let protocol_update = ProtocolUpdate::new("v2").with_flash(
+        "increase_max_validators",
+        max_validators_update(config.clone(), config.max_validators + 10),
+    );
+    let epoch_change = epoch_change_signalling(&test_runner, epoch, &["v3"]);
+
+    // Act
+    let result = test_runner.execute_protocol_update(&protocol_update, &epoch_change);
+
+    // Assert
+    assert_eq!(
+        result.unwrap_err(),
+        ProtocolUpdateError::ReadinessNotSignalled {
+            protocol_update_name: "v2".to_string(),
+            epoch,
+        }
+    );
+    assert_eq!(read_consensus_manager_config(&test_runner), config);
+}
+
+#[test]
+fn protocol_update_cannot_be_applied_twice() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let epoch = test_runner.get_current_epoch();
+    let config = read_consensus_manager_config(&test_runner);
+    let protocol_update = ProtocolUpdate::new("v2").with_flash(
+        "increase_max_validators",
+        max_validators_update(config.clone(), config.max_validators + 10),
+    );
+    let epoch_change = epoch_change_signalling(&test_runner, epoch, &["v2"]);
+    test_runner
+        .execute_protocol_update(&protocol_update, &epoch_change)
+        .unwrap();
+
+    // Act
+    let result = test_runner.execute_protocol_update(&protocol_update, &epoch_change);
+
+    // Assert
+    assert_eq!(
+        result.unwrap_err(),
+        ProtocolUpdateError::AlreadyApplied {
+            protocol_update_name: "v2".to_string(),
+            epoch,
+        }
+    );
+    assert_eq!(
+        read_applied_protocol_updates(test_runner.substate_db())
+            .applied
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn protocol_update_must_follow_the_last_applied_update() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let epoch = test_runner.get_current_epoch();
+    let config = read_consensus_manager_config(&test_runner);
+    let v2 = ProtocolUpdate::new("v2").with_flash(
+        "increase_max_validators",
+        max_validators_update(config.clone(), config.max_validators + 10),
+    );
+    let v3 = ProtocolUpdate::new("v3").after("v2").with_flash(
+        "increase_max_validators_again",
+        max_validators_update(config.clone(), config.max_validators + 20),
+    );
+    let epoch_change = epoch_change_signalling(&test_runner, epoch, &["v2", "v3"]);
+
+    // Act
+    let out_of_order_result = test_runner.execute_protocol_update(&v3, &epoch_change);
+    test_runner
+        .execute_protocol_update(&v2, &epoch_change)
+        .unwrap();
+    let in_order_result = test_runner.execute_protocol_update(&v3, &epoch_change);
+
+    // Assert
+    assert_eq!(
+        out_of_order_result.unwrap_err(),
+        ProtocolUpdateError::OutOfOrder {
+            protocol_update_name: "v3".to_string(),
+            expected_previous: Some("v2".to_string()),
+            actual_previous: None,
+        }
+    );
+    assert!(in_order_result.is_ok());
+    assert_eq!(
+        read_consensus_manager_config(&test_runner).max_validators,
+        config.max_validators + 20
+    );
+}
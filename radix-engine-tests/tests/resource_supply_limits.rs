@@ -0,0 +1,554 @@
+use radix_engine::blueprints::resource::SupplyLimitsError;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn create_fungible_with_supply_limits(
+    test_runner: &mut DefaultTestRunner,
+    initial_supply: Decimal,
+    supply_limits: ResourceSupplyLimits,
+    lock_supply_limits: bool,
+    account: ComponentAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            RESOURCE_PACKAGE,
+            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT,
+            FungibleResourceManagerCreateWithSupplyLimitsManifestInput {
+                owner_role: OwnerRole::None,
+                divisibility: 18,
+                initial_supply,
+                supply_limits,
+                lock_supply_limits,
+                resource_roles: FungibleResourceRoles {
+                    mint_roles: mint_roles! {
+                        minter => rule!(allow_all);
+                        minter_updater => rule!(allow_all);
+                    },
+                    burn_roles: burn_roles! {
+                        burner => rule!(allow_all);
+                        burner_updater => rule!(deny_all);
+                    },
+                    ..Default::default()
+                },
+                metadata: metadata!(),
+                address_reservation: None,
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn create_non_fungible_with_supply_limits(
+    test_runner: &mut DefaultTestRunner,
+    supply_limits: ResourceSupplyLimits,
+    account: ComponentAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            RESOURCE_PACKAGE,
+            NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT,
+            NonFungibleResourceManagerCreateWithSupplyLimitsManifestInput {
+                owner_role: OwnerRole::None,
+                id_type: NonFungibleIdType::Integer,
+                non_fungible_schema:
+                    NonFungibleDataSchema::new_local_without_self_package_replacement::<
+                        EmptyNonFungibleData,
+                    >(),
+                entries: indexmap!(
+                    NonFungibleLocalId::integer(1) =>
+                        (to_manifest_value_and_unwrap!(&EmptyNonFungibleData {}),)
+                ),
+                supply_limits,
+                lock_supply_limits: true,
+                resource_roles: NonFungibleResourceRoles {
+                    mint_roles: mint_roles! {
+                        minter => rule!(allow_all);
+                        minter_updater => rule!(deny_all);
+                    },
+                    ..Default::default()
+                },
+                metadata: metadata!(),
+                address_reservation: None,
+            },
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn mint(
+    test_runner: &mut DefaultTestRunner,
+    resource_address: ResourceAddress,
+    amount: Decimal,
+    account: ComponentAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(resource_address, amount)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn set_supply_limits(
+    test_runner: &mut DefaultTestRunner,
+    resource_address: ResourceAddress,
+    supply_limits: ResourceSupplyLimits,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            resource_address,
+            RESOURCE_MANAGER_SET_SUPPLY_LIMITS_IDENT,
+            ResourceManagerSetSupplyLimitsInput { supply_limits },
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn is_supply_limits_error(e: &RuntimeError, f: fn(&SupplyLimitsError) -> bool) -> bool {
+    match e {
+        RuntimeError::ApplicationError(ApplicationError::SupplyLimitsError(error)) => f(error),
+        _ => false,
+    }
+}
+
+#[test]
+fn can_mint_up_to_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(40),
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(100)),
+            mint_allowance: None,
+        },
+        false,
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    // Act
+    let receipt = mint(&mut test_runner, resource_address, dec!(60), account);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(100)
+    );
+}
+
+#[test]
+fn cannot_mint_beyond_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(40),
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(100)),
+            mint_allowance: None,
+        },
+        false,
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    // Act
+    let receipt = mint(&mut test_runner, resource_address, dec!(61), account);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::MaxSupplyExceeded { .. })
+        })
+    });
+}
+
+#[test]
+fn cannot_create_with_initial_supply_above_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(101),
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(100)),
+            mint_allowance: None,
+        },
+        false,
+        account,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::MaxSupplyBelowTotalSupply { .. })
+        })
+    });
+}
+
+#[test]
+fn cannot_create_with_zero_period_mint_allowance() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(0),
+        ResourceSupplyLimits {
+            max_supply: None,
+            mint_allowance: Some(MintAllowance {
+                amount: dec!(10),
+                period_in_epochs: 0,
+            }),
+        },
+        false,
+        account,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::InvalidSupplyLimits(..))
+        })
+    });
+}
+
+#[test]
+fn burning_frees_room_below_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(100),
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(100)),
+            mint_allowance: None,
+        },
+        false,
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource_address, dec!(30))
+        .burn_all_from_worktop(resource_address)
+        .build();
+    test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = mint(&mut test_runner, resource_address, dec!(30), account);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(100)
+    );
+}
+
+#[test]
+fn mint_allowance_is_enforced_per_period() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(1000),
+        ResourceSupplyLimits {
+            max_supply: None,
+            mint_allowance: Some(MintAllowance {
+                amount: dec!(10),
+                period_in_epochs: 5,
+            }),
+        },
+        false,
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+    let start_epoch = test_runner.get_current_epoch();
+    mint(&mut test_runner, resource_address, dec!(6), account).expect_commit_success();
+
+    // Act & Assert
+    mint(&mut test_runner, resource_address, dec!(5), account).expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::MintAllowanceExceeded { .. })
+        })
+    });
+    mint(&mut test_runner, resource_address, dec!(4), account).expect_commit_success();
+
+    test_runner.set_current_epoch(start_epoch.after(4).unwrap());
+    mint(&mut test_runner, resource_address, dec!(1), account).expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::MintAllowanceExceeded { .. })
+        })
+    });
+
+    test_runner.set_current_epoch(start_epoch.after(5).unwrap());
+    mint(&mut test_runner, resource_address, dec!(10), account).expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(1020)
+    );
+}
+
+#[test]
+fn can_update_supply_limits_and_read_them_back() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(50),
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(50)),
+            mint_allowance: None,
+        },
+        false,
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+    let supply_limits = ResourceSupplyLimits {
+        max_supply: Some(dec!(80)),
+        mint_allowance: Some(MintAllowance {
+            amount: dec!(20),
+            period_in_epochs: 1,
+        }),
+    };
+
+    // Act
+    set_supply_limits(&mut test_runner, resource_address, supply_limits.clone())
+        .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            resource_address,
+            RESOURCE_MANAGER_GET_SUPPLY_LIMITS_IDENT,
+            ResourceManagerGetSupplyLimitsInput {},
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let output: ResourceManagerGetSupplyLimitsOutput = receipt.expect_commit_success().output(1);
+    assert_eq!(output, Some(supply_limits));
+    mint(&mut test_runner, resource_address, dec!(20), account).expect_commit_success();
+}
+
+#[test]
+fn cannot_set_max_supply_below_total_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(50),
+        ResourceSupplyLimits::default(),
+        false,
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    // Act
+    let receipt = set_supply_limits(
+        &mut test_runner,
+        resource_address,
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(49)),
+            mint_allowance: None,
+        },
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::MaxSupplyBelowTotalSupply { .. })
+        })
+    });
+}
+
+#[test]
+fn cannot_update_locked_supply_limits() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(50),
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(100)),
+            mint_allowance: None,
+        },
+        false,
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            resource_address,
+            RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_IDENT,
+            ResourceManagerLockSupplyLimitsInput {},
+        )
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let receipt = set_supply_limits(
+        &mut test_runner,
+        resource_address,
+        ResourceSupplyLimits::default(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::SystemError(SystemError::FieldLocked(..)))
+    });
+}
+
+#[test]
+fn cannot_update_supply_limits_locked_at_creation() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = create_fungible_with_supply_limits(
+        &mut test_runner,
+        dec!(50),
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(100)),
+            mint_allowance: None,
+        },
+        true,
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    // Act
+    let receipt = set_supply_limits(
+        &mut test_runner,
+        resource_address,
+        ResourceSupplyLimits::default(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::SystemError(SystemError::FieldLocked(..)))
+    });
+}
+
+#[test]
+fn cannot_set_supply_limits_on_resource_created_without_them() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_freely_mintable_fungible_resource(OwnerRole::None, None, 18, account);
+
+    // Act
+    let receipt = set_supply_limits(
+        &mut test_runner,
+        resource_address,
+        ResourceSupplyLimits::default(),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::SupplyLimitsNotEnabled)
+        })
+    });
+}
+
+#[test]
+fn cannot_mint_non_fungibles_beyond_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = create_non_fungible_with_supply_limits(
+        &mut test_runner,
+        ResourceSupplyLimits {
+            max_supply: Some(dec!(2)),
+            mint_allowance: None,
+        },
+        account,
+    );
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_non_fungible(
+            resource_address,
+            [(NonFungibleLocalId::integer(2), EmptyNonFungibleData {})],
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_non_fungible(
+            resource_address,
+            [(NonFungibleLocalId::integer(3), EmptyNonFungibleData {})],
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::MaxSupplyExceeded { .. })
+        })
+    });
+}
+
+#[test]
+fn cannot_create_non_fungible_with_fractional_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt = create_non_fungible_with_supply_limits(
+        &mut test_runner,
+        ResourceSupplyLimits {
+            max_supply: Some(dec!("1.5")),
+            mint_allowance: None,
+        },
+        account,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        is_supply_limits_error(e, |e| {
+            matches!(e, SupplyLimitsError::InvalidSupplyLimits(..))
+        })
+    });
+}
@@ -242,6 +242,7 @@ fn typed_native_event_type_contains_all_native_events() {
         "Identity" => IDENTITY_PACKAGE_DEFINITION.deref(),
         "AccessController" => ACCESS_CONTROLLER_PACKAGE_DEFINITION.deref(),
         "Pool" => POOL_PACKAGE_DEFINITION.deref(),
+        "Locker" => LOCKER_PACKAGE_DEFINITION.deref(),
         "TransactionTracker" => TRANSACTION_TRACKER_PACKAGE_DEFINITION.deref(),
         "Resource" => RESOURCE_PACKAGE_DEFINITION.deref(),
         "Package" => PACKAGE_PACKAGE_DEFINITION.deref(),
@@ -0,0 +1,200 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::kernel::kernel_api::KernelInvocation;
+use radix_engine::kernel::kernel_callback_api::CreateNodeEvent;
+use radix_engine::system::actor::Actor;
+use radix_engine::system::system_modules::transaction_runtime::Event;
+use radix_engine::system::system_modules::user_modules::UserSystemModule;
+use radix_engine::system::system_modules::EnabledModules;
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use std::sync::{Arc, Mutex};
+use transaction::prelude::*;
+
+#[derive(Default)]
+struct CountingModule {
+    initialized: bool,
+    torn_down: bool,
+    depth: usize,
+    max_depth: usize,
+    invocations: usize,
+    created_nodes: usize,
+    emitted_events: usize,
+}
+
+impl UserSystemModule for CountingModule {
+    fn on_init(&mut self) -> Result<(), String> {
+        self.initialized = true;
+        Ok(())
+    }
+
+    fn on_teardown(&mut self) -> Result<(), String> {
+        self.torn_down = true;
+        Ok(())
+    }
+
+    fn before_invoke(&mut self, _invocation: &KernelInvocation<Actor>) -> Result<(), String> {
+        self.invocations += 1;
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+        Ok(())
+    }
+
+    fn after_invoke(&mut self, _output: &IndexedScryptoValue) -> Result<(), String> {
+        self.depth -= 1;
+        Ok(())
+    }
+
+    fn on_create_node(&mut self, _event: &CreateNodeEvent) -> Result<(), String> {
+        self.created_nodes += 1;
+        Ok(())
+    }
+
+    fn on_emit_event(&mut self, _event: &Event) -> Result<(), String> {
+        self.emitted_events += 1;
+        Ok(())
+    }
+}
+
+struct NoDepositsChecker;
+
+impl UserSystemModule for NoDepositsChecker {
+    fn on_emit_event(&mut self, event: &Event) -> Result<(), String> {
+        if event.type_identifier.1 == "DepositEvent" {
+            Err(format!("Unexpected deposit: {:?}", event.type_identifier))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn execute_with_config(
+    test_runner: &mut DefaultTestRunner,
+    execution_config: ExecutionConfig,
+) -> TransactionReceipt {
+    let (_, _, account) = test_runner.new_account(false);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        execution_config,
+    )
+}
+
+#[test]
+fn user_module_observes_kernel_and_system_events() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let module = Arc::new(Mutex::new(CountingModule::default()));
+
+    // Act
+    let receipt = execute_with_config(
+        &mut test_runner,
+        ExecutionConfig::for_test_transaction().with_user_module(module.clone()),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let module = module.lock().unwrap();
+    assert!(module.initialized);
+    assert!(module.torn_down);
+    assert_eq!(module.depth, 0);
+    assert!(module.max_depth > 1);
+    assert!(module.invocations > 0);
+    assert!(module.created_nodes > 0);
+    assert!(module.emitted_events > 0);
+}
+
+#[test]
+fn user_module_observes_events_without_the_transaction_runtime_module() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let module = Arc::new(Mutex::new(CountingModule::default()));
+    let mut execution_config =
+        ExecutionConfig::for_test_transaction().with_user_module(module.clone());
+    execution_config
+        .enabled_modules
+        .remove(EnabledModules::TRANSACTION_RUNTIME);
+
+    // Act
+    let receipt = execute_with_config(&mut test_runner, execution_config);
+
+    // Assert
+    assert!(receipt
+        .expect_commit_success()
+        .application_events
+        .is_empty());
+    assert!(module.lock().unwrap().emitted_events > 0);
+}
+
+#[test]
+fn user_module_errors_fail_the_transaction() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+
+    // Act
+    let receipt = execute_with_config(
+        &mut test_runner,
+        ExecutionConfig::for_test_transaction()
+            .with_user_module(Arc::new(Mutex::new(NoDepositsChecker))),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::UserModuleError(..))
+        )
+    });
+}
+
+#[test]
+fn multiple_user_modules_are_dispatched_to() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let first = Arc::new(Mutex::new(CountingModule::default()));
+    let second = Arc::new(Mutex::new(CountingModule::default()));
+
+    // Act
+    let receipt = execute_with_config(
+        &mut test_runner,
+        ExecutionConfig::for_test_transaction()
+            .with_user_module(first.clone())
+            .with_user_module(second.clone()),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(first.lock().unwrap().invocations > 0);
+    assert_eq!(
+        first.lock().unwrap().invocations,
+        second.lock().unwrap().invocations
+    );
+}
+
+#[test]
+fn execution_config_with_user_modules_can_be_sent_to_other_threads() {
+    // Arrange
+    let module = Arc::new(Mutex::new(CountingModule::default()));
+    let execution_config = ExecutionConfig::for_test_transaction().with_user_module(module.clone());
+
+    // Act
+    let is_commit_success = std::thread::spawn(move || {
+        let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+        execute_with_config(&mut test_runner, execution_config).is_commit_success()
+    })
+    .join()
+    .unwrap();
+
+    // Assert
+    assert!(is_commit_success);
+    assert!(module.lock().unwrap().invocations > 0);
+}
@@ -0,0 +1,113 @@
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::*;
+use scrypto_unit::*;
+use std::path::PathBuf;
+use transaction::prelude::*;
+
+const RETURNING_CODE: &str = r#"
+    (module
+        (memory $0 1)
+        (func $Test_f (param $0 i64) (result i64)
+          ;; Encode () in SBOR at address 0x0
+          (i32.const 0)
+          (i32.const 92)  ;; prefix
+          (i32.store8)
+          (i32.const 1)
+          (i32.const 33)  ;; tuple value kind
+          (i32.store8)
+          (i32.const 2)
+          (i32.const 0)  ;; tuple length
+          (i32.store8)
+
+          ;; Return slice (ptr = 0, len = 3)
+          (i64.const 3)
+        )
+        (export "memory" (memory $0))
+        (export "Test_f" (func $Test_f))
+    )
+"#;
+
+const TRAPPING_CODE: &str = r#"
+    (module
+        (memory $0 1)
+        (func $Test_f (param $0 i64) (result i64)
+          (unreachable)
+        )
+        (export "memory" (memory $0))
+        (export "Test_f" (func $Test_f))
+    )
+"#;
+
+fn publish_and_call(disk_cache: &WasmDiskCache, code: &[u8]) -> TransactionReceipt {
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_wasm_disk_cache(disk_cache.clone())
+        .build();
+    let package_address = test_runner.publish_package(
+        (
+            code.to_vec(),
+            single_function_package_definition("Test", "f"),
+        ),
+        BTreeMap::new(),
+        OwnerRole::None,
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Test", "f", manifest_args!())
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+/// Finds the entry of the validated code, amongst the entries of compiled modules if any.
+fn validated_code_entry(disk_cache: &WasmDiskCache) -> (PathBuf, Hash, u32) {
+    std::fs::read_dir(disk_cache.directory())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension() == Some("bin".as_ref()))
+        .find_map(|path| {
+            let name = path.file_stem()?.to_str()?;
+            let (key, version) = name.split_once("-v")?;
+            let key: Hash = key.parse().ok()?;
+            let version = version.parse().ok()?;
+            let payload = disk_cache.get(&key, version)?;
+            scrypto_decode::<(Vec<u8>, Vec<String>)>(&payload).ok()?;
+            Some((path.clone(), key, version))
+        })
+        .expect("No validated code in the disk cache")
+}
+
+#[test]
+fn disk_cache_entries_are_used_unless_tampered_with() {
+    // Arrange
+    let directory = std::env::temp_dir().join(format!(
+        "radix-engine-tests-wasm-disk-cache-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&directory);
+    let disk_cache = WasmDiskCache::new(&directory).unwrap();
+    let code = wat2wasm(RETURNING_CODE);
+    let definition = single_function_package_definition("Test", "f");
+    let (trapping_instrumented_code, exports) = WasmValidator::default()
+        .validate(&wat2wasm(TRAPPING_CODE), definition.blueprints.values())
+        .unwrap();
+    let trapping_payload = scrypto_encode(&(trapping_instrumented_code, exports)).unwrap();
+
+    publish_and_call(&disk_cache, &code).expect_commit_success();
+    let (path, key, version) = validated_code_entry(&disk_cache);
+
+    // Act & Assert: an entry forged without the secret of the cache is discarded
+    let mut forged_entry = hash(&trapping_payload).0.to_vec();
+    forged_entry.extend_from_slice(&trapping_payload);
+    std::fs::write(&path, forged_entry).unwrap();
+    publish_and_call(&disk_cache, &code).expect_commit_success();
+    assert_ne!(
+        disk_cache.get(&key, version),
+        Some(trapping_payload.clone())
+    );
+
+    // Act & Assert: an authenticated entry is used as-is, instead of validating the code again
+    disk_cache.put(&key, version, &trapping_payload).unwrap();
+    publish_and_call(&disk_cache, &code).expect_commit_failure();
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
@@ -55,6 +55,11 @@ pub struct AccessControllerSubstate {
         RecoveryRoleRecoveryAttemptState,
         RecoveryRoleBadgeWithdrawAttemptState,
     ),
+
+    /// A vault of XRD which can be used to lock fees for recovery transactions, so that they can
+    /// be paid for without the help of another account. When this is [`None`], then no XRD has
+    /// been contributed to the access controller yet.
+    pub recovery_xrd_vault: Option<Vault>,
 }
 
 impl Clone for AccessControllerSubstate {
@@ -64,6 +69,7 @@ impl Clone for AccessControllerSubstate {
             timed_recovery_delay_in_minutes: self.timed_recovery_delay_in_minutes.clone(),
             recovery_badge: self.recovery_badge,
             state: self.state.clone(),
+            recovery_xrd_vault: self.recovery_xrd_vault.as_ref().map(|vault| Vault(vault.0)),
         }
     }
 }
@@ -79,6 +85,7 @@ impl AccessControllerSubstate {
             timed_recovery_delay_in_minutes,
             recovery_badge,
             state: Default::default(),
+            recovery_xrd_vault: None,
         }
     }
 }
@@ -162,6 +169,10 @@ pub enum AccessControllerError {
         expected: Box<RecoveryProposal>,
         found: Box<RecoveryProposal>,
     },
+
+    /// Occurs when locking or withdrawing recovery fees before any XRD was contributed to the
+    /// access controller.
+    NoXrdFeeVault,
 }
 
 impl From<AccessControllerError> for RuntimeError {
@@ -179,7 +190,11 @@ declare_native_blueprint_state! {
         state:  {
             ident: State,
             field_type: {
-                kind: StaticSingleVersioned,
+                kind: StaticMultiVersioned,
+                previous_versions: [
+                    1 => { updates_to: 2 },
+                ],
+                latest_version: 2,
             },
             condition: Condition::Always,
         }
@@ -188,7 +203,35 @@ declare_native_blueprint_state! {
     }
 }
 
-pub type AccessControllerStateV1 = AccessControllerSubstate;
+/// The state of access controllers created before the recovery XRD vault was introduced, which
+/// is updated to [`AccessControllerStateV2`] when read.
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct AccessControllerStateV1 {
+    pub controlled_asset: Vault,
+    pub timed_recovery_delay_in_minutes: Option<u32>,
+    pub recovery_badge: ResourceAddress,
+    pub state: (
+        PrimaryRoleLockingState,
+        PrimaryRoleRecoveryAttemptState,
+        PrimaryRoleBadgeWithdrawAttemptState,
+        RecoveryRoleRecoveryAttemptState,
+        RecoveryRoleBadgeWithdrawAttemptState,
+    ),
+}
+
+pub type AccessControllerStateV2 = AccessControllerSubstate;
+
+impl From<AccessControllerStateV1> for AccessControllerStateV2 {
+    fn from(value: AccessControllerStateV1) -> Self {
+        Self {
+            controlled_asset: value.controlled_asset,
+            timed_recovery_delay_in_minutes: value.timed_recovery_delay_in_minutes,
+            recovery_badge: value.recovery_badge,
+            state: value.state,
+            recovery_xrd_vault: None,
+        }
+    }
+}
 
 pub struct AccessControllerBlueprint;
 
@@ -435,6 +478,51 @@ impl AccessControllerBlueprint {
                 export: ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT.to_string(),
             },
         );
+        functions.insert(
+            ACCESS_CONTROLLER_LOCK_RECOVERY_FEE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccessControllerLockRecoveryFeeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccessControllerLockRecoveryFeeOutput>(),
+                ),
+                export: ACCESS_CONTROLLER_LOCK_RECOVERY_FEE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            ACCESS_CONTROLLER_WITHDRAW_RECOVERY_FEE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccessControllerWithdrawRecoveryFeeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccessControllerWithdrawRecoveryFeeOutput>(),
+                ),
+                export: ACCESS_CONTROLLER_WITHDRAW_RECOVERY_FEE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            ACCESS_CONTROLLER_CONTRIBUTE_RECOVERY_FEE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccessControllerContributeRecoveryFeeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccessControllerContributeRecoveryFeeOutput>(),
+                ),
+                export: ACCESS_CONTROLLER_CONTRIBUTE_RECOVERY_FEE_IDENT.to_string(),
+            },
+        );
 
         let events = event_schema! {
             aggregator,
@@ -447,7 +535,9 @@ impl AccessControllerBlueprint {
                 StopTimedRecoveryEvent,
                 InitiateBadgeWithdrawAttemptEvent,
                 BadgeWithdrawEvent,
-                CancelBadgeWithdrawAttemptEvent
+                CancelBadgeWithdrawAttemptEvent,
+                DepositRecoveryXrdEvent,
+                WithdrawRecoveryXrdEvent
             ]
         };
 
@@ -479,6 +569,7 @@ impl AccessControllerBlueprint {
                     },
                     methods {
                         ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT => MethodAccessibility::Public;
+                        ACCESS_CONTROLLER_CONTRIBUTE_RECOVERY_FEE_IDENT => MethodAccessibility::Public;
 
                         ACCESS_CONTROLLER_CREATE_PROOF_IDENT => ["primary"];
 
@@ -504,6 +595,9 @@ impl AccessControllerBlueprint {
                         ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT => ["primary", "recovery"];
 
                         ACCESS_CONTROLLER_STOP_TIMED_RECOVERY_IDENT => ["primary", "confirmation", "recovery"];
+
+                        ACCESS_CONTROLLER_LOCK_RECOVERY_FEE_IDENT => ["primary", "confirmation", "recovery"];
+                        ACCESS_CONTROLLER_WITHDRAW_RECOVERY_FEE_IDENT => ["primary"];
                     }
                 )),
             },
@@ -1151,6 +1245,66 @@ impl AccessControllerBlueprint {
 
         Ok(IndexedScryptoValue::from_slice(&rtn).unwrap())
     }
+
+    pub fn lock_recovery_fee<Y>(
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let AccessControllerLockRecoveryFeeInput { amount } = input
+            .as_typed()
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
+
+        transition(
+            api,
+            AccessControllerLockRecoveryFeeStateMachineInput { amount },
+        )?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub fn withdraw_recovery_fee<Y>(
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let AccessControllerWithdrawRecoveryFeeInput { amount } = input
+            .as_typed()
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
+
+        let bucket = transition(
+            api,
+            AccessControllerWithdrawRecoveryFeeStateMachineInput { amount },
+        )?;
+        Runtime::emit_event(api, WithdrawRecoveryXrdEvent { amount })?;
+
+        Ok(IndexedScryptoValue::from_typed(&bucket))
+    }
+
+    pub fn contribute_recovery_fee<Y>(
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let AccessControllerContributeRecoveryFeeInput { bucket } = input
+            .as_typed()
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
+
+        let amount = bucket.amount(api)?;
+        transition_mut(
+            api,
+            AccessControllerContributeRecoveryFeeStateMachineInput { bucket },
+        )?;
+        Runtime::emit_event(api, DepositRecoveryXrdEvent { amount })?;
+
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
 }
 
 //=========
@@ -1,4 +1,5 @@
 use crate::types::*;
+use radix_engine_common::math::Decimal;
 use radix_engine_interface::blueprints::access_controller::{Proposer, RecoveryProposal};
 
 #[derive(ScryptoSbor, ScryptoEvent, Debug)]
@@ -41,3 +42,13 @@ pub struct UnlockPrimaryRoleEvent;
 
 #[derive(ScryptoSbor, ScryptoEvent, Debug)]
 pub struct StopTimedRecoveryEvent;
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct DepositRecoveryXrdEvent {
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct WithdrawRecoveryXrdEvent {
+    pub amount: Decimal,
+}
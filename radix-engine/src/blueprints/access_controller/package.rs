@@ -97,6 +97,15 @@ impl AccessControllerNativePackage {
             ACCESS_CONTROLLER_MINT_RECOVERY_BADGES_IDENT => {
                 AccessControllerBlueprint::mint_recovery_badges(input, api)
             }
+            ACCESS_CONTROLLER_LOCK_RECOVERY_FEE_IDENT => {
+                AccessControllerBlueprint::lock_recovery_fee(input, api)
+            }
+            ACCESS_CONTROLLER_WITHDRAW_RECOVERY_FEE_IDENT => {
+                AccessControllerBlueprint::withdraw_recovery_fee(input, api)
+            }
+            ACCESS_CONTROLLER_CONTRIBUTE_RECOVERY_FEE_IDENT => {
+                AccessControllerBlueprint::contribute_recovery_fee(input, api)
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
@@ -8,6 +8,8 @@ use radix_engine_interface::api::*;
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
 use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::constants::XRD;
+use radix_engine_interface::math::Decimal;
 use radix_engine_interface::time::TimeComparisonOperator;
 use sbor::rust::boxed::Box;
 
@@ -704,6 +706,88 @@ impl TransitionMut<AccessControllerStopTimedRecoveryStateMachineInput>
     }
 }
 
+pub(super) struct AccessControllerLockRecoveryFeeStateMachineInput {
+    pub amount: Decimal,
+}
+
+impl Transition<AccessControllerLockRecoveryFeeStateMachineInput> for AccessControllerSubstate {
+    type Output = ();
+
+    fn transition<Y>(
+        &self,
+        api: &mut Y,
+        input: AccessControllerLockRecoveryFeeStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // Fees can be locked regardless of the state of the primary role or any pending recovery
+        // or withdraw attempts, so that a locked out primary role does not prevent recovery.
+        match self.recovery_xrd_vault {
+            Some(ref vault) => Vault(vault.0).lock_fee(api, input.amount),
+            None => access_controller_runtime_error!(NoXrdFeeVault),
+        }
+    }
+}
+
+pub(super) struct AccessControllerWithdrawRecoveryFeeStateMachineInput {
+    pub amount: Decimal,
+}
+
+impl Transition<AccessControllerWithdrawRecoveryFeeStateMachineInput> for AccessControllerSubstate {
+    type Output = Bucket;
+
+    fn transition<Y>(
+        &self,
+        api: &mut Y,
+        input: AccessControllerWithdrawRecoveryFeeStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // Recovery fees can only be withdrawn when the primary role is unlocked - regardless of
+        // any pending recovery or withdraw attempts.
+        match self.state {
+            (PrimaryRoleLockingState::Unlocked, _, _, _, _) => match self.recovery_xrd_vault {
+                Some(ref vault) => Vault(vault.0).take(input.amount, api),
+                None => access_controller_runtime_error!(NoXrdFeeVault),
+            },
+            _ => access_controller_runtime_error!(OperationRequiresUnlockedPrimaryRole),
+        }
+    }
+}
+
+pub(super) struct AccessControllerContributeRecoveryFeeStateMachineInput {
+    pub bucket: Bucket,
+}
+
+impl TransitionMut<AccessControllerContributeRecoveryFeeStateMachineInput>
+    for AccessControllerSubstate
+{
+    type Output = ();
+
+    fn transition_mut<Y>(
+        &mut self,
+        api: &mut Y,
+        input: AccessControllerContributeRecoveryFeeStateMachineInput,
+    ) -> Result<Self::Output, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // Anybody can contribute XRD towards recovery fees at any time. The vault is created on
+        // the first contribution and only accepts XRD.
+        match self.recovery_xrd_vault {
+            Some(ref mut vault) => vault.put(input.bucket, api),
+            None => {
+                let mut vault = Vault::create(XRD, api)?;
+                vault.put(input.bucket, api)?;
+                self.recovery_xrd_vault = Some(vault);
+                Ok(())
+            }
+        }
+    }
+}
+
 fn validate_recovery_proposal(
     expected: &RecoveryProposal,
     actual: &RecoveryProposal,
@@ -41,6 +41,7 @@ pub enum AccountError {
     DepositIsDisallowed { resource_address: ResourceAddress },
     NotAllBucketsCouldBeDeposited,
     NotAnAuthorizedDepositor { depositor: ResourceOrNonFungible },
+    NotANonFungibleResource { resource_address: ResourceAddress },
 }
 
 impl From<AccountError> for RuntimeError {
@@ -480,6 +481,48 @@ impl AccountBlueprint {
             },
         );
 
+        functions.insert(
+            ACCOUNT_BALANCE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountBalanceInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountBalanceOutput>(),
+                ),
+                export: ACCOUNT_BALANCE_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_NON_FUNGIBLE_LOCAL_IDS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountNonFungibleLocalIdsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountNonFungibleLocalIdsOutput>(),
+                ),
+                export: ACCOUNT_NON_FUNGIBLE_LOCAL_IDS_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_HAS_NON_FUNGIBLE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountHasNonFungibleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountHasNonFungibleOutput>(),
+                ),
+                export: ACCOUNT_HAS_NON_FUNGIBLE_IDENT.to_string(),
+            },
+        );
+
         let events = event_schema! {
             aggregator,
             [
@@ -551,6 +594,10 @@ impl AccountBlueprint {
                         ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => MethodAccessibility::Public;
                         ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => MethodAccessibility::Public;
                         ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => MethodAccessibility::Public;
+
+                        ACCOUNT_BALANCE_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_NON_FUNGIBLE_LOCAL_IDS_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_HAS_NON_FUNGIBLE_IDENT => MethodAccessibility::Public;
                     }
                 )),
             },
@@ -1271,6 +1318,66 @@ impl AccountBlueprint {
         Ok(())
     }
 
+    pub fn balance<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !Self::does_vault_exist(&resource_address, api)? {
+            return Ok(Decimal::ZERO);
+        }
+
+        Self::get_vault(resource_address, |vault, api| vault.amount(api), false, api)
+    }
+
+    pub fn non_fungible_local_ids<Y>(
+        resource_address: ResourceAddress,
+        limit: u32,
+        api: &mut Y,
+    ) -> Result<IndexSet<NonFungibleLocalId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if resource_address.is_fungible() {
+            return Err(AccountError::NotANonFungibleResource { resource_address }.into());
+        }
+        if !Self::does_vault_exist(&resource_address, api)? {
+            return Ok(index_set_new());
+        }
+
+        Self::get_vault(
+            resource_address,
+            |vault, api| vault.non_fungible_local_ids(limit, api),
+            false,
+            api,
+        )
+    }
+
+    pub fn has_non_fungible<Y>(
+        resource_address: ResourceAddress,
+        local_id: NonFungibleLocalId,
+        api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if resource_address.is_fungible() {
+            return Err(AccountError::NotANonFungibleResource { resource_address }.into());
+        }
+        if !Self::does_vault_exist(&resource_address, api)? {
+            return Ok(false);
+        }
+
+        Self::get_vault(
+            resource_address,
+            |vault, api| vault.contains_non_fungible(local_id, api),
+            false,
+            api,
+        )
+    }
+
     fn get_default_deposit_rule<Y>(api: &mut Y) -> Result<DefaultDepositRule, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
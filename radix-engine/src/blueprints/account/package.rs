@@ -288,6 +288,33 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::remove_authorized_depositor(badge, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_BALANCE_IDENT => {
+                let AccountBalanceInput { resource_address } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::balance(resource_address, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_NON_FUNGIBLE_LOCAL_IDS_IDENT => {
+                let AccountNonFungibleLocalIdsInput {
+                    resource_address,
+                    limit,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::non_fungible_local_ids(resource_address, limit, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_HAS_NON_FUNGIBLE_IDENT => {
+                let AccountHasNonFungibleInput {
+                    resource_address,
+                    local_id,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::has_non_fungible(resource_address, local_id, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
 
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
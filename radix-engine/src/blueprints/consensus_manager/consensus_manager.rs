@@ -797,7 +797,7 @@ impl ConsensusManagerBlueprint {
         Instant::new(epoch_minute as i64 * SECONDS_IN_MINUTE)
     }
 
-    fn milli_to_minute(epoch_milli: i64) -> Option<i32> {
+    pub(crate) fn milli_to_minute(epoch_milli: i64) -> Option<i32> {
         i32::try_from(epoch_milli / MILLIS_IN_MINUTE).ok() // safe until A.D. 5700
     }
 
@@ -0,0 +1,737 @@
+use super::*;
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use crate::internal_prelude::*;
+use crate::types::*;
+use crate::{event_schema, roles_template};
+use native_sdk::modules::metadata::Metadata;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::field_api::LockFlags;
+use radix_engine_interface::api::{
+    AttachedModuleId, ClientApi, GenericArgs, ModuleId, ACTOR_STATE_SELF,
+};
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::locker::*;
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::{metadata_init, roles_init, rule};
+
+// =================================================================================================
+// Notes:
+// 1. Claimants are always accounts, and claims are authorized against the owner role of the
+//    claimant account - that is, the same proofs that would allow withdrawing from the account.
+// 2. Resources are only stored in the locker when they could not be sent directly to the account
+//    (or when a direct send was not requested), so the store events reflect what is claimable.
+// =================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum AccountLockerError {
+    ClaimantIsNotAnAccount {
+        claimant: ComponentAddress,
+    },
+    NoResourcesStored {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+    },
+    NotANonFungibleResource {
+        resource_address: ResourceAddress,
+    },
+}
+
+impl From<AccountLockerError> for RuntimeError {
+    fn from(value: AccountLockerError) -> Self {
+        Self::ApplicationError(ApplicationError::AccountLockerError(value))
+    }
+}
+
+/// The key of the vault holding the resources of a given resource address that were stored for a
+/// given claimant.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, ScryptoSbor)]
+pub struct AccountLockerClaimKey {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+}
+
+declare_native_blueprint_state! {
+    blueprint_ident: AccountLocker,
+    blueprint_snake_case: account_locker,
+    features: {
+    },
+    fields: {
+    },
+    collections: {
+        claims: KeyValue {
+            entry_ident: Claim,
+            key_type: {
+                kind: Static,
+                content_type: AccountLockerClaimKey,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: true,
+        },
+    }
+}
+
+pub type AccountLockerClaimV1 = Vault;
+
+pub struct AccountLockerBlueprint;
+
+impl AccountLockerBlueprint {
+    pub fn get_definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+
+        let feature_set = AccountLockerFeatureSet::all_features();
+        let state = AccountLockerStateSchemaInit::create_schema_init(&mut aggregator);
+
+        let mut functions = index_map_new();
+
+        functions.insert(
+            ACCOUNT_LOCKER_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerInstantiateOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_INSTANTIATE_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_INSTANTIATE_SIMPLE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountLockerInstantiateSimpleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountLockerInstantiateSimpleOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_INSTANTIATE_SIMPLE_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_STORE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerStoreInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerStoreOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_STORE_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_AIRDROP_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerAirdropInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerAirdropOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_AIRDROP_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_RECOVER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerRecoverInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerRecoverOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_RECOVER_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_RECOVER_NON_FUNGIBLES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountLockerRecoverNonFungiblesInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountLockerRecoverNonFungiblesOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_RECOVER_NON_FUNGIBLES_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_CLAIM_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerClaimInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerClaimOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_CLAIM_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountLockerClaimNonFungiblesInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountLockerClaimNonFungiblesOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_GET_AMOUNT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerGetAmountInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerGetAmountOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_GET_AMOUNT_IDENT.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountLockerGetNonFungibleLocalIdsInput>(
+                        ),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<AccountLockerGetNonFungibleLocalIdsOutput>(
+                        ),
+                ),
+                export: ACCOUNT_LOCKER_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT.to_string(),
+            },
+        );
+
+        let events = event_schema! {
+            aggregator,
+            [
+                StoreEvent,
+                RecoverEvent,
+                ClaimEvent,
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            feature_set,
+            dependencies: indexset!(),
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events,
+                types: BlueprintTypeSchemaInit::default(),
+                functions: BlueprintFunctionsSchemaInit { functions },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template!(
+                    roles {
+                        ACCOUNT_LOCKER_STORER_ROLE => updaters: [ACCOUNT_LOCKER_STORER_UPDATER_ROLE];
+                        ACCOUNT_LOCKER_STORER_UPDATER_ROLE => updaters: [ACCOUNT_LOCKER_STORER_UPDATER_ROLE];
+                        ACCOUNT_LOCKER_RECOVERER_ROLE => updaters: [ACCOUNT_LOCKER_RECOVERER_UPDATER_ROLE];
+                        ACCOUNT_LOCKER_RECOVERER_UPDATER_ROLE => updaters: [ACCOUNT_LOCKER_RECOVERER_UPDATER_ROLE];
+                    },
+                    methods {
+                        ACCOUNT_LOCKER_STORE_IDENT => [ACCOUNT_LOCKER_STORER_ROLE];
+                        ACCOUNT_LOCKER_AIRDROP_IDENT => [ACCOUNT_LOCKER_STORER_ROLE];
+                        ACCOUNT_LOCKER_RECOVER_IDENT => [ACCOUNT_LOCKER_RECOVERER_ROLE];
+                        ACCOUNT_LOCKER_RECOVER_NON_FUNGIBLES_IDENT => [ACCOUNT_LOCKER_RECOVERER_ROLE];
+
+                        // Claims are authorized against the owner role of the claimant account.
+                        ACCOUNT_LOCKER_CLAIM_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_LOCKER_GET_AMOUNT_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_LOCKER_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT => MethodAccessibility::Public;
+                    }
+                )),
+            },
+        }
+    }
+
+    pub fn instantiate<Y>(
+        owner_role: OwnerRole,
+        storer_role: AccessRule,
+        storer_updater_role: AccessRule,
+        recoverer_role: AccessRule,
+        recoverer_updater_role: AccessRule,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<AccountLockerInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let role_assignment = RoleAssignment::create(
+            owner_role,
+            indexmap! {
+                ModuleId::Main => roles_init! {
+                    RoleKey::new(ACCOUNT_LOCKER_STORER_ROLE) => storer_role;
+                    RoleKey::new(ACCOUNT_LOCKER_STORER_UPDATER_ROLE) => storer_updater_role;
+                    RoleKey::new(ACCOUNT_LOCKER_RECOVERER_ROLE) => recoverer_role;
+                    RoleKey::new(ACCOUNT_LOCKER_RECOVERER_UPDATER_ROLE) => recoverer_updater_role;
+                }
+            },
+            api,
+        )?
+        .0;
+        let metadata = Metadata::create(api)?;
+
+        let object_id = api.new_object(
+            ACCOUNT_LOCKER_BLUEPRINT,
+            vec![],
+            GenericArgs::default(),
+            indexmap!(),
+            indexmap!(),
+        )?;
+
+        let address = api.globalize(
+            object_id,
+            indexmap!(
+                AttachedModuleId::RoleAssignment => role_assignment.0,
+                AttachedModuleId::Metadata => metadata.0,
+            ),
+            address_reservation,
+        )?;
+
+        Ok(Global::new(ComponentAddress::new_or_panic(
+            address.as_node_id().0,
+        )))
+    }
+
+    pub fn instantiate_simple<Y>(
+        allow_recover: bool,
+        api: &mut Y,
+    ) -> Result<AccountLockerInstantiateSimpleOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (badge_resource_manager, badge) = ResourceManager::new_fungible_with_initial_supply(
+            OwnerRole::None,
+            true,
+            0,
+            Decimal::ONE,
+            FungibleResourceRoles::default(),
+            metadata_init! {
+                "name" => "Account Locker Badge".to_owned(), locked;
+            },
+            None,
+            api,
+        )?;
+        let badge_rule = rule!(require(badge_resource_manager.0));
+        let recoverer_rule = if allow_recover {
+            badge_rule.clone()
+        } else {
+            rule!(deny_all)
+        };
+
+        let locker = Self::instantiate(
+            OwnerRole::Updatable(badge_rule.clone()),
+            badge_rule.clone(),
+            badge_rule,
+            recoverer_rule.clone(),
+            recoverer_rule,
+            None,
+            api,
+        )?;
+
+        Ok((locker, badge))
+    }
+
+    pub fn store<Y>(
+        claimant: ComponentAddress,
+        bucket: Bucket,
+        try_direct_send: bool,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::validate_claimant(claimant)?;
+
+        let bucket = if try_direct_send {
+            match Self::try_direct_send(claimant, bucket, api)? {
+                Some(bucket) => bucket,
+                None => return Ok(()),
+            }
+        } else {
+            bucket
+        };
+
+        let resource_address = bucket.resource_address(api)?;
+        let resources = Self::resource_specifier_of(resource_address, &bucket, api)?;
+
+        let key = AccountLockerClaimKey {
+            claimant,
+            resource_address,
+        };
+        let encoded_key = scrypto_encode(&key).expect("Impossible Case!");
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountLockerCollection::ClaimKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::MUTABLE,
+        )?;
+        let entry = api
+            .key_value_entry_get_typed::<AccountLockerClaimEntryPayload>(handle)?
+            .map(|v| v.into_latest());
+        match entry {
+            Some(mut vault) => vault.put(bucket, api)?,
+            None => {
+                let mut vault = Vault::create(resource_address, api)?;
+                vault.put(bucket, api)?;
+                api.key_value_entry_set_typed(
+                    handle,
+                    &AccountLockerClaimEntryPayload::from_content_source(vault),
+                )?;
+            }
+        }
+        api.key_value_entry_close(handle)?;
+
+        Runtime::emit_event(
+            api,
+            StoreEvent {
+                claimant,
+                resource_address,
+                resources,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub fn airdrop<Y>(
+        claimants: IndexMap<ComponentAddress, ResourceSpecifier>,
+        bucket: Bucket,
+        try_direct_send: bool,
+        api: &mut Y,
+    ) -> Result<Option<Bucket>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        for (claimant, resources) in claimants {
+            let claimant_bucket = match resources {
+                ResourceSpecifier::Fungible(amount) => bucket.take(amount, api)?,
+                ResourceSpecifier::NonFungible(ids) => bucket.take_non_fungibles(ids, api)?,
+            };
+            Self::store(claimant, claimant_bucket, try_direct_send, api)?;
+        }
+
+        if bucket.is_empty(api)? {
+            bucket.drop_empty(api)?;
+            Ok(None)
+        } else {
+            Ok(Some(bucket))
+        }
+    }
+
+    pub fn recover<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let resources = ResourceSpecifier::Fungible(amount);
+        let bucket = Self::take(claimant, resource_address, resources.clone(), api)?;
+
+        Runtime::emit_event(
+            api,
+            RecoverEvent {
+                claimant,
+                resource_address,
+                resources,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn recover_non_fungibles<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let resources = ResourceSpecifier::NonFungible(ids);
+        let bucket = Self::take(claimant, resource_address, resources.clone(), api)?;
+
+        Runtime::emit_event(
+            api,
+            RecoverEvent {
+                claimant,
+                resource_address,
+                resources,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn claim<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_claimant_owner(claimant, api)?;
+
+        let resources = ResourceSpecifier::Fungible(amount);
+        let bucket = Self::take(claimant, resource_address, resources.clone(), api)?;
+
+        Runtime::emit_event(
+            api,
+            ClaimEvent {
+                claimant,
+                resource_address,
+                resources,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn claim_non_fungibles<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_claimant_owner(claimant, api)?;
+
+        let resources = ResourceSpecifier::NonFungible(ids);
+        let bucket = Self::take(claimant, resource_address, resources.clone(), api)?;
+
+        Runtime::emit_event(
+            api,
+            ClaimEvent {
+                claimant,
+                resource_address,
+                resources,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn get_amount<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let amount = Self::with_stored_vault(
+            claimant,
+            resource_address,
+            |vault, api| vault.amount(api),
+            api,
+        )?;
+
+        Ok(amount.unwrap_or_default())
+    }
+
+    pub fn get_non_fungible_local_ids<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        limit: u32,
+        api: &mut Y,
+    ) -> Result<IndexSet<NonFungibleLocalId>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if resource_address.is_fungible() {
+            return Err(AccountLockerError::NotANonFungibleResource { resource_address }.into());
+        }
+
+        let ids = Self::with_stored_vault(
+            claimant,
+            resource_address,
+            |vault, api| vault.non_fungible_local_ids(limit, api),
+            api,
+        )?;
+
+        Ok(ids.unwrap_or_default())
+    }
+
+    fn validate_claimant(claimant: ComponentAddress) -> Result<(), RuntimeError> {
+        let is_account = claimant
+            .as_node_id()
+            .entity_type()
+            .map_or(false, |entity_type| entity_type.is_global_account());
+        if is_account {
+            Ok(())
+        } else {
+            Err(AccountLockerError::ClaimantIsNotAnAccount { claimant }.into())
+        }
+    }
+
+    /// Asserts that the auth zone satisfies the owner role of the claimant account, which is what
+    /// proves the ownership of the account.
+    fn assert_claimant_owner<Y>(claimant: ComponentAddress, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::validate_claimant(claimant)?;
+        let owner_role = AttachedRoleAssignment(*claimant.as_node_id()).get_owner_role(api)?;
+        Runtime::assert_access_rule(owner_role.rule, api)
+    }
+
+    /// Attempts to deposit the bucket into the claimant account, returning the bucket if it was
+    /// refused by the deposit rules of the account.
+    fn try_direct_send<Y>(
+        claimant: ComponentAddress,
+        bucket: Bucket,
+        api: &mut Y,
+    ) -> Result<Option<Bucket>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let rtn = api.call_method(
+            claimant.as_node_id(),
+            ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT,
+            scrypto_encode(&AccountTryDepositOrRefundInput {
+                bucket,
+                authorized_depositor_badge: None,
+            })
+            .unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    fn take<Y>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        resources: ResourceSpecifier,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let bucket = Self::with_stored_vault(
+            claimant,
+            resource_address,
+            |vault, api| match resources {
+                ResourceSpecifier::Fungible(amount) => vault.take(amount, api),
+                ResourceSpecifier::NonFungible(ids) => vault.take_non_fungibles(ids, api),
+            },
+            api,
+        )?;
+
+        bucket.ok_or_else(|| {
+            AccountLockerError::NoResourcesStored {
+                claimant,
+                resource_address,
+            }
+            .into()
+        })
+    }
+
+    /// Runs the given function against the vault of the stored resources of the claimant, if any.
+    fn with_stored_vault<F, Y, R>(
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        vault_fn: F,
+        api: &mut Y,
+    ) -> Result<Option<R>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(&mut Vault, &mut Y) -> Result<R, RuntimeError>,
+    {
+        let key = AccountLockerClaimKey {
+            claimant,
+            resource_address,
+        };
+        let encoded_key = scrypto_encode(&key).expect("Impossible Case!");
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountLockerCollection::ClaimKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::read_only(),
+        )?;
+
+        // The entry is kept open while the vault is in use, as the vault is only visible through it.
+        let entry = api
+            .key_value_entry_get_typed::<AccountLockerClaimEntryPayload>(handle)?
+            .map(|v| v.into_latest());
+        let rtn = match entry {
+            Some(mut vault) => Some(vault_fn(&mut vault, api)?),
+            None => None,
+        };
+        api.key_value_entry_close(handle)?;
+
+        Ok(rtn)
+    }
+
+    fn resource_specifier_of<Y>(
+        resource_address: ResourceAddress,
+        bucket: &Bucket,
+        api: &mut Y,
+    ) -> Result<ResourceSpecifier, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if resource_address.is_fungible() {
+            Ok(ResourceSpecifier::Fungible(bucket.amount(api)?))
+        } else {
+            Ok(ResourceSpecifier::NonFungible(
+                bucket.non_fungible_local_ids(api)?,
+            ))
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::types::*;
+use radix_engine_common::{ScryptoEvent, ScryptoSbor};
+use radix_engine_interface::blueprints::locker::*;
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct StoreEvent {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub resources: ResourceSpecifier,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct RecoverEvent {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub resources: ResourceSpecifier,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct ClaimEvent {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub resources: ResourceSpecifier,
+}
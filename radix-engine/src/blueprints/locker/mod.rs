@@ -0,0 +1,7 @@
+mod blueprint;
+mod events;
+mod package;
+
+pub use blueprint::*;
+pub use events::*;
+pub use package::*;
@@ -0,0 +1,173 @@
+use crate::blueprints::locker::AccountLockerBlueprint;
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use crate::types::*;
+use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::locker::*;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+
+pub struct LockerNativePackage;
+
+impl LockerNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let blueprints = indexmap!(
+            ACCOUNT_LOCKER_BLUEPRINT.to_string() => AccountLockerBlueprint::get_definition()
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match export_name {
+            ACCOUNT_LOCKER_INSTANTIATE_IDENT => {
+                let input: AccountLockerInstantiateInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::instantiate(
+                    input.owner_role,
+                    input.storer_role,
+                    input.storer_updater_role,
+                    input.recoverer_role,
+                    input.recoverer_updater_role,
+                    input.address_reservation,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_INSTANTIATE_SIMPLE_IDENT => {
+                let input: AccountLockerInstantiateSimpleInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::instantiate_simple(input.allow_recover, api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_STORE_IDENT => {
+                let input: AccountLockerStoreInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::store(
+                    input.claimant,
+                    input.bucket,
+                    input.try_direct_send,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_AIRDROP_IDENT => {
+                let input: AccountLockerAirdropInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::airdrop(
+                    input.claimants,
+                    input.bucket,
+                    input.try_direct_send,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_RECOVER_IDENT => {
+                let input: AccountLockerRecoverInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::recover(
+                    input.claimant,
+                    input.resource_address,
+                    input.amount,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_RECOVER_NON_FUNGIBLES_IDENT => {
+                let input: AccountLockerRecoverNonFungiblesInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+
+                let rtn = AccountLockerBlueprint::recover_non_fungibles(
+                    input.claimant,
+                    input.resource_address,
+                    input.ids,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_CLAIM_IDENT => {
+                let input: AccountLockerClaimInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::claim(
+                    input.claimant,
+                    input.resource_address,
+                    input.amount,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT => {
+                let input: AccountLockerClaimNonFungiblesInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::claim_non_fungibles(
+                    input.claimant,
+                    input.resource_address,
+                    input.ids,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_GET_AMOUNT_IDENT => {
+                let input: AccountLockerGetAmountInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::get_amount(
+                    input.claimant,
+                    input.resource_address,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT => {
+                let input: AccountLockerGetNonFungibleLocalIdsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+
+                let rtn = AccountLockerBlueprint::get_non_fungible_local_ids(
+                    input.claimant,
+                    input.resource_address,
+                    input.limit,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::ExportDoesNotExist(export_name.to_string()),
+            )),
+        }
+    }
+}
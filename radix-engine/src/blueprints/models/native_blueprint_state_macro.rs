@@ -31,11 +31,10 @@ use crate::internal_prelude::*;
 ///         kind: Generic,
 ///         ident: BlueprintGenericParameterIdent,
 ///     },
-///     // In future
 ///     {
 ///         kind: StaticMultiVersioned,
-///         previous_versions: [V1, V2],
-///         latest: V3,
+///         previous_versions: [1 => { updates_to: 2 }, 2 => { updates_to: 3 }],
+///         latest_version: 3,
 ///     }
 /// ```
 ///
@@ -50,6 +49,11 @@ use crate::internal_prelude::*;
 /// and generate the following types:
 /// * `<BlueprintIdent><CollectionIdent>` - a type alias for the latest version (V1).
 /// * `Versioned<BlueprintIdent><CollectionIdent>` - the enum wrapper with a single version. This will be the content of `<BlueprintIdent><CollectionIdent>EntryPayload`.
+///
+/// Choosing `StaticMultiVersioned` creates the same types, except that the enum wrapper has a
+/// variant for each version, and assumes the existence of `<BlueprintIdent><FieldIdent>V<N>` for
+/// every version `N`. Previous versions are updated to the latest version when the content is read
+/// with `into_latest`, through the `From` implementations between successive versions.
 #[allow(unused)]
 macro_rules! declare_native_blueprint_state {
     (
@@ -660,7 +664,60 @@ mod helper_macros {
                 impl [<$ident_core ContentMarker>] for RawScryptoValue<'_> {}
             }
         };
-        // TODO - Add support for some kind of StaticMultiVersioned type here
+        (
+            content_trait: $content_trait:ident,
+            payload_trait: $payload_trait:ident,
+            ident_core: $ident_core:ident,
+            $(#[$attributes:meta])*
+            struct $payload_type_name:ident = {
+                kind: StaticMultiVersioned,
+                previous_versions: [
+                    $($version_num:literal => { updates_to: $update_to_version_num:literal }),*
+                    $(,)?
+                ],
+                latest_version: $latest_version:literal
+                $(,)?
+            }$(,)?
+        ) => {
+            paste::paste! {
+                sbor::define_versioned!(
+                    $(#[$attributes])*
+                    pub enum [<Versioned $ident_core>] {
+                        previous_versions: [
+                            $($version_num => [<$ident_core V $version_num>]: { updates_to: $update_to_version_num },)*
+                        ],
+                        latest_version: {
+                            $latest_version => $ident_core = [<$ident_core V $latest_version>],
+                        },
+                    }
+                );
+                declare_payload_new_type!(
+                    content_trait: $content_trait,
+                    payload_trait: $payload_trait,
+                    ----
+                    $(#[$attributes])*
+                    pub struct $payload_type_name([<Versioned $ident_core>]);
+                );
+
+                impl HasLatestVersion for $payload_type_name
+                {
+                    type Latest = <[<Versioned $ident_core>] as HasLatestVersion>::Latest;
+                    fn into_latest(self) -> Self::Latest {
+                        self.into_content().into_latest()
+                    }
+
+                    fn as_latest_ref(&self) -> Option<&Self::Latest> {
+                        self.as_ref().as_latest_ref()
+                    }
+                }
+
+                impl $content_trait<$payload_type_name> for $ident_core {
+                    fn into_content(self) -> [<Versioned $ident_core>] {
+                        self.into()
+                    }
+                }
+            }
+        };
     }
 
     #[allow(unused)]
@@ -817,6 +874,17 @@ mod helper_macros {
         ) => {
             TypeRef::Static($aggregator.add_child_type_and_descendents::<$payload_alias>())
         };
+        (
+            $blueprint_ident:ident,
+            $aggregator:ident,
+            {
+                kind: StaticMultiVersioned,
+                $($versions:tt)*
+            },
+            $payload_alias:ident$(,)?
+        ) => {
+            TypeRef::Static($aggregator.add_child_type_and_descendents::<$payload_alias>())
+        };
         (
             $blueprint_ident:ident,
             $aggregator:ident,
@@ -842,7 +910,7 @@ mod helper_macros {
             paste::paste! {
                 TypeRef::Generic([<$blueprint_ident Generic>]::$generic_ident.generic_index())
             }
-        }; // TODO - Add support for some kind of StaticMultiVersioned type here
+        };
     }
 
     #[allow(unused)]
@@ -0,0 +1,14 @@
+use crate::types::*;
+use radix_engine_common::{ScryptoEvent, ScryptoSbor};
+use radix_engine_interface::blueprints::package::CodeHash;
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, Clone, PartialEq, Eq)]
+pub struct PackageUpgradedEvent {
+    pub version: u32,
+    pub code_hash: CodeHash,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, Clone, PartialEq, Eq)]
+pub struct PackageUpgradesLockedEvent {
+    pub version: u32,
+}
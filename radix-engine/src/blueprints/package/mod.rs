@@ -1,5 +1,7 @@
+mod events;
 mod package;
 mod substates;
 
+pub use events::*;
 pub use package::*;
 pub use substates::*;
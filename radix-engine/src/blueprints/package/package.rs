@@ -1,3 +1,4 @@
+use super::events::*;
 use super::substates::*;
 use crate::blueprints::util::{check_name, InvalidNameError, SecurifiedRoleAssignment};
 use crate::internal_prelude::*;
@@ -8,17 +9,19 @@ use crate::system::system_modules::costing::{apply_royalty_cost, RoyaltyRecipien
 use crate::system::type_info::TypeInfoSubstate;
 use crate::track::interface::NodeSubstates;
 use crate::types::*;
-use crate::vm::wasm::PrepareError;
+use crate::vm::wasm::{PrepareError, WasmValidator};
 use native_sdk::modules::metadata::Metadata;
 use native_sdk::modules::role_assignment::RoleAssignment;
 use native_sdk::resource::NativeVault;
 use native_sdk::resource::ResourceManager;
+use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::node_modules::auth::{AuthAddresses, ROLE_ASSIGNMENT_BLUEPRINT};
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
 use radix_engine_interface::api::*;
 pub use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::blueprints::resource::{require, Bucket};
 use radix_engine_interface::schema::*;
+use sbor::schema::{compare_named_type_schemas, SchemaComparisonSettings};
 use sbor::LocalTypeId;
 
 // Import and re-export substate types
@@ -137,6 +140,14 @@ pub enum PackageError {
 
     RoyaltiesNotEnabled,
     RoyaltyAmountIsNegative(RoyaltyAmount),
+
+    UpgradesNotEnabled,
+    UpgradesLocked,
+    UpgradeRemovesBlueprint(String),
+    IncompatibleBlueprintUpgrade {
+        blueprint: String,
+        errors: Vec<String>,
+    },
 }
 
 impl From<InvalidNameError> for PackageError {
@@ -681,8 +692,9 @@ pub fn create_bootstrap_package_partitions(
     let mut node_substates = NodeSubstates::new();
 
     let own_features = PackageFeatureSet {
-        // Bootstrap packages are native packages which don't need royalties
+        // Bootstrap packages are native packages which don't need royalties or upgrades
         package_royalty: false,
+        package_upgrades: false,
     };
 
     //-----------------
@@ -698,7 +710,7 @@ pub fn create_bootstrap_package_partitions(
             indexmap!(PackageCollection::SchemaKeyValue.collection_index() as usize => SCHEMAS_PARTITION),
         );
         let package_system_struct =
-            PackageNativePackage::init_system_struct(None, package_structure);
+            PackageNativePackage::init_system_struct(None, None, package_structure);
         let package_substates = SystemMapper::system_struct_to_node_substates(
             &package_schema,
             package_system_struct,
@@ -776,6 +788,7 @@ fn globalize_package<Y>(
     package_structure: PackageStructure,
     metadata: Own,
     role_assignment: RoleAssignment,
+    upgradeable: bool,
     api: &mut Y,
 ) -> Result<PackageAddress, RuntimeError>
 where
@@ -783,12 +796,24 @@ where
 {
     let vault = Vault(ResourceManager(XRD).new_empty_vault(api)?);
 
+    let mut features = vec![PackageFeature::PackageRoyalty.feature_name()];
+    let upgrade_state = if upgradeable {
+        features.push(PackageFeature::PackageUpgrades.feature_name());
+        Some(PackageUpgradeState {
+            version: 0,
+            blueprints: package_structure.definitions.keys().cloned().collect(),
+            is_locked: false,
+        })
+    } else {
+        None
+    };
+
     let (fields, kv_entries) =
-        PackageNativePackage::init_system_struct(Some(vault), package_structure);
+        PackageNativePackage::init_system_struct(Some(vault), upgrade_state, package_structure);
 
     let package_object = api.new_object(
         PACKAGE_BLUEPRINT,
-        vec![PackageFeature::PackageRoyalty.feature_name()],
+        features,
         GenericArgs::default(),
         fields,
         kv_entries,
@@ -852,6 +877,21 @@ impl PackageNativePackage {
                 export: PACKAGE_PUBLISH_WASM_ADVANCED_IDENT.to_string(),
             },
         );
+        functions.insert(
+            PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackagePublishWasmUpgradeableInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackagePublishWasmUpgradeableOutput>(),
+                ),
+                export: PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT.to_string(),
+            },
+        );
         functions.insert(
             PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
             FunctionSchemaInit {
@@ -878,6 +918,37 @@ impl PackageNativePackage {
                 export: PACKAGE_CLAIM_ROYALTIES_IDENT.to_string(),
             },
         );
+        functions.insert(
+            PACKAGE_UPGRADE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PackageUpgradeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PackageUpgradeOutput>(),
+                ),
+                export: PACKAGE_UPGRADE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            PACKAGE_LOCK_UPGRADES_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PackageLockUpgradesInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PackageLockUpgradesOutput>(),
+                ),
+                export: PACKAGE_LOCK_UPGRADES_IDENT.to_string(),
+            },
+        );
+
+        let events = event_schema! {
+            aggregator,
+            [PackageUpgradedEvent, PackageUpgradesLockedEvent]
+        };
 
         let schema = generate_full_schema(aggregator);
         let blueprints = indexmap!(
@@ -893,7 +964,7 @@ impl PackageNativePackage {
                     generics: vec![],
                     schema,
                     state,
-                    events: BlueprintEventSchemaInit::default(),
+                    events,
                     types: BlueprintTypeSchemaInit::default(),
                     functions: BlueprintFunctionsSchemaInit {
                         functions,
@@ -906,6 +977,7 @@ impl PackageNativePackage {
                         indexmap!(
                             PACKAGE_PUBLISH_WASM_IDENT.to_string() => rule!(require(package_of_direct_caller(TRANSACTION_PROCESSOR_PACKAGE))),
                             PACKAGE_PUBLISH_WASM_ADVANCED_IDENT.to_string() => rule!(require(package_of_direct_caller(TRANSACTION_PROCESSOR_PACKAGE))),
+                            PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT.to_string() => rule!(require(package_of_direct_caller(TRANSACTION_PROCESSOR_PACKAGE))),
                             PACKAGE_PUBLISH_NATIVE_IDENT.to_string() => rule!(require(AuthAddresses::system_role())),
                         )
                     ),
@@ -916,6 +988,8 @@ impl PackageNativePackage {
                             },
                             methods {
                                 PACKAGE_CLAIM_ROYALTIES_IDENT => [SECURIFY_OWNER_ROLE];
+                                PACKAGE_UPGRADE_IDENT => [SECURIFY_OWNER_ROLE];
+                                PACKAGE_LOCK_UPGRADES_IDENT => [SECURIFY_OWNER_ROLE];
                             }
                         },
                     ),
@@ -929,6 +1003,7 @@ impl PackageNativePackage {
     pub fn invoke_export<Y>(
         export_name: &str,
         input: &IndexedScryptoValue,
+        wasm_validator: &WasmValidator,
         api: &mut Y,
     ) -> Result<IndexedScryptoValue, RuntimeError>
     where
@@ -955,7 +1030,13 @@ impl PackageNativePackage {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
 
-                let rtn = Self::publish_wasm(input.code, input.definition, input.metadata, api)?;
+                let rtn = Self::publish_wasm(
+                    input.code,
+                    input.definition,
+                    input.metadata,
+                    wasm_validator,
+                    api,
+                )?;
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
@@ -970,6 +1051,22 @@ impl PackageNativePackage {
                     input.definition,
                     input.metadata,
                     input.owner_role,
+                    wasm_validator,
+                    api,
+                )?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT => {
+                let input: PackagePublishWasmUpgradeableInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::publish_wasm_upgradeable(
+                    input.code,
+                    input.definition,
+                    input.metadata,
+                    wasm_validator,
                     api,
                 )?;
 
@@ -982,6 +1079,25 @@ impl PackageNativePackage {
                 let rtn = PackageRoyaltyNativeBlueprint::claim_royalties(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            PACKAGE_UPGRADE_IDENT => {
+                let input: PackageUpgradeInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PackageUpgradeNativeBlueprint::upgrade(
+                    input.definition,
+                    input.code,
+                    wasm_validator,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PACKAGE_LOCK_UPGRADES_IDENT => {
+                let _input: PackageLockUpgradesInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = PackageUpgradeNativeBlueprint::lock_upgrades(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
@@ -990,11 +1106,17 @@ impl PackageNativePackage {
 
     fn init_system_struct(
         royalty_vault: Option<Vault>,
+        upgrade_state: Option<PackageUpgradeState>,
         package_structure: PackageStructure,
     ) -> (
         IndexMap<u8, FieldValue>,
         IndexMap<u8, IndexMap<Vec<u8>, KVEntry>>,
     ) {
+        // The blueprint entries of upgradeable packages are left unlocked so that they can be
+        // overwritten by an upgrade. Code and schema entries are keyed by their hash, so they
+        // are always immutable.
+        let lock_blueprint_entries = upgrade_state.is_none();
+
         let mut fields = index_map_new();
         if let Some(vault) = royalty_vault {
             let royalty = PackageRoyaltyAccumulator {
@@ -1003,6 +1125,10 @@ impl PackageNativePackage {
             .into_payload();
             fields.insert(0u8, FieldValue::immutable(&royalty));
         }
+        if let Some(upgrade_state) = upgrade_state {
+            let upgrade_state = upgrade_state.into_payload();
+            fields.insert(1u8, FieldValue::new(&upgrade_state));
+        }
 
         let mut kv_entries: IndexMap<u8, IndexMap<Vec<u8>, KVEntry>> = index_map_new();
         {
@@ -1011,7 +1137,7 @@ impl PackageNativePackage {
                 let key = BlueprintVersionKey::new_default(blueprint);
                 let entry = KVEntry {
                     value: Some(scrypto_encode(&definition).unwrap()),
-                    locked: true,
+                    locked: lock_blueprint_entries,
                 };
                 definition_partition.insert(scrypto_encode(&key).unwrap(), entry);
             }
@@ -1027,7 +1153,7 @@ impl PackageNativePackage {
                 let key = BlueprintVersionKey::new_default(blueprint);
                 let entry = KVEntry {
                     value: Some(scrypto_encode(&dependencies).unwrap()),
-                    locked: true,
+                    locked: lock_blueprint_entries,
                 };
                 dependency_partition.insert(scrypto_encode(&key).unwrap(), entry);
             }
@@ -1043,7 +1169,7 @@ impl PackageNativePackage {
                 let key = BlueprintVersionKey::new_default(blueprint);
                 let entry = KVEntry {
                     value: Some(scrypto_encode(&package_royalty).unwrap()),
-                    locked: true,
+                    locked: lock_blueprint_entries,
                 };
                 package_royalties_partition.insert(scrypto_encode(&key).unwrap(), entry);
             }
@@ -1059,7 +1185,7 @@ impl PackageNativePackage {
                 let key = BlueprintVersionKey::new_default(blueprint);
                 let entry = KVEntry {
                     value: Some(scrypto_encode(&auth_config).unwrap()),
-                    locked: true,
+                    locked: lock_blueprint_entries,
                 };
                 auth_partition.insert(scrypto_encode(&key).unwrap(), entry);
             }
@@ -1137,6 +1263,7 @@ impl PackageNativePackage {
         vm_type: VmType,
         original_code: Vec<u8>,
         system_instructions: BTreeMap<String, Vec<SystemInstruction>>,
+        wasm_validator: &WasmValidator,
     ) -> Result<PackageStructure, RuntimeError> {
         // Validate schema
         validate_package_schema(&definition.blueprints)
@@ -1152,7 +1279,7 @@ impl PackageNativePackage {
 
         // Validate VM specific properties
         let instrumented_code =
-            VmPackageValidation::validate(&definition, vm_type, &original_code)?;
+            VmPackageValidation::validate(&definition, vm_type, &original_code, wasm_validator)?;
 
         // Build Package structure
         let mut definitions = index_map_new();
@@ -1337,6 +1464,7 @@ impl PackageNativePackage {
             VmType::Native,
             native_package_code_id.to_be_bytes().to_vec(),
             Default::default(),
+            &WasmValidator::default(),
         )?;
         let role_assignment = RoleAssignment::create(OwnerRole::None, indexmap!(), api)?;
         let metadata = Metadata::create_with_data(metadata_init, api)?;
@@ -1346,6 +1474,7 @@ impl PackageNativePackage {
             package_structure,
             metadata,
             role_assignment,
+            false,
             api,
         )
     }
@@ -1354,6 +1483,50 @@ impl PackageNativePackage {
         code: Vec<u8>,
         definition: PackageDefinition,
         metadata_init: MetadataInit,
+        wasm_validator: &WasmValidator,
+        api: &mut Y,
+    ) -> Result<(PackageAddress, Bucket), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::publish_wasm_with_owner_badge(
+            code,
+            definition,
+            metadata_init,
+            false,
+            wasm_validator,
+            api,
+        )
+    }
+
+    /// Publishes a package whose owner may later replace its code and blueprint definitions,
+    /// see [`PackageUpgradeNativeBlueprint::upgrade`].
+    pub(crate) fn publish_wasm_upgradeable<Y>(
+        code: Vec<u8>,
+        definition: PackageDefinition,
+        metadata_init: MetadataInit,
+        wasm_validator: &WasmValidator,
+        api: &mut Y,
+    ) -> Result<(PackageAddress, Bucket), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::publish_wasm_with_owner_badge(
+            code,
+            definition,
+            metadata_init,
+            true,
+            wasm_validator,
+            api,
+        )
+    }
+
+    fn publish_wasm_with_owner_badge<Y>(
+        code: Vec<u8>,
+        definition: PackageDefinition,
+        metadata_init: MetadataInit,
+        upgradeable: bool,
+        wasm_validator: &WasmValidator,
         api: &mut Y,
     ) -> Result<(PackageAddress, Bucket), RuntimeError>
     where
@@ -1365,6 +1538,7 @@ impl PackageNativePackage {
             VmType::ScryptoV1,
             code,
             Default::default(),
+            wasm_validator,
         )?;
 
         let (address_reservation, address) = api.allocate_global_address(BlueprintId {
@@ -1387,6 +1561,7 @@ impl PackageNativePackage {
             package_structure,
             metadata,
             role_assignment,
+            upgradeable,
             api,
         )?;
 
@@ -1399,6 +1574,7 @@ impl PackageNativePackage {
         definition: PackageDefinition,
         metadata_init: MetadataInit,
         owner_role: OwnerRole,
+        wasm_validator: &WasmValidator,
         api: &mut Y,
     ) -> Result<PackageAddress, RuntimeError>
     where
@@ -1410,6 +1586,7 @@ impl PackageNativePackage {
             VmType::ScryptoV1,
             code,
             Default::default(),
+            wasm_validator,
         )?;
         let metadata = Metadata::create_with_data(metadata_init, api)?;
         let role_assignment = SecurifiedPackage::create_advanced(owner_role, api)?;
@@ -1419,6 +1596,7 @@ impl PackageNativePackage {
             package_structure,
             metadata,
             role_assignment,
+            false,
             api,
         )
     }
@@ -1531,6 +1709,522 @@ impl PackageRoyaltyNativeBlueprint {
     }
 }
 
+/// Whether a payload of a blueprint is received by it (state, function inputs) or produced by it
+/// for others to decode (function outputs, events).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum PayloadDirection {
+    Inbound,
+    Outbound,
+}
+
+pub struct PackageUpgradeNativeBlueprint;
+
+impl PackageUpgradeNativeBlueprint {
+    /// Replaces the code and blueprint definitions of the package.
+    ///
+    /// Every existing blueprint must be kept. Its state, type and function input schemas may only
+    /// be extended, so that all substates created under a previous version remain valid, while
+    /// its function output and event schemas may only be narrowed, so that callers built against
+    /// a previous version can still decode them.
+    ///
+    /// Instances are not migrated: their substates remain valid under the extended schemas, and
+    /// they resolve the new definitions on their next invocation. Any change to the shape of
+    /// existing state is left to the blueprint itself.
+    pub(crate) fn upgrade<Y>(
+        definition: PackageDefinition,
+        code: Vec<u8>,
+        wasm_validator: &WasmValidator,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = Self::open_upgrade_state(api)?;
+        let mut upgrade_state = api
+            .field_read_typed::<PackageUpgradeStateFieldPayload>(handle)?
+            .into_latest();
+        if upgrade_state.is_locked {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::UpgradesLocked),
+            ));
+        }
+
+        validate_royalties(&definition, api)?;
+        let code_hash = CodeHash::from_hash(hash(&code));
+        let package_structure = PackageNativePackage::validate_and_build_package_structure(
+            definition,
+            VmType::ScryptoV1,
+            code,
+            Default::default(),
+            wasm_validator,
+        )?;
+
+        for blueprint in upgrade_state.blueprints.iter() {
+            let new_definition = package_structure
+                .definitions
+                .get(blueprint)
+                .and_then(|definition| definition.as_latest_ref())
+                .ok_or_else(|| {
+                    RuntimeError::ApplicationError(ApplicationError::PackageError(
+                        PackageError::UpgradeRemovesBlueprint(blueprint.clone()),
+                    ))
+                })?;
+            let old_definition = Self::read_blueprint_definition(blueprint, api)?;
+
+            let errors = Self::check_compatibility(
+                &old_definition.interface,
+                &new_definition.interface,
+                &package_structure.schemas,
+                api,
+            )?;
+            if !errors.is_empty() {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::PackageError(PackageError::IncompatibleBlueprintUpgrade {
+                        blueprint: blueprint.clone(),
+                        errors,
+                    }),
+                ));
+            }
+        }
+
+        for (schema_hash, schema) in package_structure.schemas {
+            Self::insert_immutable_entry(
+                PackageCollection::SchemaKeyValue,
+                &schema_hash,
+                schema,
+                api,
+            )?;
+        }
+        for (code_hash, vm_type) in package_structure.vm_type {
+            Self::insert_immutable_entry(
+                PackageCollection::CodeVmTypeKeyValue,
+                &code_hash,
+                vm_type,
+                api,
+            )?;
+        }
+        for (code_hash, code) in package_structure.original_code {
+            Self::insert_immutable_entry(
+                PackageCollection::CodeOriginalCodeKeyValue,
+                &code_hash,
+                code,
+                api,
+            )?;
+        }
+        for (code_hash, code) in package_structure.instrumented_code {
+            Self::insert_immutable_entry(
+                PackageCollection::CodeInstrumentedCodeKeyValue,
+                &code_hash,
+                code,
+                api,
+            )?;
+        }
+
+        for (blueprint, definition) in package_structure.definitions {
+            Self::set_blueprint_entry(
+                PackageCollection::BlueprintVersionDefinitionKeyValue,
+                &blueprint,
+                definition,
+                api,
+            )?;
+            upgrade_state.blueprints.insert(blueprint);
+        }
+        for (blueprint, dependencies) in package_structure.dependencies {
+            Self::set_blueprint_entry(
+                PackageCollection::BlueprintVersionDependenciesKeyValue,
+                &blueprint,
+                dependencies,
+                api,
+            )?;
+        }
+        for (blueprint, auth_config) in package_structure.auth_configs {
+            Self::set_blueprint_entry(
+                PackageCollection::BlueprintVersionAuthConfigKeyValue,
+                &blueprint,
+                auth_config,
+                api,
+            )?;
+        }
+        for (blueprint, royalty_config) in package_structure.package_royalties {
+            Self::set_blueprint_entry(
+                PackageCollection::BlueprintVersionRoyaltyConfigKeyValue,
+                &blueprint,
+                royalty_config,
+                api,
+            )?;
+        }
+
+        upgrade_state.version += 1;
+        let version = upgrade_state.version;
+        api.field_write_typed(handle, &upgrade_state.into_payload())?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(api, PackageUpgradedEvent { version, code_hash })?;
+
+        Ok(())
+    }
+
+    /// Permanently disables upgrades, locking the blueprint entries of the package.
+    pub(crate) fn lock_upgrades<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = Self::open_upgrade_state(api)?;
+        let mut upgrade_state = api
+            .field_read_typed::<PackageUpgradeStateFieldPayload>(handle)?
+            .into_latest();
+        if upgrade_state.is_locked {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::UpgradesLocked),
+            ));
+        }
+
+        for blueprint in upgrade_state.blueprints.iter() {
+            for collection in [
+                PackageCollection::BlueprintVersionDefinitionKeyValue,
+                PackageCollection::BlueprintVersionDependenciesKeyValue,
+                PackageCollection::BlueprintVersionAuthConfigKeyValue,
+                PackageCollection::BlueprintVersionRoyaltyConfigKeyValue,
+            ] {
+                let key = scrypto_encode(&BlueprintVersionKey::new_default(blueprint)).unwrap();
+                let entry_handle = api.actor_open_key_value_entry(
+                    ACTOR_STATE_SELF,
+                    collection.collection_index(),
+                    &key,
+                    LockFlags::MUTABLE,
+                )?;
+                api.key_value_entry_lock(entry_handle)?;
+                api.key_value_entry_close(entry_handle)?;
+            }
+        }
+
+        upgrade_state.is_locked = true;
+        let version = upgrade_state.version;
+        api.field_write_typed(handle, &upgrade_state.into_payload())?;
+        api.field_lock(handle)?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(api, PackageUpgradesLockedEvent { version })?;
+
+        Ok(())
+    }
+
+    fn open_upgrade_state<Y>(api: &mut Y) -> Result<FieldHandle, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            PackageFeature::PackageUpgrades.feature_name(),
+        )? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::UpgradesNotEnabled),
+            ));
+        }
+
+        api.actor_open_field(
+            ACTOR_STATE_SELF,
+            PackageField::UpgradeState.into(),
+            LockFlags::MUTABLE,
+        )
+    }
+
+    fn check_compatibility<Y>(
+        old: &BlueprintInterface,
+        new: &BlueprintInterface,
+        new_schemas: &IndexMap<SchemaHash, PackageSchemaEntryPayload>,
+        api: &mut Y,
+    ) -> Result<Vec<String>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let mut errors = vec![];
+
+        if old.blueprint_type != new.blueprint_type {
+            errors.push("blueprint type was changed".to_string());
+        }
+        if old.is_transient != new.is_transient {
+            errors.push("transience was changed".to_string());
+        }
+        if old.generics != new.generics {
+            errors.push("generics were changed".to_string());
+        }
+        for feature in old.feature_set.iter() {
+            if !new.feature_set.contains(feature) {
+                errors.push(format!("feature {} was removed", feature));
+            }
+        }
+
+        // Existing instances only have substates for the fields they were created with, so the
+        // fields must stay as they are. Collections may be added, as they start out empty.
+        match (&old.state.fields, &new.state.fields) {
+            (None, None) => {}
+            (Some((old_partition, old_fields)), Some((new_partition, new_fields)))
+                if old_partition == new_partition && old_fields.len() == new_fields.len() =>
+            {
+                for (index, (old_field, new_field)) in
+                    old_fields.iter().zip(new_fields.iter()).enumerate()
+                {
+                    if old_field.condition != new_field.condition
+                        || old_field.transience != new_field.transience
+                    {
+                        errors.push(format!(
+                            "fields[{}] had its condition or transience changed",
+                            index
+                        ));
+                    }
+                }
+            }
+            _ => errors.push("fields were added or removed".to_string()),
+        }
+        for (index, (old_partition, old_collection)) in old.state.collections.iter().enumerate() {
+            let compatible = match new.state.collections.get(index) {
+                Some((new_partition, new_collection)) => {
+                    old_partition == new_partition
+                        && match (old_collection, new_collection) {
+                            (
+                                BlueprintCollectionSchema::KeyValueStore(old),
+                                BlueprintCollectionSchema::KeyValueStore(new),
+                            )
+                            | (
+                                BlueprintCollectionSchema::Index(old),
+                                BlueprintCollectionSchema::Index(new),
+                            )
+                            | (
+                                BlueprintCollectionSchema::SortedIndex(old),
+                                BlueprintCollectionSchema::SortedIndex(new),
+                            ) => !old.allow_ownership || new.allow_ownership,
+                            _ => false,
+                        }
+                }
+                None => false,
+            };
+            if !compatible {
+                errors.push(format!("collections[{}] was removed or changed", index));
+            }
+        }
+
+        // Payloads are compared per pair of old and new schema, as each blueprint version
+        // resolves its types against the schema it was published with.
+        let new_defs = Self::named_payload_defs(new);
+        let mut comparisons: IndexMap<
+            (SchemaHash, SchemaHash, PayloadDirection),
+            (IndexMap<String, LocalTypeId>, IndexMap<String, LocalTypeId>),
+        > = index_map_new();
+        for (name, (old_def, direction)) in Self::named_payload_defs(old) {
+            match (old_def, new_defs.get(&name)) {
+                (
+                    BlueprintPayloadDef::Static(ScopedTypeId(old_hash, old_type_id)),
+                    Some((BlueprintPayloadDef::Static(ScopedTypeId(new_hash, new_type_id)), _)),
+                ) => {
+                    let (old_types, new_types) = comparisons
+                        .entry((old_hash, *new_hash, direction))
+                        .or_default();
+                    old_types.insert(name.clone(), old_type_id);
+                    new_types.insert(name, *new_type_id);
+                }
+                (
+                    BlueprintPayloadDef::Generic(old_index),
+                    Some((BlueprintPayloadDef::Generic(new_index), _)),
+                ) if old_index == *new_index => {}
+                (_, Some(_)) => errors.push(format!("{} changed between static and generic", name)),
+                (_, None) => errors.push(format!("{} was removed", name)),
+            }
+        }
+
+        for ((old_hash, new_hash, direction), (old_types, new_types)) in comparisons {
+            let old_schema = Self::read_schema(&old_hash, api)?;
+            let stored_new_schema;
+            let new_schema = match new_schemas.get(&new_hash) {
+                Some(schema) => schema.as_ref(),
+                None => {
+                    stored_new_schema = Self::read_schema(&new_hash, api)?;
+                    &stored_new_schema
+                }
+            };
+            // Inbound payloads written against the old schema must still be accepted, so the new
+            // types may only extend the old ones. Outbound payloads are decoded by callers and
+            // indexers against the old schema, so the new types may only narrow them instead.
+            let result = match direction {
+                PayloadDirection::Inbound => compare_named_type_schemas(
+                    &SchemaComparisonSettings::allow_extension(),
+                    old_schema.v1(),
+                    &old_types,
+                    new_schema.v1(),
+                    &new_types,
+                ),
+                PayloadDirection::Outbound => compare_named_type_schemas(
+                    &SchemaComparisonSettings::allow_extension(),
+                    new_schema.v1(),
+                    &new_types,
+                    old_schema.v1(),
+                    &old_types,
+                ),
+            };
+            errors.extend(result.errors.iter().map(|error| error.to_string()));
+        }
+
+        Ok(errors)
+    }
+
+    fn named_payload_defs(
+        interface: &BlueprintInterface,
+    ) -> IndexMap<String, (BlueprintPayloadDef, PayloadDirection)> {
+        let mut defs = index_map_new();
+        if let Some((_, fields)) = &interface.state.fields {
+            for (index, field) in fields.iter().enumerate() {
+                defs.insert(
+                    format!("fields[{}]", index),
+                    (field.field, PayloadDirection::Inbound),
+                );
+            }
+        }
+        for (index, (_, collection)) in interface.state.collections.iter().enumerate() {
+            let (BlueprintCollectionSchema::KeyValueStore(schema)
+            | BlueprintCollectionSchema::Index(schema)
+            | BlueprintCollectionSchema::SortedIndex(schema)) = collection;
+            defs.insert(
+                format!("collections[{}].key", index),
+                (schema.key, PayloadDirection::Inbound),
+            );
+            defs.insert(
+                format!("collections[{}].value", index),
+                (schema.value, PayloadDirection::Inbound),
+            );
+        }
+        for (name, event) in interface.events.iter() {
+            defs.insert(
+                format!("events.{}", name),
+                (*event, PayloadDirection::Outbound),
+            );
+        }
+        for (name, type_id) in interface.types.iter() {
+            defs.insert(
+                format!("types.{}", name),
+                (
+                    BlueprintPayloadDef::Static(*type_id),
+                    PayloadDirection::Inbound,
+                ),
+            );
+        }
+        for (name, function) in interface.functions.iter() {
+            defs.insert(
+                format!("functions.{}.input", name),
+                (function.input, PayloadDirection::Inbound),
+            );
+            defs.insert(
+                format!("functions.{}.output", name),
+                (function.output, PayloadDirection::Outbound),
+            );
+        }
+        defs
+    }
+
+    fn read_blueprint_definition<Y>(
+        blueprint: &str,
+        api: &mut Y,
+    ) -> Result<BlueprintDefinition, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let key = scrypto_encode(&BlueprintVersionKey::new_default(blueprint)).unwrap();
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PackageCollection::BlueprintVersionDefinitionKeyValue.collection_index(),
+            &key,
+            LockFlags::read_only(),
+        )?;
+        let definition = api
+            .key_value_entry_get_typed::<PackageBlueprintVersionDefinitionEntryPayload>(handle)?
+            .expect("Blueprints tracked by the upgrade state must have a definition");
+        api.key_value_entry_close(handle)?;
+
+        Ok(definition.into_latest())
+    }
+
+    fn read_schema<Y>(
+        schema_hash: &SchemaHash,
+        api: &mut Y,
+    ) -> Result<VersionedScryptoSchema, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PackageCollection::SchemaKeyValue.collection_index(),
+            &scrypto_encode(schema_hash).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let schema = api
+            .key_value_entry_get_typed::<PackageSchemaEntryPayload>(handle)?
+            .expect("Blueprint definitions must reference stored schemas");
+        api.key_value_entry_close(handle)?;
+
+        Ok(schema.into_content())
+    }
+
+    fn set_blueprint_entry<Y, V: ScryptoEncode>(
+        collection: PackageCollection,
+        blueprint: &str,
+        value: V,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let key = scrypto_encode(&BlueprintVersionKey::new_default(blueprint)).unwrap();
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            collection.collection_index(),
+            &key,
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(handle, value)?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    /// Inserts an entry of a hash-keyed collection (code and schemas). An existing entry under
+    /// the same hash has identical content, so it is left as is.
+    fn insert_immutable_entry<Y, K: ScryptoEncode, V: ScryptoEncode>(
+        collection: PackageCollection,
+        key: &K,
+        value: V,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let key = scrypto_encode(key).unwrap();
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            collection.collection_index(),
+            &key,
+            LockFlags::read_only(),
+        )?;
+        let exists = api
+            .key_value_entry_get_typed::<ScryptoValue>(handle)?
+            .is_some();
+        api.key_value_entry_close(handle)?;
+        if exists {
+            return Ok(());
+        }
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            collection.collection_index(),
+            &key,
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(handle, value)?;
+        api.key_value_entry_lock(handle)?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+}
+
 pub struct PackageAuthNativeBlueprint;
 
 impl PackageAuthNativeBlueprint {
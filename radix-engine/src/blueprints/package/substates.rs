@@ -8,6 +8,10 @@ declare_native_blueprint_state! {
         package_royalty: {
             ident: PackageRoyalty,
             description: "Enables the package royalty substate",
+        },
+        package_upgrades: {
+            ident: PackageUpgrades,
+            description: "Enables owner-controlled upgrades of the package code and blueprints",
         }
     },
     fields: {
@@ -17,6 +21,13 @@ declare_native_blueprint_state! {
                 kind: StaticSingleVersioned,
             },
             condition: Condition::if_feature(PackageFeature::PackageRoyalty),
+        },
+        upgrade_state:  {
+            ident: UpgradeState,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(PackageFeature::PackageUpgrades),
         }
     },
     collections: {
@@ -123,6 +134,17 @@ pub struct PackageRoyaltyAccumulatorV1 {
     pub royalty_vault: Vault,
 }
 
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct PackageUpgradeStateV1 {
+    /// The number of upgrades which have been applied, starting at 0 on publish.
+    pub version: u32,
+    /// The blueprints of the package. An upgrade may add blueprints but never remove them,
+    /// as existing instances must keep resolving their definition.
+    pub blueprints: IndexSet<String>,
+    /// Whether upgrades have been permanently disabled by the owner.
+    pub is_locked: bool,
+}
+
 //---------------------------------------
 // Collection models - By BlueprintVersion
 //---------------------------------------
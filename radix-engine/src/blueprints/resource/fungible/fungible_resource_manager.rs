@@ -46,6 +46,10 @@ declare_native_blueprint_state! {
             ident: Burn,
             description: "Enabled if the resource can ever support burning",
         },
+        supply_limits: {
+            ident: SupplyLimits,
+            description: "Enables the max supply and mint allowance limits of the resource",
+        },
     },
     fields: {
         divisibility: {
@@ -61,12 +65,28 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_feature(FungibleResourceManagerFeature::TrackTotalSupply),
         },
+        supply_limits: {
+            ident: SupplyLimits,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(FungibleResourceManagerFeature::SupplyLimits),
+        },
+        mint_allowance_usage: {
+            ident: MintAllowanceUsage,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(FungibleResourceManagerFeature::SupplyLimits),
+        },
     },
     collections: {}
 }
 
 pub type FungibleResourceManagerDivisibilityV1 = u8;
 pub type FungibleResourceManagerTotalSupplyV1 = Decimal;
+pub type FungibleResourceManagerSupplyLimitsV1 = ResourceSupplyLimits;
+pub type FungibleResourceManagerMintAllowanceUsageV1 = MintAllowanceUsage;
 
 /// Represents an error when accessing a bucket.
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -124,6 +144,7 @@ fn to_features_and_roles(
         vault_recall: role_init.recall_roles.is_some(),
         mint: role_init.mint_roles.is_some(),
         burn: role_init.burn_roles.is_some(),
+        supply_limits: false, // Will be set later
     };
 
     roles
@@ -200,6 +221,18 @@ impl FungibleResourceManagerBlueprint {
             },
         );
 
+        functions.insert(
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithSupplyLimitsInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithSupplyLimitsOutput>()),
+                export: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_EXPORT_NAME.to_string(),
+            },
+        );
+
         functions.insert(
             FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT.to_string(),
             FunctionSchemaInit {
@@ -333,6 +366,51 @@ impl FungibleResourceManagerBlueprint {
                 export: FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            RESOURCE_MANAGER_GET_SUPPLY_LIMITS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerGetSupplyLimitsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerGetSupplyLimitsOutput>(),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_GET_SUPPLY_LIMITS_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            RESOURCE_MANAGER_SET_SUPPLY_LIMITS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerSetSupplyLimitsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerSetSupplyLimitsOutput>(),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_SET_SUPPLY_LIMITS_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerLockSupplyLimitsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerLockSupplyLimitsOutput>(),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_EXPORT_NAME.to_string(),
+            },
+        );
 
         let event_schema = event_schema! {
             aggregator,
@@ -387,6 +465,9 @@ impl FungibleResourceManagerBlueprint {
                         RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_DROP_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_RESOURCE_TYPE_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_GET_SUPPLY_LIMITS_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_SET_SUPPLY_LIMITS_IDENT => [MINTER_UPDATER_ROLE];
+                        RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_IDENT => [MINTER_UPDATER_ROLE];
                     }
                 }),
             },
@@ -409,6 +490,7 @@ impl FungibleResourceManagerBlueprint {
             Decimal::ZERO,
             track_total_supply,
             divisibility,
+            None,
             resource_roles,
             api,
         )?;
@@ -443,6 +525,7 @@ impl FungibleResourceManagerBlueprint {
             initial_supply,
             track_total_supply,
             divisibility,
+            None,
             resource_roles,
             api,
         )?;
@@ -450,6 +533,68 @@ impl FungibleResourceManagerBlueprint {
 
         check_mint_amount(divisibility, initial_supply)?;
 
+        Self::globalize_with_initial_supply(
+            object_id,
+            owner_role,
+            address_reservation,
+            roles,
+            metadata,
+            initial_supply,
+            api,
+        )
+    }
+
+    pub(crate) fn create_with_supply_limits<Y>(
+        owner_role: OwnerRole,
+        divisibility: u8,
+        initial_supply: Decimal,
+        supply_limits: ResourceSupplyLimits,
+        lock_supply_limits: bool,
+        resource_roles: FungibleResourceRoles,
+        metadata: ModuleConfig<MetadataInit>,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<(ResourceAddress, Bucket), RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        verify_supply_limits(&supply_limits, initial_supply)?;
+
+        let (object_id, roles) = Self::create_object(
+            initial_supply,
+            true,
+            divisibility,
+            Some((supply_limits, lock_supply_limits)),
+            resource_roles,
+            api,
+        )?;
+        let address_reservation = Self::create_address_reservation(address_reservation, api)?;
+
+        check_mint_amount(divisibility, initial_supply)?;
+
+        Self::globalize_with_initial_supply(
+            object_id,
+            owner_role,
+            address_reservation,
+            roles,
+            metadata,
+            initial_supply,
+            api,
+        )
+    }
+
+    fn globalize_with_initial_supply<Y>(
+        object_id: NodeId,
+        owner_role: OwnerRole,
+        address_reservation: GlobalAddressReservation,
+        roles: RoleAssignmentInit,
+        metadata: ModuleConfig<MetadataInit>,
+        initial_supply: Decimal,
+        api: &mut Y,
+    ) -> Result<(ResourceAddress, Bucket), RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
         let (resource_address, bucket) = {
             let (address, inner_object) = globalize_object_with_inner_object_and_event(
                 object_id,
@@ -503,6 +648,7 @@ impl FungibleResourceManagerBlueprint {
         initial_supply: Decimal,
         track_total_supply: bool,
         divisibility: u8,
+        supply_limits: Option<(ResourceSupplyLimits, bool)>,
         resource_roles: FungibleResourceRoles,
         api: &mut Y,
     ) -> Result<(NodeId, RoleAssignmentInit), RuntimeError>
@@ -519,7 +665,7 @@ impl FungibleResourceManagerBlueprint {
                 )
         };
 
-        let (features, roles) = to_features_and_roles(track_total_supply, resource_roles);
+        let (mut features, roles) = to_features_and_roles(track_total_supply, resource_roles);
 
         if features.track_total_supply {
             let total_supply_field = if features.mint || features.burn {
@@ -542,6 +688,38 @@ impl FungibleResourceManagerBlueprint {
             );
         }
 
+        if let Some((supply_limits, lock_supply_limits)) = supply_limits {
+            features.supply_limits = true;
+
+            let supply_limits_field = if lock_supply_limits {
+                FieldValue::immutable(
+                    &FungibleResourceManagerSupplyLimitsFieldPayload::from_content_source(
+                        supply_limits,
+                    ),
+                )
+            } else {
+                FieldValue::new(
+                    &FungibleResourceManagerSupplyLimitsFieldPayload::from_content_source(
+                        supply_limits,
+                    ),
+                )
+            };
+            let mint_allowance_usage = new_mint_allowance_usage(api)?;
+
+            fields.insert(
+                FungibleResourceManagerField::SupplyLimits.into(),
+                supply_limits_field,
+            );
+            fields.insert(
+                FungibleResourceManagerField::MintAllowanceUsage.into(),
+                FieldValue::new(
+                    &FungibleResourceManagerMintAllowanceUsageFieldPayload::from_content_source(
+                        mint_allowance_usage,
+                    ),
+                ),
+            );
+        }
+
         let object_id = api.new_object(
             FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
             features.feature_names_str(),
@@ -608,6 +786,8 @@ impl FungibleResourceManagerBlueprint {
                 &FungibleResourceManagerTotalSupplyFieldPayload::from_content_source(total_supply),
             )?;
             api.field_close(total_supply_handle)?;
+
+            Self::apply_mint_to_supply_limits(amount, total_supply, api)?;
         }
 
         Ok(bucket)
@@ -791,6 +971,160 @@ impl FungibleResourceManagerBlueprint {
         }
     }
 
+    pub(crate) fn get_supply_limits<Y>(
+        api: &mut Y,
+    ) -> Result<Option<ResourceSupplyLimits>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerFeature::SupplyLimits.feature_name(),
+        )? {
+            let supply_limits_handle = api.actor_open_field(
+                ACTOR_STATE_SELF,
+                FungibleResourceManagerField::SupplyLimits.into(),
+                LockFlags::read_only(),
+            )?;
+            let supply_limits = api
+                .field_read_typed::<FungibleResourceManagerSupplyLimitsFieldPayload>(
+                    supply_limits_handle,
+                )?
+                .into_latest();
+            Ok(Some(supply_limits))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn set_supply_limits<Y>(
+        supply_limits: ResourceSupplyLimits,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_supply_limits_enabled(api)?;
+
+        let total_supply = Self::get_total_supply(api)?.unwrap_or_default();
+        verify_supply_limits(&supply_limits, total_supply)?;
+
+        let supply_limits_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerField::SupplyLimits.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let previous_supply_limits = api
+            .field_read_typed::<FungibleResourceManagerSupplyLimitsFieldPayload>(
+                supply_limits_handle,
+            )?
+            .into_latest();
+        api.field_write_typed(
+            supply_limits_handle,
+            &FungibleResourceManagerSupplyLimitsFieldPayload::from_content_source(
+                supply_limits.clone(),
+            ),
+        )?;
+        api.field_close(supply_limits_handle)?;
+
+        // A changed allowance starts a new period at the current epoch.
+        if previous_supply_limits.mint_allowance != supply_limits.mint_allowance {
+            let mint_allowance_usage = new_mint_allowance_usage(api)?;
+            let usage_handle = api.actor_open_field(
+                ACTOR_STATE_SELF,
+                FungibleResourceManagerField::MintAllowanceUsage.into(),
+                LockFlags::MUTABLE,
+            )?;
+            api.field_write_typed(
+                usage_handle,
+                &FungibleResourceManagerMintAllowanceUsageFieldPayload::from_content_source(
+                    mint_allowance_usage,
+                ),
+            )?;
+            api.field_close(usage_handle)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn lock_supply_limits<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_supply_limits_enabled(api)?;
+
+        let supply_limits_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerField::SupplyLimits.into(),
+            LockFlags::MUTABLE,
+        )?;
+        api.field_lock(supply_limits_handle)?;
+        api.field_close(supply_limits_handle)?;
+
+        Ok(())
+    }
+
+    fn apply_mint_to_supply_limits<Y>(
+        amount: Decimal,
+        total_supply: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerFeature::SupplyLimits.feature_name(),
+        )? {
+            return Ok(());
+        }
+
+        let supply_limits_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerField::SupplyLimits.into(),
+            LockFlags::read_only(),
+        )?;
+        let supply_limits = api
+            .field_read_typed::<FungibleResourceManagerSupplyLimitsFieldPayload>(
+                supply_limits_handle,
+            )?
+            .into_latest();
+        api.field_close(supply_limits_handle)?;
+
+        let usage_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerField::MintAllowanceUsage.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut usage = api
+            .field_read_typed::<FungibleResourceManagerMintAllowanceUsageFieldPayload>(
+                usage_handle,
+            )?
+            .into_latest();
+        apply_mint_to_supply_limits(&supply_limits, &mut usage, amount, total_supply, api)?;
+        api.field_write_typed(
+            usage_handle,
+            &FungibleResourceManagerMintAllowanceUsageFieldPayload::from_content_source(usage),
+        )?;
+        api.field_close(usage_handle)?;
+
+        Ok(())
+    }
+
+    fn assert_supply_limits_enabled<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerFeature::SupplyLimits.feature_name(),
+        )? {
+            return Err(SupplyLimitsError::SupplyLimitsNotEnabled.into());
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn amount_for_withdrawal<Y>(
         api: &mut Y,
         amount: Decimal,
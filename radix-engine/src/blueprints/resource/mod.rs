@@ -5,6 +5,7 @@ mod fungible;
 mod non_fungible;
 mod package;
 mod proof_common;
+mod supply_limits;
 mod vault_common;
 mod worktop;
 
@@ -15,5 +16,6 @@ pub use fungible::*;
 pub use non_fungible::*;
 pub use package::*;
 pub use proof_common::*;
+pub use supply_limits::*;
 pub use vault_common::*;
 pub use worktop::*;
@@ -46,6 +46,10 @@ declare_native_blueprint_state! {
             ident: Burn,
             description: "Enabled if the resource can ever support burning",
         },
+        supply_limits: {
+            ident: SupplyLimits,
+            description: "Enables the max supply and mint allowance limits of the resource",
+        },
     },
     fields: {
         id_type: {
@@ -67,6 +71,20 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_feature(NonFungibleResourceManagerFeature::TrackTotalSupply),
         },
+        supply_limits: {
+            ident: SupplyLimits,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(NonFungibleResourceManagerFeature::SupplyLimits),
+        },
+        mint_allowance_usage: {
+            ident: MintAllowanceUsage,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(NonFungibleResourceManagerFeature::SupplyLimits),
+        },
     },
     collections: {
         data: KeyValue {
@@ -86,6 +104,8 @@ declare_native_blueprint_state! {
 
 pub type NonFungibleResourceManagerIdTypeV1 = NonFungibleIdType;
 pub type NonFungibleResourceManagerTotalSupplyV1 = Decimal;
+pub type NonFungibleResourceManagerSupplyLimitsV1 = ResourceSupplyLimits;
+pub type NonFungibleResourceManagerMintAllowanceUsageV1 = MintAllowanceUsage;
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct NonFungibleResourceManagerMutableFieldsV1 {
     pub mutable_field_index: IndexMap<String, usize>,
@@ -221,6 +241,17 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithSupplyLimitsInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithSupplyLimitsOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_EXPORT_NAME.to_string(),
+            },
+        );
 
         functions.insert(
             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT.to_string(),
@@ -424,6 +455,51 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            RESOURCE_MANAGER_GET_SUPPLY_LIMITS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerGetSupplyLimitsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerGetSupplyLimitsOutput>(),
+                ),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_GET_SUPPLY_LIMITS_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            RESOURCE_MANAGER_SET_SUPPLY_LIMITS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerSetSupplyLimitsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerSetSupplyLimitsOutput>(),
+                ),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_SET_SUPPLY_LIMITS_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerLockSupplyLimitsInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerLockSupplyLimitsOutput>(),
+                ),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_EXPORT_NAME.to_string(),
+            },
+        );
 
         let event_schema = event_schema! {
             aggregator,
@@ -486,6 +562,9 @@ impl NonFungibleResourceManagerBlueprint {
                         RESOURCE_MANAGER_GET_RESOURCE_TYPE_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_GET_SUPPLY_LIMITS_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_SET_SUPPLY_LIMITS_IDENT => [MINTER_UPDATER_ROLE];
+                        RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_IDENT => [MINTER_UPDATER_ROLE];
                     }
                 }),
             },
@@ -648,6 +727,7 @@ impl NonFungibleResourceManagerBlueprint {
             indexmap!(),
             track_total_supply,
             non_fungible_schema,
+            None,
             resource_roles,
             api,
         )?;
@@ -705,6 +785,7 @@ impl NonFungibleResourceManagerBlueprint {
             entries,
             track_total_supply,
             non_fungible_schema,
+            None,
             resource_roles,
             api,
         )?;
@@ -772,6 +853,78 @@ impl NonFungibleResourceManagerBlueprint {
             entries,
             track_total_supply,
             non_fungible_schema,
+            None,
+            resource_roles,
+            api,
+        )?;
+
+        let address_reservation = match address_reservation {
+            Some(address_reservation) => address_reservation,
+            None => {
+                let (reservation, _) = api.allocate_global_address(BlueprintId {
+                    package_address: RESOURCE_PACKAGE,
+                    blueprint_name: NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+                })?;
+                reservation
+            }
+        };
+
+        let (resource_address, bucket) = globalize_object_with_inner_object_and_event(
+            object_id,
+            owner_role,
+            address_reservation,
+            roles,
+            metadata,
+            NON_FUNGIBLE_BUCKET_BLUEPRINT,
+            indexmap! {
+                NonFungibleBucketField::Liquid.field_index() => FieldValue::new(&LiquidNonFungibleResource::new(ids.clone())),
+                NonFungibleBucketField::Locked.field_index() => FieldValue::new(&LockedNonFungibleResource::default()),
+            },
+            MintNonFungibleResourceEvent::EVENT_NAME,
+            MintNonFungibleResourceEvent { ids },
+            api,
+        )?;
+
+        Ok((
+            ResourceAddress::new_or_panic(resource_address.into()),
+            Bucket(Own(bucket)),
+        ))
+    }
+
+    pub(crate) fn create_with_supply_limits<Y>(
+        owner_role: OwnerRole,
+        id_type: NonFungibleIdType,
+        non_fungible_schema: NonFungibleDataSchema,
+        entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
+        supply_limits: ResourceSupplyLimits,
+        lock_supply_limits: bool,
+        resource_roles: NonFungibleResourceRoles,
+        metadata: ModuleConfig<MetadataInit>,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<(ResourceAddress, Bucket), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if id_type == NonFungibleIdType::RUID && !entries.is_empty() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::NonFungibleLocalIdProvidedForRUIDType,
+                ),
+            ));
+        }
+
+        verify_non_fungible_supply_limits(&supply_limits)?;
+        verify_supply_limits(&supply_limits, entries.len().into())?;
+
+        let ids: IndexSet<NonFungibleLocalId> = entries.keys().cloned().collect();
+
+        let (object_id, roles) = Self::create_object(
+            id_type,
+            entries,
+            true,
+            non_fungible_schema,
+            Some((supply_limits, lock_supply_limits)),
             resource_roles,
             api,
         )?;
@@ -1204,6 +1357,7 @@ impl NonFungibleResourceManagerBlueprint {
         entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
         track_total_supply: bool,
         non_fungible_schema: NonFungibleDataSchema,
+        supply_limits: Option<(ResourceSupplyLimits, bool)>,
         resource_roles: NonFungibleResourceRoles,
         api: &mut Y,
     ) -> Result<(NodeId, RoleAssignmentInit), RuntimeError>
@@ -1271,6 +1425,38 @@ impl NonFungibleResourceManagerBlueprint {
             );
         }
 
+        if let Some((supply_limits, lock_supply_limits)) = supply_limits {
+            features.supply_limits = true;
+
+            let supply_limits_field = if lock_supply_limits {
+                FieldValue::immutable(
+                    &NonFungibleResourceManagerSupplyLimitsFieldPayload::from_content_source(
+                        supply_limits,
+                    ),
+                )
+            } else {
+                FieldValue::new(
+                    &NonFungibleResourceManagerSupplyLimitsFieldPayload::from_content_source(
+                        supply_limits,
+                    ),
+                )
+            };
+            let mint_allowance_usage = new_mint_allowance_usage(api)?;
+
+            fields.insert(
+                NonFungibleResourceManagerField::SupplyLimits.into(),
+                supply_limits_field,
+            );
+            fields.insert(
+                NonFungibleResourceManagerField::MintAllowanceUsage.into(),
+                FieldValue::new(
+                    &NonFungibleResourceManagerMintAllowanceUsageFieldPayload::from_content_source(
+                        mint_allowance_usage,
+                    ),
+                ),
+            );
+        }
+
         let object_id = api.new_object(
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
             features.feature_names_str(),
@@ -1407,6 +1593,165 @@ impl NonFungibleResourceManagerBlueprint {
                     total_supply,
                 ),
             )?;
+
+            if amount.is_positive() {
+                Self::apply_mint_to_supply_limits(amount, total_supply, api)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn get_supply_limits<Y>(
+        api: &mut Y,
+    ) -> Result<Option<ResourceSupplyLimits>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::SupplyLimits.feature_name(),
+        )? {
+            let supply_limits_handle = api.actor_open_field(
+                ACTOR_STATE_SELF,
+                NonFungibleResourceManagerField::SupplyLimits.into(),
+                LockFlags::read_only(),
+            )?;
+            let supply_limits = api
+                .field_read_typed::<NonFungibleResourceManagerSupplyLimitsFieldPayload>(
+                    supply_limits_handle,
+                )?
+                .into_latest();
+            Ok(Some(supply_limits))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn set_supply_limits<Y>(
+        supply_limits: ResourceSupplyLimits,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_supply_limits_enabled(api)?;
+
+        let total_supply = Self::get_total_supply(api)?.unwrap_or_default();
+        verify_non_fungible_supply_limits(&supply_limits)?;
+        verify_supply_limits(&supply_limits, total_supply)?;
+
+        let supply_limits_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::SupplyLimits.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let previous_supply_limits = api
+            .field_read_typed::<NonFungibleResourceManagerSupplyLimitsFieldPayload>(
+                supply_limits_handle,
+            )?
+            .into_latest();
+        api.field_write_typed(
+            supply_limits_handle,
+            &NonFungibleResourceManagerSupplyLimitsFieldPayload::from_content_source(
+                supply_limits.clone(),
+            ),
+        )?;
+        api.field_close(supply_limits_handle)?;
+
+        // A changed allowance starts a new period at the current epoch.
+        if previous_supply_limits.mint_allowance != supply_limits.mint_allowance {
+            let mint_allowance_usage = new_mint_allowance_usage(api)?;
+            let usage_handle = api.actor_open_field(
+                ACTOR_STATE_SELF,
+                NonFungibleResourceManagerField::MintAllowanceUsage.into(),
+                LockFlags::MUTABLE,
+            )?;
+            api.field_write_typed(
+                usage_handle,
+                &NonFungibleResourceManagerMintAllowanceUsageFieldPayload::from_content_source(
+                    mint_allowance_usage,
+                ),
+            )?;
+            api.field_close(usage_handle)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn lock_supply_limits<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_supply_limits_enabled(api)?;
+
+        let supply_limits_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::SupplyLimits.into(),
+            LockFlags::MUTABLE,
+        )?;
+        api.field_lock(supply_limits_handle)?;
+        api.field_close(supply_limits_handle)?;
+
+        Ok(())
+    }
+
+    fn apply_mint_to_supply_limits<Y>(
+        amount: Decimal,
+        total_supply: Decimal,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::SupplyLimits.feature_name(),
+        )? {
+            return Ok(());
+        }
+
+        let supply_limits_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::SupplyLimits.into(),
+            LockFlags::read_only(),
+        )?;
+        let supply_limits = api
+            .field_read_typed::<NonFungibleResourceManagerSupplyLimitsFieldPayload>(
+                supply_limits_handle,
+            )?
+            .into_latest();
+        api.field_close(supply_limits_handle)?;
+
+        let usage_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerField::MintAllowanceUsage.into(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut usage = api
+            .field_read_typed::<NonFungibleResourceManagerMintAllowanceUsageFieldPayload>(
+                usage_handle,
+            )?
+            .into_latest();
+        apply_mint_to_supply_limits(&supply_limits, &mut usage, amount, total_supply, api)?;
+        api.field_write_typed(
+            usage_handle,
+            &NonFungibleResourceManagerMintAllowanceUsageFieldPayload::from_content_source(usage),
+        )?;
+        api.field_close(usage_handle)?;
+
+        Ok(())
+    }
+
+    fn assert_supply_limits_enabled<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::SupplyLimits.feature_name(),
+        )? {
+            return Err(SupplyLimitsError::SupplyLimitsNotEnabled.into());
         }
 
         Ok(())
@@ -1441,6 +1786,7 @@ fn to_features_and_roles(
         vault_recall: role_init.recall_roles.is_some(),
         mint: role_init.mint_roles.is_some(),
         burn: role_init.burn_roles.is_some(),
+        supply_limits: false, // Will be set later
     };
 
     roles
@@ -37,6 +37,14 @@ pub(crate) const FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME: &s
     "amount_for_withdrawal_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME: &str =
     "drop_empty_bucket_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_EXPORT_NAME: &str =
+    "create_with_supply_limits_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_GET_SUPPLY_LIMITS_EXPORT_NAME: &str =
+    "get_supply_limits_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_SET_SUPPLY_LIMITS_EXPORT_NAME: &str =
+    "set_supply_limits_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_EXPORT_NAME: &str =
+    "lock_supply_limits_FungibleResourceManager";
 
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME: &str =
     "create_NonFungibleResourceManager";
@@ -62,6 +70,14 @@ pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME
     "amount_for_withdrawal_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME: &str =
     "drop_empty_bucket_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_EXPORT_NAME: &str =
+    "create_with_supply_limits_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_GET_SUPPLY_LIMITS_EXPORT_NAME: &str =
+    "get_supply_limits_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_SET_SUPPLY_LIMITS_EXPORT_NAME: &str =
+    "set_supply_limits_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_EXPORT_NAME: &str =
+    "lock_supply_limits_NonFungibleResourceManager";
 
 pub(crate) const FUNGIBLE_VAULT_TAKE_EXPORT_NAME: &str = "take_FungibleVault";
 pub(crate) const FUNGIBLE_VAULT_TAKE_ADVANCED_EXPORT_NAME: &str = "take_advanced_FungibleVault";
@@ -1185,6 +1201,24 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_EXPORT_NAME => {
+                let input: FungibleResourceManagerCreateWithSupplyLimitsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::create_with_supply_limits(
+                    input.owner_role,
+                    input.divisibility,
+                    input.initial_supply,
+                    input.supply_limits,
+                    input.lock_supply_limits,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME => {
                 let input: FungibleResourceManagerMintInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1257,6 +1291,30 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_RESOURCE_MANAGER_GET_SUPPLY_LIMITS_EXPORT_NAME => {
+                let _input: ResourceManagerGetSupplyLimitsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::get_supply_limits(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            FUNGIBLE_RESOURCE_MANAGER_SET_SUPPLY_LIMITS_EXPORT_NAME => {
+                let input: ResourceManagerSetSupplyLimitsInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    FungibleResourceManagerBlueprint::set_supply_limits(input.supply_limits, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            FUNGIBLE_RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_EXPORT_NAME => {
+                let _input: ResourceManagerLockSupplyLimitsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::lock_supply_limits(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EXPORT_NAME => {
                 let input: NonFungibleResourceManagerCreateInput =
                     input.as_typed().map_err(|e| {
@@ -1311,6 +1369,25 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_EXPORT_NAME => {
+                let input: NonFungibleResourceManagerCreateWithSupplyLimitsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::create_with_supply_limits(
+                    input.owner_role,
+                    input.id_type,
+                    input.non_fungible_schema,
+                    input.entries,
+                    input.supply_limits,
+                    input.lock_supply_limits,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME => {
                 let input: NonFungibleResourceManagerMintInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1429,6 +1506,32 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_SUPPLY_LIMITS_EXPORT_NAME => {
+                let _input: ResourceManagerGetSupplyLimitsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::get_supply_limits(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            NON_FUNGIBLE_RESOURCE_MANAGER_SET_SUPPLY_LIMITS_EXPORT_NAME => {
+                let input: ResourceManagerSetSupplyLimitsInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleResourceManagerBlueprint::set_supply_limits(
+                    input.supply_limits,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            NON_FUNGIBLE_RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_EXPORT_NAME => {
+                let _input: ResourceManagerLockSupplyLimitsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::lock_supply_limits(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_IDENT => {
                 let input: NonFungibleResourceManagerGetNonFungibleInput =
                     input.as_typed().map_err(|e| {
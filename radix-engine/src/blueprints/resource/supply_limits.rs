@@ -0,0 +1,154 @@
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use crate::types::*;
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::resource::*;
+
+/// Tracks the amount minted within the current period of a resource's mint allowance.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct MintAllowanceUsage {
+    /// The first epoch of the current period.
+    pub period_start: Epoch,
+    /// The amount minted since the start of the current period.
+    pub minted: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum SupplyLimitsError {
+    SupplyLimitsNotEnabled,
+    InvalidSupplyLimits(ResourceSupplyLimits),
+    MaxSupplyBelowTotalSupply {
+        max_supply: Decimal,
+        total_supply: Decimal,
+    },
+    MaxSupplyExceeded {
+        max_supply: Decimal,
+        total_supply: Decimal,
+    },
+    MintAllowanceExceeded {
+        allowance: Decimal,
+        minted: Decimal,
+    },
+    UnexpectedDecimalComputationError,
+}
+
+impl From<SupplyLimitsError> for RuntimeError {
+    fn from(error: SupplyLimitsError) -> Self {
+        RuntimeError::ApplicationError(ApplicationError::SupplyLimitsError(error))
+    }
+}
+
+/// Checks that the supply limits are well-formed and that the max supply is not below the current
+/// total supply of the resource.
+pub(crate) fn verify_supply_limits(
+    supply_limits: &ResourceSupplyLimits,
+    total_supply: Decimal,
+) -> Result<(), RuntimeError> {
+    let is_valid = supply_limits
+        .max_supply
+        .map_or(true, |max_supply| !max_supply.is_negative())
+        && supply_limits
+            .mint_allowance
+            .as_ref()
+            .map_or(true, |allowance| {
+                !allowance.amount.is_negative() && allowance.period_in_epochs > 0
+            });
+    if !is_valid {
+        return Err(SupplyLimitsError::InvalidSupplyLimits(supply_limits.clone()).into());
+    }
+
+    if let Some(max_supply) = supply_limits.max_supply {
+        if total_supply > max_supply {
+            return Err(SupplyLimitsError::MaxSupplyBelowTotalSupply {
+                max_supply,
+                total_supply,
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the max supply and mint allowance of a non-fungible resource are whole numbers, as
+/// non-fungibles can't be divided.
+pub(crate) fn verify_non_fungible_supply_limits(
+    supply_limits: &ResourceSupplyLimits,
+) -> Result<(), RuntimeError> {
+    let is_whole = |amount: &Decimal| check_fungible_amount(amount, 0);
+    let is_valid = supply_limits.max_supply.as_ref().map_or(true, is_whole)
+        && supply_limits
+            .mint_allowance
+            .as_ref()
+            .map_or(true, |allowance| is_whole(&allowance.amount));
+    if !is_valid {
+        return Err(SupplyLimitsError::InvalidSupplyLimits(supply_limits.clone()).into());
+    }
+
+    Ok(())
+}
+
+/// Returns the usage of a freshly configured mint allowance, with its first period starting at the
+/// current epoch.
+pub(crate) fn new_mint_allowance_usage<Y>(api: &mut Y) -> Result<MintAllowanceUsage, RuntimeError>
+where
+    Y: ClientApi<RuntimeError>,
+{
+    Ok(MintAllowanceUsage {
+        period_start: Runtime::current_epoch(api)?,
+        minted: Decimal::ZERO,
+    })
+}
+
+/// Checks a mint of `amount`, which brings the total supply of the resource to `total_supply`,
+/// against the supply limits, and records it in the mint allowance usage.
+pub(crate) fn apply_mint_to_supply_limits<Y>(
+    supply_limits: &ResourceSupplyLimits,
+    usage: &mut MintAllowanceUsage,
+    amount: Decimal,
+    total_supply: Decimal,
+    api: &mut Y,
+) -> Result<(), RuntimeError>
+where
+    Y: ClientApi<RuntimeError>,
+{
+    if let Some(max_supply) = supply_limits.max_supply {
+        if total_supply > max_supply {
+            return Err(SupplyLimitsError::MaxSupplyExceeded {
+                max_supply,
+                total_supply,
+            }
+            .into());
+        }
+    }
+
+    if let Some(allowance) = &supply_limits.mint_allowance {
+        // Periods are aligned to the epoch at which the allowance was configured, so that the
+        // schedule can be verified from the state alone.
+        let current_epoch = Runtime::current_epoch(api)?;
+        let elapsed_epochs = current_epoch
+            .number()
+            .saturating_sub(usage.period_start.number());
+        if elapsed_epochs >= allowance.period_in_epochs {
+            let skipped_epochs = elapsed_epochs - elapsed_epochs % allowance.period_in_epochs;
+            usage.period_start = Epoch::of(usage.period_start.number() + skipped_epochs);
+            usage.minted = Decimal::ZERO;
+        }
+
+        let minted = usage
+            .minted
+            .checked_add(amount)
+            .ok_or(SupplyLimitsError::UnexpectedDecimalComputationError)?;
+        if minted > allowance.amount {
+            return Err(SupplyLimitsError::MintAllowanceExceeded {
+                allowance: allowance.amount,
+                minted,
+            }
+            .into());
+        }
+        usage.minted = minted;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::blueprints::access_controller::AccessControllerError;
 use crate::blueprints::account::AccountError;
 use crate::blueprints::consensus_manager::{ConsensusManagerError, ValidatorError};
+use crate::blueprints::locker::AccountLockerError;
 use crate::blueprints::package::PackageError;
 use crate::blueprints::pool::multi_resource_pool::MultiResourcePoolError;
 use crate::blueprints::pool::one_resource_pool::OneResourcePoolError;
@@ -8,7 +9,7 @@ use crate::blueprints::pool::two_resource_pool::TwoResourcePoolError;
 use crate::blueprints::resource::{AuthZoneError, NonFungibleVaultError};
 use crate::blueprints::resource::{
     BucketError, FungibleResourceManagerError, NonFungibleResourceManagerError, ProofError,
-    VaultError, WorktopError,
+    SupplyLimitsError, VaultError, WorktopError,
 };
 use crate::blueprints::transaction_processor::TransactionProcessorError;
 use crate::kernel::call_frame::{
@@ -56,6 +57,7 @@ pub enum RejectionReason {
     },
     IntentHashPreviouslyCommitted,
     IntentHashPreviouslyCancelled,
+    AuthRolesSkippedOutsidePreview,
 }
 
 impl fmt::Display for RejectionReason {
@@ -317,6 +319,7 @@ pub enum SystemModuleError {
     CostingError(CostingError),
     TransactionLimitsError(TransactionLimitsError),
     EventError(Box<EventError>),
+    UserModuleError(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -464,6 +467,10 @@ pub enum ApplicationError {
     TwoResourcePoolError(TwoResourcePoolError),
 
     MultiResourcePoolError(MultiResourcePoolError),
+
+    SupplyLimitsError(SupplyLimitsError),
+
+    AccountLockerError(AccountLockerError),
 }
 
 impl From<TransactionProcessorError> for ApplicationError {
@@ -490,6 +497,12 @@ impl From<FungibleResourceManagerError> for ApplicationError {
     }
 }
 
+impl From<SupplyLimitsError> for ApplicationError {
+    fn from(value: SupplyLimitsError) -> Self {
+        Self::SupplyLimitsError(value)
+    }
+}
+
 impl From<RoleAssignmentError> for ApplicationError {
     fn from(value: RoleAssignmentError) -> Self {
         Self::RoleAssignmentError(value)
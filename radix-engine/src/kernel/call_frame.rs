@@ -1292,6 +1292,7 @@ impl<C, L: Clone> CallFrame<C, L> {
         substate_io: &'f mut SubstateIO<S>,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        range: &SortedKeyRange,
         count: u32,
         handler: &mut impl CallFrameIOAccessHandler<C, L, E>,
     ) -> Result<
@@ -1312,7 +1313,7 @@ impl<C, L: Clone> CallFrame<C, L> {
         };
 
         let substates =
-            substate_io.scan_sorted(device, node_id, partition_num, count, &mut adapter)?;
+            substate_io.scan_sorted(device, node_id, partition_num, range, count, &mut adapter)?;
 
         for (key, substate) in &substates {
             self.process_output_substate_key(&SubstateKey::Sorted(key.clone()))
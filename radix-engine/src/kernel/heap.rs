@@ -180,6 +180,32 @@ impl Heap {
         }
     }
 
+    /// Scans the substates of a node's sorted partition, within the given range of sort prefixes.
+    /// On an non-existing node/partition, this will return an empty vector
+    pub fn scan_sorted(
+        &self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        range: &SortedKeyRange,
+        count: u32,
+    ) -> Vec<(SortedKey, IndexedScryptoValue)> {
+        let node_substates = self.nodes.get(node_id).and_then(|n| n.get(&partition_num));
+        if let Some(substates) = node_substates {
+            let start = SubstateKey::Sorted((range.start, vec![]));
+            substates
+                .range(start..)
+                .filter_map(|(key, value)| match key {
+                    SubstateKey::Sorted(sorted_key) => Some((sorted_key.clone(), value.clone())),
+                    _ => None,
+                })
+                .take_while(|(sorted_key, _)| range.contains(&sorted_key.0))
+                .take(count.try_into().unwrap())
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
     /// Drains the substates from a node's partition. On an non-existing node/partition, this
     /// will return an empty vector
     pub fn drain_substates<E, F: FnMut(&Heap, IOAccess) -> Result<(), E>>(
@@ -1036,6 +1036,7 @@ where
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        range: &SortedKeyRange,
         limit: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         self.callback
@@ -1056,6 +1057,7 @@ where
                 &mut self.substate_io,
                 node_id,
                 partition_num,
+                range,
                 limit,
                 &mut handler,
             )
@@ -125,7 +125,8 @@ pub trait KernelSubstateApi<L> {
         substate_key: &SubstateKey,
     ) -> Result<Option<IndexedScryptoValue>, RuntimeError>;
 
-    /// Reads substates under a node in sorted lexicographical order
+    /// Reads substates under a node in sorted lexicographical order, within the given range of
+    /// sort prefixes
     ///
     /// Clients must ensure that this isn't used in conjunction with virtualized
     /// substates; otherwise, the behavior is undefined
@@ -133,6 +134,7 @@ pub trait KernelSubstateApi<L> {
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        range: &SortedKeyRange,
         count: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError>;
 
@@ -645,6 +645,7 @@ impl<'g, S: CommitableSubstateStore + 'g> SubstateIO<'g, S> {
         device: SubstateDevice,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        range: &SortedKeyRange,
         count: u32,
         handler: &mut impl IOAccessHandler<E>,
     ) -> Result<
@@ -652,14 +653,10 @@ impl<'g, S: CommitableSubstateStore + 'g> SubstateIO<'g, S> {
         CallbackError<CallFrameScanSortedSubstatesError, E>,
     > {
         let substates = match device {
-            SubstateDevice::Heap => {
-                // This should never be triggered because sorted index store is
-                // used by consensus manager only.
-                panic!("Unexpected code path")
-            }
+            SubstateDevice::Heap => self.heap.scan_sorted(node_id, partition_num, range, count),
             SubstateDevice::Store => self
                 .store
-                .scan_sorted_substates(node_id, partition_num, count, &mut |io_access| {
+                .scan_sorted_substates(node_id, partition_num, range, count, &mut |io_access| {
                     handler.on_io_access(&self.heap, io_access)
                 })
                 .map_err(|e| CallbackError::CallbackError(e))?,
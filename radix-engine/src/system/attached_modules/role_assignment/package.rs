@@ -115,6 +115,19 @@ impl RoleAssignmentNativePackage {
                 export: ROLE_ASSIGNMENT_GET_IDENT.to_string(),
             },
         );
+        functions.insert(
+            ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentGetOwnerRoleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentGetOwnerRoleOutput>(),
+                ),
+                export: ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT.to_string(),
+            },
+        );
 
         let events = event_schema! {
             aggregator,
@@ -186,6 +199,7 @@ impl RoleAssignmentNativePackage {
                 Self::resolve_update_owner_role_method_permission(global_address.as_node_id(), api)?
             }
             ROLE_ASSIGNMENT_GET_IDENT => ResolvedPermission::AllowAll,
+            ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT => ResolvedPermission::AllowAll,
             _ => {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::AuthError(AuthError::NoMethodMapping(FnIdentifier {
@@ -251,6 +265,14 @@ impl RoleAssignmentNativePackage {
                 let rtn = Self::get_role(input.module, input.role_key, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT => {
+                let _input: RoleAssignmentGetOwnerRoleInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::get_owner_role(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
@@ -577,6 +599,23 @@ impl RoleAssignmentNativePackage {
         Ok(())
     }
 
+    pub(crate) fn get_owner_role<Y>(api: &mut Y) -> Result<OwnerRoleEntry, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            RoleAssignmentField::Owner.into(),
+            LockFlags::read_only(),
+        )?;
+        let owner_role = api
+            .field_read_typed::<RoleAssignmentOwnerFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        Ok(owner_role.owner_role_entry)
+    }
+
     pub(crate) fn get_role<Y>(
         module: ModuleId,
         role_key: RoleKey,
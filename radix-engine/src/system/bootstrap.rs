@@ -2,6 +2,7 @@ use crate::blueprints::access_controller::*;
 use crate::blueprints::account::{AccountNativePackage, AccountOwnerBadgeData};
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
 use crate::blueprints::identity::{IdentityNativePackage, IdentityOwnerBadgeData};
+use crate::blueprints::locker::LockerNativePackage;
 use crate::blueprints::package::{
     create_bootstrap_package_partitions, PackageCollection, PackageNativePackage,
     PackageOwnerBadgeData, SystemInstruction,
@@ -29,6 +30,7 @@ use crate::transaction::{
     SubstateSchemaMapper, SubstateSystemStructures, TransactionOutcome, TransactionReceipt,
     TransactionResult,
 };
+use crate::vm::wasm::WasmValidator;
 use lazy_static::lazy_static;
 use radix_engine_common::crypto::Secp256k1PublicKey;
 use radix_engine_common::types::ComponentAddress;
@@ -577,6 +579,7 @@ pub fn create_system_bootstrap_flash(
                 VmType::Native,
                 native_code_id.to_be_bytes().to_vec(),
                 system_instructions,
+                &WasmValidator::default(),
             )
             .unwrap_or_else(|err| {
                 panic!(
@@ -1069,6 +1072,28 @@ pub fn create_system_bootstrap_transaction(
         });
     }
 
+    // Locker Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(LOCKER_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: LockerNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Locker Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of account lockers, which hold resources that account owners can claim.".to_owned(), locked;
+                },
+                native_package_code_id: LOCKER_CODE_ID,
+            }),
+        });
+    }
+
     // ECDSA Secp256k1
     {
         pre_allocated_addresses.push((
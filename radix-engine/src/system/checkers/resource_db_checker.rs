@@ -3,8 +3,10 @@ use crate::blueprints::consensus_manager::{
     ConsensusManagerCurrentValidatorSetFieldPayload, ConsensusManagerField,
 };
 use crate::blueprints::resource::{
-    FungibleResourceManagerField, FungibleResourceManagerTotalSupplyFieldPayload,
-    FungibleVaultBalanceFieldPayload, FungibleVaultField, NonFungibleResourceManagerField,
+    FungibleResourceManagerField, FungibleResourceManagerSupplyLimitsFieldPayload,
+    FungibleResourceManagerTotalSupplyFieldPayload, FungibleVaultBalanceFieldPayload,
+    FungibleVaultField, NonFungibleResourceManagerField,
+    NonFungibleResourceManagerSupplyLimitsFieldPayload,
     NonFungibleResourceManagerTotalSupplyFieldPayload, NonFungibleVaultBalanceFieldPayload,
     NonFungibleVaultCollection, NonFungibleVaultField,
 };
@@ -27,6 +29,7 @@ use sbor::HasLatestVersion;
 #[derive(Debug, Default)]
 pub struct ResourceCounter {
     expected: Option<Decimal>,
+    max_supply: Option<Decimal>,
     tracking_supply: Decimal,
 }
 
@@ -69,6 +72,13 @@ impl ApplicationChecker for ResourceDatabaseChecker {
                         let tracker = self.resources.entry(address).or_default();
                         tracker.expected = Some(total_supply.into_latest());
                     }
+                    FungibleResourceManagerField::SupplyLimits => {
+                        let supply_limits: FungibleResourceManagerSupplyLimitsFieldPayload =
+                            scrypto_decode(value).unwrap();
+                        let address = ResourceAddress::new_or_panic(node_id.0);
+                        let tracker = self.resources.entry(address).or_default();
+                        tracker.max_supply = supply_limits.into_latest().max_supply;
+                    }
                     _ => {}
                 }
             }
@@ -106,6 +116,13 @@ impl ApplicationChecker for ResourceDatabaseChecker {
                         let tracker = self.resources.entry(address).or_default();
                         tracker.expected = Some(total_supply.into_latest());
                     }
+                    NonFungibleResourceManagerField::SupplyLimits => {
+                        let supply_limits: NonFungibleResourceManagerSupplyLimitsFieldPayload =
+                            scrypto_decode(value).unwrap();
+                        let address = ResourceAddress::new_or_panic(node_id.0);
+                        let tracker = self.resources.entry(address).or_default();
+                        tracker.max_supply = supply_limits.into_latest().max_supply;
+                    }
                     _ => {}
                 }
             }
@@ -238,6 +255,15 @@ impl ApplicationChecker for ResourceDatabaseChecker {
                 }
             }
 
+            if let Some(max_supply) = tracker.max_supply {
+                if tracker.tracking_supply > max_supply {
+                    panic!(
+                        "Max supply exceeded: {:?} max_supply: {:?} tracked_supply: {:?}",
+                        address, max_supply, tracker.tracking_supply,
+                    );
+                }
+            }
+
             total_supply.insert(*address, tracker.tracking_supply);
         }
 
@@ -7,6 +7,7 @@ pub mod id_allocation;
 pub mod module;
 pub mod node_init;
 pub mod payload_validation;
+pub mod protocol_updates;
 pub mod system;
 pub mod system_callback;
 pub mod system_callback_api;
@@ -0,0 +1,400 @@
+use crate::blueprints::consensus_manager::{
+    ConsensusManagerField, ConsensusManagerStateFieldPayload, EpochChangeEvent,
+};
+use crate::internal_prelude::*;
+use crate::system::bootstrap::FlashReceipt;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::system::system_db_reader::{SystemDatabaseReader, SystemReaderError};
+use crate::track::{LegacyStateUpdates, StateUpdates};
+use crate::transaction::{
+    execute_transaction, CostingParameters, ExecutionConfig, StateUpdateSummary,
+    SubstateSchemaMapper, TransactionReceipt,
+};
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+use radix_engine_interface::api::ModuleId;
+use radix_engine_store_interface::interface::DatabaseUpdate;
+use radix_engine_store_interface::{
+    db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper},
+    interface::{CommittableSubstateDatabase, SubstateDatabase},
+};
+use transaction::model::{SystemTransactionV1, TransactionPayload};
+
+//==========================================================================================
+// PROTOCOL UPDATE MODELS
+//==========================================================================================
+
+/// A single step of a [`ProtocolUpdate`].
+#[derive(Debug, Clone)]
+pub enum ProtocolUpdateAction {
+    /// Directly sets or deletes the given substates, bypassing the engine.
+    /// This is how e.g. native package code and immutable configuration gets replaced.
+    FlashSubstates {
+        name: String,
+        substate_updates:
+            IndexMap<(NodeId, PartitionNumber), IndexMap<SubstateKey, DatabaseUpdate>>,
+    },
+    /// Executes the given system transaction, which is required to commit successfully.
+    SystemTransaction {
+        name: String,
+        transaction: SystemTransactionV1,
+    },
+}
+
+impl ProtocolUpdateAction {
+    pub fn name(&self) -> &str {
+        match self {
+            ProtocolUpdateAction::FlashSubstates { name, .. }
+            | ProtocolUpdateAction::SystemTransaction { name, .. } => name,
+        }
+    }
+}
+
+/// A named protocol update, defined as an ordered sequence of [`ProtocolUpdateAction`]s.
+///
+/// The name must match the readiness signal which validators use for the update
+/// (see `signal_protocol_update_readiness`).
+#[derive(Debug, Clone)]
+pub struct ProtocolUpdate {
+    pub name: String,
+    /// The name of the protocol update which must have been applied last before this one, or
+    /// `None` if this must be the first protocol update applied to the ledger.
+    pub previous: Option<String>,
+    pub actions: Vec<ProtocolUpdateAction>,
+}
+
+impl ProtocolUpdate {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            previous: None,
+            actions: vec![],
+        }
+    }
+
+    pub fn after<S: Into<String>>(mut self, previous: S) -> Self {
+        self.previous = Some(previous.into());
+        self
+    }
+
+    pub fn with_flash<S: Into<String>>(
+        mut self,
+        name: S,
+        substate_updates: IndexMap<
+            (NodeId, PartitionNumber),
+            IndexMap<SubstateKey, DatabaseUpdate>,
+        >,
+    ) -> Self {
+        self.actions.push(ProtocolUpdateAction::FlashSubstates {
+            name: name.into(),
+            substate_updates,
+        });
+        self
+    }
+
+    pub fn with_system_transaction<S: Into<String>>(
+        mut self,
+        name: S,
+        transaction: SystemTransactionV1,
+    ) -> Self {
+        self.actions.push(ProtocolUpdateAction::SystemTransaction {
+            name: name.into(),
+            transaction,
+        });
+        self
+    }
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct ProtocolUpdateReceipt {
+    pub protocol_update_name: String,
+    pub epoch: Epoch,
+    /// The receipts of the actions, in execution order. Flashes are recorded as
+    /// successful receipts without any transaction output.
+    pub action_receipts: Vec<(String, TransactionReceipt)>,
+}
+
+/// The protocol updates which have been applied to the ledger, in order of application.
+///
+/// This is stored in the [`PROTOCOL_UPDATE_STATUS_PARTITION`] of the transaction tracker.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct AppliedProtocolUpdatesSubstate {
+    pub applied: Vec<AppliedProtocolUpdate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct AppliedProtocolUpdate {
+    pub name: String,
+    pub epoch: Epoch,
+}
+
+pub fn read_applied_protocol_updates<S: SubstateDatabase>(
+    substate_db: &S,
+) -> AppliedProtocolUpdatesSubstate {
+    substate_db
+        .get_mapped::<SpreadPrefixKeyMapper, FieldSubstate<AppliedProtocolUpdatesSubstate>>(
+            TRANSACTION_TRACKER.as_node_id(),
+            PROTOCOL_UPDATE_STATUS_PARTITION,
+            &ProtocolUpdateStatusField::AppliedProtocolUpdates.into(),
+        )
+        .map(|substate| substate.into_payload())
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtocolUpdateError {
+    ConsensusManagerStateUnavailable(SystemReaderError),
+    NotAtEpochBoundary {
+        expected_epoch: Epoch,
+        actual_epoch: Epoch,
+        actual_round: Round,
+    },
+    ReadinessNotSignalled {
+        protocol_update_name: String,
+        epoch: Epoch,
+    },
+    AlreadyApplied {
+        protocol_update_name: String,
+        epoch: Epoch,
+    },
+    OutOfOrder {
+        protocol_update_name: String,
+        expected_previous: Option<String>,
+        actual_previous: Option<String>,
+    },
+}
+
+//==========================================================================================
+// PROTOCOL UPDATE EXECUTION
+//==========================================================================================
+
+pub struct ProtocolUpdateExecutor<'s, S, V>
+where
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    V: SystemCallbackObject + Clone,
+{
+    network_definition: NetworkDefinition,
+    substate_db: &'s mut S,
+    vm: V,
+    trace: bool,
+}
+
+impl<'s, S, V> ProtocolUpdateExecutor<'s, S, V>
+where
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    V: SystemCallbackObject + Clone,
+{
+    pub fn new(
+        network_definition: NetworkDefinition,
+        substate_db: &'s mut S,
+        vm: V,
+        trace: bool,
+    ) -> ProtocolUpdateExecutor<'s, S, V> {
+        ProtocolUpdateExecutor {
+            network_definition,
+            substate_db,
+            vm,
+            trace,
+        }
+    }
+
+    /// Applies the given protocol update, triggered by the readiness signals of the given epoch
+    /// change. It must happen right at the start of the new epoch (i.e. before any round of that
+    /// epoch has been processed), so that every node applies it against the exact same state.
+    ///
+    /// The update must have been significantly signalled for, must not have been applied
+    /// before, and must directly follow the last applied update. Applied updates are recorded
+    /// on ledger, in the [`PROTOCOL_UPDATE_STATUS_PARTITION`] of the transaction tracker.
+    ///
+    /// Actions are committed one by one in order. A failing system transaction is a bug in
+    /// the protocol update definition and results in a panic, as it does during genesis.
+    pub fn execute_at_epoch_boundary(
+        &mut self,
+        protocol_update: &ProtocolUpdate,
+        epoch_change: &EpochChangeEvent,
+    ) -> Result<ProtocolUpdateReceipt, ProtocolUpdateError> {
+        let epoch = epoch_change.epoch;
+        let consensus_manager_state = SystemDatabaseReader::new(self.substate_db)
+            .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
+                CONSENSUS_MANAGER.as_node_id(),
+                ModuleId::Main,
+                ConsensusManagerField::State.field_index(),
+            )
+            .map_err(ProtocolUpdateError::ConsensusManagerStateUnavailable)?
+            .into_latest();
+        if consensus_manager_state.epoch != epoch || consensus_manager_state.round != Round::zero()
+        {
+            return Err(ProtocolUpdateError::NotAtEpochBoundary {
+                expected_epoch: epoch,
+                actual_epoch: consensus_manager_state.epoch,
+                actual_round: consensus_manager_state.round,
+            });
+        }
+
+        if !epoch_change
+            .significant_protocol_update_readiness
+            .contains_key(&protocol_update.name)
+        {
+            return Err(ProtocolUpdateError::ReadinessNotSignalled {
+                protocol_update_name: protocol_update.name.clone(),
+                epoch,
+            });
+        }
+
+        let mut applied_protocol_updates = read_applied_protocol_updates(self.substate_db);
+        if let Some(applied) = applied_protocol_updates
+            .applied
+            .iter()
+            .find(|applied| applied.name == protocol_update.name)
+        {
+            return Err(ProtocolUpdateError::AlreadyApplied {
+                protocol_update_name: applied.name.clone(),
+                epoch: applied.epoch,
+            });
+        }
+        let actual_previous = applied_protocol_updates
+            .applied
+            .last()
+            .map(|applied| applied.name.clone());
+        if actual_previous != protocol_update.previous {
+            return Err(ProtocolUpdateError::OutOfOrder {
+                protocol_update_name: protocol_update.name.clone(),
+                expected_previous: protocol_update.previous.clone(),
+                actual_previous,
+            });
+        }
+
+        let mut action_receipts = vec![];
+        for action in &protocol_update.actions {
+            let receipt = match action {
+                ProtocolUpdateAction::FlashSubstates {
+                    substate_updates, ..
+                } => self.execute_flash(substate_updates.clone()).into(),
+                ProtocolUpdateAction::SystemTransaction { name, transaction } => {
+                    self.execute_system_transaction(name, transaction)
+                }
+            };
+            action_receipts.push((action.name().to_string(), receipt));
+        }
+
+        applied_protocol_updates
+            .applied
+            .push(AppliedProtocolUpdate {
+                name: protocol_update.name.clone(),
+                epoch,
+            });
+        self.write_applied_protocol_updates(applied_protocol_updates);
+
+        Ok(ProtocolUpdateReceipt {
+            protocol_update_name: protocol_update.name.clone(),
+            epoch,
+            action_receipts,
+        })
+    }
+
+    fn execute_flash(
+        &mut self,
+        substate_updates: IndexMap<
+            (NodeId, PartitionNumber),
+            IndexMap<SubstateKey, DatabaseUpdate>,
+        >,
+    ) -> FlashReceipt {
+        let mut new_packages = index_set_new();
+        let mut new_components = index_set_new();
+        let mut new_resources = index_set_new();
+        let mut new_vaults = index_set_new();
+        {
+            let reader = SystemDatabaseReader::new(self.substate_db);
+            for (node_id, _) in substate_updates.keys() {
+                if reader.get_type_info(node_id).is_ok() {
+                    continue;
+                }
+                if node_id.is_global_package() {
+                    new_packages.insert(PackageAddress::new_or_panic(node_id.0));
+                }
+                if node_id.is_global_component() {
+                    new_components.insert(ComponentAddress::new_or_panic(node_id.0));
+                }
+                if node_id.is_global_resource_manager() {
+                    new_resources.insert(ResourceAddress::new_or_panic(node_id.0));
+                }
+                if node_id.is_internal_vault() {
+                    new_vaults.insert(InternalAddress::new_or_panic(node_id.0));
+                }
+            }
+        }
+
+        let state_updates = StateUpdates::from(LegacyStateUpdates {
+            partition_deletions: index_set_new(),
+            system_updates: substate_updates,
+        });
+        self.substate_db
+            .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+
+        let mut substate_schema_mapper =
+            SubstateSchemaMapper::new(SystemDatabaseReader::new(self.substate_db));
+        substate_schema_mapper.add_for_all_individually_updated(&state_updates);
+        let substate_system_structures = substate_schema_mapper.done();
+
+        FlashReceipt {
+            state_updates,
+            state_update_summary: StateUpdateSummary {
+                new_packages,
+                new_components,
+                new_resources,
+                new_vaults,
+                vault_balance_changes: index_map_new(),
+            },
+            substate_system_structures,
+        }
+    }
+
+    fn write_applied_protocol_updates(
+        &mut self,
+        applied_protocol_updates: AppliedProtocolUpdatesSubstate,
+    ) {
+        let substate = FieldSubstate::new_locked_field(applied_protocol_updates);
+        let state_updates = StateUpdates::from(LegacyStateUpdates {
+            partition_deletions: index_set_new(),
+            system_updates: indexmap!(
+                (TRANSACTION_TRACKER.into_node_id(), PROTOCOL_UPDATE_STATUS_PARTITION) => indexmap!(
+                    SubstateKey::from(ProtocolUpdateStatusField::AppliedProtocolUpdates)
+                        => DatabaseUpdate::Set(scrypto_encode(&substate).unwrap())
+                )
+            ),
+        });
+        self.substate_db
+            .commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+
+    fn execute_system_transaction(
+        &mut self,
+        name: &str,
+        transaction: &SystemTransactionV1,
+    ) -> TransactionReceipt {
+        let receipt = execute_transaction(
+            self.substate_db,
+            self.vm.clone(),
+            &CostingParameters::default(),
+            &ExecutionConfig::for_genesis_transaction(self.network_definition.clone())
+                .with_kernel_trace(self.trace),
+            &transaction
+                .prepare()
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "Expected protocol update transaction {} to be preparable: {:?}",
+                        name, err
+                    )
+                })
+                .get_executable(btreeset![AuthAddresses::system_role()]),
+        );
+
+        let commit_result = receipt.expect_commit(true);
+        self.substate_db.commit(
+            &commit_result
+                .state_updates
+                .create_database_updates::<SpreadPrefixKeyMapper>(),
+        );
+
+        receipt
+    }
+}
@@ -1,6 +1,8 @@
 use super::id_allocation::IDAllocation;
 use super::system_modules::costing::ExecutionCostingEntry;
-use crate::blueprints::package::PackageBlueprintVersionDefinitionEntrySubstate;
+use crate::blueprints::package::{
+    PackageBlueprintVersionDefinitionEntrySubstate, PackageCollection,
+};
 use crate::blueprints::resource::LockFeeEvent;
 use crate::errors::{
     ApplicationError, CannotGlobalizeError, CreateObjectError, InvalidDropAccess,
@@ -47,6 +49,9 @@ use radix_engine_store_interface::db_key_mapper::SubstateKeyContent;
 use resources_tracker_macro::trace_resources;
 use sbor::rust::string::ToString;
 use sbor::rust::vec::Vec;
+use transaction::validation::{
+    verify_bls12381_v1, verify_bls12381_v1_aggregate, verify_ed25519_message, verify_secp256k1,
+};
 
 /// Provided to upper layer for invoking lower layer service
 pub struct SystemService<'a, Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject> {
@@ -377,6 +382,43 @@ where
         Ok(definition)
     }
 
+    /// Blueprint definitions and auth configs are cached for the rest of the transaction once
+    /// loaded. Upgradeable packages may overwrite them, so the cached copies are dropped as soon
+    /// as the package opens such an entry for writing.
+    fn invalidate_package_blueprint_caches(
+        &mut self,
+        node_id: &NodeId,
+        blueprint_id: &BlueprintId,
+        collection_index: CollectionIndex,
+        key: &[u8],
+    ) {
+        if blueprint_id.package_address != PACKAGE_PACKAGE
+            || blueprint_id.blueprint_name != PACKAGE_BLUEPRINT
+        {
+            return;
+        }
+        if collection_index
+            != PackageCollection::BlueprintVersionDefinitionKeyValue.collection_index()
+            && collection_index
+                != PackageCollection::BlueprintVersionAuthConfigKeyValue.collection_index()
+        {
+            return;
+        }
+        let bp_version_key: BlueprintVersionKey = match scrypto_decode(key) {
+            Ok(bp_version_key) => bp_version_key,
+            Err(_) => return,
+        };
+
+        let canonical_bp_id = CanonicalBlueprintId {
+            address: PackageAddress::new_or_panic(node_id.0),
+            blueprint: bp_version_key.blueprint,
+            version: bp_version_key.version,
+        };
+        let system = self.api.kernel_get_system_state().system;
+        system.blueprint_cache.remove(&canonical_bp_id);
+        system.auth_cache.remove(&canonical_bp_id);
+    }
+
     pub fn prepare_global_address(
         &mut self,
         blueprint_id: BlueprintId,
@@ -2097,10 +2139,11 @@ where
 
     // Costing through kernel
     #[trace_resources]
-    fn actor_sorted_index_scan(
+    fn actor_sorted_index_scan_range(
         &mut self,
         object_handle: ActorStateHandle,
         collection_index: CollectionIndex,
+        range: SortedKeyRange,
         limit: u32,
     ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError> {
         let actor_object_type: ActorStateRef = object_handle.try_into()?;
@@ -2113,7 +2156,7 @@ where
 
         let substates = self
             .api
-            .kernel_scan_sorted_substates(&node_id, partition_num, limit)?
+            .kernel_scan_sorted_substates(&node_id, partition_num, &range, limit)?
             .into_iter()
             .map(|(key, value)| {
                 let value: SortedIndexEntrySubstate<ScryptoValue> = value.as_typed().unwrap();
@@ -2666,6 +2709,7 @@ where
             collection_index,
             &BlueprintPartitionType::KeyValueCollection,
         )?;
+        let blueprint_id = info.blueprint_id.clone();
 
         let target = BlueprintTypeTarget {
             blueprint_info: info,
@@ -2708,6 +2752,13 @@ where
             if substate.is_locked() {
                 return Err(RuntimeError::SystemError(SystemError::KeyValueEntryLocked));
             }
+
+            self.invalidate_package_blueprint_caches(
+                &node_id,
+                &blueprint_id,
+                collection_index,
+                key,
+            );
         }
 
         Ok(handle)
@@ -2839,6 +2890,99 @@ where
     }
 }
 
+#[cfg_attr(
+    feature = "std",
+    catch_unwind(crate::utils::catch_unwind_system_panic_transformer)
+)]
+impl<'a, Y, V> ClientCryptoUtilsApi<RuntimeError> for SystemService<'a, Y, V>
+where
+    Y: KernelApi<SystemConfig<V>>,
+    V: SystemCallbackObject,
+{
+    #[trace_resources]
+    fn keccak256_hash(&mut self, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::Keccak256Hash { size: data.len() })?;
+
+        Ok(keccak256_hash(&data))
+    }
+
+    #[trace_resources]
+    fn blake2b_256_hash(&mut self, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::Blake2b256Hash { size: data.len() })?;
+
+        Ok(blake2b_256_hash(&data))
+    }
+
+    #[trace_resources]
+    fn ed25519_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Ed25519PublicKey,
+        signature: Ed25519Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.api.kernel_get_system().modules.apply_execution_cost(
+            ExecutionCostingEntry::Ed25519Verify {
+                size: message.len(),
+            },
+        )?;
+
+        Ok(verify_ed25519_message(&message, &public_key, &signature))
+    }
+
+    #[trace_resources]
+    fn secp256k1_verify(
+        &mut self,
+        hash: Hash,
+        public_key: Secp256k1PublicKey,
+        signature: Secp256k1Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::Secp256k1Verify)?;
+
+        Ok(verify_secp256k1(&hash, &public_key, &signature))
+    }
+
+    #[trace_resources]
+    fn bls12381_v1_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Bls12381G1PublicKey,
+        signature: Bls12381G2Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.api.kernel_get_system().modules.apply_execution_cost(
+            ExecutionCostingEntry::Bls12381V1Verify {
+                size: message.len(),
+            },
+        )?;
+
+        Ok(verify_bls12381_v1(&message, &public_key, &signature))
+    }
+
+    #[trace_resources]
+    fn bls12381_v1_aggregate_verify(
+        &mut self,
+        pub_keys_and_msgs: Vec<(Bls12381G1PublicKey, Vec<u8>)>,
+        signature: Bls12381G2Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.api.kernel_get_system().modules.apply_execution_cost(
+            ExecutionCostingEntry::Bls12381V1AggregateVerify {
+                num_of_keys: pub_keys_and_msgs.len(),
+                total_size: pub_keys_and_msgs.iter().map(|(_, msg)| msg.len()).sum(),
+            },
+        )?;
+
+        Ok(verify_bls12381_v1_aggregate(&pub_keys_and_msgs, &signature))
+    }
+}
+
 #[cfg_attr(
     feature = "std",
     catch_unwind(crate::utils::catch_unwind_system_panic_transformer)
@@ -2977,10 +3121,11 @@ where
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        range: &SortedKeyRange,
         limit: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         self.api
-            .kernel_scan_sorted_substates(node_id, partition_num, limit)
+            .kernel_scan_sorted_substates(node_id, partition_num, range, limit)
     }
 
     fn kernel_scan_keys<K: SubstateKeyContent + 'static>(
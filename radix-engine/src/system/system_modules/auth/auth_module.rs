@@ -45,9 +45,50 @@ pub struct Unauthorized {
     pub fn_identifier: FnIdentifier,
 }
 
+/// Roles whose checks are bypassed by the [`AuthModule`], eg to preview a transaction which would
+/// otherwise require badges or signatures the caller does not hold.
+///
+/// A role list is considered satisfied as soon as any of its roles is skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkippedAuthRoles {
+    /// Roles skipped on every global entity.
+    pub roles: IndexSet<RoleKey>,
+    /// Roles skipped on a specific global entity only.
+    pub entity_roles: IndexSet<(GlobalAddress, RoleKey)>,
+}
+
+impl SkippedAuthRoles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn skip_role<R: Into<RoleKey>>(mut self, role: R) -> Self {
+        self.roles.insert(role.into());
+        self
+    }
+
+    pub fn skip_entity_role<A: Into<GlobalAddress>, R: Into<RoleKey>>(
+        mut self,
+        address: A,
+        role: R,
+    ) -> Self {
+        self.entity_roles.insert((address.into(), role.into()));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roles.is_empty() && self.entity_roles.is_empty()
+    }
+
+    pub fn contains(&self, address: &GlobalAddress, role: &RoleKey) -> bool {
+        self.roles.contains(role) || self.entity_roles.contains(&(*address, role.clone()))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthModule {
     pub params: AuthZoneParams,
+    pub skipped_roles: SkippedAuthRoles,
 }
 
 pub enum AuthorizationCheckResult {
@@ -414,6 +455,15 @@ impl AuthModule {
                 role_list,
                 module_id,
             } => {
+                let skipped_roles = &api.api.kernel_get_system().modules.auth.skipped_roles;
+                if role_list
+                    .list
+                    .iter()
+                    .any(|role| skipped_roles.contains(&role_assignment_of, role))
+                {
+                    return Ok(());
+                }
+
                 let result = Authorization::check_authorization_against_role_list(
                     &auth_zone,
                     &role_assignment_of,
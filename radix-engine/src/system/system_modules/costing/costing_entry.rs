@@ -110,6 +110,25 @@ pub enum ExecutionCostingEntry<'a> {
     Panic {
         size: usize,
     },
+
+    /* crypto utils */
+    Keccak256Hash {
+        size: usize,
+    },
+    Blake2b256Hash {
+        size: usize,
+    },
+    Ed25519Verify {
+        size: usize,
+    },
+    Secp256k1Verify,
+    Bls12381V1Verify {
+        size: usize,
+    },
+    Bls12381V1AggregateVerify {
+        num_of_keys: usize,
+        total_size: usize,
+    },
 }
 
 #[derive(Debug, IntoStaticStr)]
@@ -172,6 +191,15 @@ impl<'a> ExecutionCostingEntry<'a> {
             ExecutionCostingEntry::EmitEvent { size } => ft.emit_event_cost(*size),
             ExecutionCostingEntry::EmitLog { size } => ft.emit_log_cost(*size),
             ExecutionCostingEntry::Panic { size } => ft.panic_cost(*size),
+            ExecutionCostingEntry::Keccak256Hash { size } => ft.keccak256_hash_cost(*size),
+            ExecutionCostingEntry::Blake2b256Hash { size } => ft.blake2b_256_hash_cost(*size),
+            ExecutionCostingEntry::Ed25519Verify { size } => ft.ed25519_verify_cost(*size),
+            ExecutionCostingEntry::Secp256k1Verify => ft.secp256k1_verify_cost(),
+            ExecutionCostingEntry::Bls12381V1Verify { size } => ft.bls12381_v1_verify_cost(*size),
+            ExecutionCostingEntry::Bls12381V1AggregateVerify {
+                num_of_keys,
+                total_size,
+            } => ft.bls12381_v1_aggregate_verify_cost(*num_of_keys, *total_size),
         }
     }
 }
@@ -21,6 +21,7 @@ use radix_engine_interface::api::AttachedModuleId;
 use radix_engine_interface::blueprints::package::BlueprintVersionKey;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use radix_engine_interface::{types::NodeId, *};
+use sbor::rust::sync::Arc;
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum CostingError {
@@ -70,7 +71,7 @@ impl OnApplyCost {
 #[derive(Debug, Clone)]
 pub struct CostingModule {
     pub fee_reserve: SystemLoanFeeReserve,
-    pub fee_table: FeeTable,
+    pub fee_table: Arc<FeeTable>,
     pub max_call_depth: usize,
     pub tx_payload_len: usize,
     pub tx_num_of_signature_validations: usize,
@@ -54,6 +54,14 @@ lazy_static! {
     };
 }
 
+define_single_versioned! {
+    /// A versioned [`FeeTable`], which allows fee tables to be stored and loaded as configuration
+    /// (eg from an SBOR or JSON payload), and the fee tables of different protocol versions
+    /// to coexist.
+    #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+    pub enum VersionedFeeTable => FeeTable = FeeTableV1
+}
+
 /// Fee table specifies how each costing entry should be costed.
 ///
 /// ## High Level Guideline
@@ -63,42 +71,252 @@ lazy_static! {
 /// - Execution time for 100,000,000 cost units' worth of computation: <= 1 second
 /// - Baseline: 1 microsecond = 100 cost units
 ///
-#[derive(Debug, Clone, ScryptoSbor)]
-pub struct FeeTable;
+/// Costs suffixed with `_cpu_instructions` are measured in CPU instructions, and are converted
+/// to cost units using `cpu_instructions_to_cost_unit`. All other costs are in cost units.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct FeeTableV1 {
+    /// The number of CPU instructions which are worth one cost unit.
+    pub cpu_instructions_to_cost_unit: u32,
+    /// The cost of decoding and validating a byte of SBOR payload.
+    pub data_processing_cost_per_byte: u32,
+
+    /* IO */
+    pub read_from_db_base_cost: u32,
+    pub read_from_db_bytes_per_cost_unit: u32,
+    pub read_from_db_not_found_cost: u32,
+
+    /* Transaction validation */
+    pub verify_tx_signature_cost: u32,
+    pub validate_tx_payload_cost_per_byte: u32,
+
+    /* Code execution */
+    /// Overrides of the native function costs in `assets/native_function_base_costs.csv`,
+    /// in CPU instructions.
+    pub native_function_base_cost_overrides: IndexMap<PackageAddress, IndexMap<String, u32>>,
+    pub wasm_execution_units_per_cost_unit: u32,
+    pub instantiate_wasm_code_cost_per_byte: u32,
+
+    /* Kernel */
+    pub allocate_node_id_cpu_instructions: u32,
+    pub create_node_cpu_instructions: u32,
+    pub pin_node_cpu_instructions: u32,
+    pub drop_node_cpu_instructions: u32,
+    pub move_module_cpu_instructions: u32,
+    pub open_substate_cpu_instructions: u32,
+    pub read_substate_from_heap_cpu_instructions: u32,
+    pub read_substate_from_store_cpu_instructions: u32,
+    pub write_substate_cpu_instructions: u32,
+    pub close_substate_cpu_instructions: u32,
+    pub set_substate_cpu_instructions: u32,
+    pub remove_substate_cpu_instructions: u32,
+    pub mark_substate_as_transient_cpu_instructions: u32,
+    pub scan_keys_cpu_instructions: u32,
+    pub drain_substates_base_cpu_instructions: u32,
+    pub drain_substates_per_substate_cpu_instructions: u32,
+    pub scan_sorted_substates_cpu_instructions: u32,
+
+    /* System */
+    /// The base cost of system calls such as locking fees, querying the actor or emitting events.
+    pub system_call_base_cost: u32,
+
+    /* Crypto utils */
+    pub keccak256_hash_base_cost: u32,
+    pub keccak256_hash_bytes_per_cost_unit: u32,
+    pub blake2b_256_hash_base_cost: u32,
+    pub blake2b_256_hash_bytes_per_cost_unit: u32,
+    pub ed25519_verify_base_cost: u32,
+    pub ed25519_verify_bytes_per_cost_unit: u32,
+    pub secp256k1_verify_cost: u32,
+    pub bls12381_v1_verify_base_cost: u32,
+    pub bls12381_v1_verify_bytes_per_cost_unit: u32,
+    /// The additional cost of each (public key, message) pair of an aggregate verification,
+    /// on top of `bls12381_v1_verify_base_cost`.
+    pub bls12381_v1_aggregate_verify_per_key_cost: u32,
+
+    /* Finalization */
+    pub commit_state_update_base_cost: u32,
+    pub commit_state_update_bytes_per_cost_unit: u32,
+    pub commit_event_base_cost: u32,
+    pub commit_event_bytes_per_cost_unit: u32,
+    pub commit_log_base_cost: u32,
+    pub commit_log_bytes_per_cost_unit: u32,
+}
+
+/// Error when a fee table can't be used for costing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeeTableError {
+    /// A divisor of the fee table is zero, which would make costing panic.
+    ZeroDivisor(&'static str),
+}
+
+impl Default for FeeTableV1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-impl FeeTable {
+impl FeeTableV1 {
+    /// Creates the fee table of the current protocol version.
     pub fn new() -> Self {
-        Self
+        Self {
+            cpu_instructions_to_cost_unit: CPU_INSTRUCTIONS_TO_COST_UNIT,
+            // Based on benchmark `bench_decode_sbor`
+            // Time for processing a byte: 10.244 µs / 1068 = 0.00959176029
+            //
+            // Based on benchmark `bench_validate_sbor_payload`
+            // Time for processing a byte: 10.075 µs / 1169 = 0.00861847733
+            data_processing_cost_per_byte: 2,
+
+            // Execution time (µs): 0.0009622109 * size + 389.5155
+            // Execution cost: (0.0009622109 * size + 389.5155) * 100 = 0.1 * size + 40,000
+            // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
+            read_from_db_base_cost: 40_000,
+            read_from_db_bytes_per_cost_unit: 10,
+            // Execution time (µs): varies, using max 1,600
+            // Execution cost: 1,600 * 100
+            // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
+            read_from_db_not_found_cost: 160_000,
+
+            // Based on benchmark `bench_validate_secp256k1`
+            // The cost for validating a single signature is: 67.522 µs * 100 units/µs = 7,000 cost units
+            verify_tx_signature_cost: 7_000,
+            // Rational:
+            // Transaction payload is propagated over a P2P network.
+            // Larger size may slows down the network performance.
+            // The size of a typical transfer transaction is 400 bytes, and the cost will be 400 * 40 = 16,000 cost units
+            // The max size of a transaction is 1 MiB, and the cost will be 1,048,576 * 40 = 41,943,040 cost units
+            // This is roughly 1/24 of storing data in substate store per current setup.
+            validate_tx_payload_cost_per_byte: 40,
+
+            native_function_base_cost_overrides: index_map_new(),
+            // From `costing::spin_loop`, it takes 5.5391 ms for 1918122691 wasm execution units.
+            // Therefore, cost for single unit: 5.5391 *  1000 / 1918122691 * 100 = 0.00028877714
+            wasm_execution_units_per_cost_unit: 3000,
+            // From `costing::instantiate_radiswap`, it takes 3.3271 ms to instantiate WASM of length 288406.
+            // Therefore, cost for byte: 3.3271 *  1000 / 203950 * 100 = 1.63133120863
+            instantiate_wasm_code_cost_per_byte: 2,
+
+            allocate_node_id_cpu_instructions: 3312,
+            create_node_cpu_instructions: 15510,
+            pin_node_cpu_instructions: 424,
+            drop_node_cpu_instructions: 38883,
+            move_module_cpu_instructions: 4791,
+            open_substate_cpu_instructions: 10318,
+            read_substate_from_heap_cpu_instructions: 2234,
+            read_substate_from_store_cpu_instructions: 3868,
+            write_substate_cpu_instructions: 7441,
+            close_substate_cpu_instructions: 4390,
+            set_substate_cpu_instructions: 4530,
+            remove_substate_cpu_instructions: 24389,
+            mark_substate_as_transient_cpu_instructions: 1896,
+            scan_keys_cpu_instructions: 16938,
+            drain_substates_base_cpu_instructions: 9262,
+            drain_substates_per_substate_cpu_instructions: 9286,
+            scan_sorted_substates_cpu_instructions: 6369,
+
+            system_call_base_cost: 500,
+
+            // Estimated from the throughput of the `sha3` and `blake2` crates on the reference
+            // instance (~250 MB/s and ~500 MB/s respectively), ie. 2.5 and 5 bytes per cost unit,
+            // rounded down.
+            keccak256_hash_base_cost: 500,
+            keccak256_hash_bytes_per_cost_unit: 2,
+            blake2b_256_hash_base_cost: 500,
+            blake2b_256_hash_bytes_per_cost_unit: 4,
+            // Ed25519 verification is a little cheaper than secp256k1 (see `verify_tx_signature_cost`),
+            // plus a SHA-512 over the message.
+            ed25519_verify_base_cost: 5_000,
+            ed25519_verify_bytes_per_cost_unit: 4,
+            secp256k1_verify_cost: 7_000,
+            // A BLS12-381 verification is dominated by two pairings, estimated at ~1 ms in total.
+            // Each additional key of an aggregate verification adds a pairing and a hash-to-curve,
+            // estimated at ~0.5 ms.
+            bls12381_v1_verify_base_cost: 100_000,
+            bls12381_v1_verify_bytes_per_cost_unit: 4,
+            bls12381_v1_aggregate_verify_per_key_cost: 50_000,
+
+            // Committing state time (µs): 0.0025 * size + 1000
+            // Finalization cost: (0.0025 * size + 1000) * 100 = 0.25 * size + 100,000
+            // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
+            commit_state_update_base_cost: 100_000,
+            commit_state_update_bytes_per_cost_unit: 4,
+            commit_event_base_cost: 5_000,
+            commit_event_bytes_per_cost_unit: 4,
+            commit_log_base_cost: 1_000,
+            commit_log_bytes_per_cost_unit: 4,
+        }
+    }
+
+    /// Checks that the fee table can be used for costing, ie. that none of its divisors is zero.
+    pub fn validate(&self) -> Result<(), FeeTableError> {
+        let divisors = [
+            (
+                "cpu_instructions_to_cost_unit",
+                self.cpu_instructions_to_cost_unit,
+            ),
+            (
+                "read_from_db_bytes_per_cost_unit",
+                self.read_from_db_bytes_per_cost_unit,
+            ),
+            (
+                "wasm_execution_units_per_cost_unit",
+                self.wasm_execution_units_per_cost_unit,
+            ),
+            (
+                "keccak256_hash_bytes_per_cost_unit",
+                self.keccak256_hash_bytes_per_cost_unit,
+            ),
+            (
+                "blake2b_256_hash_bytes_per_cost_unit",
+                self.blake2b_256_hash_bytes_per_cost_unit,
+            ),
+            (
+                "ed25519_verify_bytes_per_cost_unit",
+                self.ed25519_verify_bytes_per_cost_unit,
+            ),
+            (
+                "bls12381_v1_verify_bytes_per_cost_unit",
+                self.bls12381_v1_verify_bytes_per_cost_unit,
+            ),
+            (
+                "commit_state_update_bytes_per_cost_unit",
+                self.commit_state_update_bytes_per_cost_unit,
+            ),
+            (
+                "commit_event_bytes_per_cost_unit",
+                self.commit_event_bytes_per_cost_unit,
+            ),
+            (
+                "commit_log_bytes_per_cost_unit",
+                self.commit_log_bytes_per_cost_unit,
+            ),
+        ];
+        match divisors.into_iter().find(|(_, divisor)| *divisor == 0) {
+            Some((name, _)) => Err(FeeTableError::ZeroDivisor(name)),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn cpu_instructions_cost(&self, cpu_instructions: u32) -> u32 {
+        cpu_instructions / self.cpu_instructions_to_cost_unit
     }
 
     //======================
     // Execution costs
     //======================
 
-    fn data_processing_cost(size: usize) -> u32 {
-        // Based on benchmark `bench_decode_sbor`
-        // Time for processing a byte: 10.244 µs / 1068 = 0.00959176029
-
-        // Based on benchmark `bench_validate_sbor_payload`
-        // Time for processing a byte: 10.075 µs / 1169 = 0.00861847733
-
-        mul(cast(size), 2)
+    fn data_processing_cost(&self, size: usize) -> u32 {
+        mul(cast(size), self.data_processing_cost_per_byte)
     }
 
     fn io_access_cost(&self, io_access: &IOAccess) -> u32 {
         match io_access {
-            IOAccess::ReadFromDb(_, size) => {
-                // Execution time (µs): 0.0009622109 * size + 389.5155
-                // Execution cost: (0.0009622109 * size + 389.5155) * 100 = 0.1 * size + 40,000
-                // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
-                add(cast(*size) / 10, 40_000)
-            }
-            IOAccess::ReadFromDbNotFound(_) => {
-                // Execution time (µs): varies, using max 1,600
-                // Execution cost: 1,600 * 100
-                // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
-                160_000
-            }
+            IOAccess::ReadFromDb(_, size) => add(
+                cast(*size) / self.read_from_db_bytes_per_cost_unit,
+                self.read_from_db_base_cost,
+            ),
+            IOAccess::ReadFromDbNotFound(_) => self.read_from_db_not_found_cost,
             IOAccess::HeapSubstateUpdated { .. } | IOAccess::TrackSubstateUpdated { .. } => {
                 // Heap/track substate total size is limited by limits module.
                 0
@@ -108,20 +326,12 @@ impl FeeTable {
 
     #[inline]
     pub fn verify_tx_signatures_cost(&self, n: usize) -> u32 {
-        // Based on benchmark `bench_validate_secp256k1`
-        // The cost for validating a single signature is: 67.522 µs * 100 units/µs = 7,000 cost units
-        mul(cast(n), 7_000)
+        mul(cast(n), self.verify_tx_signature_cost)
     }
 
     #[inline]
     pub fn validate_tx_payload_cost(&self, size: usize) -> u32 {
-        // Rational:
-        // Transaction payload is propagated over a P2P network.
-        // Larger size may slows down the network performance.
-        // The size of a typical transfer transaction is 400 bytes, and the cost will be 400 * 40 = 16,000 cost units
-        // The max size of a transaction is 1 MiB, and the cost will be 1,048,576 * 40 = 41,943,040 cost units
-        // This is roughly 1/24 of storing data in substate store per current setup.
-        mul(cast(size), 40)
+        mul(cast(size), self.validate_tx_payload_cost_per_byte)
     }
 
     #[inline]
@@ -131,9 +341,15 @@ impl FeeTable {
         export_name: &str,
         input_size: &usize,
     ) -> u32 {
-        let native_execution_units = NATIVE_FUNCTION_BASE_COSTS
+        let native_execution_units = self
+            .native_function_base_cost_overrides
             .get(package_address)
             .and_then(|x| x.get(export_name).cloned())
+            .or_else(|| {
+                NATIVE_FUNCTION_BASE_COSTS
+                    .get(package_address)
+                    .and_then(|x| x.get(export_name).cloned())
+            })
             .unwrap_or_else(|| {
                 NATIVE_FUNCTION_BASE_COSTS_SIZE_DEPENDENT
                     .get(package_address)
@@ -145,7 +361,7 @@ impl FeeTable {
                     ))
             });
 
-        native_execution_units / CPU_INSTRUCTIONS_TO_COST_UNIT
+        self.cpu_instructions_cost(native_execution_units)
     }
 
     #[inline]
@@ -155,33 +371,27 @@ impl FeeTable {
         _export_name: &str,
         wasm_execution_units: u32,
     ) -> u32 {
-        // From `costing::spin_loop`, it takes 5.5391 ms for 1918122691 wasm execution units.
-        // Therefore, cost for single unit: 5.5391 *  1000 / 1918122691 * 100 = 0.00028877714
-
-        wasm_execution_units / 3000
+        wasm_execution_units / self.wasm_execution_units_per_cost_unit
     }
 
     #[inline]
     pub fn instantiate_wasm_code_cost(&self, size: usize) -> u32 {
-        // From `costing::instantiate_radiswap`, it takes 3.3271 ms to instantiate WASM of length 288406.
-        // Therefore, cost for byte: 3.3271 *  1000 / 203950 * 100 = 1.63133120863
-
-        mul(cast(size), 2)
+        mul(cast(size), self.instantiate_wasm_code_cost_per_byte)
     }
 
     #[inline]
     pub fn before_invoke_cost(&self, _actor: &Actor, input_size: usize) -> u32 {
-        Self::data_processing_cost(input_size)
+        self.data_processing_cost(input_size)
     }
 
     #[inline]
     pub fn after_invoke_cost(&self, input_size: usize) -> u32 {
-        Self::data_processing_cost(input_size)
+        self.data_processing_cost(input_size)
     }
 
     #[inline]
     pub fn allocate_node_id_cost(&self) -> u32 {
-        3312 / CPU_INSTRUCTIONS_TO_COST_UNIT
+        self.cpu_instructions_cost(self.allocate_node_id_cpu_instructions)
     }
 
     #[inline]
@@ -193,8 +403,8 @@ impl FeeTable {
                     .map(|x| x.values().map(|x| x.len()).sum::<usize>())
                     .sum::<usize>();
                 add(
-                    15510 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                    Self::data_processing_cost(total_substate_size),
+                    self.cpu_instructions_cost(self.create_node_cpu_instructions),
+                    self.data_processing_cost(total_substate_size),
                 )
             }
             CreateNodeEvent::IOAccess(io_access) => self.io_access_cost(io_access),
@@ -204,7 +414,7 @@ impl FeeTable {
 
     #[inline]
     pub fn pin_node_cost(&self, _node_id: &NodeId) -> u32 {
-        424 / CPU_INSTRUCTIONS_TO_COST_UNIT
+        self.cpu_instructions_cost(self.pin_node_cpu_instructions)
     }
 
     #[inline]
@@ -218,8 +428,8 @@ impl FeeTable {
                     .map(|x| x.values().map(|x| x.len()).sum::<usize>())
                     .sum::<usize>();
                 add(
-                    38883 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                    Self::data_processing_cost(total_substate_size),
+                    self.cpu_instructions_cost(self.drop_node_cpu_instructions),
+                    self.data_processing_cost(total_substate_size),
                 )
             }
         }
@@ -229,7 +439,7 @@ impl FeeTable {
     pub fn move_module_cost(&self, event: &MoveModuleEvent) -> u32 {
         match event {
             MoveModuleEvent::IOAccess(io_access) => add(
-                4791 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+                self.cpu_instructions_cost(self.move_module_cpu_instructions),
                 self.io_access_cost(io_access),
             ),
         }
@@ -241,8 +451,8 @@ impl FeeTable {
             OpenSubstateEvent::Start { .. } => 0,
             OpenSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
             OpenSubstateEvent::End { size, .. } => add(
-                10318 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                Self::data_processing_cost(*size),
+                self.cpu_instructions_cost(self.open_substate_cpu_instructions),
+                self.data_processing_cost(*size),
             ),
         }
     }
@@ -251,14 +461,14 @@ impl FeeTable {
     pub fn read_substate_cost(&self, event: &ReadSubstateEvent) -> u32 {
         match event {
             ReadSubstateEvent::OnRead { value, device, .. } => {
-                let base_cost: u32 = match device {
-                    SubstateDevice::Heap => 2234,
-                    SubstateDevice::Store => 3868,
+                let base_cpu_instructions = match device {
+                    SubstateDevice::Heap => self.read_substate_from_heap_cpu_instructions,
+                    SubstateDevice::Store => self.read_substate_from_store_cpu_instructions,
                 };
 
                 add(
-                    base_cost / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                    Self::data_processing_cost(value.len()),
+                    self.cpu_instructions_cost(base_cpu_instructions),
+                    self.data_processing_cost(value.len()),
                 )
             }
             ReadSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
@@ -270,8 +480,8 @@ impl FeeTable {
         match event {
             WriteSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
             WriteSubstateEvent::Start { value, .. } => add(
-                7441 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                Self::data_processing_cost(value.len()),
+                self.cpu_instructions_cost(self.write_substate_cpu_instructions),
+                self.data_processing_cost(value.len()),
             ),
         }
     }
@@ -279,7 +489,9 @@ impl FeeTable {
     #[inline]
     pub fn close_substate_cost(&self, event: &CloseSubstateEvent) -> u32 {
         match event {
-            CloseSubstateEvent::Start(..) => 4390 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+            CloseSubstateEvent::Start(..) => {
+                self.cpu_instructions_cost(self.close_substate_cpu_instructions)
+            }
         }
     }
 
@@ -287,8 +499,8 @@ impl FeeTable {
     pub fn set_substate_cost(&self, event: &SetSubstateEvent) -> u32 {
         match event {
             SetSubstateEvent::Start(.., value) => add(
-                4530 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                Self::data_processing_cost(value.len()),
+                self.cpu_instructions_cost(self.set_substate_cpu_instructions),
+                self.data_processing_cost(value.len()),
             ),
             SetSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
@@ -297,7 +509,9 @@ impl FeeTable {
     #[inline]
     pub fn remove_substate_cost(&self, event: &RemoveSubstateEvent) -> u32 {
         match event {
-            RemoveSubstateEvent::Start(..) => 24389 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+            RemoveSubstateEvent::Start(..) => {
+                self.cpu_instructions_cost(self.remove_substate_cpu_instructions)
+            }
             RemoveSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
     }
@@ -309,13 +523,13 @@ impl FeeTable {
         _partition_number: &PartitionNumber,
         _substate_key: &SubstateKey,
     ) -> u32 {
-        1896 / CPU_INSTRUCTIONS_TO_COST_UNIT
+        self.cpu_instructions_cost(self.mark_substate_as_transient_cpu_instructions)
     }
 
     #[inline]
     pub fn scan_keys_cost(&self, event: &ScanKeysEvent) -> u32 {
         match event {
-            ScanKeysEvent::Start => 16938 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+            ScanKeysEvent::Start => self.cpu_instructions_cost(self.scan_keys_cpu_instructions),
             ScanKeysEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
     }
@@ -324,8 +538,11 @@ impl FeeTable {
     pub fn drain_substates_cost(&self, event: &DrainSubstatesEvent) -> u32 {
         match event {
             DrainSubstatesEvent::Start(count) => {
-                let cpu_instructions = add(9262, mul(9286, *count));
-                cpu_instructions / CPU_INSTRUCTIONS_TO_COST_UNIT
+                let cpu_instructions = add(
+                    self.drain_substates_base_cpu_instructions,
+                    mul(self.drain_substates_per_substate_cpu_instructions, *count),
+                );
+                self.cpu_instructions_cost(cpu_instructions)
             }
             DrainSubstatesEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
@@ -334,49 +551,102 @@ impl FeeTable {
     #[inline]
     pub fn scan_sorted_substates_cost(&self, event: &ScanSortedSubstatesEvent) -> u32 {
         match event {
-            ScanSortedSubstatesEvent::Start => 6369 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+            ScanSortedSubstatesEvent::Start => {
+                self.cpu_instructions_cost(self.scan_sorted_substates_cpu_instructions)
+            }
             ScanSortedSubstatesEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
     }
 
     #[inline]
     pub fn lock_fee_cost(&self) -> u32 {
-        500
+        self.system_call_base_cost
     }
 
     #[inline]
     pub fn query_fee_reserve_cost(&self) -> u32 {
-        500
+        self.system_call_base_cost
     }
 
     #[inline]
     pub fn query_actor_cost(&self) -> u32 {
-        500
+        self.system_call_base_cost
     }
 
     #[inline]
     pub fn query_transaction_hash_cost(&self) -> u32 {
-        500
+        self.system_call_base_cost
     }
 
     #[inline]
     pub fn generate_ruid_cost(&self) -> u32 {
-        500
+        self.system_call_base_cost
     }
 
     #[inline]
     pub fn emit_event_cost(&self, size: usize) -> u32 {
-        500 + Self::data_processing_cost(size)
+        add(self.system_call_base_cost, self.data_processing_cost(size))
     }
 
     #[inline]
     pub fn emit_log_cost(&self, size: usize) -> u32 {
-        500 + Self::data_processing_cost(size)
+        add(self.system_call_base_cost, self.data_processing_cost(size))
     }
 
     #[inline]
     pub fn panic_cost(&self, size: usize) -> u32 {
-        500 + Self::data_processing_cost(size)
+        add(self.system_call_base_cost, self.data_processing_cost(size))
+    }
+
+    #[inline]
+    pub fn keccak256_hash_cost(&self, size: usize) -> u32 {
+        add(
+            self.keccak256_hash_base_cost,
+            cast(size) / self.keccak256_hash_bytes_per_cost_unit,
+        )
+    }
+
+    #[inline]
+    pub fn blake2b_256_hash_cost(&self, size: usize) -> u32 {
+        add(
+            self.blake2b_256_hash_base_cost,
+            cast(size) / self.blake2b_256_hash_bytes_per_cost_unit,
+        )
+    }
+
+    #[inline]
+    pub fn ed25519_verify_cost(&self, size: usize) -> u32 {
+        add(
+            self.ed25519_verify_base_cost,
+            cast(size) / self.ed25519_verify_bytes_per_cost_unit,
+        )
+    }
+
+    #[inline]
+    pub fn secp256k1_verify_cost(&self) -> u32 {
+        self.secp256k1_verify_cost
+    }
+
+    #[inline]
+    pub fn bls12381_v1_verify_cost(&self, size: usize) -> u32 {
+        add(
+            self.bls12381_v1_verify_base_cost,
+            cast(size) / self.bls12381_v1_verify_bytes_per_cost_unit,
+        )
+    }
+
+    #[inline]
+    pub fn bls12381_v1_aggregate_verify_cost(&self, num_of_keys: usize, total_size: usize) -> u32 {
+        add(
+            add(
+                self.bls12381_v1_verify_base_cost,
+                mul(
+                    cast(num_of_keys),
+                    self.bls12381_v1_aggregate_verify_per_key_cost,
+                ),
+            ),
+            cast(total_size) / self.bls12381_v1_verify_bytes_per_cost_unit,
+        )
     }
 
     //======================
@@ -386,13 +656,12 @@ impl FeeTable {
 
     #[inline]
     pub fn commit_state_updates_cost(&self, store_commit: &StoreCommit) -> u32 {
-        // Committing state time (µs): 0.0025 * size + 1000
-        // Finalization cost: (0.0025 * size + 1000) * 100 = 0.25 * size + 100,000
-        // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
         match store_commit {
-            StoreCommit::Insert { size, .. } => add(cast(*size) / 4, 100_000),
-            StoreCommit::Update { size, .. } => add(cast(*size) / 4, 100_000),
-            StoreCommit::Delete { .. } => 100_000,
+            StoreCommit::Insert { size, .. } | StoreCommit::Update { size, .. } => add(
+                cast(*size) / self.commit_state_update_bytes_per_cost_unit,
+                self.commit_state_update_base_cost,
+            ),
+            StoreCommit::Delete { .. } => self.commit_state_update_base_cost,
         }
     }
 
@@ -400,7 +669,10 @@ impl FeeTable {
     pub fn commit_events_cost(&self, events: &Vec<Event>) -> u32 {
         let mut sum = 0;
         for event in events {
-            sum += add(cast(event.payload.len()) / 4, 5_000)
+            sum += add(
+                cast(event.payload.len()) / self.commit_event_bytes_per_cost_unit,
+                self.commit_event_base_cost,
+            )
         }
         sum
     }
@@ -409,7 +681,10 @@ impl FeeTable {
     pub fn commit_logs_cost(&self, logs: &Vec<(Level, String)>) -> u32 {
         let mut sum = 0;
         for log in logs {
-            sum += add(cast(log.1.len()) / 4, 1_000)
+            sum += add(
+                cast(log.1.len()) / self.commit_log_bytes_per_cost_unit,
+                self.commit_log_base_cost,
+            )
         }
         sum
     }
@@ -4,6 +4,7 @@ pub mod execution_trace;
 pub mod kernel_trace;
 pub mod limits;
 pub mod transaction_runtime;
+pub mod user_modules;
 
 mod module_mixer;
 pub use module_mixer::{EnabledModules, SystemModuleMixer};
@@ -24,6 +24,7 @@ use crate::system::system_modules::execution_trace::ExecutionTraceModule;
 use crate::system::system_modules::kernel_trace::KernelTraceModule;
 use crate::system::system_modules::limits::{LimitsModule, TransactionLimitsConfig};
 use crate::system::system_modules::transaction_runtime::{Event, TransactionRuntimeModule};
+use crate::system::system_modules::user_modules::{UserModulesModule, UserSystemModules};
 use crate::transaction::ExecutionConfig;
 use crate::types::*;
 use bitflags::bitflags;
@@ -31,6 +32,7 @@ use paste::paste;
 use radix_engine_interface::api::ModuleId;
 use radix_engine_interface::crypto::Hash;
 use resources_tracker_macro::trace_resources;
+use sbor::rust::sync::Arc;
 use transaction::model::AuthZoneParams;
 
 bitflags! {
@@ -90,6 +92,7 @@ pub struct SystemModuleMixer {
     pub(crate) auth: AuthModule,
     pub(crate) transaction_runtime: TransactionRuntimeModule,
     pub(super) execution_trace: ExecutionTraceModule,
+    pub(crate) user_modules: UserSystemModules,
 }
 
 // Macro generates default modules dispatches call based on passed function name and arguments.
@@ -98,6 +101,7 @@ macro_rules! internal_call_dispatch {
         paste! {
         {
             let modules: EnabledModules = $system.modules.enabled_modules;
+            let has_user_modules = !$system.modules.user_modules.is_empty();
             if modules.contains(EnabledModules::KERNEL_TRACE) {
                 KernelTraceModule::[< $fn >]($($param, )*)?;
             }
@@ -116,6 +120,9 @@ macro_rules! internal_call_dispatch {
             if modules.contains(EnabledModules::EXECUTION_TRACE) {
                 ExecutionTraceModule::[< $fn >]($($param, )*)?;
             }
+            if has_user_modules {
+                UserModulesModule::[< $fn >]($($param, )*)?;
+            }
             Ok(())
         }
     }};
@@ -128,7 +135,7 @@ impl SystemModuleMixer {
         tx_hash: Hash,
         auth_zone_params: AuthZoneParams,
         fee_reserve: SystemLoanFeeReserve,
-        fee_table: FeeTable,
+        fee_table: Arc<FeeTable>,
         payload_len: usize,
         num_of_signature_validations: usize,
         execution_config: &ExecutionConfig,
@@ -151,6 +158,7 @@ impl SystemModuleMixer {
             },
             auth: AuthModule {
                 params: auth_zone_params.clone(),
+                skipped_roles: execution_config.skipped_auth_roles.clone(),
             },
             limits: LimitsModule::new(TransactionLimitsConfig {
                 max_heap_substate_total_bytes: execution_config.max_heap_substate_total_bytes,
@@ -173,6 +181,7 @@ impl SystemModuleMixer {
                 events: Vec::new(),
                 replacements: index_map_new(),
             },
+            user_modules: execution_config.user_modules.clone(),
         }
     }
 
@@ -227,6 +236,11 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
             KernelTraceModule::on_init(api)?;
         }
 
+        // Enable user modules, which always come after the native modules
+        if !api.kernel_get_system().modules.user_modules.is_empty() {
+            UserModulesModule::on_init(api)?;
+        }
+
         Ok(())
     }
 
@@ -497,6 +511,13 @@ impl SystemModuleMixer {
             .contains(EnabledModules::TRANSACTION_RUNTIME)
         {
             self.transaction_runtime.add_log(level, message);
+            if let Some((level, message)) = self.transaction_runtime.logs.last() {
+                self.user_modules
+                    .dispatch(|module| module.on_add_log(level, message))?;
+            }
+        } else {
+            self.user_modules
+                .dispatch(|module| module.on_add_log(&level, &message))?;
         }
 
         Ok(())
@@ -535,6 +556,13 @@ impl SystemModuleMixer {
             .contains(EnabledModules::TRANSACTION_RUNTIME)
         {
             self.transaction_runtime.add_event(event);
+            if let Some(event) = self.transaction_runtime.events.last() {
+                self.user_modules
+                    .dispatch(|module| module.on_emit_event(event))?;
+            }
+        } else {
+            self.user_modules
+                .dispatch(|module| module.on_emit_event(&event))?;
         }
 
         Ok(())
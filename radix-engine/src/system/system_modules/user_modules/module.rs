@@ -0,0 +1,259 @@
+use crate::errors::{RuntimeError, SystemModuleError};
+use crate::kernel::kernel_api::{KernelApi, KernelInternalApi, KernelInvocation};
+use crate::kernel::kernel_callback_api::{
+    CloseSubstateEvent, CreateNodeEvent, DropNodeEvent, OpenSubstateEvent, ReadSubstateEvent,
+    RemoveSubstateEvent, SetSubstateEvent, WriteSubstateEvent,
+};
+use crate::system::actor::Actor;
+use crate::system::module::SystemModule;
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::system::system_modules::transaction_runtime::Event;
+use crate::types::*;
+use sbor::rust::sync::Arc;
+
+/// A system module defined outside of the engine, which observes the kernel and system events of
+/// a transaction, such as invocations, node creation, substate access and emitted events.
+///
+/// All hooks have no-op default implementations. Returning an error from a hook fails the
+/// transaction with [`SystemModuleError::UserModuleError`], which allows invariant checkers to be
+/// built on top of this trait.
+///
+/// User modules are dispatched to after all native modules, in the order they were registered,
+/// for every hook including `on_init` and `on_teardown`. They therefore observe the effects of
+/// the native modules (eg limits have already been checked), and can't affect them.
+///
+/// User modules are not part of the protocol - they should never be registered when executing
+/// transactions which are committed to a ledger shared with other nodes.
+pub trait UserSystemModule {
+    fn on_init(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_teardown(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn before_invoke(&mut self, _invocation: &KernelInvocation<Actor>) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn after_invoke(&mut self, _output: &IndexedScryptoValue) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_create_node(&mut self, _event: &CreateNodeEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_drop_node(&mut self, _event: &DropNodeEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_open_substate(&mut self, _event: &OpenSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_read_substate(&mut self, _event: &ReadSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_write_substate(&mut self, _event: &WriteSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_close_substate(&mut self, _event: &CloseSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_set_substate(&mut self, _event: &SetSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_remove_substate(&mut self, _event: &RemoveSubstateEvent) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_emit_event(&mut self, _event: &Event) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn on_add_log(&mut self, _level: &Level, _message: &str) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// The lock a [`UserSystemModule`] is shared behind. With `std` this is a `Mutex`, so that an
+/// `ExecutionConfig` holding user modules can be sent to other threads.
+#[cfg(not(feature = "alloc"))]
+pub type UserSystemModuleLock<M> = sbor::rust::sync::Mutex<M>;
+#[cfg(feature = "alloc")]
+pub type UserSystemModuleLock<M> = sbor::rust::cell::RefCell<M>;
+
+/// A [`UserSystemModule`] shared between the caller and the engine.
+pub type SharedUserSystemModule = Arc<UserSystemModuleLock<dyn UserSystemModule + Send>>;
+
+/// The user modules registered for a transaction, in the order they are dispatched to.
+///
+/// Modules are shared with the caller, so that any data they collect can be inspected once the
+/// transaction has been executed.
+#[derive(Clone, Default)]
+pub struct UserSystemModules(Vec<SharedUserSystemModule>);
+
+impl UserSystemModules {
+    pub fn push(&mut self, module: SharedUserSystemModule) {
+        self.0.push(module);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub(crate) fn dispatch<F>(&self, mut f: F) -> Result<(), RuntimeError>
+    where
+        F: FnMut(&mut dyn UserSystemModule) -> Result<(), String>,
+    {
+        for module in &self.0 {
+            // A module which panicked in another transaction is still dispatched to
+            #[cfg(not(feature = "alloc"))]
+            let mut module = module.lock().unwrap_or_else(|e| e.into_inner());
+            #[cfg(feature = "alloc")]
+            let mut module = module.borrow_mut();
+            f(&mut *module).map_err(|message| {
+                RuntimeError::SystemModuleError(SystemModuleError::UserModuleError(message))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for UserSystemModules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserSystemModules")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+/// Forwards the kernel callbacks to the registered [`UserSystemModule`]s.
+pub struct UserModulesModule;
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for UserModulesModule {
+    fn on_init<Y: KernelApi<SystemConfig<V>>>(api: &mut Y) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_init())
+    }
+
+    fn on_teardown<Y: KernelApi<SystemConfig<V>>>(api: &mut Y) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_teardown())
+    }
+
+    fn before_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        invocation: &KernelInvocation<Actor>,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.before_invoke(invocation))
+    }
+
+    fn after_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        output: &IndexedScryptoValue,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.after_invoke(output))
+    }
+
+    fn on_create_node<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &CreateNodeEvent,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_create_node(event))
+    }
+
+    fn on_drop_node<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &DropNodeEvent,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_drop_node(event))
+    }
+
+    fn on_open_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &OpenSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_open_substate(event))
+    }
+
+    fn on_read_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &ReadSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_read_substate(event))
+    }
+
+    fn on_write_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &WriteSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_write_substate(event))
+    }
+
+    fn on_close_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &CloseSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_close_substate(event))
+    }
+
+    fn on_set_substate(
+        system: &mut SystemConfig<V>,
+        event: &SetSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        system
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_set_substate(event))
+    }
+
+    fn on_remove_substate(
+        system: &mut SystemConfig<V>,
+        event: &RemoveSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        system
+            .modules
+            .user_modules
+            .dispatch(|module| module.on_remove_substate(event))
+    }
+}
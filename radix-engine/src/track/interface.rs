@@ -164,6 +164,7 @@ pub trait CommitableSubstateStore {
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        range: &SortedKeyRange,
         count: u32,
         on_io_access: &mut F,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, E>;
@@ -78,6 +78,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
     >(
         substate_db: &'x S,
         partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
         on_io_access: &'x mut F,
         canonical_partition: CanonicalPartition,
     ) -> Box<dyn Iterator<Item = Result<(DbSortKey, (SubstateKey, IndexedScryptoValue)), E>> + 'x>
@@ -136,7 +137,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
         }
 
         Box::new(TracedIterator {
-            iterator: substate_db.list_entries(partition_key),
+            iterator: substate_db.list_entries_from(partition_key, from_sort_key),
             on_io_access,
             canonical_partition,
             errored_out: false,
@@ -588,6 +589,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         let mut tracked_iter = IterationCountedIter::new(Self::list_entries_from_db::<E, F, K>(
             self.substate_db,
             &db_partition_key,
+            None,
             on_io_access,
             CanonicalPartition {
                 node_id: *node_id,
@@ -679,6 +681,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
                 IterationCountedIter::new(Self::list_entries_from_db::<E, F, K>(
                     self.substate_db,
                     &db_partition_key,
+                    None,
                     on_io_access,
                     CanonicalPartition {
                         node_id: *node_id,
@@ -753,6 +756,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         &mut self,
         node_id: &NodeId,
         partition_number: PartitionNumber,
+        range: &SortedKeyRange,
         limit: u32,
         on_io_access: &mut F,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, E> {
@@ -769,6 +773,10 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
             .entry(partition_number)
             .or_insert(TrackedPartition::new());
 
+        // map the requested range of sort prefixes onto the database's sort keys
+        let from_db_sort_key = M::sort_prefix_to_db_sort_key(&range.start);
+        let to_db_sort_key = range.end.map(|end| M::sort_prefix_to_db_sort_key(&end));
+
         // initialize the "from db" iterator: use `dyn`, since we want to skip it altogether if the node is marked as `is_new` in our track
         let mut db_values_count = 0u32;
         let raw_db_entries: Box<
@@ -780,6 +788,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
             Box::new(Self::list_entries_from_db::<E, F, SortedKey>(
                 self.substate_db,
                 &partition_key,
+                Some(&from_db_sort_key),
                 on_io_access,
                 CanonicalPartition {
                     node_id: *node_id,
@@ -792,26 +801,29 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         });
 
         // initialize the "from track" iterator
-        let tracked_entry_changes =
-            tracked_partition
-                .substates
-                .iter()
-                .map(|(db_sort_key, tracked_substate)| {
-                    // TODO: ensure we abort if any substates are write locked.
-                    if let Some(value) = tracked_substate.substate_value.get() {
-                        (
-                            db_sort_key.clone(),
-                            Some((tracked_substate.substate_key.clone(), value.clone())),
-                        )
-                    } else {
-                        (db_sort_key.clone(), None)
-                    }
-                });
+        let tracked_entry_changes = tracked_partition
+            .substates
+            .range(from_db_sort_key.clone()..)
+            .map(|(db_sort_key, tracked_substate)| {
+                // TODO: ensure we abort if any substates are write locked.
+                if let Some(value) = tracked_substate.substate_value.get() {
+                    (
+                        db_sort_key.clone(),
+                        Some((tracked_substate.substate_key.clone(), value.clone())),
+                    )
+                } else {
+                    (db_sort_key.clone(), None)
+                }
+            });
 
         let mut items = Vec::new();
         // construct the composite iterator, which applies changes read from our track on top of db values
-        for result in
-            OverlayingResultIterator::new(db_read_entries, tracked_entry_changes).take(limit)
+        for result in OverlayingResultIterator::new(db_read_entries, tracked_entry_changes)
+            .take_while(|result| match (result, &to_db_sort_key) {
+                (Ok((db_sort_key, _)), Some(to_db_sort_key)) => db_sort_key < to_db_sort_key,
+                _ => true,
+            })
+            .take(limit)
         {
             let (_db_sort_key, (substate_key, substate_value)) = result?;
             let sorted_key = match substate_key {
@@ -822,7 +834,10 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         }
 
         // Use the statistics (gathered by the `.inspect()`s above) to update the track's metadata and to return costing info
-        tracked_partition.range_read = u32::max(tracked_partition.range_read, db_values_count);
+        // (only meaningful for reads starting at the beginning of the partition)
+        if range.start == SortedKeyRange::all().start {
+            tracked_partition.range_read = u32::max(tracked_partition.range_read, db_values_count);
+        }
 
         // TODO: cache read substates in Track (and notify upper layer)
 
@@ -1,16 +1,336 @@
+use crate::blueprints::consensus_manager::{
+    ConsensusManagerBlueprint, ConsensusManagerField,
+    ConsensusManagerProposerMilliTimestampFieldPayload,
+    ConsensusManagerProposerMinuteTimestampFieldPayload, ConsensusManagerStateFieldPayload,
+    ProposerMilliTimestampSubstate, ProposerMinuteTimestampSubstate,
+};
+use crate::blueprints::resource::{FungibleVaultBalanceFieldPayload, FungibleVaultField};
 use crate::system::system_callback_api::SystemCallbackObject;
+use crate::system::system_db_reader::{
+    SystemDatabaseReader, SystemDatabaseWriter, SystemReaderError,
+};
+use crate::system::system_modules::auth::SkippedAuthRoles;
 use crate::transaction::TransactionReceipt;
 use crate::transaction::*;
+use crate::types::*;
+use radix_engine_interface::api::ModuleId;
+use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use radix_engine_interface::network::NetworkDefinition;
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
 use radix_engine_store_interface::interface::*;
+use radix_engine_store_interface::overlay::SubstateDatabaseOverlay;
 use transaction::errors::TransactionValidationError;
-use transaction::model::PreviewIntentV1;
+use transaction::model::{
+    BlobV1, BlobsV1, InstructionsV1, IntentV1, MessageV1, PreviewFlags, PreviewIntentV1,
+    TransactionHeaderV1, TransactionManifestV1,
+};
 use transaction::validation::NotarizedTransactionValidator;
 use transaction::validation::ValidationConfig;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PreviewError {
     TransactionValidationError(TransactionValidationError),
+    StateOverrideError(StateOverrideError),
+    BundleTransactionValidationError {
+        index: usize,
+        error: TransactionValidationError,
+    },
+    /// The current epoch, which is needed to build an intent for a manifest, could not be read.
+    SystemReaderError(SystemReaderError),
+}
+
+/// A temporary change to the ledger state, applied before a preview is executed.
+///
+/// Overrides are applied to an in-memory overlay, so the underlying database is never modified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateOverride {
+    /// Sets the current epoch of the consensus manager.
+    Epoch(Epoch),
+    /// Sets the proposer timestamp of the consensus manager (both its milli and minute precision).
+    ProposerTimestamp { epoch_milli: i64 },
+    /// Sets the liquid balance of a fungible vault.
+    FungibleVaultBalance {
+        vault_id: InternalAddress,
+        amount: Decimal,
+    },
+    /// Replaces the value of an object field, eg the state of a scrypto component.
+    ///
+    /// The value is not validated against the blueprint schema, so it is up to the caller to
+    /// provide a value of the expected type.
+    ObjectField {
+        node_id: NodeId,
+        module_id: ModuleId,
+        field_index: u8,
+        value: ScryptoValue,
+    },
+    /// Sets (or deletes, if `value` is `None`) a raw substate.
+    Substate {
+        node_id: NodeId,
+        partition_number: PartitionNumber,
+        substate_key: SubstateKey,
+        value: Option<DbSubstateValue>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateOverrideError {
+    SystemReaderError(SystemReaderError),
+    InvalidTimestamp(i64),
+    NotAFungibleVault(InternalAddress),
+}
+
+/// A check on the ledger state left behind by a preview.
+///
+/// Assertions are evaluated against the overlay after the preview's committed state updates (if
+/// any) have been applied, so they see both the state overrides and the preview's own changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewAssertion {
+    /// Checks the liquid balance of a fungible vault.
+    FungibleVaultBalance {
+        vault_id: InternalAddress,
+        amount: Decimal,
+    },
+    /// Checks the value of an object field, eg the state of a scrypto component.
+    ObjectField {
+        node_id: NodeId,
+        module_id: ModuleId,
+        field_index: u8,
+        value: ScryptoValue,
+    },
+    /// Checks a raw substate, or that it does not exist if `value` is `None`.
+    Substate {
+        node_id: NodeId,
+        partition_number: PartitionNumber,
+        substate_key: SubstateKey,
+        value: Option<DbSubstateValue>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewAssertionError {
+    SystemReaderError(SystemReaderError),
+    NotAFungibleVault(InternalAddress),
+    FungibleVaultBalanceMismatch {
+        vault_id: InternalAddress,
+        actual: Decimal,
+    },
+    ObjectFieldMismatch {
+        node_id: NodeId,
+        module_id: ModuleId,
+        field_index: u8,
+        actual: ScryptoValue,
+    },
+    SubstateMismatch {
+        node_id: NodeId,
+        partition_number: PartitionNumber,
+        substate_key: SubstateKey,
+        actual: Option<DbSubstateValue>,
+    },
+}
+
+impl PreviewAssertion {
+    /// Evaluates this assertion against the given database.
+    pub fn check<S: SubstateDatabase>(&self, substate_db: &S) -> Result<(), PreviewAssertionError> {
+        match self {
+            PreviewAssertion::FungibleVaultBalance { vault_id, amount } => {
+                let reader = SystemDatabaseReader::new(substate_db);
+                let blueprint_id = reader
+                    .get_blueprint_id(vault_id.as_node_id(), ModuleId::Main)
+                    .map_err(PreviewAssertionError::SystemReaderError)?;
+                if !blueprint_id.package_address.eq(&RESOURCE_PACKAGE)
+                    || !blueprint_id.blueprint_name.eq(FUNGIBLE_VAULT_BLUEPRINT)
+                {
+                    return Err(PreviewAssertionError::NotAFungibleVault(*vault_id));
+                }
+
+                let actual = reader
+                    .read_typed_object_field::<FungibleVaultBalanceFieldPayload>(
+                        vault_id.as_node_id(),
+                        ModuleId::Main,
+                        FungibleVaultField::Balance.field_index(),
+                    )
+                    .map_err(PreviewAssertionError::SystemReaderError)?
+                    .into_latest()
+                    .amount();
+                if actual.ne(amount) {
+                    return Err(PreviewAssertionError::FungibleVaultBalanceMismatch {
+                        vault_id: *vault_id,
+                        actual,
+                    });
+                }
+            }
+            PreviewAssertion::ObjectField {
+                node_id,
+                module_id,
+                field_index,
+                value,
+            } => {
+                let actual = SystemDatabaseReader::new(substate_db)
+                    .read_typed_object_field::<ScryptoValue>(node_id, *module_id, *field_index)
+                    .map_err(PreviewAssertionError::SystemReaderError)?;
+                if actual.ne(value) {
+                    return Err(PreviewAssertionError::ObjectFieldMismatch {
+                        node_id: *node_id,
+                        module_id: *module_id,
+                        field_index: *field_index,
+                        actual,
+                    });
+                }
+            }
+            PreviewAssertion::Substate {
+                node_id,
+                partition_number,
+                substate_key,
+                value,
+            } => {
+                let actual = substate_db.get_substate(
+                    &SpreadPrefixKeyMapper::to_db_partition_key(node_id, *partition_number),
+                    &SpreadPrefixKeyMapper::to_db_sort_key(substate_key),
+                );
+                if actual.ne(value) {
+                    return Err(PreviewAssertionError::SubstateMismatch {
+                        node_id: *node_id,
+                        partition_number: *partition_number,
+                        substate_key: substate_key.clone(),
+                        actual,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Adjustments to the ledger state and to auth, which only apply to a single preview.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreviewOverrides {
+    pub state_overrides: Vec<StateOverride>,
+    pub skipped_auth_roles: SkippedAuthRoles,
+}
+
+impl PreviewOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_state_override(mut self, state_override: StateOverride) -> Self {
+        self.state_overrides.push(state_override);
+        self
+    }
+
+    pub fn with_skipped_auth_roles(mut self, skipped_auth_roles: SkippedAuthRoles) -> Self {
+        self.skipped_auth_roles = skipped_auth_roles;
+        self
+    }
+}
+
+impl StateOverride {
+    /// Applies this override to the given database.
+    pub fn apply<S: SubstateDatabase + CommittableSubstateDatabase>(
+        &self,
+        substate_db: &mut S,
+    ) -> Result<(), StateOverrideError> {
+        match self {
+            StateOverride::Epoch(epoch) => {
+                let mut substate = SystemDatabaseReader::new(substate_db)
+                    .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
+                        CONSENSUS_MANAGER.as_node_id(),
+                        ModuleId::Main,
+                        ConsensusManagerField::State.field_index(),
+                    )
+                    .map_err(StateOverrideError::SystemReaderError)?
+                    .into_latest();
+                substate.epoch = *epoch;
+
+                SystemDatabaseWriter::new(substate_db)
+                    .write_typed_object_field(
+                        CONSENSUS_MANAGER.as_node_id(),
+                        ModuleId::Main,
+                        ConsensusManagerField::State.field_index(),
+                        ConsensusManagerStateFieldPayload::from_content_source(substate),
+                    )
+                    .map_err(StateOverrideError::SystemReaderError)?;
+            }
+            StateOverride::ProposerTimestamp { epoch_milli } => {
+                let epoch_minute = ConsensusManagerBlueprint::milli_to_minute(*epoch_milli)
+                    .ok_or(StateOverrideError::InvalidTimestamp(*epoch_milli))?;
+
+                let mut writer = SystemDatabaseWriter::new(substate_db);
+                writer
+                    .write_typed_object_field(
+                        CONSENSUS_MANAGER.as_node_id(),
+                        ModuleId::Main,
+                        ConsensusManagerField::ProposerMilliTimestamp.field_index(),
+                        ConsensusManagerProposerMilliTimestampFieldPayload::from_content_source(
+                            ProposerMilliTimestampSubstate {
+                                epoch_milli: *epoch_milli,
+                            },
+                        ),
+                    )
+                    .map_err(StateOverrideError::SystemReaderError)?;
+                writer
+                    .write_typed_object_field(
+                        CONSENSUS_MANAGER.as_node_id(),
+                        ModuleId::Main,
+                        ConsensusManagerField::ProposerMinuteTimestamp.field_index(),
+                        ConsensusManagerProposerMinuteTimestampFieldPayload::from_content_source(
+                            ProposerMinuteTimestampSubstate { epoch_minute },
+                        ),
+                    )
+                    .map_err(StateOverrideError::SystemReaderError)?;
+            }
+            StateOverride::FungibleVaultBalance { vault_id, amount } => {
+                let blueprint_id = SystemDatabaseReader::new(substate_db)
+                    .get_blueprint_id(vault_id.as_node_id(), ModuleId::Main)
+                    .map_err(StateOverrideError::SystemReaderError)?;
+                if !blueprint_id.package_address.eq(&RESOURCE_PACKAGE)
+                    || !blueprint_id.blueprint_name.eq(FUNGIBLE_VAULT_BLUEPRINT)
+                {
+                    return Err(StateOverrideError::NotAFungibleVault(*vault_id));
+                }
+
+                SystemDatabaseWriter::new(substate_db)
+                    .write_typed_object_field(
+                        vault_id.as_node_id(),
+                        ModuleId::Main,
+                        FungibleVaultField::Balance.field_index(),
+                        FungibleVaultBalanceFieldPayload::from_content_source(
+                            LiquidFungibleResource::new(*amount),
+                        ),
+                    )
+                    .map_err(StateOverrideError::SystemReaderError)?;
+            }
+            StateOverride::ObjectField {
+                node_id,
+                module_id,
+                field_index,
+                value,
+            } => {
+                SystemDatabaseWriter::new(substate_db)
+                    .write_typed_object_field(node_id, *module_id, *field_index, value)
+                    .map_err(StateOverrideError::SystemReaderError)?;
+            }
+            StateOverride::Substate {
+                node_id,
+                partition_number,
+                substate_key,
+                value,
+            } => {
+                let update = match value {
+                    Some(value) => DatabaseUpdate::Set(value.clone()),
+                    None => DatabaseUpdate::Delete,
+                };
+                substate_db.commit(&DatabaseUpdates::from_delta_maps(indexmap!(
+                    SpreadPrefixKeyMapper::to_db_partition_key(node_id, *partition_number) => indexmap!(
+                        SpreadPrefixKeyMapper::to_db_sort_key(substate_key) => update
+                    )
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 pub fn execute_preview<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
@@ -20,6 +340,51 @@ pub fn execute_preview<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
     preview_intent: PreviewIntentV1,
     with_kernel_trace: bool,
 ) -> Result<TransactionReceipt, PreviewError> {
+    execute_preview_with_overrides(
+        substate_db,
+        vm,
+        network,
+        preview_intent,
+        &PreviewOverrides::default(),
+        with_kernel_trace,
+    )
+}
+
+/// Executes a preview on top of the given state overrides, optionally skipping some auth roles.
+///
+/// The overrides and the preview's own state updates are kept in an in-memory overlay which is
+/// discarded afterwards, so `substate_db` is left untouched.
+pub fn execute_preview_with_overrides<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    network: &NetworkDefinition,
+    preview_intent: PreviewIntentV1,
+    overrides: &PreviewOverrides,
+    with_kernel_trace: bool,
+) -> Result<TransactionReceipt, PreviewError> {
+    execute_preview_with_assertions(
+        substate_db,
+        vm,
+        network,
+        preview_intent,
+        overrides,
+        &[],
+        with_kernel_trace,
+    )
+    .map(|(receipt, _)| receipt)
+}
+
+/// As [`execute_preview_with_overrides`], and then evaluates the given assertions against the
+/// resulting state, returning one result per assertion alongside the receipt.
+pub fn execute_preview_with_assertions<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    network: &NetworkDefinition,
+    preview_intent: PreviewIntentV1,
+    overrides: &PreviewOverrides,
+    assertions: &[PreviewAssertion],
+    with_kernel_trace: bool,
+) -> Result<(TransactionReceipt, Vec<Result<(), PreviewAssertionError>>), PreviewError> {
     let validation_config = ValidationConfig::default(network.id);
 
     let validator = NotarizedTransactionValidator::new(validation_config);
@@ -28,11 +393,168 @@ pub fn execute_preview<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
         .validate_preview_intent_v1(preview_intent)
         .map_err(PreviewError::TransactionValidationError)?;
 
-    Ok(execute_transaction(
-        substate_db,
+    let mut overlay = SubstateDatabaseOverlay::new(substate_db);
+    for state_override in &overrides.state_overrides {
+        state_override
+            .apply(&mut overlay)
+            .map_err(PreviewError::StateOverrideError)?;
+    }
+
+    let receipt = execute_transaction(
+        &overlay,
         vm,
         &CostingParameters::default(),
-        &ExecutionConfig::for_preview(network.clone()).with_kernel_trace(with_kernel_trace),
+        &ExecutionConfig::for_preview(network.clone())
+            .with_kernel_trace(with_kernel_trace)
+            .with_skipped_auth_roles(overrides.skipped_auth_roles.clone()),
         &validated.get_executable(),
-    ))
+    );
+
+    if assertions.is_empty() {
+        return Ok((receipt, vec![]));
+    }
+    if let TransactionResult::Commit(commit) = &receipt.result {
+        overlay.commit(
+            &commit
+                .state_updates
+                .create_database_updates::<SpreadPrefixKeyMapper>(),
+        );
+    }
+    let assertion_results = assertions
+        .iter()
+        .map(|assertion| assertion.check(&overlay))
+        .collect();
+
+    Ok((receipt, assertion_results))
+}
+
+/// A transaction of a preview bundle, given either as a preview intent or as a bare manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewBundleTransaction {
+    Intent(PreviewIntentV1),
+    /// A manifest which is wrapped into an intent valid from the current epoch (after state
+    /// overrides), with no tip and a nonce set to its index in the bundle.
+    Manifest {
+        manifest: TransactionManifestV1,
+        signer_public_keys: Vec<PublicKey>,
+        flags: PreviewFlags,
+    },
+}
+
+impl From<PreviewIntentV1> for PreviewBundleTransaction {
+    fn from(preview_intent: PreviewIntentV1) -> Self {
+        PreviewBundleTransaction::Intent(preview_intent)
+    }
+}
+
+impl PreviewBundleTransaction {
+    fn into_preview_intent(
+        self,
+        index: usize,
+        current_epoch: Epoch,
+        network: &NetworkDefinition,
+    ) -> PreviewIntentV1 {
+        match self {
+            PreviewBundleTransaction::Intent(preview_intent) => preview_intent,
+            PreviewBundleTransaction::Manifest {
+                manifest,
+                signer_public_keys,
+                flags,
+            } => PreviewIntentV1 {
+                intent: IntentV1 {
+                    header: TransactionHeaderV1 {
+                        network_id: network.id,
+                        start_epoch_inclusive: current_epoch,
+                        end_epoch_exclusive: current_epoch.after(10).unwrap_or(current_epoch),
+                        nonce: index as u32,
+                        notary_public_key: PublicKey::Secp256k1(Secp256k1PublicKey([0u8; 33])),
+                        notary_is_signatory: false,
+                        tip_percentage: 0,
+                    },
+                    instructions: InstructionsV1(manifest.instructions),
+                    blobs: BlobsV1 {
+                        blobs: manifest.blobs.into_values().map(BlobV1).collect(),
+                    },
+                    message: MessageV1::default(),
+                },
+                signer_public_keys,
+                flags,
+            },
+        }
+    }
+}
+
+/// Executes the given previews in order, each on top of the state updates of the previous ones,
+/// and returns one receipt per preview.
+///
+/// State overrides are applied once, before the first preview. Previews which are rejected or
+/// fail still produce a receipt, and only their committed updates (eg fee payments) are seen by
+/// subsequent previews. As with [`execute_preview_with_overrides`], all updates are kept in an
+/// in-memory overlay, so `substate_db` is left untouched.
+pub fn execute_preview_bundle<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    network: &NetworkDefinition,
+    transactions: Vec<PreviewBundleTransaction>,
+    overrides: &PreviewOverrides,
+    with_kernel_trace: bool,
+) -> Result<Vec<TransactionReceipt>, PreviewError> {
+    let validation_config = ValidationConfig::default(network.id);
+
+    let validator = NotarizedTransactionValidator::new(validation_config);
+
+    let mut overlay = SubstateDatabaseOverlay::new(substate_db);
+    for state_override in &overrides.state_overrides {
+        state_override
+            .apply(&mut overlay)
+            .map_err(PreviewError::StateOverrideError)?;
+    }
+
+    let has_manifests = transactions
+        .iter()
+        .any(|transaction| matches!(transaction, PreviewBundleTransaction::Manifest { .. }));
+    let current_epoch = if has_manifests {
+        SystemDatabaseReader::new(&overlay)
+            .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
+                CONSENSUS_MANAGER.as_node_id(),
+                ModuleId::Main,
+                ConsensusManagerField::State.field_index(),
+            )
+            .map_err(PreviewError::SystemReaderError)?
+            .into_latest()
+            .epoch
+    } else {
+        Epoch::zero()
+    };
+
+    let validated_transactions = transactions
+        .into_iter()
+        .enumerate()
+        .map(|(index, transaction)| {
+            validator
+                .validate_preview_intent_v1(transaction.into_preview_intent(
+                    index,
+                    current_epoch,
+                    network,
+                ))
+                .map_err(|error| PreviewError::BundleTransactionValidationError { index, error })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let execution_config = ExecutionConfig::for_preview(network.clone())
+        .with_kernel_trace(with_kernel_trace)
+        .with_skipped_auth_roles(overrides.skipped_auth_roles.clone());
+
+    Ok(validated_transactions
+        .iter()
+        .map(|validated| {
+            execute_and_commit_transaction(
+                &mut overlay,
+                vm.clone(),
+                &CostingParameters::default(),
+                &execution_config,
+                &validated.get_executable(),
+            )
+        })
+        .collect())
 }
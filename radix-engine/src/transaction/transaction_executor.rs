@@ -18,9 +18,13 @@ use crate::kernel::kernel_callback_api::*;
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_db_reader::SystemDatabaseReader;
+use crate::system::system_modules::auth::SkippedAuthRoles;
 use crate::system::system_modules::costing::*;
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
 use crate::system::system_modules::transaction_runtime::TransactionRuntimeModule;
+use crate::system::system_modules::user_modules::{
+    UserSystemModule, UserSystemModuleLock, UserSystemModules,
+};
 use crate::system::system_modules::{EnabledModules, SystemModuleMixer};
 use crate::system::system_substates::KeyValueEntrySubstate;
 use crate::system::system_substates::{FieldSubstate, LockStatus};
@@ -33,6 +37,7 @@ use radix_engine_interface::api::ModuleId;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
 use radix_engine_store_interface::{db_key_mapper::SpreadPrefixKeyMapper, interface::*};
+use sbor::rust::sync::Arc;
 use transaction::model::*;
 
 /// Protocol-defined costing parameters
@@ -99,6 +104,13 @@ pub struct ExecutionConfig {
     pub max_number_of_logs: usize,
     pub max_number_of_events: usize,
     pub max_per_function_royalty_in_xrd: Decimal,
+    /// Shared between transactions, see [`ExecutionConfig::with_fee_table`].
+    pub(crate) fee_table: Arc<FeeTable>,
+    /// Only true for configs created with [`ExecutionConfig::for_preview`].
+    pub(crate) is_preview: bool,
+    /// Only set by the preview executor, from [`crate::transaction::PreviewOverrides`].
+    pub(crate) skipped_auth_roles: SkippedAuthRoles,
+    pub user_modules: UserSystemModules,
 }
 
 impl ExecutionConfig {
@@ -124,6 +136,10 @@ impl ExecutionConfig {
             max_number_of_events: MAX_NUMBER_OF_EVENTS,
             max_per_function_royalty_in_xrd: Decimal::try_from(MAX_PER_FUNCTION_ROYALTY_IN_XRD)
                 .unwrap(),
+            fee_table: Arc::new(FeeTable::new()),
+            is_preview: false,
+            skipped_auth_roles: SkippedAuthRoles::default(),
+            user_modules: UserSystemModules::default(),
         }
     }
 
@@ -163,6 +179,7 @@ impl ExecutionConfig {
         Self {
             enabled_modules: EnabledModules::for_preview(),
             enable_cost_breakdown: true,
+            is_preview: true,
             ..Self::default(network_definition)
         }
     }
@@ -185,6 +202,38 @@ impl ExecutionConfig {
         self.abort_when_loan_repaid = enabled;
         self
     }
+
+    /// Overrides the fee table of the current protocol version, eg to model proposed fee changes.
+    /// A [`VersionedFeeTable`] loaded from config can be passed in with `into_latest()`.
+    ///
+    /// The fee table is validated once here, and then shared by all transactions executed with
+    /// this config.
+    pub fn with_fee_table(mut self, fee_table: FeeTable) -> Result<Self, FeeTableError> {
+        fee_table.validate()?;
+        self.fee_table = Arc::new(fee_table);
+        Ok(self)
+    }
+
+    pub fn fee_table(&self) -> &FeeTable {
+        &self.fee_table
+    }
+
+    /// Bypasses the given auth roles. Transactions executed with a non-preview config which skips
+    /// roles are rejected, see [`RejectionReason::AuthRolesSkippedOutsidePreview`].
+    pub(crate) fn with_skipped_auth_roles(mut self, skipped_auth_roles: SkippedAuthRoles) -> Self {
+        self.skipped_auth_roles = skipped_auth_roles;
+        self
+    }
+
+    /// Registers a user-defined system module, which is dispatched to after the native modules.
+    /// Keep a clone of the `Arc` to inspect the module once the transaction has been executed.
+    pub fn with_user_module<M: UserSystemModule + Send + 'static>(
+        mut self,
+        module: Arc<UserSystemModuleLock<M>>,
+    ) -> Self {
+        self.user_modules.push(module);
+        self
+    }
 }
 
 impl<C: SystemCallbackObject> WrappedSystem<C> for SystemConfig<C> {
@@ -235,7 +284,7 @@ where
             executable.costing_parameters(),
             execution_config.abort_when_loan_repaid,
         );
-        let fee_table = FeeTable::new();
+        let fee_table = execution_config.fee_table.clone();
 
         // Dump executable
         #[cfg(not(feature = "alloc"))]
@@ -277,7 +326,8 @@ where
             }
         } else {
             Ok(())
-        };
+        }
+        .and_then(|_| Self::validate_skipped_auth_roles(execution_config));
 
         // Run manifest
         let (fee_summary, fee_details, result) = match validation_result {
@@ -517,6 +567,16 @@ where
         }
     }
 
+    fn validate_skipped_auth_roles(
+        execution_config: &ExecutionConfig,
+    ) -> Result<(), RejectionReason> {
+        if !execution_config.is_preview && !execution_config.skipped_auth_roles.is_empty() {
+            return Err(RejectionReason::AuthRolesSkippedOutsidePreview);
+        }
+
+        Ok(())
+    }
+
     fn validate_epoch_range(
         current_epoch: Epoch,
         start_epoch_inclusive: Epoch,
@@ -593,7 +653,7 @@ where
         executable: &Executable,
         execution_config: &ExecutionConfig,
         fee_reserve: SystemLoanFeeReserve,
-        fee_table: FeeTable,
+        fee_table: Arc<FeeTable>,
         init: T::Init,
     ) -> (
         Result<Vec<InstructionOutput>, RuntimeError>,
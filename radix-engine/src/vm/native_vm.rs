@@ -2,6 +2,7 @@ use crate::blueprints::access_controller::AccessControllerNativePackage;
 use crate::blueprints::account::AccountNativePackage;
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
 use crate::blueprints::identity::IdentityNativePackage;
+use crate::blueprints::locker::LockerNativePackage;
 use crate::blueprints::package::PackageNativePackage;
 use crate::blueprints::pool::PoolNativePackage;
 use crate::blueprints::resource::ResourceNativePackage;
@@ -15,6 +16,7 @@ use crate::system::attached_modules::role_assignment::RoleAssignmentNativePackag
 use crate::system::attached_modules::royalty::RoyaltyNativePackage;
 use crate::system::system_callback::SystemLockData;
 use crate::types::*;
+use crate::vm::wasm::WasmValidator;
 use crate::vm::VmInvoke;
 use radix_engine_interface::api::ClientApi;
 use radix_engine_interface::blueprints::package::*;
@@ -30,10 +32,13 @@ impl<E: NativeVmExtension> NativeVm<E> {
         Self { extension }
     }
 
+    /// The WASM validator is used by the package blueprint, to validate the code of the packages
+    /// it publishes.
     pub fn create_instance(
         &self,
         package_address: &PackageAddress,
         code: &[u8],
+        wasm_validator: WasmValidator,
     ) -> Result<NativeVmInstance<E::Instance>, RuntimeError> {
         if let Some(custom_invoke) = self.extension.try_create_instance(code) {
             return Ok(NativeVmInstance::Extension(custom_invoke));
@@ -56,6 +61,7 @@ impl<E: NativeVmExtension> NativeVm<E> {
         let instance = NativeVmInstance::Native {
             package_address: *package_address,
             native_package_code_id,
+            wasm_validator,
         };
 
         Ok(instance)
@@ -68,6 +74,7 @@ pub enum NativeVmInstance<I: VmInvoke> {
         #[allow(dead_code)]
         package_address: PackageAddress,
         native_package_code_id: u64,
+        wasm_validator: WasmValidator,
     },
     Extension(I),
 }
@@ -102,6 +109,7 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
             NativeVmInstance::Native {
                 native_package_code_id,
                 package_address,
+                wasm_validator,
             } => {
                 api.consume_cost_units(ClientCostingEntry::RunNativeCode {
                     package_address: package_address,
@@ -110,7 +118,9 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
                 })?;
 
                 match *native_package_code_id {
-                    PACKAGE_CODE_ID => PackageNativePackage::invoke_export(export_name, input, api),
+                    PACKAGE_CODE_ID => {
+                        PackageNativePackage::invoke_export(export_name, input, wasm_validator, api)
+                    }
                     RESOURCE_CODE_ID => {
                         ResourceNativePackage::invoke_export(export_name, input, api)
                     }
@@ -135,6 +145,7 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
                         RoleAssignmentNativePackage::invoke_export(export_name, input, api)
                     }
                     POOL_CODE_ID => PoolNativePackage::invoke_export(export_name, input, api),
+                    LOCKER_CODE_ID => LockerNativePackage::invoke_export(export_name, input, api),
                     TRANSACTION_TRACKER_CODE_ID => {
                         TransactionTrackerNativePackage::invoke_export(export_name, input, api)
                     }
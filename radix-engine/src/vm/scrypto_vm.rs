@@ -10,6 +10,14 @@ use resources_tracker_macro::trace_resources;
 pub struct ScryptoVm<W: WasmEngine> {
     pub wasm_engine: W,
     pub wasm_validator_config: WasmValidatorConfigV1,
+    /// Optional disk cache of instrumented code and, if the engine supports it, of compiled
+    /// modules, so that they can be reused across processes.
+    ///
+    /// Only Wasmer persists compiled modules. Wasmi modules can't be serialized, so with the
+    /// default Wasmi engine the cache only saves the validation and instrumentation of newly
+    /// published packages, and every module is still compiled once per process.
+    #[cfg(feature = "std")]
+    pub wasm_disk_cache: Option<WasmDiskCache>,
 }
 
 impl<W: WasmEngine + Default> Default for ScryptoVm<W> {
@@ -17,11 +25,33 @@ impl<W: WasmEngine + Default> Default for ScryptoVm<W> {
         Self {
             wasm_engine: W::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            #[cfg(feature = "std")]
+            wasm_disk_cache: None,
         }
     }
 }
 
 impl<W: WasmEngine> ScryptoVm<W> {
+    /// Enables the disk cache, see [`ScryptoVm::wasm_disk_cache`] for what it caches per engine.
+    #[cfg(feature = "std")]
+    pub fn with_disk_cache(self, disk_cache: WasmDiskCache) -> Self {
+        Self {
+            wasm_engine: self.wasm_engine.with_disk_cache(disk_cache.clone()),
+            wasm_validator_config: self.wasm_validator_config,
+            wasm_disk_cache: Some(disk_cache),
+        }
+    }
+
+    /// The validator of the code of newly published packages.
+    pub fn wasm_validator(&self) -> WasmValidator {
+        #[cfg(feature = "std")]
+        if let Some(disk_cache) = &self.wasm_disk_cache {
+            return WasmValidator::default().with_disk_cache(disk_cache.clone());
+        }
+
+        WasmValidator::default()
+    }
+
     pub fn create_instance(
         &self,
         package_address: &PackageAddress,
@@ -94,11 +94,16 @@ impl<'g, W: WasmEngine + 'g, E: NativeVmExtension> SystemCallbackObject for Vm<'
                         .expect(&format!("Original code not found: {:?}", export))
                 };
 
+                let wasm_validator = api
+                    .kernel_get_system()
+                    .callback_obj
+                    .scrypto_vm
+                    .wasm_validator();
                 let mut vm_instance = api
                     .kernel_get_system()
                     .callback_obj
                     .native_vm
-                    .create_instance(address, &original_code.into_latest().code)?;
+                    .create_instance(address, &original_code.into_latest().code, wasm_validator)?;
                 let output = { vm_instance.invoke(export.export_name.as_str(), input, api)? };
 
                 output
@@ -173,12 +178,13 @@ impl VmPackageValidation {
         definition: &PackageDefinition,
         vm_type: VmType,
         code: &[u8],
+        wasm_validator: &WasmValidator,
     ) -> Result<Option<Vec<u8>>, RuntimeError> {
         match vm_type {
             VmType::Native => Ok(None),
             VmType::ScryptoV1 => {
                 // Validate WASM
-                let instrumented_code = WasmValidator::default()
+                let instrumented_code = wasm_validator
                     .validate(&code, definition.blueprints.values())
                     .map_err(|e| {
                         RuntimeError::ApplicationError(ApplicationError::PackageError(
@@ -0,0 +1,321 @@
+use crate::types::*;
+use blake2::digest::consts::U32;
+use blake2::digest::Mac;
+use blake2::Blake2bMac;
+use rand_core::{OsRng, RngCore};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const ENTRY_FILE_EXTENSION: &str = "bin";
+const SECRET_KEY_FILE_NAME: &str = "cache.key";
+const SECRET_KEY_LENGTH: usize = 32;
+const MAC_LENGTH: usize = 32;
+
+/// A best-effort, disk-backed cache for WASM artifacts which are expensive to recompute,
+/// e.g. instrumented code or compiled modules.
+///
+/// Entries are keyed by a hash (typically the code hash) and a version, which should be bumped
+/// whenever the process producing the artifact changes (e.g. a new instrumenter config).
+/// Entries which fail authentication are discarded, so callers always fall back to recomputing
+/// the artifact.
+///
+/// # Threat model
+///
+/// Cached artifacts may end up being executed without being validated again (compiled modules
+/// are deserialized as-is), so a checksum is not enough: anyone able to write to the cache
+/// directory could plant an entry together with a matching checksum. Instead, every entry is
+/// authenticated with a keyed BLAKE2b MAC over its key, version and payload. The MAC key is a
+/// random secret generated on first use and stored in the cache directory, which is only
+/// accessible by its owner on unix.
+///
+/// This protects against entries being planted, swapped between keys or corrupted by anyone who
+/// can't read the secret. It does not protect against the owner of the cache directory, who has
+/// to be trusted as much as the binary itself, so the cache must never be pointed at a directory
+/// which less trusted users can read or write.
+#[derive(Clone)]
+pub struct WasmDiskCache {
+    directory: Arc<Path>,
+    secret_key: [u8; SECRET_KEY_LENGTH],
+}
+
+impl fmt::Debug for WasmDiskCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The secret key is deliberately left out
+        f.debug_struct("WasmDiskCache")
+            .field("directory", &self.directory)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WasmDiskCache {
+    /// Opens the cache in the given directory, creating the directory and its secret key if
+    /// they don't exist yet.
+    pub fn new<P: Into<PathBuf>>(directory: P) -> io::Result<Self> {
+        let directory: PathBuf = directory.into();
+        create_private_dir(&directory)?;
+        let secret_key = load_or_create_secret_key(&directory.join(SECRET_KEY_FILE_NAME))?;
+
+        Ok(Self {
+            directory: directory.into(),
+            secret_key,
+        })
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    pub fn get(&self, key: &Hash, version: u32) -> Option<Vec<u8>> {
+        let path = self.entry_path(key, version);
+        let content = fs::read(&path).ok()?;
+
+        match self.decode_entry(key, version, content) {
+            Some(payload) => Some(payload),
+            None => {
+                // Corrupted, truncated or forged entry, remove it so that it gets rewritten
+                let _ = fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    pub fn put(&self, key: &Hash, version: u32, payload: &[u8]) -> io::Result<()> {
+        // Write to a temporary file first and rename it afterwards, so that concurrent readers
+        // never observe a partially written entry.
+        let path = self.entry_path(key, version);
+        let temp_path = path.with_extension(format!(
+            "{}.{}.tmp",
+            ENTRY_FILE_EXTENSION,
+            std::process::id()
+        ));
+        {
+            let mut file = create_private_file(&temp_path)?;
+            file.write_all(&self.mac(key, version, payload).finalize().into_bytes())?;
+            file.write_all(payload)?;
+            file.sync_all()?;
+        }
+        fs::rename(&temp_path, &path).map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            e
+        })
+    }
+
+    pub fn remove(&self, key: &Hash, version: u32) {
+        let _ = fs::remove_file(self.entry_path(key, version));
+    }
+
+    fn entry_path(&self, key: &Hash, version: u32) -> PathBuf {
+        self.directory
+            .join(format!("{}-v{}.{}", key, version, ENTRY_FILE_EXTENSION))
+    }
+
+    /// The key and version are authenticated along with the payload, so that a valid entry
+    /// can't be passed off as the entry of some other code.
+    fn mac(&self, key: &Hash, version: u32, payload: &[u8]) -> Blake2bMac<U32> {
+        let mut mac = <Blake2bMac<U32> as Mac>::new_from_slice(&self.secret_key)
+            .expect("The secret key has a valid length");
+        mac.update(&key.0);
+        mac.update(&version.to_le_bytes());
+        mac.update(payload);
+        mac
+    }
+
+    fn decode_entry(&self, key: &Hash, version: u32, mut content: Vec<u8>) -> Option<Vec<u8>> {
+        if content.len() < MAC_LENGTH {
+            return None;
+        }
+        let payload = content.split_off(MAC_LENGTH);
+        self.mac(key, version, &payload)
+            .verify_slice(&content)
+            .ok()?;
+        Some(payload)
+    }
+}
+
+fn create_private_dir(directory: &Path) -> io::Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(directory)
+}
+
+fn create_private_file(path: &Path) -> io::Result<fs::File> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)
+}
+
+fn load_or_create_secret_key(path: &Path) -> io::Result<[u8; SECRET_KEY_LENGTH]> {
+    if let Some(secret_key) = read_secret_key(path)? {
+        return Ok(secret_key);
+    }
+
+    let mut secret_key = [0u8; SECRET_KEY_LENGTH];
+    OsRng.fill_bytes(&mut secret_key);
+
+    // Write the secret to a temporary file, and only link it into place if no other process
+    // did so in the meantime, so that all processes end up with the same secret.
+    let temp_path = path.with_extension(format!("key.{}.tmp", std::process::id()));
+    {
+        let mut file = create_private_file(&temp_path)?;
+        file.write_all(&secret_key)?;
+        file.sync_all()?;
+    }
+    let linked = fs::hard_link(&temp_path, path);
+    let _ = fs::remove_file(&temp_path);
+    match linked {
+        Ok(()) => Ok(secret_key),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => read_secret_key(path)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Secret key disappeared")),
+        Err(e) => Err(e),
+    }
+}
+
+fn read_secret_key(path: &Path) -> io::Result<Option<[u8; SECRET_KEY_LENGTH]>> {
+    let content = match fs::read(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    // A secret which others can read doesn't authenticate anything
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if fs::metadata(path)?.permissions().mode() & 0o077 != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "The secret key of the WASM disk cache is accessible by other users",
+            ));
+        }
+    }
+
+    content
+        .try_into()
+        .map(Some)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Invalid secret key length"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "radix-engine-wasm-disk-cache-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn test_put_and_get() {
+        let directory = new_directory("put_and_get");
+        let cache = WasmDiskCache::new(&directory).unwrap();
+        let key = hash("code");
+
+        assert_eq!(cache.get(&key, 1), None);
+        cache.put(&key, 1, b"instrumented").unwrap();
+        assert_eq!(cache.get(&key, 1), Some(b"instrumented".to_vec()));
+        assert_eq!(cache.get(&key, 2), None);
+        assert_eq!(cache.get(&hash("other code"), 1), None);
+
+        // The secret is persisted, so entries survive reopening the cache
+        let reopened = WasmDiskCache::new(&directory).unwrap();
+        assert_eq!(reopened.get(&key, 1), Some(b"instrumented".to_vec()));
+
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn test_corrupted_entry_is_discarded() {
+        let directory = new_directory("corrupted_entry");
+        let cache = WasmDiskCache::new(&directory).unwrap();
+        let key = hash("code");
+        cache.put(&key, 1, b"instrumented").unwrap();
+
+        let path = cache.entry_path(&key, 1);
+        let mut content = fs::read(&path).unwrap();
+        let last = content.len() - 1;
+        content[last] ^= 0xff;
+        fs::write(&path, content).unwrap();
+
+        assert_eq!(cache.get(&key, 1), None);
+        assert!(!path.exists());
+
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn test_truncated_entry_is_discarded() {
+        let directory = new_directory("truncated_entry");
+        let cache = WasmDiskCache::new(&directory).unwrap();
+        let key = hash("code");
+        fs::write(cache.entry_path(&key, 1), [0u8; 4]).unwrap();
+
+        assert_eq!(cache.get(&key, 1), None);
+
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn test_forged_entry_is_discarded() {
+        let directory = new_directory("forged_entry");
+        let cache = WasmDiskCache::new(&directory).unwrap();
+        let key = hash("code");
+
+        // An entry checksummed without the secret, as anyone could have written it
+        let mut content = hash(b"malicious").0.to_vec();
+        content.extend_from_slice(b"malicious");
+        fs::write(cache.entry_path(&key, 1), content).unwrap();
+        assert_eq!(cache.get(&key, 1), None);
+
+        // A valid entry of some other code
+        cache.put(&hash("other code"), 1, b"other").unwrap();
+        fs::copy(
+            cache.entry_path(&hash("other code"), 1),
+            cache.entry_path(&key, 1),
+        )
+        .unwrap();
+        assert_eq!(cache.get(&key, 1), None);
+
+        // A valid entry of another cache
+        let other_directory = new_directory("forged_entry_other");
+        let other_cache = WasmDiskCache::new(&other_directory).unwrap();
+        other_cache.put(&key, 1, b"malicious").unwrap();
+        fs::copy(other_cache.entry_path(&key, 1), cache.entry_path(&key, 1)).unwrap();
+        assert_eq!(cache.get(&key, 1), None);
+
+        let _ = fs::remove_dir_all(directory);
+        let _ = fs::remove_dir_all(other_directory);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_secret_key_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory = new_directory("secret_key_is_private");
+        WasmDiskCache::new(&directory).unwrap();
+        let path = directory.join(SECRET_KEY_FILE_NAME);
+        assert_eq!(
+            fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        assert_eq!(
+            WasmDiskCache::new(&directory).unwrap_err().kind(),
+            io::ErrorKind::PermissionDenied
+        );
+
+        let _ = fs::remove_dir_all(directory);
+    }
+}
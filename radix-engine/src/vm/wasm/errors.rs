@@ -153,6 +153,29 @@ pub enum WasmRuntimeError {
     InvalidPackageAddress,
 
     TooManyBuffers,
+
+    InvalidHash,
+
+    InvalidEd25519PublicKey,
+
+    InvalidEd25519Signature,
+
+    InvalidSecp256k1PublicKey,
+
+    InvalidSecp256k1Signature,
+
+    InvalidBls12381PublicKey,
+
+    InvalidBls12381Signature,
+
+    /// Invalid (public key, message) pairs of a BLS12-381 aggregate verification
+    InvalidBls12381PublicKeysAndMessages(DecodeError),
+
+    /// Invalid sorted index key
+    InvalidSortedKey(DecodeError),
+
+    /// Invalid range of sorted index keys
+    InvalidSortedKeyRange(DecodeError),
 }
 
 impl SelfError for WasmRuntimeError {
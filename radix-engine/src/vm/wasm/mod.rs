@@ -1,4 +1,6 @@
 mod constants;
+#[cfg(feature = "std")]
+mod disk_cache;
 mod errors;
 mod prepare;
 mod traits;
@@ -13,6 +15,8 @@ mod weights;
 pub use self::wasmer::*;
 pub use self::wasmi::*;
 pub use constants::*;
+#[cfg(feature = "std")]
+pub use disk_cache::*;
 pub use errors::*;
 pub use prepare::*;
 pub use traits::*;
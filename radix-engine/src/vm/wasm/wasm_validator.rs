@@ -11,6 +11,10 @@ pub struct WasmValidator {
     pub max_number_of_function_locals: u32,
     pub max_number_of_globals: u32,
     pub instrumenter_config: WasmValidatorConfigV1,
+    /// Optional disk cache of validated and instrumented code, which allows skipping
+    /// re-instrumentation of code which has been seen before, e.g. in a previous process.
+    #[cfg(feature = "std")]
+    pub disk_cache: Option<WasmDiskCache>,
 }
 
 impl Default for WasmValidator {
@@ -24,15 +28,70 @@ impl Default for WasmValidator {
             max_number_of_function_locals: MAX_NUMBER_OF_FUNCTION_LOCALS,
             max_number_of_globals: MAX_NUMBER_OF_GLOBALS,
             instrumenter_config: WasmValidatorConfigV1::new(),
+            #[cfg(feature = "std")]
+            disk_cache: None,
         }
     }
 }
 
 impl WasmValidator {
+    #[cfg(feature = "std")]
+    pub fn with_disk_cache(mut self, disk_cache: WasmDiskCache) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
+    }
+
     pub fn validate<'a, I: Iterator<Item = &'a BlueprintDefinitionInit>>(
         &self,
         code: &[u8],
         blueprints: I,
+    ) -> Result<(Vec<u8>, Vec<String>), PrepareError> {
+        #[cfg(feature = "std")]
+        if let Some(disk_cache) = &self.disk_cache {
+            let blueprints: Vec<&BlueprintDefinitionInit> = blueprints.collect();
+            let key = self.disk_cache_key(code, &blueprints);
+            let version = self.instrumenter_config.version() as u32;
+
+            if let Some(cached) = disk_cache
+                .get(&key, version)
+                .and_then(|payload| scrypto_decode::<(Vec<u8>, Vec<String>)>(&payload).ok())
+            {
+                return Ok(cached);
+            }
+
+            let validated = self.validate_uncached(code, blueprints.into_iter())?;
+            // The cache is best-effort, failing to write to it must not fail the validation
+            let _ = disk_cache.put(&key, version, &scrypto_encode(&validated).unwrap());
+            return Ok(validated);
+        }
+
+        self.validate_uncached(code, blueprints)
+    }
+
+    /// The outcome of the validation depends on the code, the blueprint definitions and
+    /// the limits enforced, so all of them are part of the cache key.
+    #[cfg(feature = "std")]
+    fn disk_cache_key(&self, code: &[u8], blueprints: &[&BlueprintDefinitionInit]) -> Hash {
+        let limits = [
+            self.max_memory_size_in_pages,
+            self.max_initial_table_size,
+            self.max_number_of_br_table_targets,
+            self.max_number_of_functions,
+            self.max_number_of_function_params,
+            self.max_number_of_function_locals,
+            self.max_number_of_globals,
+            self.instrumenter_config.max_stack_size(),
+        ];
+        let mut preimage = hash(code).0.to_vec();
+        preimage.extend(scrypto_encode(&limits).unwrap());
+        preimage.extend(scrypto_encode(&blueprints.to_vec()).unwrap());
+        hash(preimage)
+    }
+
+    fn validate_uncached<'a, I: Iterator<Item = &'a BlueprintDefinitionInit>>(
+        &self,
+        code: &[u8],
+        blueprints: I,
     ) -> Result<(Vec<u8>, Vec<String>), PrepareError> {
         WasmModule::init(code)?
             .enforce_no_start_function()?
@@ -157,4 +216,53 @@ mod tests {
 "#
         )
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_validate_with_disk_cache() {
+        use crate::vm::wasm::WasmDiskCache;
+
+        let code = wat2wasm(
+            r#"
+        (module
+            (func $Test_f (param $0 i64) (result i64)
+              (i64.const 0)
+            )
+            (memory $0 1)
+            (export "memory" (memory $0))
+            (export "Test_f" (func $Test_f))
+        )"#,
+        )
+        .unwrap();
+        let definition = PackageDefinition::new_single_function_test_definition("Test", "f");
+        let directory = std::env::temp_dir().join(format!(
+            "radix-engine-wasm-validator-cache-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+        let validator =
+            WasmValidator::default().with_disk_cache(WasmDiskCache::new(&directory).unwrap());
+
+        let uncached = WasmValidator::default()
+            .validate(&code, definition.blueprints.values())
+            .unwrap();
+        let first = validator
+            .validate(&code, definition.blueprints.values())
+            .unwrap();
+        let entries = || {
+            std::fs::read_dir(&directory)
+                .unwrap()
+                .filter(|entry| entry.as_ref().unwrap().path().extension() == Some("bin".as_ref()))
+                .count()
+        };
+        assert_eq!(entries(), 1);
+        let second = validator
+            .validate(&code, definition.blueprints.values())
+            .unwrap();
+
+        assert_eq!(first, uncached);
+        assert_eq!(second, uncached);
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
 }
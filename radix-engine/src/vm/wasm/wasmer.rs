@@ -1,6 +1,8 @@
 use crate::errors::InvokeError;
 use crate::types::*;
 use crate::vm::wasm::constants::*;
+#[cfg(feature = "std")]
+use crate::vm::wasm::disk_cache::WasmDiskCache;
 use crate::vm::wasm::errors::*;
 use crate::vm::wasm::traits::*;
 use radix_engine_interface::api::actor_api::EventFlags;
@@ -12,6 +14,11 @@ use wasmer::{
 };
 use wasmer_compiler_singlepass::Singlepass;
 
+/// The version of compiled modules persisted in the disk cache, to be bumped whenever
+/// the wasmer version or the compiler configuration changes.
+#[cfg(feature = "std")]
+const WASMER_DISK_CACHE_VERSION: u32 = 1;
+
 // IMPORTANT:
 // The below integration of Wasmer is not yet checked rigorously enough for production use
 // TODO: Address the below issues before considering production use.
@@ -83,6 +90,8 @@ pub struct WasmerEngine {
     modules_cache: moka::sync::Cache<CodeHash, Arc<WasmerModule>>,
     #[cfg(feature = "radix_engine_fuzzing")]
     modules_cache: usize,
+    #[cfg(feature = "std")]
+    disk_cache: Option<WasmDiskCache>,
 }
 
 pub fn read_memory(instance: &Instance, ptr: u32, len: u32) -> Result<Vec<u8>, WasmRuntimeError> {
@@ -501,6 +510,126 @@ impl WasmerModule {
             runtime.actor_open_field(object_handle, field, flags)
         }
 
+        pub fn actor_index_insert(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            collection_index: u8,
+            key_ptr: u32,
+            key_len: u32,
+            value_ptr: u32,
+            value_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime.actor_index_insert(
+                object_handle,
+                collection_index,
+                read_memory(&instance, key_ptr, key_len)?,
+                read_memory(&instance, value_ptr, value_len)?,
+            )
+        }
+
+        pub fn actor_index_remove(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            collection_index: u8,
+            key_ptr: u32,
+            key_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .actor_index_remove(
+                    object_handle,
+                    collection_index,
+                    read_memory(&instance, key_ptr, key_len)?,
+                )
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn actor_index_scan_keys(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            collection_index: u8,
+            limit: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (_instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .actor_index_scan_keys(object_handle, collection_index, limit)
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn actor_index_drain(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            collection_index: u8,
+            limit: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (_instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .actor_index_drain(object_handle, collection_index, limit)
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn actor_sorted_index_insert(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            collection_index: u8,
+            sorted_key_ptr: u32,
+            sorted_key_len: u32,
+            value_ptr: u32,
+            value_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime.actor_sorted_index_insert(
+                object_handle,
+                collection_index,
+                read_memory(&instance, sorted_key_ptr, sorted_key_len)?,
+                read_memory(&instance, value_ptr, value_len)?,
+            )
+        }
+
+        pub fn actor_sorted_index_remove(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            collection_index: u8,
+            sorted_key_ptr: u32,
+            sorted_key_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .actor_sorted_index_remove(
+                    object_handle,
+                    collection_index,
+                    read_memory(&instance, sorted_key_ptr, sorted_key_len)?,
+                )
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn actor_sorted_index_scan(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            collection_index: u8,
+            range_ptr: u32,
+            range_len: u32,
+            limit: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .actor_sorted_index_scan(
+                    object_handle,
+                    collection_index,
+                    read_memory(&instance, range_ptr, range_len)?,
+                    limit,
+                )
+                .map(|buffer| buffer.0)
+        }
+
         pub fn actor_get_node_id(
             env: &WasmerInstanceEnv,
             actor_ref_handle: u32,
@@ -674,6 +803,104 @@ impl WasmerModule {
             runtime.sys_generate_ruid().map(|buffer| buffer.0)
         }
 
+        pub fn crypto_utils_keccak256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            runtime
+                .crypto_utils_keccak256_hash(data)
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn crypto_utils_blake2b_256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            runtime
+                .crypto_utils_blake2b_256_hash(data)
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn crypto_utils_ed25519_verify(
+            env: &WasmerInstanceEnv,
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            runtime.crypto_utils_ed25519_verify(message, public_key, signature)
+        }
+
+        pub fn crypto_utils_secp256k1_verify(
+            env: &WasmerInstanceEnv,
+            hash_ptr: u32,
+            hash_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let hash = read_memory(&instance, hash_ptr, hash_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            runtime.crypto_utils_secp256k1_verify(hash, public_key, signature)
+        }
+
+        pub fn crypto_utils_bls12381_v1_verify(
+            env: &WasmerInstanceEnv,
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            runtime.crypto_utils_bls12381_v1_verify(message, public_key, signature)
+        }
+
+        pub fn crypto_utils_bls12381_v1_aggregate_verify(
+            env: &WasmerInstanceEnv,
+            pub_keys_and_msgs_ptr: u32,
+            pub_keys_and_msgs_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let pub_keys_and_msgs =
+                read_memory(&instance, pub_keys_and_msgs_ptr, pub_keys_and_msgs_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            runtime.crypto_utils_bls12381_v1_aggregate_verify(pub_keys_and_msgs, signature)
+        }
+
         #[cfg(feature = "radix_engine_tests")]
         pub fn host_read_memory(
             env: &WasmerInstanceEnv,
@@ -763,6 +990,13 @@ impl WasmerModule {
                 ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_get_package_address),
                 ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_get_blueprint_name),
                 ACTOR_EMIT_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_emit_event),
+                ACTOR_INDEX_INSERT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_index_insert),
+                ACTOR_INDEX_REMOVE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_index_remove),
+                ACTOR_INDEX_SCAN_KEYS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_index_scan_keys),
+                ACTOR_INDEX_DRAIN_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_index_drain),
+                ACTOR_SORTED_INDEX_INSERT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_sorted_index_insert),
+                ACTOR_SORTED_INDEX_REMOVE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_sorted_index_remove),
+                ACTOR_SORTED_INDEX_SCAN_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_sorted_index_scan),
                 COSTING_CONSUME_WASM_EXECUTION_UNITS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), consume_wasm_execution_units),
                 COSTING_GET_EXECUTION_COST_UNIT_LIMIT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_execution_cost_unit_limit),
                 COSTING_GET_EXECUTION_COST_UNIT_PRICE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_execution_cost_unit_price),
@@ -776,6 +1010,12 @@ impl WasmerModule {
                 SYS_PANIC_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_panic),
                 SYS_GET_TRANSACTION_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_get_transaction_hash),
                 SYS_GENERATE_RUID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_generate_ruid),
+                CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_keccak256_hash),
+                CRYPTO_UTILS_BLAKE2B_256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_blake2b_256_hash),
+                CRYPTO_UTILS_ED25519_VERIFY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_ed25519_verify),
+                CRYPTO_UTILS_SECP256K1_VERIFY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_secp256k1_verify),
+                CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_bls12381_v1_verify),
+                CRYPTO_UTILS_BLS12381_V1_AGGREGATE_VERIFY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_bls12381_v1_aggregate_verify),
                 BUFFER_CONSUME_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), buffer_consume),
                 #[cfg(feature = "radix_engine_tests")]
                 "test_host_read_memory" => Function::new_native_with_env(self.module.store(), env.clone(), host_read_memory),
@@ -882,13 +1122,53 @@ impl WasmerEngine {
         Self {
             store: Store::new(&Universal::new(compiler).engine()),
             modules_cache,
+            #[cfg(feature = "std")]
+            disk_cache: None,
         }
     }
+
+    fn compile_module(&self, instrumented_code: &[u8]) -> Module {
+        #[cfg(feature = "std")]
+        if let Some(disk_cache) = &self.disk_cache {
+            // Compiled modules are keyed by the instrumented code they were compiled from,
+            // rather than by the code hash, which doesn't change with the instrumentation.
+            let key = hash(instrumented_code);
+            if let Some(serialized) = disk_cache.get(&key, WASMER_DISK_CACHE_VERSION) {
+                // SAFETY: `Module::deserialize` trusts its input to be the output of
+                // `Module::serialize` for the same wasmer version and compiler configuration.
+                // Entries are authenticated with the secret key of the disk cache, so they were
+                // written by this very function, and the cache version is bumped whenever the
+                // serialization format may change. See the threat model of `WasmDiskCache`.
+                match unsafe { Module::deserialize(&self.store, &serialized) } {
+                    Ok(module) => return module,
+                    Err(_) => disk_cache.remove(&key, WASMER_DISK_CACHE_VERSION),
+                }
+            }
+
+            let module =
+                Module::new(&self.store, instrumented_code).expect("Failed to parse WASM module");
+            if let Ok(serialized) = module.serialize() {
+                // The cache is best-effort, failing to write to it must not fail the instantiation
+                let _ = disk_cache.put(&key, WASMER_DISK_CACHE_VERSION, &serialized);
+            }
+            return module;
+        }
+
+        Module::new(&self.store, instrumented_code).expect("Failed to parse WASM module")
+    }
 }
 
 impl WasmEngine for WasmerEngine {
     type WasmInstance = WasmerInstance;
 
+    /// Persists compiled modules in the given disk cache, so that they don't need to be
+    /// recompiled when the engine is restarted.
+    #[cfg(feature = "std")]
+    fn with_disk_cache(mut self, disk_cache: WasmDiskCache) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
+    }
+
     fn instantiate(&self, code_hash: CodeHash, instrumented_code: &[u8]) -> WasmerInstance {
         #[cfg(not(feature = "radix_engine_fuzzing"))]
         {
@@ -905,8 +1185,7 @@ impl WasmEngine for WasmerEngine {
         }
 
         let new_module = Arc::new(WasmerModule {
-            module: Module::new(&self.store, instrumented_code)
-                .expect("Failed to parse WASM module"),
+            module: self.compile_module(instrumented_code),
             code_size_bytes: instrumented_code.len(),
         });
 
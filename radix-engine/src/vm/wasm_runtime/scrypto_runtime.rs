@@ -349,6 +349,110 @@ where
         Ok(())
     }
 
+    fn actor_index_insert(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api
+            .actor_index_insert(object_handle, collection_index, key, value)?;
+
+        Ok(())
+    }
+
+    fn actor_index_remove(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let rtn = self
+            .api
+            .actor_index_remove(object_handle, collection_index, key)?;
+
+        self.allocate_buffer(scrypto_encode(&rtn).expect("Failed to encode removed entry"))
+    }
+
+    fn actor_index_scan_keys(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let keys = self
+            .api
+            .actor_index_scan_keys(object_handle, collection_index, limit)?;
+
+        self.allocate_buffer(scrypto_encode(&keys).expect("Failed to encode scanned keys"))
+    }
+
+    fn actor_index_drain(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let entries = self
+            .api
+            .actor_index_drain(object_handle, collection_index, limit)?;
+
+        self.allocate_buffer(scrypto_encode(&entries).expect("Failed to encode drained entries"))
+    }
+
+    fn actor_sorted_index_insert(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        sorted_key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        let sorted_key =
+            scrypto_decode::<SortedKey>(&sorted_key).map_err(WasmRuntimeError::InvalidSortedKey)?;
+
+        self.api
+            .actor_sorted_index_insert(object_handle, collection_index, sorted_key, value)?;
+
+        Ok(())
+    }
+
+    fn actor_sorted_index_remove(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        sorted_key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let sorted_key =
+            scrypto_decode::<SortedKey>(&sorted_key).map_err(WasmRuntimeError::InvalidSortedKey)?;
+
+        let rtn =
+            self.api
+                .actor_sorted_index_remove(object_handle, collection_index, &sorted_key)?;
+
+        self.allocate_buffer(scrypto_encode(&rtn).expect("Failed to encode removed entry"))
+    }
+
+    fn actor_sorted_index_scan(
+        &mut self,
+        object_handle: u32,
+        collection_index: u8,
+        range: Vec<u8>,
+        limit: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let range = scrypto_decode::<SortedKeyRange>(&range)
+            .map_err(WasmRuntimeError::InvalidSortedKeyRange)?;
+
+        let entries = self.api.actor_sorted_index_scan_range(
+            object_handle,
+            collection_index,
+            range,
+            limit,
+        )?;
+
+        self.allocate_buffer(scrypto_encode(&entries).expect("Failed to encode scanned entries"))
+    }
+
     fn actor_get_node_id(
         &mut self,
         actor_ref_handle: ActorRefHandle,
@@ -504,6 +608,85 @@ where
         self.allocate_buffer(ruid.to_vec())
     }
 
+    fn crypto_utils_keccak256_hash(
+        &mut self,
+        data: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let hash = self.api.keccak256_hash(data)?;
+
+        self.allocate_buffer(hash.to_vec())
+    }
+
+    fn crypto_utils_blake2b_256_hash(
+        &mut self,
+        data: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let hash = self.api.blake2b_256_hash(data)?;
+
+        self.allocate_buffer(hash.to_vec())
+    }
+
+    fn crypto_utils_ed25519_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let public_key = Ed25519PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidEd25519PublicKey)?;
+        let signature = Ed25519Signature::try_from(signature.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidEd25519Signature)?;
+
+        Ok(self.api.ed25519_verify(message, public_key, signature)? as u32)
+    }
+
+    fn crypto_utils_secp256k1_verify(
+        &mut self,
+        hash: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let hash = Hash::try_from(hash.as_slice()).map_err(|_| WasmRuntimeError::InvalidHash)?;
+        let public_key = Secp256k1PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidSecp256k1PublicKey)?;
+        let signature = Secp256k1Signature::try_from(signature.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidSecp256k1Signature)?;
+
+        Ok(self.api.secp256k1_verify(hash, public_key, signature)? as u32)
+    }
+
+    fn crypto_utils_bls12381_v1_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let public_key = Bls12381G1PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidBls12381PublicKey)?;
+        let signature = Bls12381G2Signature::try_from(signature.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidBls12381Signature)?;
+
+        Ok(self
+            .api
+            .bls12381_v1_verify(message, public_key, signature)? as u32)
+    }
+
+    fn crypto_utils_bls12381_v1_aggregate_verify(
+        &mut self,
+        pub_keys_and_msgs: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let pub_keys_and_msgs =
+            scrypto_decode::<Vec<(Bls12381G1PublicKey, Vec<u8>)>>(&pub_keys_and_msgs)
+                .map_err(WasmRuntimeError::InvalidBls12381PublicKeysAndMessages)?;
+        let signature = Bls12381G2Signature::try_from(signature.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidBls12381Signature)?;
+
+        Ok(self
+            .api
+            .bls12381_v1_aggregate_verify(pub_keys_and_msgs, signature)? as u32)
+    }
+
     fn costing_get_execution_cost_unit_limit(
         &mut self,
     ) -> Result<u32, InvokeError<WasmRuntimeError>> {
@@ -225,6 +225,13 @@ impl CustomSchema for NoCustomSchema {
         unreachable!("No custom type kinds exist")
     }
 
+    fn is_custom_type_validation_compatible(
+        _: &Self::CustomTypeValidation,
+        _: &Self::CustomTypeValidation,
+    ) -> bool {
+        unreachable!("No custom type validation")
+    }
+
     fn empty_schema() -> &'static Schema<Self> {
         &EMPTY_SCHEMA
     }
@@ -327,6 +334,25 @@ mod serde_serialization {
             unreachable!("No custom values exist")
         }
     }
+
+    impl DeserializableCustomExtension for NoCustomExtension {
+        type CustomValue = NoCustomValue;
+        type CustomDeserializationContext<'a> = ();
+
+        fn parse_custom_value(
+            _: &Self::CustomDeserializationContext<'_>,
+            _: Self::CustomValueKind,
+            _: &str,
+        ) -> Result<Self::CustomValue, String> {
+            unreachable!("No custom values exist")
+        }
+
+        fn untagged_custom_value_kind(
+            _: &<Self::CustomSchema as CustomSchema>::CustomTypeKind<LocalTypeId>,
+        ) -> Option<Self::CustomValueKind> {
+            unreachable!("No custom type kinds exist")
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,1072 @@
+use super::*;
+use crate::rust::prelude::*;
+use crate::*;
+use serde::de::{self, Deserialize, Deserializer, Error as _, MapAccess, SeqAccess, Visitor};
+
+pub enum DeserializationParameters<'s, 'a, E: DeserializableCustomExtension> {
+    Schemaless {
+        mode: SerializationMode,
+        custom_context: E::CustomDeserializationContext<'a>,
+        depth_limit: usize,
+    },
+    WithSchema {
+        mode: SerializationMode,
+        custom_context: E::CustomDeserializationContext<'a>,
+        schema: &'s Schema<E::CustomSchema>,
+        type_id: LocalTypeId,
+        depth_limit: usize,
+    },
+}
+
+impl<'s, 'a, E: DeserializableCustomExtension> DeserializationParameters<'s, 'a, E> {
+    pub fn get_context_params(&self) -> (DeserializationContext<'s, 'a, E>, LocalTypeId, usize) {
+        match self {
+            DeserializationParameters::Schemaless {
+                mode,
+                custom_context,
+                depth_limit,
+            } => (
+                DeserializationContext {
+                    schema: E::CustomSchema::empty_schema(),
+                    mode: *mode,
+                    custom_context: *custom_context,
+                },
+                LocalTypeId::any(),
+                *depth_limit,
+            ),
+            DeserializationParameters::WithSchema {
+                mode,
+                custom_context,
+                schema,
+                type_id,
+                depth_limit,
+            } => (
+                DeserializationContext {
+                    schema: *schema,
+                    mode: *mode,
+                    custom_context: *custom_context,
+                },
+                *type_id,
+                *depth_limit,
+            ),
+        }
+    }
+}
+
+pub struct DeserializationContext<'s, 'a, E: DeserializableCustomExtension> {
+    pub schema: &'s Schema<E::CustomSchema>,
+    pub mode: SerializationMode,
+    pub custom_context: E::CustomDeserializationContext<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeserializationError {
+    /// The `Model` mode is deprecated, and not invertible (children of arrays and maps don't
+    /// carry their value kinds).
+    UnsupportedMode(SerializationMode),
+    UnexpectedJson {
+        path: String,
+        expected: &'static str,
+    },
+    MissingField {
+        path: String,
+        field_name: String,
+    },
+    UnknownValueKind {
+        path: String,
+        value_kind: String,
+    },
+    MismatchingValueKind {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+    MismatchingFieldCount {
+        path: String,
+        expected: usize,
+        actual: usize,
+    },
+    InvalidValue {
+        path: String,
+        message: String,
+    },
+    UnknownTypeId {
+        path: String,
+        type_id: LocalTypeId,
+    },
+    UnknownEnumVariant {
+        path: String,
+        variant_id: u8,
+    },
+    /// The `Natural` mode output doesn't capture the value kind, and the schema doesn't define it
+    /// either (eg because the type is `Any`).
+    TypeInformationRequired {
+        path: String,
+    },
+    DepthLimitExceeded {
+        path: String,
+        depth_limit: usize,
+    },
+    EncodeError(EncodeError),
+}
+
+impl fmt::Display for DeserializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Deserializes a value from its serde representation (eg JSON), as output by the serialization
+/// in the given mode - ie this is the inverse of the `ContextualSerialize` of a payload.
+///
+/// * In the `Programmatic` mode, each value is wrapped in an object with its "kind", so no schema
+///   is needed. Any annotations (type names, field names, variant names) are ignored.
+/// * In the `Natural` mode, the value kinds have to be resolved from the schema, so this requires
+///   the `WithSchema` parameters. Custom values which were serialized with a "kind" tag are also
+///   accepted where the schema doesn't define them.
+pub fn deserialize_value<'de, D: Deserializer<'de>, E: DeserializableCustomExtension>(
+    deserializer: D,
+    parameters: &DeserializationParameters<'_, '_, E>,
+) -> Result<Value<E::CustomValueKind, E::CustomValue>, D::Error> {
+    let serde_value = SerdeValue::deserialize(deserializer)?;
+    let (context, type_id, depth_limit) = parameters.get_context_params();
+    let parser = ValueParser {
+        context: &context,
+        depth_limit,
+    };
+    let value = match context.mode {
+        SerializationMode::Programmatic => parser.parse_programmatic(&serde_value, "$", 1),
+        SerializationMode::Natural => parser.parse_natural(&serde_value, type_id, "$", 1),
+        SerializationMode::Model => Err(DeserializationError::UnsupportedMode(context.mode)),
+    };
+    value.map_err(D::Error::custom)
+}
+
+/// Deserializes a value (see [`deserialize_value`]) and encodes it into a full payload (ie with the
+/// extension's payload prefix).
+pub fn deserialize_payload<'de, D: Deserializer<'de>, E: DeserializableCustomExtension>(
+    deserializer: D,
+    parameters: &DeserializationParameters<'_, '_, E>,
+) -> Result<Vec<u8>, D::Error> {
+    let value = deserialize_value(deserializer, parameters)?;
+    let (_, _, depth_limit) = parameters.get_context_params();
+    let mut buf = Vec::with_capacity(512);
+    VecEncoder::<E::CustomValueKind>::new(&mut buf, depth_limit)
+        .encode_payload(&value, E::PAYLOAD_PREFIX)
+        .map_err(|error| D::Error::custom(DeserializationError::EncodeError(error)))?;
+    Ok(buf)
+}
+
+/// A self-contained tree of the serde data model, as used by the serialization.
+/// It is needed because the fields of the objects may arrive in any order (eg the "kind" field
+/// after the value content).
+enum SerdeValue {
+    Null,
+    Bool(bool),
+    /// Numbers are kept in their textual form, to be parsed into the expected integer type later.
+    Number(String),
+    String(String),
+    Array(Vec<SerdeValue>),
+    Object(Vec<(String, SerdeValue)>),
+}
+
+impl<'de> Deserialize<'de> for SerdeValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SerdeValueVisitor)
+    }
+}
+
+struct SerdeValueVisitor;
+
+impl<'de> Visitor<'de> for SerdeValueVisitor {
+    type Value = SerdeValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a serialized SBOR value")
+    }
+
+    fn visit_bool<Er: de::Error>(self, value: bool) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::Bool(value))
+    }
+
+    fn visit_i64<Er: de::Error>(self, value: i64) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::Number(value.to_string()))
+    }
+
+    fn visit_i128<Er: de::Error>(self, value: i128) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::Number(value.to_string()))
+    }
+
+    fn visit_u64<Er: de::Error>(self, value: u64) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::Number(value.to_string()))
+    }
+
+    fn visit_u128<Er: de::Error>(self, value: u128) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::Number(value.to_string()))
+    }
+
+    fn visit_f64<Er: de::Error>(self, value: f64) -> Result<Self::Value, Er> {
+        // Not a valid integer - but the error is better reported with the value's path
+        Ok(SerdeValue::Number(value.to_string()))
+    }
+
+    fn visit_str<Er: de::Error>(self, value: &str) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::String(value.to_string()))
+    }
+
+    fn visit_string<Er: de::Error>(self, value: String) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::String(value))
+    }
+
+    fn visit_none<Er: de::Error>(self) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        SerdeValue::deserialize(deserializer)
+    }
+
+    fn visit_unit<Er: de::Error>(self) -> Result<Self::Value, Er> {
+        Ok(SerdeValue::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elements = Vec::new();
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(SerdeValue::Array(elements))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = Vec::new();
+        while let Some(field) = map.next_entry::<String, SerdeValue>()? {
+            fields.push(field);
+        }
+        Ok(SerdeValue::Object(fields))
+    }
+}
+
+type ParsedValue<E> = Value<
+    <E as CustomExtension>::CustomValueKind,
+    <E as DeserializableCustomExtension>::CustomValue,
+>;
+type ParsedValueKind<E> = ValueKind<<E as CustomExtension>::CustomValueKind>;
+
+struct ValueParser<'c, 's, 'a, E: DeserializableCustomExtension> {
+    context: &'c DeserializationContext<'s, 'a, E>,
+    depth_limit: usize,
+}
+
+impl<'c, 's, 'a, E: DeserializableCustomExtension> ValueParser<'c, 's, 'a, E> {
+    fn parse_programmatic(
+        &self,
+        json: &SerdeValue,
+        path: &str,
+        depth: usize,
+    ) -> Result<ParsedValue<E>, DeserializationError> {
+        self.check_depth(path, depth)?;
+        let kind = expect_str(get_field(json, path, "kind")?, &child_path(path, "kind"))?;
+        if kind == "Bytes" {
+            return Ok(Value::Array {
+                element_value_kind: ValueKind::U8,
+                elements: parse_hex(json, path)?,
+            });
+        }
+        match self.parse_value_kind(kind, path)? {
+            ValueKind::Tuple => Ok(Value::Tuple {
+                fields: self.parse_programmatic_fields(json, path, depth)?,
+            }),
+            ValueKind::Enum => Ok(Value::Enum {
+                discriminator: parse_integer(
+                    get_field(json, path, "variant_id")?,
+                    &child_path(path, "variant_id"),
+                )?,
+                fields: self.parse_programmatic_fields(json, path, depth)?,
+            }),
+            ValueKind::Array => {
+                let element_value_kind = self.parse_child_value_kind(json, path, "element_kind")?;
+                if element_value_kind == ValueKind::U8 && get_field(json, path, "hex").is_ok() {
+                    return Ok(Value::Array {
+                        element_value_kind,
+                        elements: parse_hex(json, path)?,
+                    });
+                }
+                let elements_path = child_path(path, "elements");
+                let elements = expect_array(get_field(json, path, "elements")?, &elements_path)?
+                    .iter()
+                    .enumerate()
+                    .map(|(index, element)| {
+                        let element_path = index_path(&elements_path, index);
+                        let element = self.parse_programmatic(element, &element_path, depth + 1)?;
+                        check_value_kind(&element, element_value_kind, &element_path)?;
+                        Ok(element)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(Value::Array {
+                    element_value_kind,
+                    elements,
+                })
+            }
+            ValueKind::Map => {
+                let key_value_kind = self.parse_child_value_kind(json, path, "key_kind")?;
+                let value_value_kind = self.parse_child_value_kind(json, path, "value_kind")?;
+                let entries_path = child_path(path, "entries");
+                let entries = expect_array(get_field(json, path, "entries")?, &entries_path)?
+                    .iter()
+                    .enumerate()
+                    .map(|(index, entry)| {
+                        let entry_path = index_path(&entries_path, index);
+                        let key_path = child_path(&entry_path, "key");
+                        let key = self.parse_programmatic(
+                            get_field(entry, &entry_path, "key")?,
+                            &key_path,
+                            depth + 1,
+                        )?;
+                        check_value_kind(&key, key_value_kind, &key_path)?;
+                        let value_path = child_path(&entry_path, "value");
+                        let value = self.parse_programmatic(
+                            get_field(entry, &entry_path, "value")?,
+                            &value_path,
+                            depth + 1,
+                        )?;
+                        check_value_kind(&value, value_value_kind, &value_path)?;
+                        Ok((key, value))
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(Value::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                })
+            }
+            value_kind => self.parse_terminal(
+                value_kind,
+                get_field(json, path, "value")?,
+                &child_path(path, "value"),
+            ),
+        }
+    }
+
+    fn parse_programmatic_fields(
+        &self,
+        json: &SerdeValue,
+        path: &str,
+        depth: usize,
+    ) -> Result<Vec<ParsedValue<E>>, DeserializationError> {
+        let fields_path = child_path(path, "fields");
+        expect_array(get_field(json, path, "fields")?, &fields_path)?
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                self.parse_programmatic(field, &index_path(&fields_path, index), depth + 1)
+            })
+            .collect()
+    }
+
+    fn parse_child_value_kind(
+        &self,
+        json: &SerdeValue,
+        path: &str,
+        field_name: &str,
+    ) -> Result<ParsedValueKind<E>, DeserializationError> {
+        let field_path = child_path(path, field_name);
+        let kind = expect_str(get_field(json, path, field_name)?, &field_path)?;
+        self.parse_value_kind(kind, &field_path)
+    }
+
+    fn parse_natural(
+        &self,
+        json: &SerdeValue,
+        type_id: LocalTypeId,
+        path: &str,
+        depth: usize,
+    ) -> Result<ParsedValue<E>, DeserializationError> {
+        self.check_depth(path, depth)?;
+        let schema = self.context.schema;
+        let type_kind = schema.resolve_type_kind(type_id).ok_or_else(|| {
+            DeserializationError::UnknownTypeId {
+                path: path.to_string(),
+                type_id,
+            }
+        })?;
+        // Tuples and maps can be output as JSON objects themselves - so we only look for the
+        // tagged custom values elsewhere
+        if !matches!(type_kind, TypeKind::Tuple { .. } | TypeKind::Map { .. }) {
+            if let Some(value) = self.parse_tagged_custom_value(json, path)? {
+                return Ok(value);
+            }
+        }
+        match type_kind {
+            TypeKind::Any => Err(DeserializationError::TypeInformationRequired {
+                path: path.to_string(),
+            }),
+            TypeKind::Bool => self.parse_terminal(ValueKind::Bool, json, path),
+            TypeKind::I8 => self.parse_terminal(ValueKind::I8, json, path),
+            TypeKind::I16 => self.parse_terminal(ValueKind::I16, json, path),
+            TypeKind::I32 => self.parse_terminal(ValueKind::I32, json, path),
+            TypeKind::I64 => self.parse_terminal(ValueKind::I64, json, path),
+            TypeKind::I128 => self.parse_terminal(ValueKind::I128, json, path),
+            TypeKind::U8 => self.parse_terminal(ValueKind::U8, json, path),
+            TypeKind::U16 => self.parse_terminal(ValueKind::U16, json, path),
+            TypeKind::U32 => self.parse_terminal(ValueKind::U32, json, path),
+            TypeKind::U64 => self.parse_terminal(ValueKind::U64, json, path),
+            TypeKind::U128 => self.parse_terminal(ValueKind::U128, json, path),
+            TypeKind::String => self.parse_terminal(ValueKind::String, json, path),
+            TypeKind::Array { element_type } => {
+                if let Some(TypeKind::U8) = schema.resolve_type_kind(*element_type) {
+                    return Ok(Value::Array {
+                        element_value_kind: ValueKind::U8,
+                        elements: parse_hex(json, path)?,
+                    });
+                }
+                let elements = expect_array(json, path)?
+                    .iter()
+                    .enumerate()
+                    .map(|(index, element)| {
+                        self.parse_natural(
+                            element,
+                            *element_type,
+                            &index_path(path, index),
+                            depth + 1,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let element_value_kind =
+                    self.resolve_natural_value_kind(elements.first(), *element_type, path)?;
+                for (index, element) in elements.iter().enumerate() {
+                    check_value_kind(element, element_value_kind, &index_path(path, index))?;
+                }
+                Ok(Value::Array {
+                    element_value_kind,
+                    elements,
+                })
+            }
+            TypeKind::Tuple { field_types } => {
+                let tuple_metadata =
+                    schema.resolve_matching_tuple_metadata(type_id, field_types.len());
+                Ok(Value::Tuple {
+                    fields: self.parse_natural_fields(
+                        json,
+                        field_types,
+                        tuple_metadata.field_names,
+                        path,
+                        depth,
+                    )?,
+                })
+            }
+            TypeKind::Enum { variants } => {
+                let discriminator = parse_integer(
+                    get_field(json, path, "variant_id")?,
+                    &child_path(path, "variant_id"),
+                )?;
+                let field_types = variants.get(&discriminator).ok_or_else(|| {
+                    DeserializationError::UnknownEnumVariant {
+                        path: path.to_string(),
+                        variant_id: discriminator,
+                    }
+                })?;
+                let enum_metadata = schema.resolve_matching_enum_metadata(
+                    type_id,
+                    discriminator,
+                    field_types.len(),
+                );
+                Ok(Value::Enum {
+                    discriminator,
+                    fields: self.parse_natural_fields(
+                        get_field(json, path, "fields")?,
+                        field_types,
+                        enum_metadata.field_names,
+                        &child_path(path, "fields"),
+                        depth,
+                    )?,
+                })
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let entries = if self.natural_value_kind(*key_type) == Some(ValueKind::String) {
+                    expect_object(json, path)?
+                        .iter()
+                        .map(|(key, value)| {
+                            let value = self.parse_natural(
+                                value,
+                                *value_type,
+                                &child_path(path, key),
+                                depth + 1,
+                            )?;
+                            Ok((Value::String { value: key.clone() }, value))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?
+                } else {
+                    expect_array(json, path)?
+                        .iter()
+                        .enumerate()
+                        .map(|(index, entry)| {
+                            let entry_path = index_path(path, index);
+                            let key = self.parse_natural(
+                                get_field(entry, &entry_path, "key")?,
+                                *key_type,
+                                &child_path(&entry_path, "key"),
+                                depth + 1,
+                            )?;
+                            let value = self.parse_natural(
+                                get_field(entry, &entry_path, "value")?,
+                                *value_type,
+                                &child_path(&entry_path, "value"),
+                                depth + 1,
+                            )?;
+                            Ok((key, value))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?
+                };
+                let key_value_kind = self.resolve_natural_value_kind(
+                    entries.first().map(|(key, _)| key),
+                    *key_type,
+                    path,
+                )?;
+                let value_value_kind = self.resolve_natural_value_kind(
+                    entries.first().map(|(_, value)| value),
+                    *value_type,
+                    path,
+                )?;
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    let entry_path = index_path(path, index);
+                    check_value_kind(key, key_value_kind, &child_path(&entry_path, "key"))?;
+                    check_value_kind(value, value_value_kind, &child_path(&entry_path, "value"))?;
+                }
+                Ok(Value::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                })
+            }
+            TypeKind::Custom(custom_type_kind) => {
+                match E::untagged_custom_value_kind(custom_type_kind) {
+                    Some(custom_value_kind) => {
+                        self.parse_terminal(ValueKind::Custom(custom_value_kind), json, path)
+                    }
+                    None => Err(DeserializationError::TypeInformationRequired {
+                        path: path.to_string(),
+                    }),
+                }
+            }
+        }
+    }
+
+    /// Parses the fields of a tuple or an enum variant - which are output as a JSON object if
+    /// their names are known, or as a JSON array otherwise.
+    fn parse_natural_fields(
+        &self,
+        json: &SerdeValue,
+        field_types: &[LocalTypeId],
+        field_names: Option<&[Cow<'static, str>]>,
+        path: &str,
+        depth: usize,
+    ) -> Result<Vec<ParsedValue<E>>, DeserializationError> {
+        match field_names {
+            Some(field_names) => field_types
+                .iter()
+                .zip(field_names.iter())
+                .map(|(field_type, field_name)| {
+                    self.parse_natural(
+                        get_field(json, path, field_name)?,
+                        *field_type,
+                        &child_path(path, field_name),
+                        depth + 1,
+                    )
+                })
+                .collect(),
+            None => {
+                let fields = expect_array(json, path)?;
+                if fields.len() != field_types.len() {
+                    return Err(DeserializationError::MismatchingFieldCount {
+                        path: path.to_string(),
+                        expected: field_types.len(),
+                        actual: fields.len(),
+                    });
+                }
+                fields
+                    .iter()
+                    .zip(field_types.iter())
+                    .enumerate()
+                    .map(|(index, (field, field_type))| {
+                        self.parse_natural(field, *field_type, &index_path(path, index), depth + 1)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Custom values may opt into a "kind" tag in the `Natural` mode, in which case they are
+    /// output as a `{ "kind": ..., "value": ... }` object.
+    fn parse_tagged_custom_value(
+        &self,
+        json: &SerdeValue,
+        path: &str,
+    ) -> Result<Option<ParsedValue<E>>, DeserializationError> {
+        let (Ok(fields), Ok(SerdeValue::String(kind)), Ok(value)) = (
+            expect_object(json, path),
+            get_field(json, path, "kind"),
+            get_field(json, path, "value"),
+        ) else {
+            return Ok(None);
+        };
+        if fields.len() != 2 {
+            return Ok(None);
+        }
+        match self.parse_value_kind(kind, path) {
+            Ok(value_kind @ ValueKind::Custom(_)) => self
+                .parse_terminal(value_kind, value, &child_path(path, "value"))
+                .map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Resolves the value kind of a collection's children - from the first parsed child if there
+    /// is one (which may be more specific than the schema), or from the schema otherwise.
+    fn resolve_natural_value_kind(
+        &self,
+        first_child: Option<&ParsedValue<E>>,
+        type_id: LocalTypeId,
+        path: &str,
+    ) -> Result<ParsedValueKind<E>, DeserializationError> {
+        first_child
+            .map(|child| child.get_value_kind())
+            .or_else(|| self.natural_value_kind(type_id))
+            .ok_or_else(|| DeserializationError::TypeInformationRequired {
+                path: path.to_string(),
+            })
+    }
+
+    fn natural_value_kind(&self, type_id: LocalTypeId) -> Option<ParsedValueKind<E>> {
+        match self.context.schema.resolve_type_kind(type_id)? {
+            TypeKind::Any => None,
+            TypeKind::Bool => Some(ValueKind::Bool),
+            TypeKind::I8 => Some(ValueKind::I8),
+            TypeKind::I16 => Some(ValueKind::I16),
+            TypeKind::I32 => Some(ValueKind::I32),
+            TypeKind::I64 => Some(ValueKind::I64),
+            TypeKind::I128 => Some(ValueKind::I128),
+            TypeKind::U8 => Some(ValueKind::U8),
+            TypeKind::U16 => Some(ValueKind::U16),
+            TypeKind::U32 => Some(ValueKind::U32),
+            TypeKind::U64 => Some(ValueKind::U64),
+            TypeKind::U128 => Some(ValueKind::U128),
+            TypeKind::String => Some(ValueKind::String),
+            TypeKind::Array { .. } => Some(ValueKind::Array),
+            TypeKind::Tuple { .. } => Some(ValueKind::Tuple),
+            TypeKind::Enum { .. } => Some(ValueKind::Enum),
+            TypeKind::Map { .. } => Some(ValueKind::Map),
+            TypeKind::Custom(custom_type_kind) => {
+                E::untagged_custom_value_kind(custom_type_kind).map(ValueKind::Custom)
+            }
+        }
+    }
+
+    fn parse_terminal(
+        &self,
+        value_kind: ParsedValueKind<E>,
+        json: &SerdeValue,
+        path: &str,
+    ) -> Result<ParsedValue<E>, DeserializationError> {
+        let value = match value_kind {
+            ValueKind::Bool => match json {
+                SerdeValue::Bool(value) => Value::Bool { value: *value },
+                _ => return Err(unexpected_json(path, "a boolean")),
+            },
+            ValueKind::I8 => Value::I8 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::I16 => Value::I16 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::I32 => Value::I32 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::I64 => Value::I64 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::I128 => Value::I128 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::U8 => Value::U8 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::U16 => Value::U16 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::U32 => Value::U32 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::U64 => Value::U64 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::U128 => Value::U128 {
+                value: parse_integer(json, path)?,
+            },
+            ValueKind::String => Value::String {
+                value: expect_str(json, path)?.to_string(),
+            },
+            ValueKind::Custom(custom_value_kind) => Value::Custom {
+                value: E::parse_custom_value(
+                    &self.context.custom_context,
+                    custom_value_kind,
+                    expect_str(json, path)?,
+                )
+                .map_err(|message| DeserializationError::InvalidValue {
+                    path: path.to_string(),
+                    message,
+                })?,
+            },
+            ValueKind::Tuple | ValueKind::Enum | ValueKind::Array | ValueKind::Map => {
+                unreachable!("Composite value kinds are handled by the callers")
+            }
+        };
+        Ok(value)
+    }
+
+    fn parse_value_kind(
+        &self,
+        name: &str,
+        path: &str,
+    ) -> Result<ParsedValueKind<E>, DeserializationError> {
+        (0..=u8::MAX)
+            .filter_map(ValueKind::from_u8)
+            .find(|value_kind| value_kind.to_string() == name)
+            .ok_or_else(|| DeserializationError::UnknownValueKind {
+                path: path.to_string(),
+                value_kind: name.to_string(),
+            })
+    }
+
+    fn check_depth(&self, path: &str, depth: usize) -> Result<(), DeserializationError> {
+        if depth > self.depth_limit {
+            return Err(DeserializationError::DepthLimitExceeded {
+                path: path.to_string(),
+                depth_limit: self.depth_limit,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn check_value_kind<X: CustomValueKind, Y: CustomValue<X>>(
+    value: &Value<X, Y>,
+    expected: ValueKind<X>,
+    path: &str,
+) -> Result<(), DeserializationError> {
+    let actual = value.get_value_kind();
+    if actual != expected {
+        return Err(DeserializationError::MismatchingValueKind {
+            path: path.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn parse_hex<X: CustomValueKind, Y: CustomValue<X>>(
+    json: &SerdeValue,
+    path: &str,
+) -> Result<Vec<Value<X, Y>>, DeserializationError> {
+    let hex_path = child_path(path, "hex");
+    let bytes =
+        hex::decode(expect_str(get_field(json, path, "hex")?, &hex_path)?).map_err(|error| {
+            DeserializationError::InvalidValue {
+                path: hex_path,
+                message: format!("{:?}", error),
+            }
+        })?;
+    Ok(bytes.into_iter().map(|value| Value::U8 { value }).collect())
+}
+
+fn parse_integer<T: FromStr>(json: &SerdeValue, path: &str) -> Result<T, DeserializationError>
+where
+    T::Err: fmt::Debug,
+{
+    // Integers are output as JSON strings in the Programmatic mode, and as JSON numbers (if small
+    // enough) in the Natural mode - either is accepted
+    let text = match json {
+        SerdeValue::Number(text) | SerdeValue::String(text) => text,
+        _ => return Err(unexpected_json(path, "an integer")),
+    };
+    text.parse()
+        .map_err(|error| DeserializationError::InvalidValue {
+            path: path.to_string(),
+            message: format!("{:?}", error),
+        })
+}
+
+fn get_field<'j>(
+    json: &'j SerdeValue,
+    path: &str,
+    field_name: &str,
+) -> Result<&'j SerdeValue, DeserializationError> {
+    expect_object(json, path)?
+        .iter()
+        .find(|(key, _)| key == field_name)
+        .map(|(_, value)| value)
+        .ok_or_else(|| DeserializationError::MissingField {
+            path: path.to_string(),
+            field_name: field_name.to_string(),
+        })
+}
+
+fn expect_object<'j>(
+    json: &'j SerdeValue,
+    path: &str,
+) -> Result<&'j [(String, SerdeValue)], DeserializationError> {
+    match json {
+        SerdeValue::Object(fields) => Ok(fields),
+        _ => Err(unexpected_json(path, "an object")),
+    }
+}
+
+fn expect_array<'j>(
+    json: &'j SerdeValue,
+    path: &str,
+) -> Result<&'j [SerdeValue], DeserializationError> {
+    match json {
+        SerdeValue::Array(elements) => Ok(elements),
+        _ => Err(unexpected_json(path, "an array")),
+    }
+}
+
+fn expect_str<'j>(json: &'j SerdeValue, path: &str) -> Result<&'j str, DeserializationError> {
+    match json {
+        SerdeValue::String(value) => Ok(value),
+        _ => Err(unexpected_json(path, "a string")),
+    }
+}
+
+fn unexpected_json(path: &str, expected: &'static str) -> DeserializationError {
+    DeserializationError::UnexpectedJson {
+        path: path.to_string(),
+        expected,
+    }
+}
+
+fn child_path(path: &str, field_name: &str) -> String {
+    format!("{}.{}", path, field_name)
+}
+
+fn index_path(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")] // Ensures that VS Code runs this module with the features serde tag!
+mod tests {
+    use super::*;
+    use serde_json::{json, to_value, Value as JsonValue};
+    use utils::ContextualSerialize;
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    enum TestEnum {
+        UnitVariant,
+        SingleFieldVariant { field: u8 },
+        UnnamedVariant(i128, Vec<u8>),
+    }
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    struct TestStruct {
+        numbers: Vec<u64>,
+        bytes: Vec<u8>,
+        names: BTreeMap<String, TestEnum>,
+        enums: IndexMap<TestEnum, (i8, bool)>,
+        nested: Vec<Vec<u32>>,
+    }
+
+    fn test_struct() -> TestStruct {
+        TestStruct {
+            numbers: vec![1, u64::MAX],
+            bytes: vec![0x3a, 0x92],
+            names: btreemap! {
+                "first".to_string() => TestEnum::UnitVariant,
+                "second".to_string() => TestEnum::SingleFieldVariant { field: 5 },
+            },
+            enums: indexmap! {
+                TestEnum::UnnamedVariant(-3, vec![7]) => (-1, true),
+                TestEnum::UnitVariant => (2, false),
+            },
+            nested: vec![vec![], vec![1, 2]],
+        }
+    }
+
+    fn assert_round_trip(mode: SerializationMode) {
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<TestStruct, NoCustomSchema>();
+        let payload = basic_encode(&test_struct()).unwrap();
+        let json = to_value(
+            BasicRawPayload::new_from_valid_slice_with_checks(&payload)
+                .unwrap()
+                .serializable(SerializationParameters::WithSchema {
+                    mode,
+                    custom_context: (),
+                    schema: schema.v1(),
+                    type_id,
+                    depth_limit: 64,
+                }),
+        )
+        .unwrap();
+
+        let deserialized = deserialize_payload(
+            json,
+            &DeserializationParameters::<NoCustomExtension>::WithSchema {
+                mode,
+                custom_context: (),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: 64,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(deserialized, payload);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn programmatic_json_round_trips() {
+        assert_round_trip(SerializationMode::Programmatic);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn natural_json_round_trips_with_schema() {
+        assert_round_trip(SerializationMode::Natural);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn programmatic_json_deserializes_without_schema() {
+        let json = json!({
+            "kind": "Tuple",
+            "type_name": "Ignored",
+            "fields": [
+                { "kind": "U64", "field_name": "ignored", "value": "18446744073709551615" },
+                { "kind": "Bytes", "element_kind": "U8", "hex": "3a92" },
+                { "kind": "Enum", "variant_id": "1", "fields": [{ "kind": "I8", "value": "-5" }] },
+                {
+                    "kind": "Map",
+                    "key_kind": "String",
+                    "value_kind": "Array",
+                    "entries": [{
+                        "key": { "kind": "String", "value": "hello" },
+                        "value": { "kind": "Array", "element_kind": "Bool", "elements": [] }
+                    }]
+                }
+            ]
+        });
+
+        let value = deserialize_value(
+            json,
+            &DeserializationParameters::<NoCustomExtension>::Schemaless {
+                mode: SerializationMode::Programmatic,
+                custom_context: (),
+                depth_limit: 64,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            value,
+            BasicValue::Tuple {
+                fields: vec![
+                    Value::U64 { value: u64::MAX },
+                    Value::Array {
+                        element_value_kind: ValueKind::U8,
+                        elements: vec![Value::U8 { value: 0x3a }, Value::U8 { value: 0x92 }],
+                    },
+                    Value::Enum {
+                        discriminator: 1,
+                        fields: vec![Value::I8 { value: -5 }],
+                    },
+                    Value::Map {
+                        key_value_kind: ValueKind::String,
+                        value_value_kind: ValueKind::Array,
+                        entries: vec![(
+                            Value::String {
+                                value: "hello".to_string()
+                            },
+                            Value::Array {
+                                element_value_kind: ValueKind::Bool,
+                                elements: vec![],
+                            }
+                        )],
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn invalid_json_is_reported_with_its_path() {
+        let parameters = DeserializationParameters::<NoCustomExtension>::Schemaless {
+            mode: SerializationMode::Programmatic,
+            custom_context: (),
+            depth_limit: 64,
+        };
+        let deserialize = |json: JsonValue| {
+            deserialize_value(json, &parameters)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            deserialize(json!({ "kind": "U9", "value": "1" })),
+            DeserializationError::UnknownValueKind {
+                path: "$".to_string(),
+                value_kind: "U9".to_string(),
+            }
+            .to_string()
+        );
+        assert_eq!(
+            deserialize(json!({
+                "kind": "Array",
+                "element_kind": "U16",
+                "elements": [{ "kind": "U16", "value": "1" }, { "kind": "U32", "value": "1" }]
+            })),
+            DeserializationError::MismatchingValueKind {
+                path: "$.elements[1]".to_string(),
+                expected: "U16".to_string(),
+                actual: "U32".to_string(),
+            }
+            .to_string()
+        );
+        assert_eq!(
+            deserialize(json!({ "kind": "Tuple", "fields": [{ "kind": "U8", "value": "256" }] })),
+            DeserializationError::InvalidValue {
+                path: "$.fields[0].value".to_string(),
+                message: format!("{:?}", "256".parse::<u8>().unwrap_err()),
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn natural_json_requires_type_information() {
+        let error = deserialize_value(
+            json!(5),
+            &DeserializationParameters::<NoCustomExtension>::Schemaless {
+                mode: SerializationMode::Natural,
+                custom_context: (),
+                depth_limit: 64,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            DeserializationError::TypeInformationRequired {
+                path: "$".to_string()
+            }
+            .to_string()
+        );
+    }
+}
@@ -17,3 +17,23 @@ pub trait SerializableCustomExtension: FormattableCustomExtension + Copy {
         value: <Self::CustomTraversal as CustomTraversal>::CustomTerminalValueRef<'de>,
     ) -> CustomTypeSerialization<'a, 't, 'de, 's1, 's2, Self>;
 }
+
+pub trait DeserializableCustomExtension: CustomExtension {
+    type CustomValue: CustomValue<Self::CustomValueKind>
+        + for<'b> Encode<Self::CustomValueKind, VecEncoder<'b, Self::CustomValueKind>>;
+    type CustomDeserializationContext<'a>: Default + Copy;
+
+    /// Parses the string representation of a custom value of the given kind, as output by
+    /// [`SerializableCustomExtension::map_value_for_serialization`].
+    fn parse_custom_value(
+        context: &Self::CustomDeserializationContext<'_>,
+        custom_value_kind: Self::CustomValueKind,
+        content: &str,
+    ) -> Result<Self::CustomValue, String>;
+
+    /// The custom value kind to assume for a value of the given custom type kind, if it is not
+    /// wrapped in an object with a "kind" tag (which is only possible in `Natural` mode).
+    fn untagged_custom_value_kind(
+        custom_type_kind: &<Self::CustomSchema as CustomSchema>::CustomTypeKind<LocalTypeId>,
+    ) -> Option<Self::CustomValueKind>;
+}
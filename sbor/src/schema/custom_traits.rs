@@ -53,6 +53,15 @@ pub trait CustomSchema: Debug + Clone + Copy + PartialEq + Eq + 'static {
         type_metadata: &TypeMetadata,
     ) -> Result<(), SchemaValidationError>;
 
+    /// Used when comparing schemas for backwards compatibility.
+    ///
+    /// Returns true if every value permitted by the old custom type validation
+    /// is also permitted by the new custom type validation.
+    fn is_custom_type_validation_compatible(
+        old_validation: &Self::CustomTypeValidation,
+        new_validation: &Self::CustomTypeValidation,
+    ) -> bool;
+
     fn empty_schema() -> &'static Schema<Self>;
 }
 
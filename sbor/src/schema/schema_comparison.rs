@@ -0,0 +1,794 @@
+use crate::rust::prelude::*;
+use crate::*;
+
+/// Configures which differences between two schemas are reported by the schema comparison functions,
+/// such as [`compare_single_type_schemas`] and [`compare_named_type_schemas`].
+///
+/// The comparison always reports changes which would cause payloads valid under the old schema to
+/// fail to decode or validate under the new schema. These settings control whether additionally
+/// permitted "extensions" are also reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaComparisonSettings {
+    /// Whether enum variants may be added. Old payloads still decode under the new schema,
+    /// but payloads using the new variants can't be decoded under the old schema.
+    pub allow_new_enum_variants: bool,
+    /// Whether type validations may be loosened, including replacing a type with `Any`.
+    pub allow_weakened_validation: bool,
+    /// Whether type, field and variant names may change. Names don't affect the binary encoding,
+    /// but they do affect name-based representations such as the natural JSON representation.
+    pub allow_name_changes: bool,
+}
+
+impl SchemaComparisonSettings {
+    /// Reports any change, including names. Useful for checking that a type hasn't changed at all.
+    pub const fn require_equality() -> Self {
+        Self {
+            allow_new_enum_variants: false,
+            allow_weakened_validation: false,
+            allow_name_changes: false,
+        }
+    }
+
+    /// Permits backwards-compatible extensions: new enum variants and weakened validations.
+    pub const fn allow_extension() -> Self {
+        Self {
+            allow_new_enum_variants: true,
+            allow_weakened_validation: true,
+            allow_name_changes: false,
+        }
+    }
+
+    pub const fn with_name_changes_allowed(mut self) -> Self {
+        self.allow_name_changes = true;
+        self
+    }
+}
+
+impl Default for SchemaComparisonSettings {
+    fn default() -> Self {
+        Self::allow_extension()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaComparisonErrorKind {
+    NamedTypeRemoved,
+    TypeKindMismatch {
+        old_type_kind: String,
+        new_type_kind: String,
+    },
+    TupleFieldCountMismatch {
+        old_field_count: usize,
+        new_field_count: usize,
+    },
+    EnumVariantRemoved {
+        variant_id: u8,
+    },
+    EnumVariantAdded {
+        variant_id: u8,
+    },
+    EnumVariantDiscriminatorChanged {
+        variant_name: String,
+        old_variant_id: u8,
+        new_variant_id: u8,
+    },
+    EnumVariantFieldCountMismatch {
+        variant_id: u8,
+        old_field_count: usize,
+        new_field_count: usize,
+    },
+    TypeNameChanged {
+        old_name: Option<String>,
+        new_name: Option<String>,
+    },
+    FieldNamesChanged {
+        old_field_names: Option<Vec<String>>,
+        new_field_names: Option<Vec<String>>,
+    },
+    EnumVariantNameChanged {
+        variant_id: u8,
+        old_name: Option<String>,
+        new_name: Option<String>,
+    },
+    TypeValidationNarrowed,
+    TypeValidationWeakened,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaComparisonError {
+    /// A path to the type in the old schema, built from type, field and variant names where known.
+    /// For example: `MyStruct.values[*]::Some.0`
+    pub path: String,
+    pub kind: SchemaComparisonErrorKind,
+}
+
+impl fmt::Display for SchemaComparisonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {:?}", self.path, self.kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SchemaComparisonResult {
+    pub errors: Vec<SchemaComparisonError>,
+}
+
+impl SchemaComparisonResult {
+    pub fn is_compatible(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Panics with a list of all the reported changes if the schemas aren't compatible.
+    /// This is intended for use in tests which guard against accidental schema changes.
+    pub fn assert_compatible(&self) {
+        if self.is_compatible() {
+            return;
+        }
+        let mut message = String::from("Schemas are not compatible:");
+        for error in self.errors.iter() {
+            message.push_str("\n- ");
+            message.push_str(&error.to_string());
+        }
+        panic!("{}", message);
+    }
+}
+
+/// Compares the type `old_type_id` in `old_schema` with the type `new_type_id` in `new_schema`,
+/// and reports any changes which the `settings` don't permit.
+///
+/// Custom type kinds are compared for equality, and custom type validations are compared with
+/// [`CustomSchema::is_custom_type_validation_compatible`].
+pub fn compare_single_type_schemas<S: CustomSchema>(
+    settings: &SchemaComparisonSettings,
+    old_schema: &Schema<S>,
+    old_type_id: LocalTypeId,
+    new_schema: &Schema<S>,
+    new_type_id: LocalTypeId,
+) -> SchemaComparisonResult {
+    let mut comparer = SchemaComparer::new(settings, old_schema, new_schema);
+    let root_path = old_schema
+        .resolve_type_name_from_metadata(old_type_id)
+        .unwrap_or("<root>")
+        .to_string();
+    comparer.compare(root_path, old_type_id, new_type_id);
+    comparer.into_result()
+}
+
+/// Compares a set of named types between two schemas - for example, the types registered against
+/// a blueprint. Each type in `old_types` must be present in `new_types`, with a compatible type.
+/// New named types are always permitted.
+pub fn compare_named_type_schemas<S: CustomSchema>(
+    settings: &SchemaComparisonSettings,
+    old_schema: &Schema<S>,
+    old_types: &IndexMap<String, LocalTypeId>,
+    new_schema: &Schema<S>,
+    new_types: &IndexMap<String, LocalTypeId>,
+) -> SchemaComparisonResult {
+    let mut comparer = SchemaComparer::new(settings, old_schema, new_schema);
+    for (name, old_type_id) in old_types.iter() {
+        match new_types.get(name) {
+            Some(new_type_id) => comparer.compare(name.clone(), *old_type_id, *new_type_id),
+            None => comparer.report(name.clone(), SchemaComparisonErrorKind::NamedTypeRemoved),
+        }
+    }
+    comparer.into_result()
+}
+
+struct SchemaComparer<'s, S: CustomSchema> {
+    settings: &'s SchemaComparisonSettings,
+    old_schema: &'s Schema<S>,
+    new_schema: &'s Schema<S>,
+    visited: IndexSet<(LocalTypeId, LocalTypeId)>,
+    pending: Vec<(String, LocalTypeId, LocalTypeId)>,
+    errors: Vec<SchemaComparisonError>,
+}
+
+impl<'s, S: CustomSchema> SchemaComparer<'s, S> {
+    fn new(
+        settings: &'s SchemaComparisonSettings,
+        old_schema: &'s Schema<S>,
+        new_schema: &'s Schema<S>,
+    ) -> Self {
+        Self {
+            settings,
+            old_schema,
+            new_schema,
+            visited: index_set_new(),
+            pending: vec![],
+            errors: vec![],
+        }
+    }
+
+    fn into_result(self) -> SchemaComparisonResult {
+        SchemaComparisonResult {
+            errors: self.errors,
+        }
+    }
+
+    fn report(&mut self, path: String, kind: SchemaComparisonErrorKind) {
+        self.errors.push(SchemaComparisonError { path, kind });
+    }
+
+    fn compare(&mut self, path: String, old_type_id: LocalTypeId, new_type_id: LocalTypeId) {
+        self.pending.push((path, old_type_id, new_type_id));
+        // Types are compared breadth-first, and each pair of types is only compared once,
+        // which ensures that recursive types terminate
+        while !self.pending.is_empty() {
+            let (path, old_type_id, new_type_id) = self.pending.remove(0);
+            if !self.visited.insert((old_type_id, new_type_id)) {
+                continue;
+            }
+            self.compare_type(path, old_type_id, new_type_id);
+        }
+    }
+
+    fn compare_type(&mut self, path: String, old_type_id: LocalTypeId, new_type_id: LocalTypeId) {
+        // Well known types are identical across schemas
+        if let (LocalTypeId::WellKnown(old_id), LocalTypeId::WellKnown(new_id)) =
+            (old_type_id, new_type_id)
+        {
+            if old_id == new_id {
+                return;
+            }
+        }
+
+        let (Some(old_type_kind), Some(new_type_kind)) = (
+            self.old_schema.resolve_type_kind(old_type_id),
+            self.new_schema.resolve_type_kind(new_type_id),
+        ) else {
+            // The schemas are assumed to have been validated, so this can only happen if the
+            // caller passed in an invalid type id - we report this as a mismatch
+            self.report_type_kind_mismatch(path, old_type_id, new_type_id);
+            return;
+        };
+        let old_metadata = self.old_schema.resolve_type_metadata(old_type_id);
+        let new_metadata = self.new_schema.resolve_type_metadata(new_type_id);
+
+        if !self.settings.allow_name_changes {
+            let old_name = old_metadata.and_then(|m| m.get_name());
+            let new_name = new_metadata.and_then(|m| m.get_name());
+            if old_name != new_name {
+                self.report(
+                    path.clone(),
+                    SchemaComparisonErrorKind::TypeNameChanged {
+                        old_name: old_name.map(|n| n.to_string()),
+                        new_name: new_name.map(|n| n.to_string()),
+                    },
+                );
+            }
+        }
+
+        match (old_type_kind, new_type_kind) {
+            (TypeKind::Any, TypeKind::Any)
+            | (TypeKind::Bool, TypeKind::Bool)
+            | (TypeKind::I8, TypeKind::I8)
+            | (TypeKind::I16, TypeKind::I16)
+            | (TypeKind::I32, TypeKind::I32)
+            | (TypeKind::I64, TypeKind::I64)
+            | (TypeKind::I128, TypeKind::I128)
+            | (TypeKind::U8, TypeKind::U8)
+            | (TypeKind::U16, TypeKind::U16)
+            | (TypeKind::U32, TypeKind::U32)
+            | (TypeKind::U64, TypeKind::U64)
+            | (TypeKind::U128, TypeKind::U128)
+            | (TypeKind::String, TypeKind::String) => {}
+            (_, TypeKind::Any) => {
+                // Any accepts all values, so this only weakens the schema
+                if !self.settings.allow_weakened_validation {
+                    self.report_type_kind_mismatch(path, old_type_id, new_type_id);
+                }
+                return;
+            }
+            (
+                TypeKind::Array {
+                    element_type: old_element_type,
+                },
+                TypeKind::Array {
+                    element_type: new_element_type,
+                },
+            ) => {
+                self.pending
+                    .push((format!("{}[*]", path), *old_element_type, *new_element_type));
+            }
+            (
+                TypeKind::Tuple {
+                    field_types: old_field_types,
+                },
+                TypeKind::Tuple {
+                    field_types: new_field_types,
+                },
+            ) => {
+                if old_field_types.len() != new_field_types.len() {
+                    self.report(
+                        path,
+                        SchemaComparisonErrorKind::TupleFieldCountMismatch {
+                            old_field_count: old_field_types.len(),
+                            new_field_count: new_field_types.len(),
+                        },
+                    );
+                    return;
+                }
+                let old_field_names = old_metadata.and_then(|m| m.get_field_names());
+                let new_field_names = new_metadata.and_then(|m| m.get_field_names());
+                self.compare_field_names(&path, old_field_names, new_field_names);
+                self.compare_fields(&path, old_field_names, old_field_types, new_field_types);
+            }
+            (
+                TypeKind::Enum {
+                    variants: old_variants,
+                },
+                TypeKind::Enum {
+                    variants: new_variants,
+                },
+            ) => {
+                self.compare_enum_variants(
+                    &path,
+                    old_metadata,
+                    old_variants,
+                    new_metadata,
+                    new_variants,
+                );
+            }
+            (
+                TypeKind::Map {
+                    key_type: old_key_type,
+                    value_type: old_value_type,
+                },
+                TypeKind::Map {
+                    key_type: new_key_type,
+                    value_type: new_value_type,
+                },
+            ) => {
+                self.pending
+                    .push((format!("{}{{key}}", path), *old_key_type, *new_key_type));
+                self.pending.push((
+                    format!("{}{{value}}", path),
+                    *old_value_type,
+                    *new_value_type,
+                ));
+            }
+            (TypeKind::Custom(old_custom_type_kind), TypeKind::Custom(new_custom_type_kind))
+                if old_custom_type_kind == new_custom_type_kind => {}
+            _ => {
+                self.report_type_kind_mismatch(path, old_type_id, new_type_id);
+                return;
+            }
+        }
+
+        if let (Some(old_validation), Some(new_validation)) = (
+            self.old_schema.resolve_type_validation(old_type_id),
+            self.new_schema.resolve_type_validation(new_type_id),
+        ) {
+            self.compare_validations(path, old_validation, new_validation);
+        }
+    }
+
+    fn compare_fields(
+        &mut self,
+        path: &str,
+        old_field_names: Option<&[Cow<'static, str>]>,
+        old_field_types: &[LocalTypeId],
+        new_field_types: &[LocalTypeId],
+    ) {
+        for (index, (old_field_type, new_field_type)) in old_field_types
+            .iter()
+            .zip(new_field_types.iter())
+            .enumerate()
+        {
+            let field_path = match old_field_names.and_then(|names| names.get(index)) {
+                Some(field_name) => format!("{}.{}", path, field_name),
+                None => format!("{}.{}", path, index),
+            };
+            self.pending
+                .push((field_path, *old_field_type, *new_field_type));
+        }
+    }
+
+    fn compare_field_names(
+        &mut self,
+        path: &str,
+        old_field_names: Option<&[Cow<'static, str>]>,
+        new_field_names: Option<&[Cow<'static, str>]>,
+    ) {
+        if self.settings.allow_name_changes || old_field_names == new_field_names {
+            return;
+        }
+        let to_strings =
+            |names: &[Cow<'static, str>]| names.iter().map(|name| name.to_string()).collect();
+        self.report(
+            path.to_string(),
+            SchemaComparisonErrorKind::FieldNamesChanged {
+                old_field_names: old_field_names.map(to_strings),
+                new_field_names: new_field_names.map(to_strings),
+            },
+        );
+    }
+
+    fn compare_enum_variants(
+        &mut self,
+        path: &str,
+        old_metadata: Option<&TypeMetadata>,
+        old_variants: &IndexMap<u8, Vec<LocalTypeId>>,
+        new_metadata: Option<&TypeMetadata>,
+        new_variants: &IndexMap<u8, Vec<LocalTypeId>>,
+    ) {
+        let old_variant_metadata = old_metadata.and_then(|m| match &m.child_names {
+            Some(ChildNames::EnumVariants(variants)) => Some(variants),
+            _ => None,
+        });
+        let new_variant_metadata = new_metadata.and_then(|m| match &m.child_names {
+            Some(ChildNames::EnumVariants(variants)) => Some(variants),
+            _ => None,
+        });
+        let old_variant_name = |variant_id: &u8| {
+            old_variant_metadata
+                .and_then(|variants| variants.get(variant_id))
+                .and_then(|m| m.get_name())
+        };
+        let new_variant_name = |variant_id: &u8| {
+            new_variant_metadata
+                .and_then(|variants| variants.get(variant_id))
+                .and_then(|m| m.get_name())
+        };
+
+        for (variant_id, old_field_types) in old_variants.iter() {
+            let old_name = old_variant_name(variant_id);
+            let variant_path = match old_name {
+                Some(variant_name) => format!("{}::{}", path, variant_name),
+                None => format!("{}::{}", path, variant_id),
+            };
+            let Some(new_field_types) = new_variants.get(variant_id) else {
+                // If the variant still exists under its name, it has been moved to a new discriminator
+                let moved_to = old_name.and_then(|old_name| {
+                    new_variants
+                        .keys()
+                        .find(|new_variant_id| new_variant_name(new_variant_id) == Some(old_name))
+                });
+                let kind = match moved_to {
+                    Some(new_variant_id) => {
+                        SchemaComparisonErrorKind::EnumVariantDiscriminatorChanged {
+                            variant_name: old_name.unwrap_or_default().to_string(),
+                            old_variant_id: *variant_id,
+                            new_variant_id: *new_variant_id,
+                        }
+                    }
+                    None => SchemaComparisonErrorKind::EnumVariantRemoved {
+                        variant_id: *variant_id,
+                    },
+                };
+                self.report(path.to_string(), kind);
+                continue;
+            };
+
+            let new_name = new_variant_name(variant_id);
+            if !self.settings.allow_name_changes && old_name != new_name {
+                self.report(
+                    path.to_string(),
+                    SchemaComparisonErrorKind::EnumVariantNameChanged {
+                        variant_id: *variant_id,
+                        old_name: old_name.map(|n| n.to_string()),
+                        new_name: new_name.map(|n| n.to_string()),
+                    },
+                );
+            }
+
+            if old_field_types.len() != new_field_types.len() {
+                self.report(
+                    path.to_string(),
+                    SchemaComparisonErrorKind::EnumVariantFieldCountMismatch {
+                        variant_id: *variant_id,
+                        old_field_count: old_field_types.len(),
+                        new_field_count: new_field_types.len(),
+                    },
+                );
+                continue;
+            }
+
+            let old_field_names = old_variant_metadata
+                .and_then(|variants| variants.get(variant_id))
+                .and_then(|m| m.get_field_names());
+            let new_field_names = new_variant_metadata
+                .and_then(|variants| variants.get(variant_id))
+                .and_then(|m| m.get_field_names());
+            self.compare_field_names(&variant_path, old_field_names, new_field_names);
+            self.compare_fields(
+                &variant_path,
+                old_field_names,
+                old_field_types,
+                new_field_types,
+            );
+        }
+
+        if !self.settings.allow_new_enum_variants {
+            for variant_id in new_variants.keys() {
+                if !old_variants.contains_key(variant_id) {
+                    self.report(
+                        path.to_string(),
+                        SchemaComparisonErrorKind::EnumVariantAdded {
+                            variant_id: *variant_id,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn compare_validations(
+        &mut self,
+        path: String,
+        old_validation: &TypeValidation<S::CustomTypeValidation>,
+        new_validation: &TypeValidation<S::CustomTypeValidation>,
+    ) {
+        if old_validation == new_validation {
+            return;
+        }
+        if !is_validation_compatible::<S>(old_validation, new_validation) {
+            self.report(path, SchemaComparisonErrorKind::TypeValidationNarrowed);
+        } else if !self.settings.allow_weakened_validation
+            // Validations such as `None` and a `NumericValidation` without bounds are equivalent
+            && !is_validation_compatible::<S>(new_validation, old_validation)
+        {
+            self.report(path, SchemaComparisonErrorKind::TypeValidationWeakened);
+        }
+    }
+
+    fn report_type_kind_mismatch(
+        &mut self,
+        path: String,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+    ) {
+        self.report(
+            path,
+            SchemaComparisonErrorKind::TypeKindMismatch {
+                old_type_kind: describe_type_kind(self.old_schema.resolve_type_kind(old_type_id)),
+                new_type_kind: describe_type_kind(self.new_schema.resolve_type_kind(new_type_id)),
+            },
+        );
+    }
+}
+
+fn describe_type_kind<C: Debug, L>(type_kind: Option<&TypeKind<C, L>>) -> String {
+    let Some(type_kind) = type_kind else {
+        return "<unknown>".to_string();
+    };
+    match type_kind {
+        TypeKind::Any => "Any".to_string(),
+        TypeKind::Bool => "Bool".to_string(),
+        TypeKind::I8 => "I8".to_string(),
+        TypeKind::I16 => "I16".to_string(),
+        TypeKind::I32 => "I32".to_string(),
+        TypeKind::I64 => "I64".to_string(),
+        TypeKind::I128 => "I128".to_string(),
+        TypeKind::U8 => "U8".to_string(),
+        TypeKind::U16 => "U16".to_string(),
+        TypeKind::U32 => "U32".to_string(),
+        TypeKind::U64 => "U64".to_string(),
+        TypeKind::U128 => "U128".to_string(),
+        TypeKind::String => "String".to_string(),
+        TypeKind::Array { .. } => "Array".to_string(),
+        TypeKind::Tuple { .. } => "Tuple".to_string(),
+        TypeKind::Enum { .. } => "Enum".to_string(),
+        TypeKind::Map { .. } => "Map".to_string(),
+        TypeKind::Custom(custom_type_kind) => format!("{:?}", custom_type_kind),
+    }
+}
+
+/// Returns true if every value permitted by `old_validation` is also permitted by `new_validation`.
+fn is_validation_compatible<S: CustomSchema>(
+    old_validation: &TypeValidation<S::CustomTypeValidation>,
+    new_validation: &TypeValidation<S::CustomTypeValidation>,
+) -> bool {
+    match (old_validation, new_validation) {
+        (_, TypeValidation::None) => true,
+        (TypeValidation::None, new_validation) => is_unbounded(new_validation),
+        (TypeValidation::I8(old), TypeValidation::I8(new)) => new.contains(old),
+        (TypeValidation::I16(old), TypeValidation::I16(new)) => new.contains(old),
+        (TypeValidation::I32(old), TypeValidation::I32(new)) => new.contains(old),
+        (TypeValidation::I64(old), TypeValidation::I64(new)) => new.contains(old),
+        (TypeValidation::I128(old), TypeValidation::I128(new)) => new.contains(old),
+        (TypeValidation::U8(old), TypeValidation::U8(new)) => new.contains(old),
+        (TypeValidation::U16(old), TypeValidation::U16(new)) => new.contains(old),
+        (TypeValidation::U32(old), TypeValidation::U32(new)) => new.contains(old),
+        (TypeValidation::U64(old), TypeValidation::U64(new)) => new.contains(old),
+        (TypeValidation::U128(old), TypeValidation::U128(new)) => new.contains(old),
+        (TypeValidation::String(old), TypeValidation::String(new))
+        | (TypeValidation::Array(old), TypeValidation::Array(new))
+        | (TypeValidation::Map(old), TypeValidation::Map(new)) => new.contains(old),
+        (TypeValidation::Custom(old), TypeValidation::Custom(new)) => {
+            S::is_custom_type_validation_compatible(old, new)
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if the validation doesn't restrict the values permitted by the type kind.
+fn is_unbounded<E: CustomTypeValidation>(validation: &TypeValidation<E>) -> bool {
+    match validation {
+        TypeValidation::None => true,
+        TypeValidation::I8(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::I16(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::I32(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::I64(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::I128(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::U8(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::U16(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::U32(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::U64(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::U128(v) => v.min.is_none() && v.max.is_none(),
+        TypeValidation::String(v) | TypeValidation::Array(v) | TypeValidation::Map(v) => {
+            v.min.is_none() && v.max.is_none()
+        }
+        TypeValidation::Custom(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod v1 {
+        use crate::*;
+
+        #[derive(Sbor)]
+        pub struct MyStruct {
+            pub values: Vec<MyEnum>,
+            pub count: u32,
+        }
+
+        #[derive(Sbor)]
+        pub enum MyEnum {
+            A,
+            B(u32),
+            C { inner: Box<MyEnum> },
+        }
+    }
+
+    fn compare<Old: BasicDescribe, New: BasicDescribe>(
+        settings: SchemaComparisonSettings,
+    ) -> SchemaComparisonResult {
+        let (old_type_id, old_schema) =
+            generate_full_schema_from_single_type::<Old, NoCustomSchema>();
+        let (new_type_id, new_schema) =
+            generate_full_schema_from_single_type::<New, NoCustomSchema>();
+        compare_single_type_schemas(
+            &settings,
+            old_schema.v1(),
+            old_type_id,
+            new_schema.v1(),
+            new_type_id,
+        )
+    }
+
+    #[test]
+    fn identical_recursive_types_are_compatible() {
+        compare::<v1::MyStruct, v1::MyStruct>(SchemaComparisonSettings::require_equality())
+            .assert_compatible();
+    }
+
+    #[test]
+    fn new_enum_variants_are_only_permitted_as_an_extension() {
+        mod v2 {
+            use crate::*;
+
+            #[derive(Sbor)]
+            pub struct MyStruct {
+                pub values: Vec<MyEnum>,
+                pub count: u32,
+            }
+
+            #[derive(Sbor)]
+            pub enum MyEnum {
+                A,
+                B(u32),
+                C { inner: Box<MyEnum> },
+                D,
+            }
+        }
+
+        compare::<v1::MyStruct, v2::MyStruct>(SchemaComparisonSettings::allow_extension())
+            .assert_compatible();
+        let result =
+            compare::<v1::MyStruct, v2::MyStruct>(SchemaComparisonSettings::require_equality());
+        assert_eq!(
+            result.errors,
+            vec![SchemaComparisonError {
+                path: "MyStruct.values[*]".to_string(),
+                kind: SchemaComparisonErrorKind::EnumVariantAdded { variant_id: 3 },
+            }]
+        );
+    }
+
+    #[test]
+    fn incompatible_changes_are_reported_with_their_paths() {
+        mod v2 {
+            use crate::*;
+
+            #[derive(Sbor)]
+            pub struct MyStruct {
+                pub values: Vec<MyEnum>,
+                pub total: u32,
+            }
+
+            #[derive(Sbor)]
+            pub enum MyEnum {
+                #[sbor(discriminator(5))]
+                A,
+                #[sbor(discriminator(1))]
+                B(u64),
+                #[sbor(discriminator(2))]
+                C { inner: Box<MyEnum> },
+            }
+        }
+
+        let result =
+            compare::<v1::MyStruct, v2::MyStruct>(SchemaComparisonSettings::allow_extension());
+        assert_eq!(
+            result.errors,
+            vec![
+                SchemaComparisonError {
+                    path: "MyStruct".to_string(),
+                    kind: SchemaComparisonErrorKind::FieldNamesChanged {
+                        old_field_names: Some(vec!["values".to_string(), "count".to_string()]),
+                        new_field_names: Some(vec!["values".to_string(), "total".to_string()]),
+                    },
+                },
+                SchemaComparisonError {
+                    path: "MyStruct.values[*]".to_string(),
+                    kind: SchemaComparisonErrorKind::EnumVariantDiscriminatorChanged {
+                        variant_name: "A".to_string(),
+                        old_variant_id: 0,
+                        new_variant_id: 5,
+                    },
+                },
+                SchemaComparisonError {
+                    path: "MyStruct.values[*]::B.0".to_string(),
+                    kind: SchemaComparisonErrorKind::TypeKindMismatch {
+                        old_type_kind: "U32".to_string(),
+                        new_type_kind: "U64".to_string(),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn narrowed_validations_are_incompatible() {
+        let (type_id, schema) = generate_full_schema_from_single_type::<u8, NoCustomSchema>();
+        let narrowed_schema: BasicSchema = Schema {
+            type_kinds: vec![TypeKind::U8],
+            type_metadata: vec![TypeMetadata::unnamed()],
+            type_validations: vec![TypeValidation::U8(NumericValidation {
+                min: Some(1),
+                max: None,
+            })],
+        };
+        let narrowed_type_id = LocalTypeId::SchemaLocalIndex(0);
+        let settings = SchemaComparisonSettings::allow_extension();
+
+        let result = compare_single_type_schemas(
+            &settings,
+            schema.v1(),
+            type_id,
+            &narrowed_schema,
+            narrowed_type_id,
+        );
+        assert_eq!(
+            result.errors,
+            vec![SchemaComparisonError {
+                path: "<root>".to_string(),
+                kind: SchemaComparisonErrorKind::TypeValidationNarrowed,
+            }]
+        );
+
+        // The reverse direction only weakens the validation
+        compare_single_type_schemas(
+            &settings,
+            &narrowed_schema,
+            narrowed_type_id,
+            schema.v1(),
+            type_id,
+        )
+        .assert_compatible();
+    }
+}
@@ -44,6 +44,12 @@ impl LengthValidation {
     pub fn is_valid(&self, length: usize) -> bool {
         self.min.unwrap_or(0) as usize <= length && length <= self.max.unwrap_or(u32::MAX) as usize
     }
+
+    /// Returns true if every length permitted by `other` is also permitted by this validation.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.min.unwrap_or(0) <= other.min.unwrap_or(0)
+            && self.max.unwrap_or(u32::MAX) >= other.max.unwrap_or(u32::MAX)
+    }
 }
 
 /// Represents additional validation that should be performed on the numeric value.
@@ -62,6 +68,23 @@ impl<T> NumericValidation<T> {
     }
 }
 
+impl<T: Ord + Copy> NumericValidation<T> {
+    /// Returns true if every value permitted by `other` is also permitted by this validation.
+    pub fn contains(&self, other: &Self) -> bool {
+        let min_contained = match (self.min, other.min) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(min), Some(other_min)) => min <= other_min,
+        };
+        let max_contained = match (self.max, other.max) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(max), Some(other_max)) => max >= other_max,
+        };
+        min_contained && max_contained
+    }
+}
+
 impl NumericValidation<i8> {
     pub fn is_valid(&self, value: i8) -> bool {
         self.min.unwrap_or(i8::MIN) <= value && value <= self.max.unwrap_or(i8::MAX)
@@ -81,14 +81,14 @@ pub trait CustomValue<X: CustomValueKind> {
 }
 
 impl<X: CustomValueKind, Y: CustomValue<X>> Value<X, Y> {
-    fn get_value_kind(&self) -> ValueKind<X> {
+    pub fn get_value_kind(&self) -> ValueKind<X> {
         match self {
             Value::Bool { .. } => ValueKind::Bool,
             Value::I8 { .. } => ValueKind::I8,
             Value::I16 { .. } => ValueKind::I16,
             Value::I32 { .. } => ValueKind::I32,
             Value::I64 { .. } => ValueKind::I64,
-            Value::I128 { .. } => ValueKind::U128,
+            Value::I128 { .. } => ValueKind::I128,
             Value::U8 { .. } => ValueKind::U8,
             Value::U16 { .. } => ValueKind::U16,
             Value::U32 { .. } => ValueKind::U32,
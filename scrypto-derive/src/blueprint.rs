@@ -261,6 +261,7 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     // parse blueprint struct and impl
     let blueprint = parse2::<ast::Blueprint>(input)?;
     let mut bp = blueprint.module;
+    let collection_schemas = generate_collection_schemas(&mut bp.structure.fields)?;
     let bp_strut = &bp.structure;
     let bp_fields = &bp_strut.fields;
     let bp_semi_token = &bp_strut.semi_token;
@@ -809,9 +810,12 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                     let type_index = aggregator.add_child_type_and_descendents::<#bp_ident>();
                     fields.push(FieldSchema::static_field(type_index));
 
+                    // Aggregate collections
+                    let collections = vec![#(#collection_schemas),*];
+
                     let state = BlueprintStateSchemaInit {
                         fields,
-                        collections: Vec::new(),
+                        collections,
                     };
 
                     // Aggregate functions
@@ -1737,6 +1741,93 @@ fn generate_test_bindings_fns(bp_name: &str, items: &[ImplItem]) -> Result<Vec<T
     Ok(functions)
 }
 
+/// Assigns collection indices to the `Index<K, V>` and `SortedIndex<K, V>` fields of the blueprint
+/// state struct, in order of declaration, and returns the schemas of the respective collections.
+///
+/// Before:
+/// ```rust,no_run
+/// struct Exchange {
+///     orders: SortedIndex<OrderId, Order>,
+///     traders: Index<ComponentAddress, Trader>,
+/// }
+/// ```
+///
+/// After:
+/// ```rust,no_run
+/// struct Exchange {
+///     orders: SortedIndex<OrderId, Order, 0>,
+///     traders: Index<ComponentAddress, Trader, 1>,
+/// }
+/// ```
+fn generate_collection_schemas(fields: &mut Fields) -> Result<Vec<TokenStream>> {
+    let mut collection_schemas = Vec::new();
+
+    for field in fields.iter_mut() {
+        let field_span = field.span();
+        let segment = match &mut field.ty {
+            Type::Path(TypePath { qself: None, path }) if is_scrypto_collection_path(path) => {
+                match path.segments.last_mut() {
+                    Some(segment) => segment,
+                    None => continue,
+                }
+            }
+            _ => continue,
+        };
+        let collection_ident = segment.ident.clone();
+
+        let args = match &mut segment.arguments {
+            PathArguments::AngleBracketed(args) => args,
+            _ => continue,
+        };
+        let (key_type, value_type) = match (args.args.len(), args.args.first(), args.args.last()) {
+            (2, Some(GenericArgument::Type(key_type)), Some(GenericArgument::Type(value_type))) => {
+                (key_type.clone(), value_type.clone())
+            }
+            _ => {
+                return Err(Error::new(
+                    args.span(),
+                    format!(
+                        "Expected `{}<K, V>`, the collection index is assigned automatically",
+                        collection_ident
+                    ),
+                ))
+            }
+        };
+
+        let collection_index = u8::try_from(collection_schemas.len())
+            .map_err(|_| Error::new(field_span, "Too many collections"))?;
+        let collection_index = proc_macro2::Literal::u8_unsuffixed(collection_index);
+        args.args.push(parse_quote! { #collection_index });
+
+        collection_schemas.push(quote! {
+            BlueprintCollectionSchema::#collection_ident(BlueprintKeyValueSchema {
+                key: TypeRef::Static(aggregator.add_child_type_and_descendents::<#key_type>()),
+                value: TypeRef::Static(aggregator.add_child_type_and_descendents::<#value_type>()),
+                allow_ownership: true,
+            })
+        });
+    }
+
+    Ok(collection_schemas)
+}
+
+/// Whether the path refers to Scrypto's `Index` or `SortedIndex`, either as imported by the prelude
+/// or in full through `scrypto::prelude`. User types that happen to share the name are left alone.
+fn is_scrypto_collection_path(path: &Path) -> bool {
+    let idents: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    match idents.split_last() {
+        Some((name, prefix)) => {
+            (name == "Index" || name == "SortedIndex")
+                && (prefix.is_empty() || *prefix == ["scrypto", "prelude"])
+        }
+        None => false,
+    }
+}
+
 fn generate_test_bindings_state(bp_struct: &ItemStruct) -> ItemStruct {
     let mut bp_struct = bp_struct.clone();
     bp_struct.ident = format_ident!("{}State", bp_struct.ident);
@@ -1860,6 +1951,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_only_scrypto_collections_are_assigned_collection_indices() {
+        let mut item: ItemStruct = parse_quote! {
+            struct Test {
+                a: Index<u8, u8>,
+                b: my_crate::Index<u8, u8>,
+                c: scrypto::prelude::SortedIndex<u8, u8>,
+            }
+        };
+
+        let collection_schemas = generate_collection_schemas(&mut item.fields).unwrap();
+
+        assert_eq!(collection_schemas.len(), 2);
+        let field_types: Vec<String> = item
+            .fields
+            .iter()
+            .map(|field| field.ty.to_token_stream().to_string())
+            .collect();
+        assert_eq!(
+            field_types,
+            vec![
+                quote! { Index<u8, u8, 0> }.to_string(),
+                quote! { my_crate::Index<u8, u8> }.to_string(),
+                quote! { scrypto::prelude::SortedIndex<u8, u8, 1> }.to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn test_blueprint() {
         let input = TokenStream::from_str(
@@ -2017,9 +2136,11 @@ mod tests {
                             let type_index = aggregator.add_child_type_and_descendents::<Test>();
                             fields.push(FieldSchema::static_field(type_index));
 
+                            let collections = vec![];
+
                             let state = BlueprintStateSchemaInit {
                                 fields,
-                                collections: Vec::new(),
+                                collections,
                             };
 
                             let functions = {
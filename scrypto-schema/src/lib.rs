@@ -103,6 +103,79 @@ impl BlueprintSchemaInit {
             .chain(self.hooks.hooks.values().cloned())
             .collect()
     }
+
+    /// Checks that the types of this blueprint schema are compatible with those of a `previous`
+    /// version of the blueprint - ie that state, events and function payloads valid under the
+    /// previous schema are still valid under this one.
+    ///
+    /// State fields, collections, events, registered types and functions may be added,
+    /// but not removed.
+    pub fn compare_with_previous_version(
+        &self,
+        previous: &Self,
+        settings: &SchemaComparisonSettings,
+    ) -> SchemaComparisonResult {
+        let new_type_refs = self.named_type_refs();
+        let mut old_types = index_map_new();
+        let mut new_types = index_map_new();
+        let mut errors = vec![];
+        for (name, old_type_ref) in previous.named_type_refs() {
+            match (old_type_ref, new_type_refs.get(&name)) {
+                (TypeRef::Static(old_type_id), Some(TypeRef::Static(new_type_id))) => {
+                    old_types.insert(name.clone(), old_type_id);
+                    new_types.insert(name, *new_type_id);
+                }
+                (TypeRef::Generic(old_index), Some(TypeRef::Generic(new_index)))
+                    if old_index == *new_index => {}
+                (_, Some(new_type_ref)) => errors.push(SchemaComparisonError {
+                    path: name,
+                    kind: SchemaComparisonErrorKind::TypeKindMismatch {
+                        old_type_kind: format!("{:?}", old_type_ref),
+                        new_type_kind: format!("{:?}", new_type_ref),
+                    },
+                }),
+                (_, None) => errors.push(SchemaComparisonError {
+                    path: name,
+                    kind: SchemaComparisonErrorKind::NamedTypeRemoved,
+                }),
+            }
+        }
+
+        let result = compare_named_type_schemas(
+            settings,
+            previous.schema.v1(),
+            &old_types,
+            self.schema.v1(),
+            &new_types,
+        );
+        errors.extend(result.errors);
+        SchemaComparisonResult { errors }
+    }
+
+    fn named_type_refs(&self) -> IndexMap<String, TypeRef<LocalTypeId>> {
+        let mut type_refs = index_map_new();
+        for (index, field) in self.state.fields.iter().enumerate() {
+            type_refs.insert(format!("fields[{}]", index), field.field);
+        }
+        for (index, collection) in self.state.collections.iter().enumerate() {
+            let (BlueprintCollectionSchema::KeyValueStore(schema)
+            | BlueprintCollectionSchema::Index(schema)
+            | BlueprintCollectionSchema::SortedIndex(schema)) = collection;
+            type_refs.insert(format!("collections[{}].key", index), schema.key);
+            type_refs.insert(format!("collections[{}].value", index), schema.value);
+        }
+        for (name, event) in self.events.event_schema.iter() {
+            type_refs.insert(format!("events.{}", name), *event);
+        }
+        for (name, type_id) in self.types.type_schema.iter() {
+            type_refs.insert(format!("types.{}", name), TypeRef::Static(*type_id));
+        }
+        for (name, function) in self.functions.functions.iter() {
+            type_refs.insert(format!("functions.{}.input", name), function.input);
+            type_refs.insert(format!("functions.{}.output", name), function.output);
+        }
+        type_refs
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
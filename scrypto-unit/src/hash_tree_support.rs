@@ -3,8 +3,11 @@ use radix_engine_store_interface::interface::{
     CommittableSubstateDatabase, DatabaseUpdates, DbPartitionKey, DbSortKey, DbSubstateValue,
     ListableSubstateDatabase, PartitionEntry, SubstateDatabase,
 };
+use radix_engine_stores::hash_tree::proof::SubstateProof;
 use radix_engine_stores::hash_tree::tree_store::{TypedInMemoryTreeStore, Version};
-use radix_engine_stores::hash_tree::{list_substate_hashes_at_version, put_at_next_version};
+use radix_engine_stores::hash_tree::{
+    get_substate_proof_at_version, list_substate_hashes_at_version, put_at_next_version,
+};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct HashTreeUpdatingDatabase<D> {
@@ -32,6 +35,19 @@ impl<D> HashTreeUpdatingDatabase<D> {
         list_substate_hashes_at_version(&mut self.tree_store, self.current_version)
     }
 
+    pub fn get_substate_proof(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> SubstateProof {
+        get_substate_proof_at_version(
+            &self.tree_store,
+            self.current_version,
+            partition_key,
+            sort_key,
+        )
+    }
+
     fn update_with(&mut self, db_updates: &DatabaseUpdates) {
         self.current_hash = put_at_next_version(
             &mut self.tree_store,
@@ -57,6 +73,15 @@ impl<D: SubstateDatabase> SubstateDatabase for HashTreeUpdatingDatabase<D> {
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         self.underlying.list_entries(partition_key)
     }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.underlying
+            .list_entries_from(partition_key, from_sort_key)
+    }
 }
 
 impl<D: ListableSubstateDatabase> ListableSubstateDatabase for HashTreeUpdatingDatabase<D> {
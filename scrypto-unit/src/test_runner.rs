@@ -10,6 +10,7 @@ use radix_engine::blueprints::pool::one_resource_pool::ONE_RESOURCE_POOL_BLUEPRI
 use radix_engine::errors::*;
 use radix_engine::system::bootstrap::*;
 use radix_engine::system::checkers::*;
+use radix_engine::system::protocol_updates::*;
 use radix_engine::system::system_callback::SystemConfig;
 use radix_engine::system::system_db_reader::{
     ObjectCollectionKey, SystemDatabaseReader, SystemDatabaseWriter,
@@ -17,13 +18,14 @@ use radix_engine::system::system_db_reader::{
 use radix_engine::system::system_substates::FieldSubstate;
 use radix_engine::system::type_info::TypeInfoSubstate;
 use radix_engine::transaction::{
-    execute_preview, execute_transaction_with_system, BalanceChange, CommitResult,
-    CostingParameters, ExecutionConfig, PreviewError, TransactionReceipt, TransactionResult,
-    WrappedSystem,
+    execute_preview, execute_preview_bundle, execute_preview_with_assertions,
+    execute_preview_with_overrides, execute_transaction_with_system, BalanceChange, CommitResult,
+    CostingParameters, ExecutionConfig, PreviewAssertion, PreviewAssertionError, PreviewError,
+    PreviewOverrides, TransactionReceipt, TransactionResult, WrappedSystem,
 };
 use radix_engine::types::*;
 use radix_engine::utils::*;
-use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmDiskCache, WasmValidatorConfigV1};
 use radix_engine::vm::{NativeVm, NativeVmExtension, NoExtension, ScryptoVm, Vm};
 use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
 use radix_engine_interface::api::node_modules::auth::*;
@@ -52,8 +54,10 @@ use radix_engine_queries::typed_substate_layout::*;
 use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
 use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, MappedSubstateDatabase};
 use radix_engine_store_interface::interface::{
-    CommittableSubstateDatabase, DatabaseUpdate, ListableSubstateDatabase, SubstateDatabase,
+    CommittableSubstateDatabase, DatabaseUpdate, DbPartitionKey, DbSortKey,
+    ListableSubstateDatabase, SubstateDatabase,
 };
+use radix_engine_stores::hash_tree::proof::SubstateProof;
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
 use scrypto::prelude::*;
 use transaction::prelude::*;
@@ -305,6 +309,7 @@ pub struct TestRunnerBuilder<E, D> {
     custom_database: D,
     trace: bool,
     skip_receipt_check: bool,
+    wasm_disk_cache: Option<WasmDiskCache>,
 }
 
 impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
@@ -315,6 +320,7 @@ impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
             custom_database: InMemorySubstateDatabase::standard(),
             trace: true,
             skip_receipt_check: false,
+            wasm_disk_cache: None,
         }
     }
 }
@@ -332,6 +338,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: HashTreeUpdatingDatabase::new(self.custom_database),
             trace: self.trace,
             skip_receipt_check: false,
+            wasm_disk_cache: self.wasm_disk_cache,
         }
     }
 
@@ -345,6 +352,13 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         self
     }
 
+    /// Caches instrumented and compiled code on disk, so that test runners built later, including
+    /// by other processes, don't have to redo the work for the same packages.
+    pub fn with_wasm_disk_cache(mut self, disk_cache: WasmDiskCache) -> Self {
+        self.wasm_disk_cache = Some(disk_cache);
+        self
+    }
+
     pub fn with_custom_extension<NE: NativeVmExtension>(
         self,
         extension: NE,
@@ -355,6 +369,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: self.custom_database,
             trace: self.trace,
             skip_receipt_check: self.skip_receipt_check,
+            wasm_disk_cache: self.wasm_disk_cache,
         }
     }
 
@@ -365,6 +380,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: database,
             trace: self.trace,
             skip_receipt_check: self.skip_receipt_check,
+            wasm_disk_cache: self.wasm_disk_cache,
         }
     }
 
@@ -378,10 +394,14 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         let trace = false;
         //----------------------------------------------------------------
 
-        let scrypto_vm = ScryptoVm {
+        let mut scrypto_vm = ScryptoVm {
             wasm_engine: DefaultWasmEngine::default(),
             wasm_validator_config: WasmValidatorConfigV1::new(),
+            wasm_disk_cache: None,
         };
+        if let Some(disk_cache) = self.wasm_disk_cache {
+            scrypto_vm = scrypto_vm.with_disk_cache(disk_cache);
+        }
         let native_vm = NativeVm::new_with_extension(self.custom_extension);
         let vm = Vm::new(&scrypto_vm, native_vm.clone());
         let mut substate_db = self.custom_database;
@@ -1568,6 +1588,70 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         execute_preview(&self.database, vm, network, preview_intent, self.trace)
     }
 
+    pub fn preview_with_overrides(
+        &mut self,
+        preview_intent: PreviewIntentV1,
+        network: &NetworkDefinition,
+        overrides: &PreviewOverrides,
+    ) -> Result<TransactionReceipt, PreviewError> {
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        execute_preview_with_overrides(
+            &self.database,
+            vm,
+            network,
+            preview_intent,
+            overrides,
+            self.trace,
+        )
+    }
+
+    pub fn preview_with_assertions(
+        &mut self,
+        preview_intent: PreviewIntentV1,
+        network: &NetworkDefinition,
+        overrides: &PreviewOverrides,
+        assertions: &[PreviewAssertion],
+    ) -> Result<(TransactionReceipt, Vec<Result<(), PreviewAssertionError>>), PreviewError> {
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        execute_preview_with_assertions(
+            &self.database,
+            vm,
+            network,
+            preview_intent,
+            overrides,
+            assertions,
+            self.trace,
+        )
+    }
+
+    pub fn preview_bundle(
+        &mut self,
+        transactions: Vec<PreviewBundleTransaction>,
+        network: &NetworkDefinition,
+    ) -> Result<Vec<TransactionReceipt>, PreviewError> {
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        execute_preview_bundle(
+            &self.database,
+            vm,
+            network,
+            transactions,
+            &PreviewOverrides::default(),
+            self.trace,
+        )
+    }
+
     pub fn preview_manifest(
         &mut self,
         manifest: TransactionManifestV1,
@@ -2273,6 +2357,35 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         )
     }
 
+    /// Applies the given protocol update to the ledger, which is expected to be at the very
+    /// start of the epoch started by the given epoch change.
+    pub fn execute_protocol_update(
+        &mut self,
+        protocol_update: &ProtocolUpdate,
+        epoch_change: &EpochChangeEvent,
+    ) -> Result<ProtocolUpdateReceipt, ProtocolUpdateError> {
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+        let receipt = ProtocolUpdateExecutor::new(
+            NetworkDefinition::simulator(),
+            &mut self.database,
+            vm,
+            self.trace,
+        )
+        .execute_at_epoch_boundary(protocol_update, epoch_change)?;
+
+        for (_, action_receipt) in &receipt.action_receipts {
+            if let TransactionResult::Commit(commit) = &action_receipt.result {
+                self.collected_events
+                    .push(commit.application_events.clone());
+            }
+        }
+
+        Ok(receipt)
+    }
+
     /// Executes a "start round number `round` at timestamp `timestamp_ms`" system transaction, as
     /// if it was proposed by the first validator from the validator set, after `round - 1` missed
     /// rounds by that validator.
@@ -2464,6 +2577,14 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, HashTreeUpdatingDataba
         self.database.get_current_root_hash()
     }
 
+    pub fn get_substate_proof(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> SubstateProof {
+        self.database.get_substate_proof(partition_key, sort_key)
+    }
+
     pub fn assert_state_hash_tree_matches_substate_store(&mut self) {
         let hashes_from_tree = self.database.list_substate_hashes();
         assert_eq!(
@@ -0,0 +1,140 @@
+use crate::engine::scrypto_env::ScryptoVmV1Api;
+use radix_engine_interface::api::{CollectionIndex, ACTOR_STATE_SELF};
+use radix_engine_interface::data::scrypto::*;
+use sbor::rust::marker::PhantomData;
+use sbor::rust::prelude::*;
+use sbor::*;
+
+/// An unordered index collection which is stored as part of a component's state.
+///
+/// Unlike [`KeyValueStore`](super::KeyValueStore), an index is not a separate node and its
+/// entries can be iterated over, a bounded number of entries at a time.
+///
+/// An index is declared as a field of the blueprint state struct, and the collection it refers
+/// to is assigned by the `#[blueprint]` macro, based on the order of declaration.
+pub struct Index<
+    K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    const COLLECTION_INDEX: CollectionIndex = 0,
+> {
+    pub key: PhantomData<K>,
+    pub value: PhantomData<V>,
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+    > Index<K, V, COLLECTION_INDEX>
+{
+    /// Creates a handle to the index, to be used when instantiating a component.
+    pub fn new() -> Self {
+        Self {
+            key: PhantomData,
+            value: PhantomData,
+        }
+    }
+
+    /// Inserts a new key-value pair into this index, replacing any existing value.
+    pub fn insert(&self, key: K, value: V) {
+        ScryptoVmV1Api::actor_index_insert(
+            ACTOR_STATE_SELF,
+            COLLECTION_INDEX,
+            scrypto_encode(&key).unwrap(),
+            scrypto_encode(&value).unwrap(),
+        );
+    }
+
+    /// Removes an entry from this index and returns the original value if it exists.
+    pub fn remove(&self, key: &K) -> Option<V> {
+        ScryptoVmV1Api::actor_index_remove(
+            ACTOR_STATE_SELF,
+            COLLECTION_INDEX,
+            scrypto_encode(key).unwrap(),
+        )
+        .map(|value| scrypto_decode(&value).unwrap())
+    }
+
+    /// Returns up to `limit` keys of this index, in an unspecified order.
+    pub fn scan_keys(&self, limit: u32) -> Vec<K> {
+        ScryptoVmV1Api::actor_index_scan_keys(ACTOR_STATE_SELF, COLLECTION_INDEX, limit)
+            .into_iter()
+            .map(|key| scrypto_decode(&key).unwrap())
+            .collect()
+    }
+
+    /// Removes up to `limit` entries from this index, in an unspecified order, and returns them.
+    pub fn drain(&self, limit: u32) -> Vec<(K, V)> {
+        ScryptoVmV1Api::actor_index_drain(ACTOR_STATE_SELF, COLLECTION_INDEX, limit)
+            .into_iter()
+            .map(|(key, value)| {
+                (
+                    scrypto_decode(&key).unwrap(),
+                    scrypto_decode(&value).unwrap(),
+                )
+            })
+            .collect()
+    }
+}
+
+//========
+// binary
+//========
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+    > Categorize<ScryptoCustomValueKind> for Index<K, V, COLLECTION_INDEX>
+{
+    #[inline]
+    fn value_kind() -> ValueKind<ScryptoCustomValueKind> {
+        ValueKind::Tuple
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+        E: Encoder<ScryptoCustomValueKind>,
+    > Encode<ScryptoCustomValueKind, E> for Index<K, V, COLLECTION_INDEX>
+{
+    #[inline]
+    fn encode_value_kind(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_value_kind(Self::value_kind())
+    }
+
+    #[inline]
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        ().encode_body(encoder)
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+        D: Decoder<ScryptoCustomValueKind>,
+    > Decode<ScryptoCustomValueKind, D> for Index<K, V, COLLECTION_INDEX>
+{
+    fn decode_body_with_value_kind(
+        decoder: &mut D,
+        value_kind: ValueKind<ScryptoCustomValueKind>,
+    ) -> Result<Self, DecodeError> {
+        <()>::decode_body_with_value_kind(decoder, value_kind)?;
+        Ok(Self::new())
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+    > Describe<ScryptoCustomTypeKind> for Index<K, V, COLLECTION_INDEX>
+{
+    const TYPE_ID: RustTypeId = <() as Describe<ScryptoCustomTypeKind>>::TYPE_ID;
+
+    fn type_data() -> sbor::TypeData<ScryptoCustomTypeKind, RustTypeId> {
+        <() as Describe<ScryptoCustomTypeKind>>::type_data()
+    }
+}
@@ -1,13 +1,17 @@
 mod component;
+mod index;
 mod kv_store;
 mod kv_store_data_ref;
 mod object;
 mod package;
+mod sorted_index;
 mod stubs;
 
 pub use component::*;
+pub use index::*;
 pub use kv_store::*;
 pub use kv_store_data_ref::*;
 pub use object::*;
 pub use package::*;
+pub use sorted_index::*;
 pub use stubs::*;
@@ -0,0 +1,164 @@
+use crate::engine::scrypto_env::ScryptoVmV1Api;
+use radix_engine_interface::api::{CollectionIndex, ACTOR_STATE_SELF};
+use radix_engine_interface::data::scrypto::*;
+use radix_engine_interface::types::SortedKeyRange;
+use sbor::rust::marker::PhantomData;
+use sbor::rust::ops::{Bound, RangeBounds};
+use sbor::rust::prelude::*;
+use sbor::*;
+
+/// An index collection ordered by a `u16` sort prefix, which is stored as part of a
+/// component's state.
+///
+/// Entries are returned in ascending order of their sort prefix; entries sharing a sort prefix
+/// are returned in an unspecified (but deterministic) order.
+///
+/// A sorted index is declared as a field of the blueprint state struct, and the collection it
+/// refers to is assigned by the `#[blueprint]` macro, based on the order of declaration.
+pub struct SortedIndex<
+    K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    const COLLECTION_INDEX: CollectionIndex = 0,
+> {
+    pub key: PhantomData<K>,
+    pub value: PhantomData<V>,
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+    > SortedIndex<K, V, COLLECTION_INDEX>
+{
+    /// Creates a handle to the sorted index, to be used when instantiating a component.
+    pub fn new() -> Self {
+        Self {
+            key: PhantomData,
+            value: PhantomData,
+        }
+    }
+
+    /// Inserts a new key-value pair under the given sort prefix, replacing any existing value.
+    pub fn insert(&self, sort_prefix: u16, key: K, value: V) {
+        ScryptoVmV1Api::actor_sorted_index_insert(
+            ACTOR_STATE_SELF,
+            COLLECTION_INDEX,
+            (sort_prefix.to_be_bytes(), scrypto_encode(&key).unwrap()),
+            scrypto_encode(&value).unwrap(),
+        );
+    }
+
+    /// Removes an entry from this index and returns the original value if it exists.
+    pub fn remove(&self, sort_prefix: u16, key: &K) -> Option<V> {
+        ScryptoVmV1Api::actor_sorted_index_remove(
+            ACTOR_STATE_SELF,
+            COLLECTION_INDEX,
+            &(sort_prefix.to_be_bytes(), scrypto_encode(key).unwrap()),
+        )
+        .map(|value| scrypto_decode(&value).unwrap())
+    }
+
+    /// Returns up to `limit` entries with the lowest sort prefixes.
+    pub fn scan(&self, limit: u32) -> Vec<(u16, K, V)> {
+        self.range(.., limit)
+    }
+
+    /// Returns up to `limit` entries whose sort prefix falls within the given range, in
+    /// ascending order of sort prefix.
+    pub fn range<R: RangeBounds<u16>>(&self, range: R, limit: u32) -> Vec<(u16, K, V)> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => match start.checked_add(1) {
+                Some(start) => start,
+                None => return Vec::new(),
+            },
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.checked_add(1),
+            Bound::Excluded(end) => Some(*end),
+            Bound::Unbounded => None,
+        };
+        if end.map_or(false, |end| end <= start) {
+            return Vec::new();
+        }
+
+        ScryptoVmV1Api::actor_sorted_index_scan(
+            ACTOR_STATE_SELF,
+            COLLECTION_INDEX,
+            SortedKeyRange::new(start, end),
+            limit,
+        )
+        .into_iter()
+        .map(|((sort_prefix, key), value)| {
+            (
+                u16::from_be_bytes(sort_prefix),
+                scrypto_decode(&key).unwrap(),
+                scrypto_decode(&value).unwrap(),
+            )
+        })
+        .collect()
+    }
+}
+
+//========
+// binary
+//========
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+    > Categorize<ScryptoCustomValueKind> for SortedIndex<K, V, COLLECTION_INDEX>
+{
+    #[inline]
+    fn value_kind() -> ValueKind<ScryptoCustomValueKind> {
+        ValueKind::Tuple
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+        E: Encoder<ScryptoCustomValueKind>,
+    > Encode<ScryptoCustomValueKind, E> for SortedIndex<K, V, COLLECTION_INDEX>
+{
+    #[inline]
+    fn encode_value_kind(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_value_kind(Self::value_kind())
+    }
+
+    #[inline]
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        ().encode_body(encoder)
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+        D: Decoder<ScryptoCustomValueKind>,
+    > Decode<ScryptoCustomValueKind, D> for SortedIndex<K, V, COLLECTION_INDEX>
+{
+    fn decode_body_with_value_kind(
+        decoder: &mut D,
+        value_kind: ValueKind<ScryptoCustomValueKind>,
+    ) -> Result<Self, DecodeError> {
+        <()>::decode_body_with_value_kind(decoder, value_kind)?;
+        Ok(Self::new())
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        const COLLECTION_INDEX: CollectionIndex,
+    > Describe<ScryptoCustomTypeKind> for SortedIndex<K, V, COLLECTION_INDEX>
+{
+    const TYPE_ID: RustTypeId = <() as Describe<ScryptoCustomTypeKind>>::TYPE_ID;
+
+    fn type_data() -> sbor::TypeData<ScryptoCustomTypeKind, RustTypeId> {
+        <() as Describe<ScryptoCustomTypeKind>>::type_data()
+    }
+}
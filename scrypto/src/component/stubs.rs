@@ -1,6 +1,7 @@
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::locker::*;
 
 use crate::prelude::*;
 
@@ -217,6 +218,17 @@ extern_blueprint_internal! {
         );
         fn add_authorized_depositor(&mut self, badge: ResourceOrNonFungible);
         fn remove_authorized_depositor(&mut self, badge: ResourceOrNonFungible);
+        fn balance(&self, resource_address: ResourceAddress) -> Decimal;
+        fn non_fungible_local_ids(
+            &self,
+            resource_address: ResourceAddress,
+            limit: u32,
+        ) -> Vec<NonFungibleLocalId>;
+        fn has_non_fungible(
+            &self,
+            resource_address: ResourceAddress,
+            local_id: NonFungibleLocalId,
+        ) -> bool;
     }
 }
 
@@ -383,5 +395,70 @@ extern_blueprint_internal! {
         fn cancel_primary_role_badge_withdraw_attempt(&mut self);
         fn cancel_recovery_role_badge_withdraw_attempt(&mut self);
         fn mint_recovery_badges(&mut self, non_fungible_local_ids: Vec<NonFungibleLocalId>) -> Bucket;
+        fn lock_recovery_fee(&mut self, amount: Decimal);
+        fn withdraw_recovery_fee(&mut self, amount: Decimal) -> Bucket;
+        fn contribute_recovery_fee(&mut self, bucket: Bucket);
+    }
+}
+extern_blueprint_internal! {
+    PackageAddress::new_or_panic([
+        13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 111u8, 226u8, 217u8, 25u8, 140u8, 99u8, 24u8,
+        198u8, 49u8, 140u8, 247u8, 189u8, 79u8, 59u8, 245u8, 85u8, 87u8, 198u8, 49u8, 140u8, 99u8,
+        24u8, 198u8,
+    ]),
+    AccountLocker,
+    "AccountLocker",
+    "OwnedAccountLocker",
+    "GlobalAccountLocker",
+    AccountLockerFunctions {
+        fn instantiate(
+            owner_role: OwnerRole,
+            storer_role: AccessRule,
+            storer_updater_role: AccessRule,
+            recoverer_role: AccessRule,
+            recoverer_updater_role: AccessRule,
+            address_reservation: Option<GlobalAddressReservation>,
+        ) -> Global<AccountLocker>;
+        fn instantiate_simple(allow_recover: bool) -> (Global<AccountLocker>, Bucket);
+    },
+    {
+        fn store(&mut self, claimant: ComponentAddress, bucket: Bucket, try_direct_send: bool);
+        fn airdrop(
+            &mut self,
+            claimants: IndexMap<ComponentAddress, ResourceSpecifier>,
+            bucket: Bucket,
+            try_direct_send: bool,
+        ) -> Option<Bucket>;
+        fn recover(
+            &mut self,
+            claimant: ComponentAddress,
+            resource_address: ResourceAddress,
+            amount: Decimal,
+        ) -> Bucket;
+        fn recover_non_fungibles(
+            &mut self,
+            claimant: ComponentAddress,
+            resource_address: ResourceAddress,
+            ids: Vec<NonFungibleLocalId>,
+        ) -> Bucket;
+        fn claim(
+            &mut self,
+            claimant: ComponentAddress,
+            resource_address: ResourceAddress,
+            amount: Decimal,
+        ) -> Bucket;
+        fn claim_non_fungibles(
+            &mut self,
+            claimant: ComponentAddress,
+            resource_address: ResourceAddress,
+            ids: Vec<NonFungibleLocalId>,
+        ) -> Bucket;
+        fn get_amount(&self, claimant: ComponentAddress, resource_address: ResourceAddress) -> Decimal;
+        fn get_non_fungible_local_ids(
+            &self,
+            claimant: ComponentAddress,
+            resource_address: ResourceAddress,
+            limit: u32,
+        ) -> Vec<NonFungibleLocalId>;
     }
 }
@@ -0,0 +1,61 @@
+use radix_engine_interface::crypto::*;
+use sbor::rust::prelude::*;
+use scrypto::engine::scrypto_env::ScryptoVmV1Api;
+
+/// Cryptographic utilities, such as hashing and signature verification.
+///
+/// The computation happens natively in the engine and is metered, which makes it considerably
+/// cheaper than doing the same in WASM.
+#[derive(Debug)]
+pub struct CryptoUtils {}
+
+impl CryptoUtils {
+    /// Calculates the Keccak-256 digest of the data.
+    pub fn keccak256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoVmV1Api::crypto_utils_keccak256_hash(data.as_ref())
+    }
+
+    /// Calculates the Blake2b-256 digest of the data.
+    pub fn blake2b_256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoVmV1Api::crypto_utils_blake2b_256_hash(data.as_ref())
+    }
+
+    /// Verifies an Ed25519 signature over the message.
+    pub fn ed25519_verify<T: AsRef<[u8]>>(
+        message: T,
+        public_key: &Ed25519PublicKey,
+        signature: &Ed25519Signature,
+    ) -> bool {
+        ScryptoVmV1Api::crypto_utils_ed25519_verify(message.as_ref(), public_key, signature)
+    }
+
+    /// Verifies an ECDSA Secp256k1 signature over the hash.
+    pub fn secp256k1_verify(
+        hash: &Hash,
+        public_key: &Secp256k1PublicKey,
+        signature: &Secp256k1Signature,
+    ) -> bool {
+        ScryptoVmV1Api::crypto_utils_secp256k1_verify(hash, public_key, signature)
+    }
+
+    /// Verifies a BLS12-381 G2 signature over the message, using the
+    /// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` ciphersuite.
+    pub fn bls12381_v1_verify<T: AsRef<[u8]>>(
+        message: T,
+        public_key: &Bls12381G1PublicKey,
+        signature: &Bls12381G2Signature,
+    ) -> bool {
+        ScryptoVmV1Api::crypto_utils_bls12381_v1_verify(message.as_ref(), public_key, signature)
+    }
+
+    /// Verifies an aggregate BLS12-381 G2 signature, where each of the public keys has signed
+    /// the message it's paired with, using the same ciphersuite as [`Self::bls12381_v1_verify`].
+    ///
+    /// Returns `false` if the list is empty.
+    pub fn bls12381_v1_aggregate_verify(
+        pub_keys_and_msgs: &[(Bls12381G1PublicKey, Vec<u8>)],
+        signature: &Bls12381G2Signature,
+    ) -> bool {
+        ScryptoVmV1Api::crypto_utils_bls12381_v1_aggregate_verify(pub_keys_and_msgs, signature)
+    }
+}
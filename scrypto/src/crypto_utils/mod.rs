@@ -0,0 +1,3 @@
+mod crypto_utils;
+
+pub use crypto_utils::*;
@@ -5,11 +5,14 @@ use radix_engine_interface::api::actor_api::EventFlags;
 use radix_engine_interface::api::key_value_entry_api::KeyValueEntryHandle;
 use radix_engine_interface::api::{ActorRefHandle, FieldValue};
 use radix_engine_interface::api::{AttachedModuleId, FieldIndex, LockFlags};
-use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::crypto::{
+    Bls12381G1PublicKey, Bls12381G2Signature, Ed25519PublicKey, Ed25519Signature, Hash,
+    Secp256k1PublicKey, Secp256k1Signature,
+};
 use radix_engine_interface::data::scrypto::*;
 use radix_engine_interface::types::PackageAddress;
 use radix_engine_interface::types::{BlueprintId, GlobalAddress};
-use radix_engine_interface::types::{Level, NodeId, SubstateHandle};
+use radix_engine_interface::types::{Level, NodeId, SortedKey, SortedKeyRange, SubstateHandle};
 use radix_engine_interface::*;
 use sbor::rust::prelude::*;
 
@@ -200,6 +203,117 @@ impl ScryptoVmV1Api {
         handle
     }
 
+    pub fn actor_index_insert(
+        object_handle: u32,
+        collection_index: u8,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) {
+        unsafe {
+            actor_index::actor_index_insert(
+                object_handle,
+                u32::from(collection_index),
+                key.as_ptr(),
+                key.len(),
+                value.as_ptr(),
+                value.len(),
+            )
+        };
+    }
+
+    pub fn actor_index_remove(
+        object_handle: u32,
+        collection_index: u8,
+        key: Vec<u8>,
+    ) -> Option<Vec<u8>> {
+        let removed = copy_buffer(unsafe {
+            actor_index::actor_index_remove(
+                object_handle,
+                u32::from(collection_index),
+                key.as_ptr(),
+                key.len(),
+            )
+        });
+        scrypto_decode(&removed).unwrap()
+    }
+
+    pub fn actor_index_scan_keys(
+        object_handle: u32,
+        collection_index: u8,
+        limit: u32,
+    ) -> Vec<Vec<u8>> {
+        let keys = copy_buffer(unsafe {
+            actor_index::actor_index_scan_keys(object_handle, u32::from(collection_index), limit)
+        });
+        scrypto_decode(&keys).unwrap()
+    }
+
+    pub fn actor_index_drain(
+        object_handle: u32,
+        collection_index: u8,
+        limit: u32,
+    ) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let entries = copy_buffer(unsafe {
+            actor_index::actor_index_drain(object_handle, u32::from(collection_index), limit)
+        });
+        scrypto_decode(&entries).unwrap()
+    }
+
+    pub fn actor_sorted_index_insert(
+        object_handle: u32,
+        collection_index: u8,
+        sorted_key: SortedKey,
+        value: Vec<u8>,
+    ) {
+        let sorted_key = scrypto_encode(&sorted_key).unwrap();
+        unsafe {
+            actor_sorted_index::actor_sorted_index_insert(
+                object_handle,
+                u32::from(collection_index),
+                sorted_key.as_ptr(),
+                sorted_key.len(),
+                value.as_ptr(),
+                value.len(),
+            )
+        };
+    }
+
+    pub fn actor_sorted_index_remove(
+        object_handle: u32,
+        collection_index: u8,
+        sorted_key: &SortedKey,
+    ) -> Option<Vec<u8>> {
+        let sorted_key = scrypto_encode(sorted_key).unwrap();
+        let removed = copy_buffer(unsafe {
+            actor_sorted_index::actor_sorted_index_remove(
+                object_handle,
+                u32::from(collection_index),
+                sorted_key.as_ptr(),
+                sorted_key.len(),
+            )
+        });
+        scrypto_decode(&removed).unwrap()
+    }
+
+    pub fn actor_sorted_index_scan(
+        object_handle: u32,
+        collection_index: u8,
+        range: SortedKeyRange,
+        limit: u32,
+    ) -> Vec<(SortedKey, Vec<u8>)> {
+        let range = scrypto_encode(&range).unwrap();
+        let entries = copy_buffer(unsafe {
+            actor_sorted_index::actor_sorted_index_scan(
+                object_handle,
+                u32::from(collection_index),
+                range.as_ptr(),
+                range.len(),
+                limit,
+            )
+        });
+        scrypto_decode(&entries).unwrap()
+    }
+
     pub fn actor_get_object_id(actor_ref_handle: ActorRefHandle) -> NodeId {
         let node_id = copy_buffer(unsafe { actor::actor_get_object_id(actor_ref_handle) });
 
@@ -320,4 +434,94 @@ impl ScryptoVmV1Api {
             system::sys_panic(message.as_ptr(), message.len());
         };
     }
+
+    pub fn crypto_utils_keccak256_hash(data: &[u8]) -> Hash {
+        let hash = copy_buffer(unsafe {
+            crypto_utils::crypto_utils_keccak256_hash(data.as_ptr(), data.len())
+        });
+
+        Hash(hash.try_into().unwrap())
+    }
+
+    pub fn crypto_utils_blake2b_256_hash(data: &[u8]) -> Hash {
+        let hash = copy_buffer(unsafe {
+            crypto_utils::crypto_utils_blake2b_256_hash(data.as_ptr(), data.len())
+        });
+
+        Hash(hash.try_into().unwrap())
+    }
+
+    pub fn crypto_utils_ed25519_verify(
+        message: &[u8],
+        public_key: &Ed25519PublicKey,
+        signature: &Ed25519Signature,
+    ) -> bool {
+        let result = unsafe {
+            crypto_utils::crypto_utils_ed25519_verify(
+                message.as_ptr(),
+                message.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.0.as_ptr(),
+                signature.0.len(),
+            )
+        };
+
+        result != 0
+    }
+
+    pub fn crypto_utils_secp256k1_verify(
+        hash: &Hash,
+        public_key: &Secp256k1PublicKey,
+        signature: &Secp256k1Signature,
+    ) -> bool {
+        let result = unsafe {
+            crypto_utils::crypto_utils_secp256k1_verify(
+                hash.0.as_ptr(),
+                hash.0.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.0.as_ptr(),
+                signature.0.len(),
+            )
+        };
+
+        result != 0
+    }
+
+    pub fn crypto_utils_bls12381_v1_verify(
+        message: &[u8],
+        public_key: &Bls12381G1PublicKey,
+        signature: &Bls12381G2Signature,
+    ) -> bool {
+        let result = unsafe {
+            crypto_utils::crypto_utils_bls12381_v1_verify(
+                message.as_ptr(),
+                message.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.0.as_ptr(),
+                signature.0.len(),
+            )
+        };
+
+        result != 0
+    }
+
+    pub fn crypto_utils_bls12381_v1_aggregate_verify(
+        pub_keys_and_msgs: &[(Bls12381G1PublicKey, Vec<u8>)],
+        signature: &Bls12381G2Signature,
+    ) -> bool {
+        let pub_keys_and_msgs = scrypto_encode(pub_keys_and_msgs).unwrap();
+        let result = unsafe {
+            crypto_utils::crypto_utils_bls12381_v1_aggregate_verify(
+                pub_keys_and_msgs.as_ptr(),
+                pub_keys_and_msgs.len(),
+                signature.0.as_ptr(),
+                signature.0.len(),
+            )
+        };
+
+        result != 0
+    }
 }
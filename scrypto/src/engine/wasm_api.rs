@@ -176,6 +176,80 @@ pub mod actor {
     }
 }
 
+pub mod actor_index {
+    use radix_engine_interface::api::ActorStateHandle;
+    pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
+
+    super::wasm_extern_c! {
+        /// Inserts an entry into an index collection of the actor state
+        pub fn actor_index_insert(
+            object_handle: ActorStateHandle,
+            collection_index: u32,
+            key_ptr: *const u8,
+            key_len: usize,
+            value_ptr: *const u8,
+            value_len: usize,
+        );
+
+        /// Removes an entry from an index collection of the actor state
+        pub fn actor_index_remove(
+            object_handle: ActorStateHandle,
+            collection_index: u32,
+            key_ptr: *const u8,
+            key_len: usize,
+        ) -> Buffer;
+
+        /// Scans up to `limit` keys of an index collection of the actor state
+        pub fn actor_index_scan_keys(
+            object_handle: ActorStateHandle,
+            collection_index: u32,
+            limit: u32,
+        ) -> Buffer;
+
+        /// Removes and returns up to `limit` entries of an index collection of the actor state
+        pub fn actor_index_drain(
+            object_handle: ActorStateHandle,
+            collection_index: u32,
+            limit: u32,
+        ) -> Buffer;
+    }
+}
+
+pub mod actor_sorted_index {
+    use radix_engine_interface::api::ActorStateHandle;
+    pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
+
+    super::wasm_extern_c! {
+        /// Inserts an entry into a sorted index collection of the actor state
+        pub fn actor_sorted_index_insert(
+            object_handle: ActorStateHandle,
+            collection_index: u32,
+            sorted_key_ptr: *const u8,
+            sorted_key_len: usize,
+            value_ptr: *const u8,
+            value_len: usize,
+        );
+
+        /// Removes an entry from a sorted index collection of the actor state
+        pub fn actor_sorted_index_remove(
+            object_handle: ActorStateHandle,
+            collection_index: u32,
+            sorted_key_ptr: *const u8,
+            sorted_key_len: usize,
+        ) -> Buffer;
+
+        /// Scans up to `limit` entries of a sorted index collection of the actor state
+        /// whose sort prefix falls within the given range
+        pub fn actor_sorted_index_scan(
+            object_handle: ActorStateHandle,
+            collection_index: u32,
+            range_ptr: *const u8,
+            range_len: usize,
+            limit: u32,
+        ) -> Buffer;
+    }
+}
+
 pub mod kv_store {
     pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
 
@@ -284,6 +358,57 @@ pub mod system {
     }
 }
 
+pub mod crypto_utils {
+    pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
+
+    super::wasm_extern_c! {
+        /// Calculates Keccak-256 digest over the data
+        pub fn crypto_utils_keccak256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+        /// Calculates Blake2b-256 digest over the data
+        pub fn crypto_utils_blake2b_256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+        /// Performs Ed25519 signature verification over a message, returning 1 if valid
+        pub fn crypto_utils_ed25519_verify(
+            message_ptr: *const u8,
+            message_len: usize,
+            public_key_ptr: *const u8,
+            public_key_len: usize,
+            signature_ptr: *const u8,
+            signature_len: usize,
+        ) -> u32;
+
+        /// Performs ECDSA Secp256k1 signature verification over a hash, returning 1 if valid
+        pub fn crypto_utils_secp256k1_verify(
+            hash_ptr: *const u8,
+            hash_len: usize,
+            public_key_ptr: *const u8,
+            public_key_len: usize,
+            signature_ptr: *const u8,
+            signature_len: usize,
+        ) -> u32;
+
+        /// Performs BLS12-381 G2 signature verification over a message, returning 1 if valid
+        pub fn crypto_utils_bls12381_v1_verify(
+            message_ptr: *const u8,
+            message_len: usize,
+            public_key_ptr: *const u8,
+            public_key_len: usize,
+            signature_ptr: *const u8,
+            signature_len: usize,
+        ) -> u32;
+
+        /// Performs BLS12-381 G2 aggregate signature verification over the scrypto-encoded
+        /// (public key, message) pairs, returning 1 if valid
+        pub fn crypto_utils_bls12381_v1_aggregate_verify(
+            pub_keys_and_msgs_ptr: *const u8,
+            pub_keys_and_msgs_len: usize,
+            signature_ptr: *const u8,
+            signature_len: usize,
+        ) -> u32;
+    }
+}
+
 pub mod buffer {
     pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
 
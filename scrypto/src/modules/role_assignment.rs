@@ -4,9 +4,10 @@ use crate::modules::ModuleHandle;
 use crate::prelude::Attachable;
 use radix_engine_derive::*;
 use radix_engine_interface::api::node_modules::auth::{
-    RoleAssignmentCreateInput, RoleAssignmentGetInput, RoleAssignmentLockOwnerInput,
-    RoleAssignmentSetInput, RoleAssignmentSetOwnerInput, ROLE_ASSIGNMENT_BLUEPRINT,
-    ROLE_ASSIGNMENT_CREATE_IDENT, ROLE_ASSIGNMENT_GET_IDENT, ROLE_ASSIGNMENT_LOCK_OWNER_IDENT,
+    RoleAssignmentCreateInput, RoleAssignmentGetInput, RoleAssignmentGetOwnerRoleInput,
+    RoleAssignmentLockOwnerInput, RoleAssignmentSetInput, RoleAssignmentSetOwnerInput,
+    ROLE_ASSIGNMENT_BLUEPRINT, ROLE_ASSIGNMENT_CREATE_IDENT, ROLE_ASSIGNMENT_GET_IDENT,
+    ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT, ROLE_ASSIGNMENT_LOCK_OWNER_IDENT,
     ROLE_ASSIGNMENT_SET_IDENT, ROLE_ASSIGNMENT_SET_OWNER_IDENT,
 };
 use radix_engine_interface::api::*;
@@ -64,6 +65,13 @@ impl RoleAssignment {
         );
     }
 
+    pub fn get_owner_role(&self) -> OwnerRoleEntry {
+        self.call(
+            ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT,
+            &RoleAssignmentGetOwnerRoleInput {},
+        )
+    }
+
     fn internal_set_role<A: Into<AccessRule>>(&self, module: ModuleId, name: &str, rule: A) {
         self.call_ignore_rtn(
             ROLE_ASSIGNMENT_SET_IDENT,
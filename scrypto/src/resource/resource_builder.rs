@@ -17,7 +17,7 @@ use radix_engine_interface::types::*;
 use radix_engine_interface::*;
 use sbor::rust::prelude::*;
 use sbor::FixedEnumVariant;
-use scrypto::resource::ResourceManager;
+use scrypto::resource::{ResourceManager, ScryptoBucket};
 
 /// Not divisible.
 pub const DIVISIBILITY_NONE: u8 = 0;
@@ -148,6 +148,8 @@ pub struct InProgressResourceBuilder<T: AnyResourceType> {
     resource_roles: T::ResourceRoles,
     metadata_config: Option<ModuleConfig<MetadataInit>>,
     address_reservation: Option<GlobalAddressReservation>,
+    supply_limits: Option<ResourceSupplyLimits>,
+    lock_supply_limits: bool,
 }
 
 impl<T: AnyResourceType> InProgressResourceBuilder<T> {
@@ -158,6 +160,8 @@ impl<T: AnyResourceType> InProgressResourceBuilder<T> {
             metadata_config: None,
             address_reservation: None,
             resource_roles: T::ResourceRoles::default(),
+            supply_limits: None,
+            lock_supply_limits: false,
         }
     }
 }
@@ -517,6 +521,58 @@ impl<
     }
 }
 
+impl<T: AnyResourceType> InProgressResourceBuilder<T> {
+    /// Sets the maximum total supply of the resource, which is enforced by the engine on every mint.
+    ///
+    /// Burning resources makes room for new mints. The limit can be changed later by the
+    /// `minter_updater` role, unless it is locked with `lock_supply_limits`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .max_supply(1_000_000)
+    ///    .lock_supply_limits();
+    /// ```
+    pub fn max_supply<A: Into<Decimal>>(mut self, max_supply: A) -> Self {
+        self.supply_limits
+            .get_or_insert_with(Default::default)
+            .max_supply = Some(max_supply.into());
+        self
+    }
+
+    /// Limits the amount which can be minted within each period of `period_in_epochs` epochs,
+    /// starting from the epoch at which the resource is created.
+    ///
+    /// The initial supply does not count towards the allowance.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// // At most 1000 tokens can be minted every 100 epochs.
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .mint_allowance(1000, 100);
+    /// ```
+    pub fn mint_allowance<A: Into<Decimal>>(mut self, amount: A, period_in_epochs: u64) -> Self {
+        self.supply_limits
+            .get_or_insert_with(Default::default)
+            .mint_allowance = Some(MintAllowance {
+            amount: amount.into(),
+            period_in_epochs,
+        });
+        self
+    }
+
+    /// Locks the supply limits of the resource, so that they can never be changed.
+    pub fn lock_supply_limits(mut self) -> Self {
+        self.supply_limits.get_or_insert_with(Default::default);
+        self.lock_supply_limits = true;
+        self
+    }
+}
+
 pub trait SetOwnerBuilder: private::CanAddOwner {
     /// Sets the owner badge to be the given non-fungible.
     ///
@@ -540,6 +596,38 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 resource_roles,
                 metadata,
                 address_reservation,
+                supply_limits: Some((supply_limits, lock_supply_limits)),
+            } => {
+                let metadata = metadata.unwrap_or_else(|| Default::default());
+
+                let bytes = ScryptoVmV1Api::blueprint_call(
+                    RESOURCE_PACKAGE,
+                    FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                    FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT,
+                    scrypto_encode(&FungibleResourceManagerCreateWithSupplyLimitsInput {
+                        owner_role,
+                        divisibility,
+                        initial_supply: Decimal::ZERO,
+                        supply_limits,
+                        lock_supply_limits,
+                        metadata,
+                        resource_roles,
+                        address_reservation,
+                    })
+                    .unwrap(),
+                );
+                let (resource_address, bucket): (ResourceAddress, Bucket) =
+                    scrypto_decode(&bytes).unwrap();
+                bucket.drop_empty();
+                resource_address.into()
+            }
+            private::CreateWithNoSupply::Fungible {
+                owner_role,
+                divisibility,
+                resource_roles,
+                metadata,
+                address_reservation,
+                supply_limits: None,
             } => {
                 let metadata = metadata.unwrap_or_else(|| Default::default());
 
@@ -566,6 +654,42 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 resource_roles,
                 metadata,
                 address_reservation,
+                supply_limits: Some((supply_limits, lock_supply_limits)),
+            } => {
+                let metadata = metadata.unwrap_or_else(|| Default::default());
+
+                let bytes = ScryptoVmV1Api::blueprint_call(
+                    RESOURCE_PACKAGE,
+                    NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                    NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT,
+                    scrypto_encode(
+                        &NonFungibleResourceManagerCreateWithSupplyLimitsGenericInput::<_, ()> {
+                            owner_role,
+                            id_type,
+                            non_fungible_schema,
+                            entries: index_map_new(),
+                            supply_limits,
+                            lock_supply_limits,
+                            resource_roles,
+                            metadata,
+                            address_reservation,
+                        },
+                    )
+                    .unwrap(),
+                );
+                let (resource_address, bucket): (ResourceAddress, Bucket) =
+                    scrypto_decode(&bytes).unwrap();
+                bucket.drop_empty();
+                resource_address.into()
+            }
+            private::CreateWithNoSupply::NonFungible {
+                owner_role,
+                id_type,
+                non_fungible_schema,
+                resource_roles,
+                metadata,
+                address_reservation,
+                supply_limits: None,
             } => {
                 let metadata = metadata.unwrap_or_else(|| Default::default());
 
@@ -633,21 +757,39 @@ impl InProgressResourceBuilder<FungibleResourceType> {
             .take()
             .unwrap_or_else(|| Default::default());
 
-        let bytes = ScryptoVmV1Api::blueprint_call(
-            RESOURCE_PACKAGE,
-            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
-            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
-            scrypto_encode(&FungibleResourceManagerCreateWithInitialSupplyInput {
-                owner_role: self.owner_role,
-                track_total_supply: true,
-                divisibility: self.resource_type.divisibility,
-                resource_roles: self.resource_roles,
-                metadata,
-                initial_supply: amount.into(),
-                address_reservation: self.address_reservation,
-            })
-            .unwrap(),
-        );
+        let bytes = match self.supply_limits {
+            Some(supply_limits) => ScryptoVmV1Api::blueprint_call(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT,
+                scrypto_encode(&FungibleResourceManagerCreateWithSupplyLimitsInput {
+                    owner_role: self.owner_role,
+                    divisibility: self.resource_type.divisibility,
+                    initial_supply: amount.into(),
+                    supply_limits,
+                    lock_supply_limits: self.lock_supply_limits,
+                    resource_roles: self.resource_roles,
+                    metadata,
+                    address_reservation: self.address_reservation,
+                })
+                .unwrap(),
+            ),
+            None => ScryptoVmV1Api::blueprint_call(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_IDENT,
+                scrypto_encode(&FungibleResourceManagerCreateWithInitialSupplyInput {
+                    owner_role: self.owner_role,
+                    track_total_supply: true,
+                    divisibility: self.resource_type.divisibility,
+                    resource_roles: self.resource_roles,
+                    metadata,
+                    initial_supply: amount.into(),
+                    address_reservation: self.address_reservation,
+                })
+                .unwrap(),
+            ),
+        };
 
         scrypto_decode::<(ResourceAddress, FungibleBucket)>(&bytes)
             .unwrap()
@@ -681,6 +823,10 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
     where
         T: IntoIterator<Item = (StringNonFungibleLocalId, D)>,
     {
+        if let Some(supply_limits) = self.supply_limits.take() {
+            return self.create_with_supply_limits(supply_limits, map_entries(entries));
+        }
+
         let metadata = self
             .metadata_config
             .take()
@@ -736,6 +882,10 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
     where
         T: IntoIterator<Item = (IntegerNonFungibleLocalId, D)>,
     {
+        if let Some(supply_limits) = self.supply_limits.take() {
+            return self.create_with_supply_limits(supply_limits, map_entries(entries));
+        }
+
         let metadata = self
             .metadata_config
             .take()
@@ -791,6 +941,10 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
     where
         T: IntoIterator<Item = (BytesNonFungibleLocalId, D)>,
     {
+        if let Some(supply_limits) = self.supply_limits.take() {
+            return self.create_with_supply_limits(supply_limits, map_entries(entries));
+        }
+
         let metadata = self
             .metadata_config
             .take()
@@ -845,11 +999,20 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
     ///         (NFData { name: "NF Two".to_owned(), flag: true }),
     ///     ]);
     /// ```
+    ///
+    /// # Panics
+    /// Panics if supply limits are set, as RUID resources with supply limits can only be created
+    /// with no initial supply.
     pub fn mint_initial_supply<T>(mut self, entries: T) -> NonFungibleBucket
     where
         T: IntoIterator<Item = D>,
         D: ScryptoEncode,
     {
+        assert!(
+            self.supply_limits.is_none(),
+            "RUID resources with supply limits can only be created with no initial supply"
+        );
+
         let metadata = self
             .metadata_config
             .take()
@@ -892,6 +1055,47 @@ fn map_entries<T: IntoIterator<Item = (Y, V)>, V: NonFungibleData, Y: IsNonFungi
         .collect()
 }
 
+impl<
+        Y: IsNonFungibleLocalId,
+        D: NonFungibleData,
+        S: ScryptoCategorize + ScryptoEncode + ScryptoDecode,
+    > InProgressResourceBuilder<NonFungibleResourceType<Y, D, S>>
+{
+    fn create_with_supply_limits(
+        mut self,
+        supply_limits: ResourceSupplyLimits,
+        entries: IndexMap<NonFungibleLocalId, (D,)>,
+    ) -> NonFungibleBucket {
+        let metadata = self
+            .metadata_config
+            .take()
+            .unwrap_or_else(|| Default::default());
+
+        let bytes = ScryptoVmV1Api::blueprint_call(
+            RESOURCE_PACKAGE,
+            NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_SUPPLY_LIMITS_IDENT,
+            scrypto_encode(
+                &NonFungibleResourceManagerCreateWithSupplyLimitsGenericInput {
+                    owner_role: self.owner_role,
+                    id_type: Y::id_type(),
+                    non_fungible_schema: self.resource_type.0,
+                    entries,
+                    supply_limits,
+                    lock_supply_limits: self.lock_supply_limits,
+                    resource_roles: self.resource_roles,
+                    metadata,
+                    address_reservation: self.address_reservation,
+                },
+            )
+            .unwrap(),
+        );
+        scrypto_decode::<(ResourceAddress, NonFungibleBucket)>(&bytes)
+            .unwrap()
+            .1
+    }
+}
+
 impl<T: AnyResourceType> private::CanSetMetadata for InProgressResourceBuilder<T> {
     type OutputBuilder = Self;
 
@@ -922,6 +1126,9 @@ impl private::CanCreateWithNoSupply for InProgressResourceBuilder<FungibleResour
             resource_roles: self.resource_roles,
             metadata: self.metadata_config,
             address_reservation: self.address_reservation,
+            supply_limits: self
+                .supply_limits
+                .map(|supply_limits| (supply_limits, self.lock_supply_limits)),
         }
     }
 }
@@ -943,6 +1150,9 @@ impl<
             resource_roles: self.resource_roles,
             metadata: self.metadata_config,
             address_reservation: self.address_reservation,
+            supply_limits: self
+                .supply_limits
+                .map(|supply_limits| (supply_limits, self.lock_supply_limits)),
         }
     }
 }
@@ -1008,6 +1218,7 @@ mod private {
             resource_roles: FungibleResourceRoles,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
+            supply_limits: Option<(ResourceSupplyLimits, bool)>,
         },
         NonFungible {
             owner_role: OwnerRole,
@@ -1016,6 +1227,7 @@ mod private {
             resource_roles: NonFungibleResourceRoles,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
+            supply_limits: Option<(ResourceSupplyLimits, bool)>,
         },
     }
 
@@ -175,6 +175,33 @@ impl ResourceManagerStub {
         )
     }
 
+    /// Returns the supply limits of the resource, or `None` if it was created without them.
+    pub fn supply_limits(&self) -> Option<ResourceSupplyLimits> {
+        self.call(
+            RESOURCE_MANAGER_GET_SUPPLY_LIMITS_IDENT,
+            &ResourceManagerGetSupplyLimitsInput {},
+        )
+    }
+
+    /// Replaces the supply limits of the resource.
+    ///
+    /// # Panics
+    /// Panics if the resource was created without supply limits, or they have been locked.
+    pub fn set_supply_limits(&self, supply_limits: ResourceSupplyLimits) {
+        self.call(
+            RESOURCE_MANAGER_SET_SUPPLY_LIMITS_IDENT,
+            &ResourceManagerSetSupplyLimitsInput { supply_limits },
+        )
+    }
+
+    /// Permanently locks the supply limits of the resource.
+    pub fn lock_supply_limits(&self) {
+        self.call(
+            RESOURCE_MANAGER_LOCK_SUPPLY_LIMITS_IDENT,
+            &ResourceManagerLockSupplyLimitsInput {},
+        )
+    }
+
     pub fn non_fungible_exists(&self, id: &NonFungibleLocalId) -> bool {
         self.call(
             NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT,
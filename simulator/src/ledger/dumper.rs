@@ -222,6 +222,27 @@ pub fn dump_resource_manager<T: SubstateDatabase, O: std::io::Write>(
                 total_supply
             );
         }
+
+        if info
+            .get_features()
+            .contains(NonFungibleResourceManagerFeature::SupplyLimits.feature_name())
+        {
+            let supply_limits = reader
+                .read_typed_object_field::<NonFungibleResourceManagerSupplyLimitsFieldPayload>(
+                    resource_address.as_node_id(),
+                    ModuleId::Main,
+                    NonFungibleResourceManagerField::SupplyLimits.into(),
+                )
+                .map_err(|_| EntityDumpError::InvalidStore("Missing Supply Limits".to_string()))?
+                .into_latest();
+
+            writeln!(
+                output,
+                "{}: {:?}",
+                "Supply Limits".green().bold(),
+                supply_limits
+            );
+        }
     } else {
         let divisibility = reader
             .read_typed_object_field::<FungibleResourceManagerDivisibilityFieldPayload>(
@@ -260,6 +281,27 @@ pub fn dump_resource_manager<T: SubstateDatabase, O: std::io::Write>(
                 total_supply
             );
         }
+
+        if info
+            .get_features()
+            .contains(FungibleResourceManagerFeature::SupplyLimits.feature_name())
+        {
+            let supply_limits = reader
+                .read_typed_object_field::<FungibleResourceManagerSupplyLimitsFieldPayload>(
+                    resource_address.as_node_id(),
+                    ModuleId::Main,
+                    FungibleResourceManagerField::SupplyLimits.into(),
+                )
+                .map_err(|_| EntityDumpError::InvalidStore("Missing Supply Limits".to_string()))?
+                .into_latest();
+
+            writeln!(
+                output,
+                "{}: {:?}",
+                "Supply Limits".green().bold(),
+                supply_limits
+            );
+        }
     }
 
     let metadata = get_entity_metadata(resource_address.as_node_id(), substate_db);
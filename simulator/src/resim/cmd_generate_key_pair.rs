@@ -6,7 +6,13 @@ use rand::Rng;
 
 /// Generate a key pair
 #[derive(Parser, Debug)]
-pub struct GenerateKeyPair {}
+pub struct GenerateKeyPair {
+    /// Store the private key in the keystore, encrypted with the password in the
+    /// KEYSTORE_PASSWORD environment variable, instead of printing it. The public key can
+    /// then be used in place of the private key when signing.
+    #[clap(long)]
+    keystore: bool,
+}
 
 impl GenerateKeyPair {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
@@ -14,12 +20,19 @@ impl GenerateKeyPair {
         let private_key = Secp256k1PrivateKey::from_bytes(&secret).unwrap();
         let public_key = private_key.public_key();
         writeln!(out, "Public key: {}", public_key.to_string().green()).map_err(Error::IOError)?;
-        writeln!(
-            out,
-            "Private key: {}",
-            hex::encode(private_key.to_bytes()).green()
-        )
-        .map_err(Error::IOError)?;
+        if self.keystore {
+            get_keystore()?
+                .import(&private_key.into(), &get_keystore_password()?)
+                .map_err(Error::KeystoreError)?;
+            writeln!(out, "Private key stored in the keystore").map_err(Error::IOError)?;
+        } else {
+            writeln!(
+                out,
+                "Private key: {}",
+                hex::encode(private_key.to_bytes()).green()
+            )
+            .map_err(Error::IOError)?;
+        }
         Ok(())
     }
 }
@@ -25,7 +25,7 @@ pub struct Mint {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys used for signing, separated by comma, or the public keys of keys in the keystore
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
@@ -82,7 +82,7 @@ impl Publish {
         .map_err(Error::SborDecodeError)?;
 
         if let Some(package_address) = self.package_address.clone() {
-            let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+            let scrypto_vm = new_scrypto_vm();
             let native_vm = DefaultNativeVm::new();
             let vm = Vm::new(&scrypto_vm, native_vm);
             let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -21,7 +21,7 @@ pub struct Run {
     #[clap(short, long, multiple = true)]
     pub blobs: Option<Vec<String>>,
 
-    /// The private keys used for signing, separated by comma
+    /// The private keys used for signing, separated by comma, or the public keys of keys in the keystore
     #[clap(short, long)]
     pub signing_keys: Option<String>,
 
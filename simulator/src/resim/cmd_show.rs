@@ -12,7 +12,7 @@ pub struct Show {
 
 impl Show {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+        let scrypto_vm = new_scrypto_vm();
         let native_vm = DefaultNativeVm::new();
         let vm = Vm::new(&scrypto_vm, native_vm);
         let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -2,6 +2,8 @@ use std::fs;
 use std::path::PathBuf;
 
 use radix_engine::types::*;
+use radix_engine::vm::wasm::WasmDiskCache;
+use transaction::signing::Keystore;
 
 use crate::resim::*;
 use std::env;
@@ -36,6 +38,19 @@ pub fn get_configs_path() -> Result<PathBuf, Error> {
     Ok(path.with_extension("sbor"))
 }
 
+/// The cache is best-effort: if it can't be opened, code is instrumented and compiled again.
+pub fn get_wasm_disk_cache() -> Option<WasmDiskCache> {
+    WasmDiskCache::new(get_data_dir().ok()?.join("wasm-cache")).ok()
+}
+
+pub fn get_keystore() -> Result<Keystore, Error> {
+    Ok(Keystore::new(get_data_dir()?.join("keystore")))
+}
+
+pub fn get_keystore_password() -> Result<String, Error> {
+    env::var(ENV_KEYSTORE_PASSWORD).map_err(|_| Error::NoKeystorePassword)
+}
+
 pub fn get_configs() -> Result<Configs, Error> {
     let path = get_configs_path()?;
     if path.exists() {
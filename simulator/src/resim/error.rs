@@ -67,6 +67,10 @@ pub enum Error {
 
     InvalidPrivateKey,
 
+    NoKeystorePassword,
+
+    KeystoreError(transaction::errors::KeystoreError),
+
     NonFungibleGlobalIdError(ParseNonFungibleGlobalIdError),
 
     FailedToBuildArguments(BuildCallArgumentError),
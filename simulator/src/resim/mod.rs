@@ -51,6 +51,7 @@ pub use error::*;
 pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
 pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
+pub const ENV_KEYSTORE_PASSWORD: &'static str = "KEYSTORE_PASSWORD";
 
 use clap::{Parser, Subcommand};
 use radix_engine::blueprints::consensus_manager::{
@@ -160,6 +161,16 @@ pub fn run() -> Result<(), Error> {
     }
 }
 
+/// The Scrypto VM of all commands, which keeps instrumented and compiled code in the WASM disk
+/// cache, so that it isn't redone by every invocation.
+pub fn new_scrypto_vm() -> ScryptoVm<DefaultWasmEngine> {
+    let scrypto_vm = ScryptoVm::default();
+    match get_wasm_disk_cache() {
+        Some(disk_cache) => scrypto_vm.with_disk_cache(disk_cache),
+        None => scrypto_vm,
+    }
+}
+
 pub fn handle_system_transaction<O: std::io::Write>(
     instructions: Vec<InstructionV1>,
     blobs: Vec<Vec<u8>>,
@@ -168,7 +179,7 @@ pub fn handle_system_transaction<O: std::io::Write>(
     print_receipt: bool,
     out: &mut O,
 ) -> Result<TransactionReceipt, Error> {
-    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let scrypto_vm = new_scrypto_vm();
     let native_vm = DefaultNativeVm::new();
     let vm = Vm::new(&scrypto_vm, native_vm);
     let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -249,7 +260,7 @@ pub fn handle_manifest<O: std::io::Write>(
             Ok(None)
         }
         None => {
-            let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+            let scrypto_vm = new_scrypto_vm();
             let native_vm = DefaultNativeVm::new();
             let vm = Vm::new(&scrypto_vm, native_vm);
             let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -322,12 +333,14 @@ pub fn get_signing_keys(signing_keys: &Option<String>) -> Result<Vec<Secp256k1Pr
             .map(str::trim)
             .filter(|s| !s.is_empty())
             .map(|key| {
-                hex::decode(key)
-                    .map_err(|_| Error::InvalidPrivateKey)
-                    .and_then(|bytes| {
-                        Secp256k1PrivateKey::from_bytes(&bytes)
-                            .map_err(|_| Error::InvalidPrivateKey)
-                    })
+                let bytes = hex::decode(key).map_err(|_| Error::InvalidPrivateKey)?;
+                // A public key refers to a private key held encrypted in the keystore
+                if bytes.len() == Secp256k1PublicKey::LENGTH {
+                    let public_key = Secp256k1PublicKey::try_from(bytes.as_slice())
+                        .map_err(|_| Error::InvalidPrivateKey)?;
+                    return load_from_keystore(public_key);
+                }
+                Secp256k1PrivateKey::from_bytes(&bytes).map_err(|_| Error::InvalidPrivateKey)
             })
             .collect::<Result<Vec<Secp256k1PrivateKey>, Error>>()?
     } else {
@@ -337,10 +350,20 @@ pub fn get_signing_keys(signing_keys: &Option<String>) -> Result<Vec<Secp256k1Pr
     Ok(private_keys)
 }
 
+fn load_from_keystore(public_key: Secp256k1PublicKey) -> Result<Secp256k1PrivateKey, Error> {
+    match get_keystore()?
+        .load(&public_key.into(), &get_keystore_password()?)
+        .map_err(Error::KeystoreError)?
+    {
+        PrivateKey::Secp256k1(private_key) => Ok(private_key),
+        PrivateKey::Ed25519(_) => Err(Error::InvalidPrivateKey),
+    }
+}
+
 pub fn export_package_schema(
     package_address: PackageAddress,
 ) -> Result<BTreeMap<BlueprintVersionKey, BlueprintDefinition>, Error> {
-    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let scrypto_vm = new_scrypto_vm();
     let native_vm = DefaultNativeVm::new();
     let vm = Vm::new(&scrypto_vm, native_vm);
     let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -353,7 +376,7 @@ pub fn export_package_schema(
 }
 
 pub fn export_object_info(component_address: ComponentAddress) -> Result<ObjectInfo, Error> {
-    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let scrypto_vm = new_scrypto_vm();
     let native_vm = DefaultNativeVm::new();
     let vm = Vm::new(&scrypto_vm, native_vm);
     let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -370,7 +393,7 @@ pub fn export_schema(
     node_id: &NodeId,
     schema_hash: SchemaHash,
 ) -> Result<VersionedScryptoSchema, Error> {
-    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let scrypto_vm = new_scrypto_vm();
     let native_vm = DefaultNativeVm::new();
     let vm = Vm::new(&scrypto_vm, native_vm);
     let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -401,7 +424,7 @@ pub fn export_blueprint_interface(
 }
 
 pub fn get_blueprint_id(component_address: ComponentAddress) -> Result<BlueprintId, Error> {
-    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let scrypto_vm = new_scrypto_vm();
     let native_vm = DefaultNativeVm::new();
     let vm = Vm::new(&scrypto_vm, native_vm);
     let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -473,7 +496,7 @@ pub fn db_upsert_timestamps(
     milli_timestamp: ProposerMilliTimestampSubstate,
     minute_timestamp: ProposerMinuteTimestampSubstate,
 ) -> Result<(), Error> {
-    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let scrypto_vm = new_scrypto_vm();
     let native_vm = DefaultNativeVm::new();
     let vm = Vm::new(&scrypto_vm, native_vm);
     let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
@@ -508,7 +531,7 @@ pub fn db_upsert_timestamps(
 }
 
 pub fn db_upsert_epoch(epoch: Epoch) -> Result<(), Error> {
-    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let scrypto_vm = new_scrypto_vm();
     let native_vm = DefaultNativeVm::new();
     let vm = Vm::new(&scrypto_vm, native_vm);
     let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
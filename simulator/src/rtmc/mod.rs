@@ -2,7 +2,7 @@ use clap::Parser;
 use radix_engine::{types::*, utils::*};
 use std::path::PathBuf;
 use std::str::FromStr;
-use transaction::manifest::{compile, BlobProvider};
+use transaction::manifest::{compile, compile_error_diagnostics, BlobProvider, CompileError};
 
 /// Radix transaction manifest compiler
 #[derive(Parser, Debug)]
@@ -29,11 +29,28 @@ pub struct Args {
 pub enum Error {
     IoError(std::io::Error),
     EncodeError(sbor::EncodeError),
-    CompileError(transaction::manifest::CompileError),
+    CompileError(CompileErrorReport),
     ParseNetworkError(ParseNetworkError),
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
 }
 
+/// A compile error, together with the source it was raised for, so that it is reported with
+/// diagnostics pointing into the manifest.
+pub struct CompileErrorReport {
+    pub source: String,
+    pub error: CompileError,
+}
+
+impl std::fmt::Debug for CompileErrorReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            compile_error_diagnostics(&self.source, &self.error)
+        )
+    }
+}
+
 pub fn run() -> Result<(), Error> {
     let args = Args::parse();
 
@@ -48,8 +65,13 @@ pub fn run() -> Result<(), Error> {
             blobs.push(std::fs::read(path).map_err(Error::IoError)?);
         }
     }
-    let transaction = compile(&content, &network, BlobProvider::new_with_blobs(blobs))
-        .map_err(Error::CompileError)?;
+    let transaction =
+        compile(&content, &network, BlobProvider::new_with_blobs(blobs)).map_err(|error| {
+            Error::CompileError(CompileErrorReport {
+                source: content.clone(),
+                error,
+            })
+        })?;
     validate_call_arguments_to_native_components(&transaction.instructions)
         .map_err(Error::InstructionSchemaValidationError)?;
     std::fs::write(
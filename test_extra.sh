@@ -30,4 +30,9 @@ test_crates_features \
     scrypto" \
     "--no-default-features --features alloc"
 
+echo "Testing decimal math against a big-float reference..."
+test_crates_features \
+    "radix-engine-common" \
+    "--release --features full_math_tests"
+
 echo "Congrats! All extra tests passed."
@@ -177,6 +177,10 @@ impl ManifestBuilder {
         self.registrar.object_names()
     }
 
+    pub(crate) fn registrar(&self) -> &ManifestNameRegistrar {
+        &self.registrar
+    }
+
     /// Example usage:
     /// ```
     /// # use transaction::prelude::*;
@@ -270,6 +274,34 @@ impl ManifestBuilder {
         )
     }
 
+    /// Inlines the instructions of a [`ManifestFragment`], binding its inputs and naming its
+    /// outputs as specified by `bindings`.
+    pub fn add_fragment(
+        self,
+        fragment: &ManifestFragment,
+        bindings: ManifestFragmentBindings,
+    ) -> Self {
+        self.add_fragment_advanced(fragment, bindings).0
+    }
+
+    /// Inlines the instructions of a [`ManifestFragment`], like `add_fragment`.
+    /// Also returns the names the fragment's outputs were given in this manifest.
+    pub fn add_fragment_advanced(
+        mut self,
+        fragment: &ManifestFragment,
+        bindings: ManifestFragmentBindings,
+    ) -> (Self, ManifestFragmentOutputs) {
+        let (instructions, outputs) = fragment.inline(&self.registrar, bindings);
+        self.instructions.extend(instructions);
+        self.blobs.extend(
+            fragment
+                .blobs()
+                .iter()
+                .map(|(hash, blob)| (*hash, blob.clone())),
+        );
+        (self, outputs)
+    }
+
     /// Takes resource from worktop.
     pub fn take_all_from_worktop(
         self,
@@ -1136,6 +1168,36 @@ impl ManifestBuilder {
         })
     }
 
+    /// Replaces the code and blueprint definitions of an upgradeable package.
+    /// Requires the package owner badge.
+    pub fn upgrade_package(
+        mut self,
+        package_address: impl ResolvablePackageAddress,
+        code: Vec<u8>,
+        definition: PackageDefinition,
+    ) -> Self {
+        let address = package_address.resolve(&self.registrar);
+        let code_blob_ref = self.add_blob(code);
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: PACKAGE_UPGRADE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackageUpgradeManifestInput {
+                definition,
+                code: code_blob_ref,
+            }),
+        })
+    }
+
+    /// Permanently disables upgrades of an upgradeable package.
+    pub fn lock_package_upgrades(self, package_address: impl ResolvablePackageAddress) -> Self {
+        let address = package_address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: PACKAGE_LOCK_UPGRADES_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackageLockUpgradesInput {}),
+        })
+    }
+
     pub fn set_component_royalty(
         self,
         component_address: impl ResolvableComponentAddress,
@@ -1280,6 +1342,26 @@ impl ManifestBuilder {
         })
     }
 
+    /// Publishes a package with an owner badge, which may later be used to upgrade it.
+    pub fn publish_package_upgradeable(
+        mut self,
+        code: Vec<u8>,
+        definition: PackageDefinition,
+    ) -> Self {
+        let code_blob_ref = self.add_blob(code);
+
+        self.add_instruction(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_WASM_UPGRADEABLE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishWasmUpgradeableManifestInput {
+                code: code_blob_ref,
+                definition,
+                metadata: metadata_init!(),
+            }),
+        })
+    }
+
     /// Publishes a package with an owner badge.
     pub fn publish_package_with_owner(
         mut self,
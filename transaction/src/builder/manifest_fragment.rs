@@ -0,0 +1,815 @@
+use crate::internal_prelude::*;
+use crate::manifest::decompiler::ManifestObjectNames;
+
+/// A reusable sequence of manifest instructions, such as a swap on a particular DEX, which can
+/// be inlined into any manifest with [`ManifestBuilder::add_fragment`].
+///
+/// A fragment declares the buckets, proofs and named addresses it expects from the manifest
+/// it is inlined into as inputs, and those it leaves behind for that manifest as outputs.
+/// When the fragment is inlined, its inputs are bound to existing objects in the parent
+/// manifest, and everything it creates is renamed so as not to collide with the parent's names.
+///
+/// Example usage:
+/// ```
+/// # use transaction::prelude::*;
+/// # let account = ComponentAddress::virtual_account_from_public_key(
+/// #   &Ed25519PublicKey([0; Ed25519PublicKey::LENGTH])
+/// # );
+/// # let pool = GENESIS_HELPER; // Just some address to get it to compile
+/// # let other_resource = ACCOUNT_OWNER_BADGE; // Just some address to get it to compile
+/// let swap = ManifestFragment::builder()
+///     .input_bucket("input")
+///     .instructions(|builder| {
+///         builder
+///             .call_method_with_name_lookup(pool, "swap", |lookup| (lookup.bucket("input"),))
+///             .take_all_from_worktop(other_resource, "output")
+///     })
+///     .output_bucket("output")
+///     .build();
+///
+/// let manifest = ManifestBuilder::new()
+///     .lock_fee_from_faucet()
+///     .withdraw_from_account(account, XRD, dec!(10))
+///     .take_all_from_worktop(XRD, "xrd")
+///     .add_fragment(
+///         &swap,
+///         ManifestFragmentBindings::new()
+///             .bucket("input", "xrd")
+///             .output_bucket("output", "swapped"),
+///     )
+///     .try_deposit_or_abort(account, None, "swapped")
+///     .build();
+/// ```
+pub struct ManifestFragment {
+    instructions: Vec<InstructionV1>,
+    blobs: BTreeMap<Hash, Vec<u8>>,
+    object_names: ManifestObjectNames,
+    input_buckets: IndexMap<String, ManifestBucket>,
+    input_proofs: IndexMap<String, ManifestProof>,
+    input_named_addresses: IndexMap<String, u32>,
+    output_buckets: IndexMap<String, ManifestBucket>,
+    output_proofs: IndexMap<String, ManifestProof>,
+    output_named_addresses: IndexMap<String, u32>,
+    consumed_buckets: IndexSet<ManifestBucket>,
+    consumed_proofs: IndexSet<ManifestProof>,
+    consumed_address_reservations: IndexSet<ManifestAddressReservation>,
+}
+
+/// The names of the inputs or outputs of a [`ManifestFragment`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestFragmentInterface {
+    pub buckets: Vec<String>,
+    pub proofs: Vec<String>,
+    pub named_addresses: Vec<String>,
+}
+
+pub struct ManifestFragmentBuilder {
+    builder: ManifestBuilder,
+    input_buckets: IndexMap<String, ManifestBucket>,
+    input_proofs: IndexMap<String, ManifestProof>,
+    input_named_addresses: IndexMap<String, u32>,
+    outputs: ManifestFragmentInterface,
+}
+
+/// How a [`ManifestFragment`] is connected to the manifest it is inlined into.
+///
+/// Every input of the fragment must be bound to the name of an existing object in the parent
+/// manifest. Outputs can optionally be given a name in the parent manifest - otherwise they,
+/// like every other object created by the fragment, get a collision-free name derived from
+/// their name in the fragment.
+#[derive(Debug, Clone, Default)]
+pub struct ManifestFragmentBindings {
+    buckets: IndexMap<String, String>,
+    proofs: IndexMap<String, String>,
+    named_addresses: IndexMap<String, String>,
+    output_buckets: IndexMap<String, String>,
+    output_proofs: IndexMap<String, String>,
+    output_named_addresses: IndexMap<String, String>,
+}
+
+/// The names given in the parent manifest to the outputs of an inlined [`ManifestFragment`],
+/// keyed by their name in the fragment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestFragmentOutputs {
+    pub buckets: IndexMap<String, String>,
+    pub proofs: IndexMap<String, String>,
+    pub named_addresses: IndexMap<String, String>,
+}
+
+impl ManifestFragment {
+    pub fn builder() -> ManifestFragmentBuilder {
+        ManifestFragmentBuilder::new()
+    }
+
+    pub fn inputs(&self) -> ManifestFragmentInterface {
+        ManifestFragmentInterface {
+            buckets: self.input_buckets.keys().cloned().collect(),
+            proofs: self.input_proofs.keys().cloned().collect(),
+            named_addresses: self.input_named_addresses.keys().cloned().collect(),
+        }
+    }
+
+    pub fn outputs(&self) -> ManifestFragmentInterface {
+        ManifestFragmentInterface {
+            buckets: self.output_buckets.keys().cloned().collect(),
+            proofs: self.output_proofs.keys().cloned().collect(),
+            named_addresses: self.output_named_addresses.keys().cloned().collect(),
+        }
+    }
+
+    pub fn instructions(&self) -> &[InstructionV1] {
+        &self.instructions
+    }
+
+    pub fn blobs(&self) -> &BTreeMap<Hash, Vec<u8>> {
+        &self.blobs
+    }
+
+    /// Rewrites the fragment's instructions against the parent's registrar, registering the
+    /// objects they create and marking those they consume.
+    ///
+    /// The caller is responsible for adding the returned instructions (and the fragment's
+    /// blobs) to the parent manifest.
+    pub(crate) fn inline(
+        &self,
+        registrar: &ManifestNameRegistrar,
+        bindings: ManifestFragmentBindings,
+    ) -> (Vec<InstructionV1>, ManifestFragmentOutputs) {
+        let lookup = registrar.name_lookup();
+        let mut buckets = index_map_new();
+        let mut proofs = index_map_new();
+        let mut address_reservations = index_map_new();
+        let mut named_addresses = index_map_new();
+
+        // Bind the inputs to objects in the parent manifest
+        check_bindings("bucket", &self.input_buckets, &bindings.buckets);
+        for (name, bucket) in &self.input_buckets {
+            buckets.insert(*bucket, lookup.bucket(&bindings.buckets[name]));
+        }
+        check_bindings("proof", &self.input_proofs, &bindings.proofs);
+        for (name, proof) in &self.input_proofs {
+            proofs.insert(*proof, lookup.proof(&bindings.proofs[name]));
+        }
+        check_bindings(
+            "named address",
+            &self.input_named_addresses,
+            &bindings.named_addresses,
+        );
+        for (name, address_id) in &self.input_named_addresses {
+            named_addresses.insert(
+                *address_id,
+                lookup.named_address_id(&bindings.named_addresses[name]),
+            );
+        }
+
+        // Reserve the names chosen for outputs, so that generated names can't take them
+        check_output_bindings("bucket", &self.output_buckets, &bindings.output_buckets);
+        check_output_bindings("proof", &self.output_proofs, &bindings.output_proofs);
+        check_output_bindings(
+            "named address",
+            &self.output_named_addresses,
+            &bindings.output_named_addresses,
+        );
+        let mut new_output_buckets: IndexMap<_, _> = bindings
+            .output_buckets
+            .iter()
+            .map(|(name, new_name)| {
+                let new_bucket = registrar.new_bucket(new_name);
+                (self.output_buckets[name], (new_name.clone(), new_bucket))
+            })
+            .collect();
+        let mut new_output_proofs: IndexMap<_, _> = bindings
+            .output_proofs
+            .iter()
+            .map(|(name, new_name)| {
+                let new_proof = registrar.new_proof(new_name);
+                (self.output_proofs[name], (new_name.clone(), new_proof))
+            })
+            .collect();
+        let mut new_output_named_addresses: IndexMap<_, _> = bindings
+            .output_named_addresses
+            .iter()
+            .map(|(name, new_name)| {
+                let new_address = registrar.new_named_address(new_name);
+                (
+                    self.output_named_addresses[name],
+                    (new_name.clone(), new_address),
+                )
+            })
+            .collect();
+
+        // The ids of objects created by the fragment's instructions, in order of creation
+        let mut created_buckets = (0..self.object_names.bucket_names.len() as u32)
+            .map(ManifestBucket)
+            .filter(|bucket| !buckets.contains_key(bucket))
+            .collect::<Vec<_>>()
+            .into_iter();
+        let mut created_proofs = (0..self.object_names.proof_names.len() as u32)
+            .map(ManifestProof)
+            .filter(|proof| !proofs.contains_key(proof))
+            .collect::<Vec<_>>()
+            .into_iter();
+        let mut created_address_reservations = (0
+            ..self.object_names.address_reservation_names.len() as u32)
+            .map(ManifestAddressReservation);
+        let mut created_named_addresses = (0..self.object_names.address_names.len() as u32)
+            .filter(|address_id| !named_addresses.contains_key(address_id))
+            .collect::<Vec<_>>()
+            .into_iter();
+        let mut new_names = ManifestFragmentOutputs::default();
+
+        let mut instructions = Vec::new();
+        for instruction in &self.instructions {
+            instructions.push(remap_instruction(instruction, |value| match value {
+                ManifestCustomValue::Bucket(bucket) => *bucket = buckets[&*bucket],
+                ManifestCustomValue::Proof(proof) => *proof = proofs[&*proof],
+                ManifestCustomValue::AddressReservation(reservation) => {
+                    *reservation = address_reservations[&*reservation]
+                }
+                ManifestCustomValue::Address(ManifestAddress::Named(address_id)) => {
+                    *address_id = named_addresses[&*address_id]
+                }
+                _ => {}
+            }));
+
+            match instruction {
+                InstructionV1::TakeAllFromWorktop { .. }
+                | InstructionV1::TakeFromWorktop { .. }
+                | InstructionV1::TakeNonFungiblesFromWorktop { .. } => {
+                    let bucket = created_buckets.next().expect("Bucket ids out of sync");
+                    let (name, new_bucket) =
+                        new_output_buckets.remove(&bucket).unwrap_or_else(|| {
+                            let name = registrar.new_collision_free_bucket_name(
+                                self.object_name(&self.object_names.bucket_names, &bucket),
+                            );
+                            let new_bucket = registrar.new_bucket(&name);
+                            (name, new_bucket)
+                        });
+                    registrar.register_bucket(new_bucket);
+                    buckets.insert(bucket, lookup.bucket(&name));
+                    new_names.buckets.insert(
+                        self.object_name(&self.object_names.bucket_names, &bucket),
+                        name,
+                    );
+                }
+                InstructionV1::PopFromAuthZone { .. }
+                | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+                | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+                | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
+                | InstructionV1::CreateProofFromBucketOfAmount { .. }
+                | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+                | InstructionV1::CreateProofFromBucketOfAll { .. }
+                | InstructionV1::CloneProof { .. } => {
+                    let proof = created_proofs.next().expect("Proof ids out of sync");
+                    let (name, new_proof) = new_output_proofs.remove(&proof).unwrap_or_else(|| {
+                        let name = registrar.new_collision_free_proof_name(
+                            self.object_name(&self.object_names.proof_names, &proof),
+                        );
+                        let new_proof = registrar.new_proof(&name);
+                        (name, new_proof)
+                    });
+                    registrar.register_proof(new_proof);
+                    proofs.insert(proof, lookup.proof(&name));
+                    new_names.proofs.insert(
+                        self.object_name(&self.object_names.proof_names, &proof),
+                        name,
+                    );
+                }
+                InstructionV1::AllocateGlobalAddress { .. } => {
+                    let reservation = created_address_reservations
+                        .next()
+                        .expect("Address reservation ids out of sync");
+                    let name =
+                        registrar.new_collision_free_address_reservation_name(self.object_name(
+                            &self.object_names.address_reservation_names,
+                            &reservation,
+                        ));
+                    registrar
+                        .register_address_reservation(registrar.new_address_reservation(&name));
+                    address_reservations.insert(reservation, lookup.address_reservation(&name));
+
+                    let address_id = created_named_addresses
+                        .next()
+                        .expect("Named address ids out of sync");
+                    let (name, new_address) = new_output_named_addresses
+                        .remove(&address_id)
+                        .unwrap_or_else(|| {
+                            let name = registrar.new_collision_free_address_name(
+                                self.object_name(&self.object_names.address_names, &address_id),
+                            );
+                            let new_address = registrar.new_named_address(&name);
+                            (name, new_address)
+                        });
+                    registrar.register_named_address(new_address);
+                    named_addresses.insert(address_id, lookup.named_address_id(&name));
+                    new_names.named_addresses.insert(
+                        self.object_name(&self.object_names.address_names, &address_id),
+                        name,
+                    );
+                }
+                InstructionV1::DropAllProofs | InstructionV1::DropNamedProofs => {
+                    registrar.consume_all_proofs();
+                }
+                _ => {}
+            }
+        }
+
+        // Anything the fragment consumed, including its inputs, is now consumed in the parent
+        for bucket in &self.consumed_buckets {
+            registrar.consume_bucket(buckets[bucket]);
+        }
+        for proof in &self.consumed_proofs {
+            registrar.consume_proof(proofs[proof]);
+        }
+        for reservation in &self.consumed_address_reservations {
+            registrar.consume_address_reservation(address_reservations[reservation]);
+        }
+
+        let outputs = ManifestFragmentOutputs {
+            buckets: self
+                .output_buckets
+                .keys()
+                .map(|name| (name.clone(), new_names.buckets[name].clone()))
+                .collect(),
+            proofs: self
+                .output_proofs
+                .keys()
+                .map(|name| (name.clone(), new_names.proofs[name].clone()))
+                .collect(),
+            named_addresses: self
+                .output_named_addresses
+                .keys()
+                .map(|name| (name.clone(), new_names.named_addresses[name].clone()))
+                .collect(),
+        };
+
+        (instructions, outputs)
+    }
+}
+
+impl ManifestFragment {
+    fn object_name<K: core::hash::Hash + Eq>(
+        &self,
+        names: &NonIterMap<K, String>,
+        id: &K,
+    ) -> String {
+        names
+            .get(id)
+            .expect("Fragment object names are inconsistent")
+            .clone()
+    }
+}
+
+fn check_bindings<T>(
+    kind: &str,
+    inputs: &IndexMap<String, T>,
+    bindings: &IndexMap<String, String>,
+) {
+    for name in inputs.keys() {
+        if !bindings.contains_key(name) {
+            panic!("Fragment input {kind} \"{name}\" was not bound to a {kind} in the manifest");
+        }
+    }
+    for name in bindings.keys() {
+        if !inputs.contains_key(name) {
+            panic!("Fragment has no input {kind} named \"{name}\"");
+        }
+    }
+}
+
+fn check_output_bindings<T>(
+    kind: &str,
+    outputs: &IndexMap<String, T>,
+    bindings: &IndexMap<String, String>,
+) {
+    for name in bindings.keys() {
+        if !outputs.contains_key(name) {
+            panic!("Fragment has no output {kind} named \"{name}\"");
+        }
+    }
+}
+
+fn remap_instruction(
+    instruction: &InstructionV1,
+    mut remap: impl FnMut(&mut ManifestCustomValue),
+) -> InstructionV1 {
+    let mut value: ManifestValue = manifest_decode(&manifest_encode(instruction).unwrap()).unwrap();
+    remap_custom_values(&mut value, &mut remap);
+    manifest_decode(&manifest_encode(&value).unwrap()).unwrap()
+}
+
+fn remap_custom_values(
+    value: &mut ManifestValue,
+    remap: &mut impl FnMut(&mut ManifestCustomValue),
+) {
+    match value {
+        ManifestValue::Enum { fields, .. } | ManifestValue::Tuple { fields } => {
+            for field in fields {
+                remap_custom_values(field, remap);
+            }
+        }
+        ManifestValue::Array { elements, .. } => {
+            for element in elements {
+                remap_custom_values(element, remap);
+            }
+        }
+        ManifestValue::Map { entries, .. } => {
+            for (key, value) in entries {
+                remap_custom_values(key, remap);
+                remap_custom_values(value, remap);
+            }
+        }
+        ManifestValue::Custom { value } => remap(value),
+        _ => {}
+    }
+}
+
+impl ManifestFragmentBuilder {
+    pub fn new() -> Self {
+        Self {
+            builder: ManifestBuilder::new(),
+            input_buckets: index_map_new(),
+            input_proofs: index_map_new(),
+            input_named_addresses: index_map_new(),
+            outputs: ManifestFragmentInterface::default(),
+        }
+    }
+
+    /// Declares a bucket which the fragment expects to be given by the parent manifest.
+    pub fn input_bucket(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        let registrar = self.builder.registrar();
+        registrar.register_bucket(registrar.new_bucket(&name));
+        let bucket = self.builder.bucket(&name);
+        self.input_buckets.insert(name, bucket);
+        self
+    }
+
+    /// Declares a proof which the fragment expects to be given by the parent manifest.
+    pub fn input_proof(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        let registrar = self.builder.registrar();
+        registrar.register_proof(registrar.new_proof(&name));
+        let proof = self.builder.proof(&name);
+        self.input_proofs.insert(name, proof);
+        self
+    }
+
+    /// Declares a named address which the fragment expects to be given by the parent manifest.
+    pub fn input_named_address(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        let registrar = self.builder.registrar();
+        registrar.register_named_address(registrar.new_named_address(&name));
+        let address_id = self.builder.name_lookup().named_address_id(&name);
+        self.input_named_addresses.insert(name, address_id);
+        self
+    }
+
+    /// Adds instructions to the fragment. The inputs can be used by name, as if they had been
+    /// created by earlier instructions.
+    pub fn instructions(mut self, next: impl FnOnce(ManifestBuilder) -> ManifestBuilder) -> Self {
+        self.builder = next(self.builder);
+        self
+    }
+
+    /// Declares a bucket created by the fragment which the parent manifest can use afterwards.
+    pub fn output_bucket(mut self, name: impl Into<String>) -> Self {
+        self.outputs.buckets.push(name.into());
+        self
+    }
+
+    /// Declares a proof created by the fragment which the parent manifest can use afterwards.
+    pub fn output_proof(mut self, name: impl Into<String>) -> Self {
+        self.outputs.proofs.push(name.into());
+        self
+    }
+
+    /// Declares a named address created by the fragment which the parent manifest can use
+    /// afterwards.
+    pub fn output_named_address(mut self, name: impl Into<String>) -> Self {
+        self.outputs.named_addresses.push(name.into());
+        self
+    }
+
+    pub fn build(self) -> ManifestFragment {
+        let Self {
+            builder,
+            input_buckets,
+            input_proofs,
+            input_named_addresses,
+            outputs,
+        } = self;
+        let lookup = builder.name_lookup();
+        let object_names = builder.object_names();
+
+        let passed_through = outputs
+            .buckets
+            .iter()
+            .filter(|name| input_buckets.contains_key(*name))
+            .chain(
+                outputs
+                    .proofs
+                    .iter()
+                    .filter(|name| input_proofs.contains_key(*name)),
+            )
+            .chain(
+                outputs
+                    .named_addresses
+                    .iter()
+                    .filter(|name| input_named_addresses.contains_key(*name)),
+            )
+            .next();
+        if let Some(name) = passed_through {
+            panic!("\"{name}\" cannot be both an input and an output of a fragment");
+        }
+
+        let consumed_buckets = (0..object_names.bucket_names.len() as u32)
+            .map(ManifestBucket)
+            .filter(|bucket| {
+                lookup.is_bucket_consumed(&object_names.bucket_names.get(bucket).unwrap())
+            })
+            .collect();
+        let consumed_proofs = (0..object_names.proof_names.len() as u32)
+            .map(ManifestProof)
+            .filter(|proof| lookup.is_proof_consumed(&object_names.proof_names.get(proof).unwrap()))
+            .collect();
+        let consumed_address_reservations = (0..object_names.address_reservation_names.len()
+            as u32)
+            .map(ManifestAddressReservation)
+            .filter(|reservation| {
+                lookup.is_address_reservation_consumed(
+                    &object_names
+                        .address_reservation_names
+                        .get(reservation)
+                        .unwrap(),
+                )
+            })
+            .collect();
+
+        // NB - resolving an output panics if it was never created, or has been consumed
+        let output_buckets = resolve_all(&outputs.buckets, |name| lookup.bucket(name));
+        let output_proofs = resolve_all(&outputs.proofs, |name| lookup.proof(name));
+        let output_named_addresses = resolve_all(&outputs.named_addresses, |name| {
+            lookup.named_address_id(name)
+        });
+
+        let manifest = builder.build();
+        ManifestFragment {
+            instructions: manifest.instructions,
+            blobs: manifest.blobs,
+            object_names,
+            input_buckets,
+            input_proofs,
+            input_named_addresses,
+            output_buckets,
+            output_proofs,
+            output_named_addresses,
+            consumed_buckets,
+            consumed_proofs,
+            consumed_address_reservations,
+        }
+    }
+}
+
+fn resolve_all<T>(names: &[String], resolve: impl Fn(&str) -> T) -> IndexMap<String, T> {
+    names
+        .iter()
+        .map(|name| (name.clone(), resolve(name)))
+        .collect()
+}
+
+impl ManifestFragmentBindings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds the fragment's input bucket to a bucket in the parent manifest.
+    pub fn bucket(mut self, input: impl Into<String>, bucket: impl Into<String>) -> Self {
+        self.buckets.insert(input.into(), bucket.into());
+        self
+    }
+
+    /// Binds the fragment's input proof to a proof in the parent manifest.
+    pub fn proof(mut self, input: impl Into<String>, proof: impl Into<String>) -> Self {
+        self.proofs.insert(input.into(), proof.into());
+        self
+    }
+
+    /// Binds the fragment's input named address to a named address in the parent manifest.
+    pub fn named_address(mut self, input: impl Into<String>, address: impl Into<String>) -> Self {
+        self.named_addresses.insert(input.into(), address.into());
+        self
+    }
+
+    /// Names the fragment's output bucket in the parent manifest.
+    pub fn output_bucket(mut self, output: impl Into<String>, name: impl Into<String>) -> Self {
+        self.output_buckets.insert(output.into(), name.into());
+        self
+    }
+
+    /// Names the fragment's output proof in the parent manifest.
+    pub fn output_proof(mut self, output: impl Into<String>, name: impl Into<String>) -> Self {
+        self.output_proofs.insert(output.into(), name.into());
+        self
+    }
+
+    /// Names the fragment's output named address in the parent manifest.
+    pub fn output_named_address(
+        mut self,
+        output: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.output_named_addresses
+            .insert(output.into(), name.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(seed: u8) -> ComponentAddress {
+        ComponentAddress::virtual_account_from_public_key(&Ed25519PublicKey(
+            [seed; Ed25519PublicKey::LENGTH],
+        ))
+    }
+
+    fn transfer_fragment() -> ManifestFragment {
+        ManifestFragment::builder()
+            .input_bucket("payment")
+            .instructions(|builder| {
+                builder
+                    .return_to_worktop("payment")
+                    .take_all_from_worktop(XRD, "bucket")
+                    .create_proof_from_bucket_of_all("bucket", "proof")
+                    .drop_proof("proof")
+            })
+            .output_bucket("bucket")
+            .build()
+    }
+
+    #[test]
+    fn fragment_is_inlined_with_collision_free_names() {
+        let fragment = transfer_fragment();
+
+        let builder = ManifestBuilder::new()
+            .withdraw_from_account(account(0), XRD, dec!(10))
+            .take_all_from_worktop(XRD, "bucket")
+            .create_proof_from_bucket_of_all("bucket", "proof")
+            .add_fragment(
+                &fragment,
+                ManifestFragmentBindings::new().bucket("payment", "bucket"),
+            );
+        let (builder, outputs) = builder.add_fragment_advanced(
+            &fragment,
+            ManifestFragmentBindings::new()
+                .bucket("payment", "bucket_2")
+                .output_bucket("bucket", "final"),
+        );
+        let manifest = builder
+            .try_deposit_or_abort(account(1), None, "final")
+            .drop_proof("proof")
+            .build();
+
+        assert_eq!(
+            outputs.buckets,
+            indexmap!("bucket".to_string() => "final".to_string(),)
+        );
+        assert_eq!(
+            manifest.instructions[3..11],
+            [
+                InstructionV1::ReturnToWorktop {
+                    bucket_id: ManifestBucket(0)
+                },
+                InstructionV1::TakeAllFromWorktop {
+                    resource_address: XRD
+                },
+                InstructionV1::CreateProofFromBucketOfAll {
+                    bucket_id: ManifestBucket(1)
+                },
+                InstructionV1::DropProof {
+                    proof_id: ManifestProof(1)
+                },
+                InstructionV1::ReturnToWorktop {
+                    bucket_id: ManifestBucket(1)
+                },
+                InstructionV1::TakeAllFromWorktop {
+                    resource_address: XRD
+                },
+                InstructionV1::CreateProofFromBucketOfAll {
+                    bucket_id: ManifestBucket(2)
+                },
+                InstructionV1::DropProof {
+                    proof_id: ManifestProof(2)
+                },
+            ]
+        );
+        assert_eq!(
+            manifest.instructions.last(),
+            Some(&InstructionV1::DropProof {
+                proof_id: ManifestProof(0)
+            })
+        );
+    }
+
+    #[test]
+    fn named_addresses_and_call_arguments_are_remapped() {
+        let fragment = ManifestFragment::builder()
+            .input_named_address("package")
+            .input_bucket("payment")
+            .instructions(|builder| {
+                let package = builder.name_lookup().named_address_id("package");
+                builder
+                    .allocate_global_address(
+                        RESOURCE_PACKAGE,
+                        FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                        "reservation",
+                        "address",
+                    )
+                    .call_function_with_name_lookup(
+                        DynamicPackageAddress::Named(package),
+                        "Blueprint",
+                        "function",
+                        |lookup| {
+                            (
+                                lookup.bucket("payment"),
+                                lookup.address_reservation("reservation"),
+                            )
+                        },
+                    )
+            })
+            .output_named_address("address")
+            .build();
+
+        let (builder, outputs) = ManifestBuilder::new()
+            .allocate_global_address(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                "other_reservation",
+                "other",
+            )
+            .allocate_global_address(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                "reservation",
+                "package",
+            )
+            .withdraw_from_account(account(0), XRD, dec!(10))
+            .take_all_from_worktop(XRD, "xrd")
+            .add_fragment_advanced(
+                &fragment,
+                ManifestFragmentBindings::new()
+                    .named_address("package", "package")
+                    .bucket("payment", "xrd"),
+            );
+
+        assert_eq!(outputs.named_addresses["address"], "address");
+        assert_eq!(builder.named_address("address"), ManifestAddress::Named(2));
+        assert_eq!(
+            builder.address_reservation("reservation_2"),
+            ManifestAddressReservation(2)
+        );
+        let manifest = builder.build();
+        assert_eq!(
+            manifest.instructions.last(),
+            Some(&InstructionV1::CallFunction {
+                package_address: DynamicPackageAddress::Named(1),
+                blueprint_name: "Blueprint".to_string(),
+                function_name: "function".to_string(),
+                args: (ManifestBucket(0), ManifestAddressReservation(2)).resolve(),
+            })
+        );
+    }
+
+    #[test]
+    fn inputs_consumed_by_fragment_are_consumed_in_parent() {
+        let fragment = ManifestFragment::builder()
+            .input_bucket("to_burn")
+            .instructions(|builder| builder.burn_resource("to_burn"))
+            .build();
+
+        let builder = ManifestBuilder::new()
+            .withdraw_from_account(account(0), XRD, dec!(10))
+            .take_all_from_worktop(XRD, "xrd")
+            .add_fragment(
+                &fragment,
+                ManifestFragmentBindings::new().bucket("to_burn", "xrd"),
+            );
+
+        assert_eq!(fragment.inputs().buckets, vec!["to_burn".to_string()]);
+        assert!(builder.name_lookup().is_bucket_consumed("xrd"));
+        assert_eq!(
+            builder.build().instructions.last(),
+            Some(&InstructionV1::BurnResource {
+                bucket_id: ManifestBucket(0)
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "was not bound")]
+    fn unbound_inputs_are_rejected() {
+        ManifestBuilder::new().add_fragment(&transfer_fragment(), ManifestFragmentBindings::new());
+    }
+}
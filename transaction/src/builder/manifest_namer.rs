@@ -358,6 +358,30 @@ impl ManifestNameLookup {
             ManifestAddress::Named(id) => id,
         }
     }
+
+    pub fn is_bucket_consumed(&self, name: impl AsRef<str>) -> bool {
+        matches!(
+            self.core.borrow().named_buckets.get(name.as_ref()),
+            Some(ManifestObjectState::Consumed)
+        )
+    }
+
+    pub fn is_proof_consumed(&self, name: impl AsRef<str>) -> bool {
+        matches!(
+            self.core.borrow().named_proofs.get(name.as_ref()),
+            Some(ManifestObjectState::Consumed)
+        )
+    }
+
+    pub fn is_address_reservation_consumed(&self, name: impl AsRef<str>) -> bool {
+        matches!(
+            self.core
+                .borrow()
+                .named_address_reservations
+                .get(name.as_ref()),
+            Some(ManifestObjectState::Consumed)
+        )
+    }
 }
 
 impl ManifestNameRegistrar {
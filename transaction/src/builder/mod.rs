@@ -1,7 +1,11 @@
 mod manifest_builder;
+mod manifest_fragment;
 mod manifest_namer;
+mod partially_signed_transaction;
 mod transaction_builder;
 
 pub use manifest_builder::*;
+pub use manifest_fragment::*;
 pub use manifest_namer::*;
+pub use partially_signed_transaction::*;
 pub use transaction_builder::*;
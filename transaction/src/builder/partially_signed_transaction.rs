@@ -0,0 +1,315 @@
+use crate::internal_prelude::*;
+
+/// An intent which is being signed by several parties, each possibly offline or on a
+/// different machine.
+///
+/// It can be passed between the parties with `manifest_encode` / `manifest_decode`. Each
+/// party adds their signature with [`sign`](Self::sign), the copies are combined with
+/// [`merge`](Self::merge), and once no signatures are missing, the transaction can be notarized.
+///
+/// A decoded copy is untrusted, so the signatures it carries are verified again whenever they
+/// are counted or used.
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct PartiallySignedTransactionV1 {
+    pub intent: IntentV1,
+    pub required_signers: IndexSet<PublicKey>,
+    intent_signatures: IndexMap<PublicKey, IntentSignatureV1>,
+}
+
+impl PartiallySignedTransactionV1 {
+    pub fn new(intent: IntentV1, required_signers: impl IntoIterator<Item = PublicKey>) -> Self {
+        Self {
+            intent,
+            required_signers: required_signers.into_iter().collect(),
+            intent_signatures: index_map_new(),
+        }
+    }
+
+    pub fn intent_hash(&self) -> Result<IntentHash, PartiallySignedTransactionError> {
+        Ok(self.intent.prepare()?.intent_hash())
+    }
+
+    pub fn sign<S: Signer>(&mut self, signer: &S) -> Result<(), PartiallySignedTransactionError> {
+        let signature = signer.sign_with_public_key(&self.intent_hash()?);
+        self.add_signature(signature)
+    }
+
+    pub fn sign_external<S: ExternalSigner>(
+        &mut self,
+        signer: &mut S,
+    ) -> Result<(), PartiallySignedTransactionError> {
+        let signature = signer.sign_with_public_key(&self.intent_hash()?)?;
+        self.add_signature(signature)
+    }
+
+    pub fn intent_signatures(&self) -> &IndexMap<PublicKey, IntentSignatureV1> {
+        &self.intent_signatures
+    }
+
+    /// Adds a signature produced elsewhere, after checking that it is a valid signature of the
+    /// intent by one of the required signers.
+    pub fn add_signature(
+        &mut self,
+        signature: SignatureWithPublicKeyV1,
+    ) -> Result<(), PartiallySignedTransactionError> {
+        let intent_hash = self.intent_hash()?.into_hash();
+        let public_key = verify_intent_signature(&intent_hash, &signature)
+            .ok_or(PartiallySignedTransactionError::InvalidSignature)?;
+        if !self.required_signers.contains(&public_key) {
+            return Err(PartiallySignedTransactionError::UnexpectedSigner(
+                public_key,
+            ));
+        }
+        self.intent_signatures
+            .insert(public_key, IntentSignatureV1(signature));
+        Ok(())
+    }
+
+    /// Adds the signatures collected by another party for the same intent.
+    pub fn merge(&mut self, other: &Self) -> Result<(), PartiallySignedTransactionError> {
+        if self.intent != other.intent || self.required_signers != other.required_signers {
+            return Err(PartiallySignedTransactionError::IntentMismatch);
+        }
+        for signature in other.intent_signatures.values() {
+            self.add_signature(signature.0)?;
+        }
+        Ok(())
+    }
+
+    /// The required signers which haven't validly signed yet. A notary which is a signatory
+    /// doesn't need to sign the intent separately.
+    pub fn missing_signers(&self) -> Vec<PublicKey> {
+        let signers = self.valid_signers().unwrap_or_default();
+        let header = &self.intent.header;
+        self.required_signers
+            .iter()
+            .filter(|public_key| !signers.contains(*public_key))
+            .filter(|public_key| {
+                !(header.notary_is_signatory && **public_key == header.notary_public_key)
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn is_fully_signed(&self) -> bool {
+        self.missing_signers().is_empty()
+    }
+
+    pub fn to_signed_intent(&self) -> Result<SignedIntentV1, PartiallySignedTransactionError> {
+        if self.valid_signers()?.len() != self.intent_signatures.len() {
+            return Err(PartiallySignedTransactionError::InvalidSignature);
+        }
+        let missing_signers = self.missing_signers();
+        if !missing_signers.is_empty() {
+            return Err(PartiallySignedTransactionError::MissingSignatures(
+                missing_signers,
+            ));
+        }
+        Ok(SignedIntentV1 {
+            intent: self.intent.clone(),
+            intent_signatures: IntentSignaturesV1 {
+                signatures: self.intent_signatures.values().cloned().collect(),
+            },
+        })
+    }
+
+    /// The required signers whose signature of the intent is valid.
+    fn valid_signers(&self) -> Result<IndexSet<PublicKey>, PartiallySignedTransactionError> {
+        let intent_hash = self.intent_hash()?.into_hash();
+        Ok(self
+            .intent_signatures
+            .iter()
+            .filter(|(public_key, signature)| {
+                self.required_signers.contains(*public_key)
+                    && verify_intent_signature(&intent_hash, &signature.0) == Some(**public_key)
+            })
+            .map(|(public_key, _)| *public_key)
+            .collect())
+    }
+
+    pub fn notarize<S: Signer>(
+        &self,
+        notary: &S,
+    ) -> Result<NotarizedTransactionV1, PartiallySignedTransactionError> {
+        if notary.public_key() != self.intent.header.notary_public_key {
+            return Err(PartiallySignedTransactionError::NotaryMismatch);
+        }
+        let signed_intent = self.to_signed_intent()?;
+        let signed_intent_hash = signed_intent.prepare()?.signed_intent_hash();
+        Ok(NotarizedTransactionV1 {
+            signed_intent,
+            notary_signature: NotarySignatureV1(
+                notary.sign_with_public_key(&signed_intent_hash).signature(),
+            ),
+        })
+    }
+}
+
+/// Returns the public key of the signer, if the signature is a valid signature of the intent.
+fn verify_intent_signature(
+    intent_hash: &Hash,
+    signature: &SignatureWithPublicKeyV1,
+) -> Option<PublicKey> {
+    recover(intent_hash, signature)
+        .filter(|public_key| verify(intent_hash, public_key, &signature.signature()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::{MockSignerTransport, RemoteSigner};
+    use radix_engine_interface::network::NetworkDefinition;
+
+    fn intent(notary: &Secp256k1PrivateKey, notary_is_signatory: bool) -> IntentV1 {
+        let (instructions, blobs) = ManifestBuilder::new()
+            .drop_auth_zone_proofs()
+            .build()
+            .for_intent();
+        IntentV1 {
+            header: TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                nonce: 5,
+                notary_public_key: notary.public_key().into(),
+                notary_is_signatory,
+                tip_percentage: 0,
+            },
+            instructions,
+            blobs,
+            message: MessageV1::None,
+        }
+    }
+
+    #[test]
+    fn signatures_from_several_parties_can_be_merged_and_notarized() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer1 = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let signer2 = Ed25519PrivateKey::from_u64(3).unwrap();
+        let transaction = PartiallySignedTransactionV1::new(
+            intent(&notary, false),
+            [signer1.public_key().into(), signer2.public_key().into()],
+        );
+
+        // Each party signs their own decoded copy
+        let payload = manifest_encode(&transaction).unwrap();
+        let mut copy1: PartiallySignedTransactionV1 = manifest_decode(&payload).unwrap();
+        copy1.sign(&signer1).unwrap();
+        let mut copy2: PartiallySignedTransactionV1 = manifest_decode(&payload).unwrap();
+        copy2.sign(&signer2).unwrap();
+
+        let mut merged = transaction.clone();
+        assert_eq!(
+            merged.notarize(&notary),
+            Err(PartiallySignedTransactionError::MissingSignatures(vec![
+                signer1.public_key().into(),
+                signer2.public_key().into(),
+            ]))
+        );
+        merged.merge(&copy1).unwrap();
+        assert_eq!(
+            merged.missing_signers(),
+            vec![PublicKey::from(signer2.public_key())]
+        );
+        merged.merge(&copy2).unwrap();
+        assert!(merged.is_fully_signed());
+
+        let notarized = merged.notarize(&notary).unwrap();
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let validated = validator
+            .validate(notarized.prepare().unwrap())
+            .expect("Transaction should be valid");
+        assert_eq!(validated.signer_keys.len(), 2);
+    }
+
+    #[test]
+    fn external_signers_can_sign() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer = Ed25519PrivateKey::from_u64(2).unwrap();
+        let mut transaction =
+            PartiallySignedTransactionV1::new(intent(&notary, false), [signer.public_key().into()]);
+        let mut external_signer = RemoteSigner::new(MockSignerTransport::new(
+            Ed25519PrivateKey::from_u64(2).unwrap(),
+        ))
+        .unwrap();
+
+        transaction.sign_external(&mut external_signer).unwrap();
+
+        assert!(transaction.is_fully_signed());
+    }
+
+    #[test]
+    fn notary_as_signatory_does_not_need_to_sign_intent() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let transaction =
+            PartiallySignedTransactionV1::new(intent(&notary, true), [notary.public_key().into()]);
+
+        assert!(transaction.is_fully_signed());
+        assert!(transaction.notarize(&notary).is_ok());
+    }
+
+    #[test]
+    fn signatures_from_unexpected_signers_are_rejected() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let other = Secp256k1PrivateKey::from_u64(3).unwrap();
+        let mut transaction =
+            PartiallySignedTransactionV1::new(intent(&notary, false), [signer.public_key().into()]);
+
+        assert_eq!(
+            transaction.sign(&other),
+            Err(PartiallySignedTransactionError::UnexpectedSigner(
+                other.public_key().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn signatures_of_a_different_intent_are_rejected() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer = Ed25519PrivateKey::from_u64(2).unwrap();
+        let mut transaction =
+            PartiallySignedTransactionV1::new(intent(&notary, false), [signer.public_key().into()]);
+        let mut other_intent = intent(&notary, false);
+        other_intent.header.nonce += 1;
+        let mut other =
+            PartiallySignedTransactionV1::new(other_intent, [signer.public_key().into()]);
+        other.sign(&signer).unwrap();
+
+        assert_eq!(
+            transaction.merge(&other),
+            Err(PartiallySignedTransactionError::IntentMismatch)
+        );
+        assert_eq!(
+            transaction.add_signature(other.intent_signatures[0].0),
+            Err(PartiallySignedTransactionError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn tampered_signatures_are_not_counted() {
+        let notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer = Ed25519PrivateKey::from_u64(2).unwrap();
+        let mut other_intent = intent(&notary, false);
+        other_intent.header.nonce += 1;
+        let mut other =
+            PartiallySignedTransactionV1::new(other_intent, [signer.public_key().into()]);
+        other.sign(&signer).unwrap();
+
+        // As decoded from a copy whose signature was swapped, bypassing `add_signature`
+        let mut transaction =
+            PartiallySignedTransactionV1::new(intent(&notary, false), [signer.public_key().into()]);
+        transaction.intent_signatures = other.intent_signatures.clone();
+        let payload = manifest_encode(&transaction).unwrap();
+        let transaction: PartiallySignedTransactionV1 = manifest_decode(&payload).unwrap();
+
+        assert_eq!(
+            transaction.missing_signers(),
+            vec![PublicKey::from(signer.public_key())]
+        );
+        assert_eq!(
+            transaction.notarize(&notary),
+            Err(PartiallySignedTransactionError::InvalidSignature)
+        );
+    }
+}
@@ -1,5 +1,8 @@
+use crate::builder::PartiallySignedTransactionV1;
+use crate::errors::{ExternalSignerError, PartiallySignedTransactionError};
 use crate::model::*;
-use crate::signing::Signer;
+use crate::signing::{ExternalSigner, Signer};
+use radix_engine_common::crypto::PublicKey;
 
 pub struct TransactionBuilder {
     manifest: Option<TransactionManifestV1>,
@@ -43,6 +46,19 @@ impl TransactionBuilder {
         self
     }
 
+    /// Signs the intent with a signer whose key is held outside of this process. Unlike
+    /// [`sign`](Self::sign), this can fail, eg if the signer is unreachable or declines.
+    pub fn sign_external<S: ExternalSigner>(
+        mut self,
+        signer: &mut S,
+    ) -> Result<Self, ExternalSignerError> {
+        let intent = self.transaction_intent();
+        let prepared = intent.prepare().expect("Intent could be prepared");
+        self.intent_signatures
+            .push(signer.sign_with_public_key(&prepared.intent_hash())?);
+        Ok(self)
+    }
+
     pub fn multi_sign<S: Signer>(mut self, signers: &[&S]) -> Self {
         let intent = self.transaction_intent();
         let prepared = intent.prepare().expect("Intent could be prepared");
@@ -85,6 +101,20 @@ impl TransactionBuilder {
         }
     }
 
+    /// Starts a [`PartiallySignedTransactionV1`] from the intent and any signatures collected
+    /// so far, so that the remaining signers can sign it elsewhere.
+    pub fn build_partially_signed(
+        &self,
+        required_signers: impl IntoIterator<Item = PublicKey>,
+    ) -> Result<PartiallySignedTransactionV1, PartiallySignedTransactionError> {
+        let mut transaction =
+            PartiallySignedTransactionV1::new(self.transaction_intent(), required_signers);
+        for signature in &self.intent_signatures {
+            transaction.add_signature(*signature)?;
+        }
+        Ok(transaction)
+    }
+
     fn transaction_intent(&self) -> IntentV1 {
         let (instructions, blobs) = self
             .manifest
@@ -97,3 +97,113 @@ pub enum InvalidMessageError {
         curve_type: CurveType,
     },
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageEncryptionError {
+    NoDecryptors,
+    InvalidDecryptorPublicKey(PublicKey),
+    EncodeError(EncodeError),
+}
+
+impl From<EncodeError> for MessageEncryptionError {
+    fn from(value: EncodeError) -> Self {
+        Self::EncodeError(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageDecryptionError {
+    NotADecryptor,
+    InvalidEphemeralPublicKey,
+    KeyUnwrapFailed,
+    PayloadDecryptionFailed,
+    DecodeError(DecodeError),
+}
+
+impl From<DecodeError> for MessageDecryptionError {
+    fn from(value: DecodeError) -> Self {
+        Self::DecodeError(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartiallySignedTransactionError {
+    PrepareError(PrepareError),
+    InvalidSignature,
+    UnexpectedSigner(PublicKey),
+    IntentMismatch,
+    MissingSignatures(Vec<PublicKey>),
+    NotaryMismatch,
+    ExternalSignerError(ExternalSignerError),
+}
+
+impl From<PrepareError> for PartiallySignedTransactionError {
+    fn from(value: PrepareError) -> Self {
+        Self::PrepareError(value)
+    }
+}
+
+impl From<ExternalSignerError> for PartiallySignedTransactionError {
+    fn from(value: ExternalSignerError) -> Self {
+        Self::ExternalSignerError(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalSignerError {
+    TransportError(String),
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+    UnexpectedResponse,
+    Rejected(String),
+    InvalidSignature,
+}
+
+impl From<EncodeError> for ExternalSignerError {
+    fn from(value: EncodeError) -> Self {
+        Self::EncodeError(value)
+    }
+}
+
+impl From<DecodeError> for ExternalSignerError {
+    fn from(value: DecodeError) -> Self {
+        Self::DecodeError(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ExternalSignerError {
+    fn from(value: std::io::Error) -> Self {
+        Self::TransportError(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeystoreError {
+    /// The password is wrong, or the key file has been tampered with.
+    DecryptionFailed,
+    InvalidPrivateKey,
+    KeyNotFound(PublicKey),
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+    IoError(String),
+}
+
+impl From<EncodeError> for KeystoreError {
+    fn from(value: EncodeError) -> Self {
+        Self::EncodeError(value)
+    }
+}
+
+impl From<DecodeError> for KeystoreError {
+    fn from(value: DecodeError) -> Self {
+        Self::DecodeError(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for KeystoreError {
+    fn from(value: std::io::Error) -> Self {
+        Self::IoError(value.to_string())
+    }
+}
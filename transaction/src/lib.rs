@@ -19,9 +19,10 @@ pub mod prelude {
     // Exports from this crate
     pub use crate::builder::*;
     pub use crate::model::*;
+    pub use crate::signing::bls12381::*;
     pub use crate::signing::ed25519::*;
     pub use crate::signing::secp256k1::*;
-    pub use crate::signing::{PrivateKey, Signer};
+    pub use crate::signing::{ExternalSigner, PrivateKey, Signer};
 }
 
 // Extra things which this crate wants which upstream crates likely don't
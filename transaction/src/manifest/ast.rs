@@ -1,3 +1,4 @@
+use crate::manifest::lexer::Span;
 use radix_engine_interface::data::manifest::{ManifestCustomValueKind, ManifestValueKind};
 use strum::{EnumCount, EnumDiscriminants, FromRepr};
 
@@ -255,6 +256,16 @@ pub enum Instruction {
     },
 }
 
+/// An instruction, together with the span of source text it was parsed from (from the
+/// instruction identifier up to and including the terminating semicolon).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionWithSpan {
+    pub instruction: Instruction,
+    pub span: Span,
+    /// The string literals of the instruction, with the span of each, in source order.
+    pub string_literals: Vec<(String, Span)>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ValueKind {
     // ==============
@@ -0,0 +1,557 @@
+use crate::errors::ManifestIdValidationError;
+use crate::manifest::ast;
+use crate::manifest::compiler::CompileError;
+use crate::manifest::generator::{GeneratorError, GeneratorErrorKind, NameResolverError};
+use crate::manifest::lexer::{LexerError, Position, Span, TokenKind};
+use crate::manifest::parser::{ParserError, ParserErrorKind, TokenType};
+
+/// Renders a [`CompileError`] as a human-readable diagnostic against the manifest source `s`
+/// it was produced from, for example:
+///
+/// ```text
+/// error: unknown named bucket `bucket3`, did you mean `bucket2`?
+///  --> 4:26
+///   |
+/// 4 | RETURN_TO_WORKTOP Bucket("bucket3");
+///   |                          ^^^^^^^^^
+/// ```
+pub fn compile_error_diagnostics(s: &str, err: &CompileError) -> String {
+    let source: Vec<char> = s.chars().collect();
+    let diagnostic = match err {
+        CompileError::LexerError(error) => describe_lexer_error(&source, error),
+        CompileError::ParserError(error) => describe_parser_error(error),
+        CompileError::GeneratorError(error) => describe_generator_error(error),
+    };
+    diagnostic.render(&source)
+}
+
+struct Diagnostic {
+    message: String,
+    span: Span,
+    hint: Option<String>,
+}
+
+impl Diagnostic {
+    fn new(message: String, span: Span) -> Self {
+        Self {
+            message,
+            span,
+            hint: None,
+        }
+    }
+
+    fn with_hint(mut self, hint: &str) -> Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    fn render(&self, source: &[char]) -> String {
+        let lines: Vec<String> = source
+            .split(|c| *c == '\n')
+            .map(|line| line.iter().filter(|c| **c != '\r').collect())
+            .collect();
+        let start = self.span.start;
+        // The span end is exclusive, so a span ending at the start of a line doesn't cover it
+        let last_line_number = if self.span.end.line_number > start.line_number
+            && self.span.end.line_char_index == 0
+        {
+            self.span.end.line_number - 1
+        } else {
+            self.span.end.line_number
+        }
+        .min(lines.len());
+        let gutter_width = last_line_number.to_string().len();
+        let gutter = " ".repeat(gutter_width);
+
+        let mut output = Vec::new();
+        output.push(format!("error: {}", self.message));
+        output.push(format!(
+            "{} --> {}:{}",
+            gutter,
+            start.line_number,
+            start.line_char_index + 1
+        ));
+        output.push(format!("{} |", gutter));
+        for line_number in start.line_number..=last_line_number {
+            let line: Vec<char> = lines[line_number - 1].chars().collect();
+            let from = if line_number == start.line_number {
+                start.line_char_index.min(line.len())
+            } else {
+                // Don't underline the indentation of continuation lines
+                line.iter().position(|c| !c.is_whitespace()).unwrap_or(0)
+            };
+            let to = if line_number == self.span.end.line_number {
+                self.span.end.line_char_index.min(line.len())
+            } else {
+                line.len()
+            };
+            // Keep tabs in the padding, so that the carets line up with the source line
+            let padding: String = line[..from]
+                .iter()
+                .map(|c| if *c == '\t' { '\t' } else { ' ' })
+                .collect();
+            let carets = "^".repeat(to.saturating_sub(from).max(1));
+            output.push(format!(
+                "{:>width$} | {}",
+                line_number,
+                line.iter().collect::<String>(),
+                width = gutter_width
+            ));
+            output.push(format!("{} | {}{}", gutter, padding, carets));
+        }
+        if let Some(hint) = &self.hint {
+            output.push(format!("{} = hint: {}", gutter, hint));
+        }
+        output.join("\n")
+    }
+}
+
+fn describe_lexer_error(source: &[char], error: &LexerError) -> Diagnostic {
+    match error {
+        LexerError::UnexpectedEof => Diagnostic::new(
+            "unexpected end of file".to_string(),
+            end_of_source_span(source),
+        )
+        .with_hint("check for an unterminated string literal or an incomplete number"),
+        LexerError::UnexpectedChar(c, position) => Diagnostic::new(
+            format!("unexpected character {:?}", c),
+            char_span(*position, 1),
+        ),
+        LexerError::InvalidInteger(literal, position) => {
+            // The position is the end of the integer literal
+            let length = literal.chars().count().min(position.line_char_index);
+            let start = Position {
+                full_index: position.full_index - length,
+                line_number: position.line_number,
+                line_char_index: position.line_char_index - length,
+            };
+            Diagnostic::new(
+                format!("invalid integer `{}`", literal),
+                Span {
+                    start,
+                    end: *position,
+                },
+            )
+            .with_hint("the value may be out of range for its type suffix")
+        }
+        LexerError::InvalidUnicode(code_point, position) => Diagnostic::new(
+            format!("invalid unicode code point `\\u{:04X}`", code_point),
+            char_span(*position, 0),
+        ),
+        LexerError::UnknownIdentifier(identifier, position) => Diagnostic::new(
+            format!("unknown identifier `{}`", identifier),
+            char_span(*position, 0),
+        ),
+    }
+}
+
+fn describe_parser_error(error: &ParserError) -> Diagnostic {
+    let span = error.span;
+    match &error.error_kind {
+        ParserErrorKind::UnexpectedEof => {
+            Diagnostic::new("unexpected end of file".to_string(), span)
+                .with_hint("the last instruction is incomplete or is missing a `;`")
+        }
+        ParserErrorKind::UnexpectedToken { expected, actual } => {
+            let diagnostic = Diagnostic::new(
+                format!(
+                    "expected {}, found {}",
+                    describe_token_type(expected),
+                    describe_token_kind(&actual.kind)
+                ),
+                span,
+            );
+            match expected {
+                TokenType::Exact(TokenKind::Semicolon) => {
+                    diagnostic.with_hint("each instruction must be terminated with a `;`")
+                }
+                TokenType::Instruction => diagnostic
+                    .with_hint("instruction names are upper snake case, e.g. `CALL_METHOD`"),
+                _ => diagnostic,
+            }
+        }
+        ParserErrorKind::InvalidNumberOfValues { expected, actual } => Diagnostic::new(
+            format!("expected {} value(s), found {}", expected, actual),
+            span,
+        ),
+        ParserErrorKind::InvalidNumberOfTypes { expected, actual } => Diagnostic::new(
+            format!("expected {} type argument(s), found {}", expected, actual),
+            span,
+        ),
+        ParserErrorKind::InvalidHex(hex) => {
+            Diagnostic::new(format!("invalid hex string `{}`", hex), span)
+        }
+        ParserErrorKind::UnknownEnumDiscriminator(discriminator) => Diagnostic::new(
+            format!("unknown enum discriminator `{}`", discriminator),
+            span,
+        )
+        .with_hint("use a `u8` discriminator, or a known name such as `Metadata::String`"),
+        ParserErrorKind::MaxDepthExceeded(max_depth) => Diagnostic::new(
+            format!(
+                "value is nested deeper than the maximum depth of {}",
+                max_depth
+            ),
+            span,
+        ),
+    }
+}
+
+fn describe_generator_error(error: &GeneratorError) -> Diagnostic {
+    let message = match &error.error_kind {
+        GeneratorErrorKind::NameResolverError(name_error) => {
+            let message = match name_error {
+                NameResolverError::UndefinedBucket(name) => {
+                    format!("unknown named bucket `{}`", name)
+                }
+                NameResolverError::UndefinedProof(name) => {
+                    format!("unknown named proof `{}`", name)
+                }
+                NameResolverError::UndefinedAddressReservation(name) => {
+                    format!("unknown named address reservation `{}`", name)
+                }
+                NameResolverError::UndefinedNamedAddress(name) => {
+                    format!("unknown named address `{}`", name)
+                }
+                NameResolverError::NamedAlreadyDefined(name) => {
+                    format!("the name `{}` is already defined", name)
+                }
+            };
+            match &error.suggestion {
+                Some(suggestion) => format!("{}, did you mean `{}`?", message, suggestion),
+                None => message,
+            }
+        }
+        GeneratorErrorKind::InvalidAstType {
+            expected_type,
+            actual,
+        } => format!(
+            "expected value kind `{:?}`, found `{:?}`",
+            expected_type, actual
+        ),
+        GeneratorErrorKind::InvalidAstValue {
+            expected_type,
+            actual,
+        } => format!(
+            "expected a value of kind {}, found `{:?}`",
+            describe_value_kinds(expected_type),
+            actual
+        ),
+        GeneratorErrorKind::UnexpectedValue {
+            expected_type,
+            actual,
+        } => format!(
+            "expected a value of kind `{:?}`, found `{:?}`",
+            expected_type, actual
+        ),
+        GeneratorErrorKind::InvalidPackageAddress(value) => {
+            format!("invalid package address `{}`", value)
+        }
+        GeneratorErrorKind::InvalidComponentAddress(value) => {
+            format!("invalid component address `{}`", value)
+        }
+        GeneratorErrorKind::InvalidResourceAddress(value) => {
+            format!("invalid resource address `{}`", value)
+        }
+        GeneratorErrorKind::InvalidGlobalAddress(value) => {
+            format!("invalid global address `{}`", value)
+        }
+        GeneratorErrorKind::InvalidInternalAddress(value) => {
+            format!("invalid internal address `{}`", value)
+        }
+        GeneratorErrorKind::InvalidDecimal(value) => {
+            format!("invalid decimal `{}`", value)
+        }
+        GeneratorErrorKind::InvalidPreciseDecimal(value) => {
+            format!("invalid precise decimal `{}`", value)
+        }
+        GeneratorErrorKind::InvalidHash(value) => {
+            format!("invalid hash `{}`", value)
+        }
+        GeneratorErrorKind::InvalidNodeId(value) => {
+            format!("invalid node id `{}`", value)
+        }
+        GeneratorErrorKind::InvalidVaultId(value) => {
+            format!("invalid vault id `{}`", value)
+        }
+        GeneratorErrorKind::InvalidNonFungibleLocalId(value) => {
+            format!("invalid non-fungible local id `{}`", value)
+        }
+        GeneratorErrorKind::InvalidNonFungibleGlobalId => {
+            "invalid non-fungible global id".to_string()
+        }
+        GeneratorErrorKind::InvalidExpression(value) => {
+            format!("invalid expression `{}`", value)
+        }
+        GeneratorErrorKind::InvalidComponent(value) => {
+            format!("invalid component `{}`", value)
+        }
+        GeneratorErrorKind::InvalidKeyValueStore(value) => {
+            format!("invalid key value store `{}`", value)
+        }
+        GeneratorErrorKind::InvalidBucket(value) => {
+            format!("invalid bucket `{}`", value)
+        }
+        GeneratorErrorKind::InvalidProof(value) => {
+            format!("invalid proof `{}`", value)
+        }
+        GeneratorErrorKind::InvalidVault(value) => {
+            format!("invalid vault `{}`", value)
+        }
+        GeneratorErrorKind::InvalidSecp256k1PublicKey(value) => {
+            format!("invalid Secp256k1 public key `{}`", value)
+        }
+        GeneratorErrorKind::InvalidSecp256k1Signature(value) => {
+            format!("invalid Secp256k1 signature `{}`", value)
+        }
+        GeneratorErrorKind::InvalidEd25519PublicKey(value) => {
+            format!("invalid Ed25519 public key `{}`", value)
+        }
+        GeneratorErrorKind::InvalidEd25519Signature(value) => {
+            format!("invalid Ed25519 signature `{}`", value)
+        }
+        GeneratorErrorKind::InvalidBlobHash(value) => {
+            format!("invalid blob hash `{}`", value)
+        }
+        GeneratorErrorKind::BlobNotFound(value) => {
+            format!("blob `{}` was not provided", value)
+        }
+        GeneratorErrorKind::InvalidBytesHex(value) => {
+            format!("invalid hex bytes `{}`", value)
+        }
+        GeneratorErrorKind::SborEncodeError(error)
+        | GeneratorErrorKind::ArgumentEncodingError(error) => {
+            format!("failed to encode value: {:?}", error)
+        }
+        GeneratorErrorKind::ArgumentDecodingError(error) => {
+            format!("failed to decode arguments: {:?}", error)
+        }
+        GeneratorErrorKind::IdValidationError(error) => describe_id_validation_error(error),
+        GeneratorErrorKind::InvalidLength {
+            value_type,
+            expected_length,
+            actual,
+        } => format!(
+            "invalid length for `{:?}`: expected {}, found {}",
+            value_type, expected_length, actual
+        ),
+    };
+
+    // Where the error relates to a specific string literal of the instruction, such as a name or
+    // an address, point at that rather than at the whole instruction.
+    let diagnostic = Diagnostic::new(message, error.value_span.unwrap_or(error.span));
+    match &error.error_kind {
+        GeneratorErrorKind::NameResolverError(NameResolverError::NamedAlreadyDefined(_)) => {
+            diagnostic.with_hint(
+                "names of buckets, proofs, address reservations and named addresses must be unique",
+            )
+        }
+        GeneratorErrorKind::BlobNotFound(_) => {
+            diagnostic.with_hint("blobs must be provided to the compiler alongside the manifest")
+        }
+        GeneratorErrorKind::IdValidationError(ManifestIdValidationError::BucketNotFound(_))
+        | GeneratorErrorKind::IdValidationError(ManifestIdValidationError::ProofNotFound(_)) => {
+            diagnostic.with_hint("it may have already been consumed by an earlier instruction")
+        }
+        GeneratorErrorKind::IdValidationError(ManifestIdValidationError::BucketLocked(_)) => {
+            diagnostic.with_hint("drop all proofs created from the bucket before consuming it")
+        }
+        _ => diagnostic,
+    }
+}
+
+fn describe_id_validation_error(error: &ManifestIdValidationError) -> String {
+    match error {
+        ManifestIdValidationError::BucketNotFound(bucket) => {
+            format!("bucket {} does not exist", bucket.0)
+        }
+        ManifestIdValidationError::ProofNotFound(proof) => {
+            format!("proof {} does not exist", proof.0)
+        }
+        ManifestIdValidationError::BucketLocked(bucket) => {
+            format!("bucket {} is locked by an outstanding proof", bucket.0)
+        }
+        ManifestIdValidationError::AddressReservationNotFound(reservation) => {
+            format!("address reservation {} does not exist", reservation.0)
+        }
+        ManifestIdValidationError::AddressNotFound(address) => {
+            format!("named address {} does not exist", address)
+        }
+    }
+}
+
+fn describe_token_type(token_type: &TokenType) -> String {
+    match token_type {
+        TokenType::Instruction => "an instruction".to_string(),
+        TokenType::Value => "a value".to_string(),
+        TokenType::ValueKind => "a value kind".to_string(),
+        TokenType::EnumDiscriminator => "an enum discriminator".to_string(),
+        TokenType::Exact(kind) => describe_token_kind(kind),
+    }
+}
+
+fn describe_token_kind(kind: &TokenKind) -> String {
+    match kind {
+        TokenKind::BoolLiteral(value) => format!("`{}`", value),
+        TokenKind::I8Literal(value) => format!("`{}i8`", value),
+        TokenKind::I16Literal(value) => format!("`{}i16`", value),
+        TokenKind::I32Literal(value) => format!("`{}i32`", value),
+        TokenKind::I64Literal(value) => format!("`{}i64`", value),
+        TokenKind::I128Literal(value) => format!("`{}i128`", value),
+        TokenKind::U8Literal(value) => format!("`{}u8`", value),
+        TokenKind::U16Literal(value) => format!("`{}u16`", value),
+        TokenKind::U32Literal(value) => format!("`{}u32`", value),
+        TokenKind::U64Literal(value) => format!("`{}u64`", value),
+        TokenKind::U128Literal(value) => format!("`{}u128`", value),
+        TokenKind::StringLiteral(value) => format!("string {:?}", value),
+        TokenKind::Ident(value) => format!("`{}`", value),
+        TokenKind::OpenParenthesis => "`(`".to_string(),
+        TokenKind::CloseParenthesis => "`)`".to_string(),
+        TokenKind::LessThan => "`<`".to_string(),
+        TokenKind::GreaterThan => "`>`".to_string(),
+        TokenKind::Comma => "`,`".to_string(),
+        TokenKind::Semicolon => "`;`".to_string(),
+        TokenKind::FatArrow => "`=>`".to_string(),
+    }
+}
+
+fn describe_value_kinds(value_kinds: &[ast::ValueKind]) -> String {
+    value_kinds
+        .iter()
+        .map(|value_kind| format!("`{:?}`", value_kind))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+/// A span of `length` chars on a single line, starting at `start`.
+fn char_span(start: Position, length: usize) -> Span {
+    Span {
+        start,
+        end: Position {
+            full_index: start.full_index + length,
+            line_number: start.line_number,
+            line_char_index: start.line_char_index + length,
+        },
+    }
+}
+
+fn end_of_source_span(source: &[char]) -> Span {
+    let end = position_after(
+        source,
+        Position {
+            full_index: 0,
+            line_number: 1,
+            line_char_index: 0,
+        },
+        source.len(),
+    );
+    Span { start: end, end }
+}
+
+/// Advances the position `from` up to the char at `full_index`.
+fn position_after(source: &[char], from: Position, full_index: usize) -> Position {
+    let mut position = from;
+    for c in &source[from.full_index..full_index] {
+        position.full_index += 1;
+        if *c == '\n' {
+            position.line_number += 1;
+            position.line_char_index = 0;
+        } else {
+            position.line_char_index += 1;
+        }
+    }
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::*;
+    use radix_engine_interface::network::NetworkDefinition;
+
+    fn compile_and_render(manifest: &str) -> String {
+        let error = compile(
+            manifest,
+            &NetworkDefinition::simulator(),
+            BlobProvider::default(),
+        )
+        .unwrap_err();
+        compile_error_diagnostics(manifest, &error)
+    }
+
+    #[test]
+    fn test_undefined_bucket_diagnostic_suggests_similar_name() {
+        let manifest = r#"TAKE_ALL_FROM_WORKTOP
+    Address("resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez")
+    Bucket("bucket2");
+RETURN_TO_WORKTOP Bucket("bucket3");"#;
+        assert_eq!(
+            compile_and_render(manifest),
+            r#"error: unknown named bucket `bucket3`, did you mean `bucket2`?
+ --> 4:26
+  |
+4 | RETURN_TO_WORKTOP Bucket("bucket3");
+  |                          ^^^^^^^^^"#
+        );
+    }
+
+    #[test]
+    fn test_missing_semicolon_diagnostic() {
+        let manifest = "DROP_ALL_PROOFS\nDROP_AUTH_ZONE_PROOFS;";
+        assert_eq!(
+            compile_and_render(manifest),
+            r#"error: expected `;`, found `DROP_AUTH_ZONE_PROOFS`
+ --> 2:1
+  |
+2 | DROP_AUTH_ZONE_PROOFS;
+  | ^^^^^^^^^^^^^^^^^^^^^
+  = hint: each instruction must be terminated with a `;`"#
+        );
+    }
+
+    #[test]
+    fn test_unexpected_char_diagnostic() {
+        let manifest = "DROP_ALL_PROOFS;\n\tDROP_PROOF @;";
+        assert_eq!(
+            compile_and_render(manifest),
+            "error: unexpected character '@'\n --> 2:13\n  |\n2 | \tDROP_PROOF @;\n  | \t           ^"
+        );
+    }
+
+    #[test]
+    fn test_invalid_value_diagnostic_points_at_value() {
+        let manifest = r#"CALL_METHOD
+    Address("component_sim1cqvgx33089ukm2pl97pv4max0x40ruvfy4lt60yvya744cvemygpmu")
+    "free"
+    Decimal("1.2.3");"#;
+        assert_eq!(
+            compile_and_render(manifest),
+            r#"error: invalid decimal `1.2.3`
+ --> 4:13
+  |
+4 |     Decimal("1.2.3");
+  |             ^^^^^^^"#
+        );
+    }
+
+    #[test]
+    fn test_multi_line_instruction_diagnostic() {
+        let manifest = r#"TAKE_ALL_FROM_WORKTOP
+    Address("resource_sim1thvwu8dh6lk4y9mntemkvj25wllq8adq42skzufp4m8wxxuemugnez")
+    Bucket("bucket");
+RETURN_TO_WORKTOP Bucket("bucket");
+RETURN_TO_WORKTOP
+    Bucket("bucket");
+"#;
+        assert_eq!(
+            compile_and_render(manifest),
+            r#"error: bucket 0 does not exist
+ --> 5:1
+  |
+5 | RETURN_TO_WORKTOP
+  | ^^^^^^^^^^^^^^^^^
+6 |     Bucket("bucket");
+  |     ^^^^^^^^^^^^^^^^^
+  = hint: it may have already been consumed by an earlier instruction"#
+        );
+    }
+}
@@ -3,6 +3,7 @@ use crate::data::*;
 use crate::errors::*;
 use crate::internal_prelude::TransactionManifestV1;
 use crate::manifest::ast;
+use crate::manifest::lexer::Span;
 use crate::model::*;
 use crate::validation::*;
 use radix_engine_common::constants::PACKAGE_PACKAGE;
@@ -64,7 +65,7 @@ use sbor::rust::vec;
 use sbor::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum GeneratorError {
+pub enum GeneratorErrorKind {
     InvalidAstType {
         expected_type: ast::ValueKind,
         actual: ast::ValueKind,
@@ -114,6 +115,65 @@ pub enum GeneratorError {
     },
 }
 
+impl GeneratorErrorKind {
+    /// The string value the error is about, such as an invalid address or an undefined name.
+    fn value(&self) -> Option<&str> {
+        match self {
+            GeneratorErrorKind::NameResolverError(
+                NameResolverError::UndefinedBucket(value)
+                | NameResolverError::UndefinedProof(value)
+                | NameResolverError::UndefinedAddressReservation(value)
+                | NameResolverError::UndefinedNamedAddress(value)
+                | NameResolverError::NamedAlreadyDefined(value),
+            )
+            | GeneratorErrorKind::InvalidAstValue {
+                actual: ast::Value::String(value),
+                ..
+            }
+            | GeneratorErrorKind::UnexpectedValue {
+                actual: ast::Value::String(value),
+                ..
+            }
+            | GeneratorErrorKind::InvalidPackageAddress(value)
+            | GeneratorErrorKind::InvalidComponentAddress(value)
+            | GeneratorErrorKind::InvalidResourceAddress(value)
+            | GeneratorErrorKind::InvalidGlobalAddress(value)
+            | GeneratorErrorKind::InvalidInternalAddress(value)
+            | GeneratorErrorKind::InvalidDecimal(value)
+            | GeneratorErrorKind::InvalidPreciseDecimal(value)
+            | GeneratorErrorKind::InvalidHash(value)
+            | GeneratorErrorKind::InvalidNodeId(value)
+            | GeneratorErrorKind::InvalidVaultId(value)
+            | GeneratorErrorKind::InvalidNonFungibleLocalId(value)
+            | GeneratorErrorKind::InvalidExpression(value)
+            | GeneratorErrorKind::InvalidComponent(value)
+            | GeneratorErrorKind::InvalidKeyValueStore(value)
+            | GeneratorErrorKind::InvalidBucket(value)
+            | GeneratorErrorKind::InvalidProof(value)
+            | GeneratorErrorKind::InvalidVault(value)
+            | GeneratorErrorKind::InvalidSecp256k1PublicKey(value)
+            | GeneratorErrorKind::InvalidSecp256k1Signature(value)
+            | GeneratorErrorKind::InvalidEd25519PublicKey(value)
+            | GeneratorErrorKind::InvalidEd25519Signature(value)
+            | GeneratorErrorKind::InvalidBlobHash(value)
+            | GeneratorErrorKind::BlobNotFound(value)
+            | GeneratorErrorKind::InvalidBytesHex(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// A generator error, together with the span of the instruction which caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratorError {
+    pub error_kind: GeneratorErrorKind,
+    pub span: Span,
+    /// The span of the string literal the error is about within the instruction, if any.
+    pub value_span: Option<Span>,
+    /// For an undefined name, a similar name which is defined, if any - most likely a typo.
+    pub suggestion: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NameResolverError {
     UndefinedBucket(String),
@@ -219,10 +279,52 @@ impl NameResolver {
             None => Err(NameResolverError::UndefinedNamedAddress(name.into())),
         }
     }
+
+    /// For an undefined name error, returns the closest defined name of the same kind, if it's
+    /// close enough to plausibly be what was meant.
+    pub fn suggest_similar_name(&self, error: &NameResolverError) -> Option<String> {
+        let (name, candidates): (&String, Vec<&String>) = match error {
+            NameResolverError::UndefinedBucket(name) => (name, self.named_buckets.keys().collect()),
+            NameResolverError::UndefinedProof(name) => (name, self.named_proofs.keys().collect()),
+            NameResolverError::UndefinedAddressReservation(name) => {
+                (name, self.named_address_reservations.keys().collect())
+            }
+            NameResolverError::UndefinedNamedAddress(name) => {
+                (name, self.named_addresses.keys().collect())
+            }
+            NameResolverError::NamedAlreadyDefined(_) => return None,
+        };
+        let max_distance = (name.chars().count() / 3).max(1);
+        candidates
+            .into_iter()
+            .map(|candidate| (edit_distance(name, candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate.clone())
+    }
+}
+
+/// The Levenshtein distance between two strings, in unicode chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current_row = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = if a_char == *b_char { 0 } else { 1 };
+            current_row.push(
+                (previous_row[j] + substitution_cost)
+                    .min(previous_row[j + 1] + 1)
+                    .min(current_row[j] + 1),
+            );
+        }
+        previous_row = current_row;
+    }
+    previous_row[b.len()]
 }
 
 pub fn generate_manifest<B>(
-    instructions: &[ast::Instruction],
+    instructions: &[ast::InstructionWithSpan],
     address_bech32_decoder: &AddressBech32Decoder,
     blobs: B,
 ) -> Result<TransactionManifestV1, GeneratorError>
@@ -234,13 +336,35 @@ where
     let mut output = Vec::new();
 
     for instruction in instructions {
-        output.push(generate_instruction(
-            instruction,
+        let generated = generate_instruction(
+            &instruction.instruction,
             &mut id_validator,
             &mut name_resolver,
             address_bech32_decoder,
             &blobs,
-        )?);
+        )
+        .map_err(|error_kind| {
+            let suggestion = match &error_kind {
+                GeneratorErrorKind::NameResolverError(error) => {
+                    name_resolver.suggest_similar_name(error)
+                }
+                _ => None,
+            };
+            let value_span = error_kind.value().and_then(|value| {
+                instruction
+                    .string_literals
+                    .iter()
+                    .find(|(literal, _)| literal == value)
+                    .map(|(_, span)| *span)
+            });
+            GeneratorError {
+                error_kind,
+                span: instruction.span,
+                value_span,
+                suggestion,
+            }
+        })?;
+        output.push(generated);
     }
 
     Ok(TransactionManifestV1 {
@@ -255,7 +379,7 @@ pub fn generate_instruction<B>(
     resolver: &mut NameResolver,
     address_bech32_decoder: &AddressBech32Decoder,
     blobs: &B,
-) -> Result<InstructionV1, GeneratorError>
+) -> Result<InstructionV1, GeneratorErrorKind>
 where
     B: IsBlobProvider,
 {
@@ -310,7 +434,7 @@ where
             let bucket_id = generate_bucket(bucket, resolver)?;
             id_validator
                 .drop_bucket(&bucket_id)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::ReturnToWorktop { bucket_id }
         }
         ast::Instruction::AssertWorktopContains {
@@ -338,7 +462,7 @@ where
         ast::Instruction::PopFromAuthZone { new_proof } => {
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             declare_proof(new_proof, resolver, proof_id)?;
 
             InstructionV1::PopFromAuthZone
@@ -347,7 +471,7 @@ where
             let proof_id = generate_proof(proof, resolver)?;
             id_validator
                 .drop_proof(&proof_id)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::PushToAuthZone { proof_id }
         }
         ast::Instruction::DropAuthZoneProofs => InstructionV1::DropAuthZoneProofs,
@@ -364,7 +488,7 @@ where
             let amount = generate_decimal(amount)?;
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             declare_proof(new_proof, resolver, proof_id)?;
 
             InstructionV1::CreateProofFromAuthZoneOfAmount {
@@ -382,7 +506,7 @@ where
             let ids = generate_non_fungible_local_ids(ids)?;
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             declare_proof(new_proof, resolver, proof_id)?;
 
             InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
@@ -398,7 +522,7 @@ where
                 generate_resource_address(resource_address, address_bech32_decoder)?;
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             declare_proof(new_proof, resolver, proof_id)?;
 
             InstructionV1::CreateProofFromAuthZoneOfAll { resource_address }
@@ -408,7 +532,7 @@ where
             let bucket_id = generate_bucket(bucket, resolver)?;
             id_validator
                 .drop_bucket(&bucket_id)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::BurnResource { bucket_id }
         }
 
@@ -421,7 +545,7 @@ where
             let amount = generate_decimal(amount)?;
             let proof_id = id_validator
                 .new_proof(ProofKind::BucketProof(bucket_id.clone()))
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             declare_proof(new_proof, resolver, proof_id)?;
 
             InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount }
@@ -435,7 +559,7 @@ where
             let ids = generate_non_fungible_local_ids(ids)?;
             let proof_id = id_validator
                 .new_proof(ProofKind::BucketProof(bucket_id.clone()))
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             declare_proof(new_proof, resolver, proof_id)?;
 
             InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, ids }
@@ -444,7 +568,7 @@ where
             let bucket_id = generate_bucket(bucket, resolver)?;
             let proof_id = id_validator
                 .new_proof(ProofKind::BucketProof(bucket_id.clone()))
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             declare_proof(new_proof, resolver, proof_id)?;
 
             InstructionV1::CreateProofFromBucketOfAll { bucket_id }
@@ -454,7 +578,7 @@ where
             let proof_id = generate_proof(proof, resolver)?;
             let proof_id2 = id_validator
                 .clone_proof(&proof_id)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             declare_proof(new_proof, resolver, proof_id2)?;
 
             InstructionV1::CloneProof { proof_id }
@@ -463,7 +587,7 @@ where
             let proof_id = generate_proof(proof, resolver)?;
             id_validator
                 .drop_proof(&proof_id)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::DropProof { proof_id }
         }
 
@@ -483,7 +607,7 @@ where
             let args = generate_args(args, resolver, address_bech32_decoder, blobs)?;
             id_validator
                 .process_call_data(&args)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
 
             InstructionV1::CallFunction {
                 package_address,
@@ -503,7 +627,7 @@ where
             let args = generate_args(args, resolver, address_bech32_decoder, blobs)?;
             id_validator
                 .process_call_data(&args)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::CallMethod {
                 address,
                 method_name,
@@ -521,7 +645,7 @@ where
             let args = generate_args(args, resolver, address_bech32_decoder, blobs)?;
             id_validator
                 .process_call_data(&args)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::CallRoyaltyMethod {
                 address,
                 method_name,
@@ -539,7 +663,7 @@ where
             let args = generate_args(args, resolver, address_bech32_decoder, blobs)?;
             id_validator
                 .process_call_data(&args)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::CallMetadataMethod {
                 address,
                 method_name,
@@ -557,7 +681,7 @@ where
             let args = generate_args(args, resolver, address_bech32_decoder, blobs)?;
             id_validator
                 .process_call_data(&args)
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::CallRoleAssignmentMethod {
                 address,
                 method_name,
@@ -568,14 +692,14 @@ where
         ast::Instruction::DropNamedProofs => {
             id_validator
                 .drop_all_named_proofs()
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::DropNamedProofs
         }
 
         ast::Instruction::DropAllProofs => {
             id_validator
                 .drop_all_named_proofs()
-                .map_err(GeneratorError::IdValidationError)?;
+                .map_err(GeneratorErrorKind::IdValidationError)?;
             InstructionV1::DropAllProofs
         }
 
@@ -808,7 +932,7 @@ where
 #[macro_export]
 macro_rules! invalid_type {
     ( $v:expr, $($exp:expr),+ ) => {
-        Err(GeneratorError::InvalidAstValue {
+        Err(GeneratorErrorKind::InvalidAstValue {
             expected_type: vec!($($exp),+),
             actual: $v.clone(),
         })
@@ -820,7 +944,7 @@ fn generate_args<B>(
     resolver: &mut NameResolver,
     address_bech32_decoder: &AddressBech32Decoder,
     blobs: &B,
-) -> Result<ManifestValue, GeneratorError>
+) -> Result<ManifestValue, GeneratorErrorKind>
 where
     B: IsBlobProvider,
 {
@@ -838,18 +962,18 @@ where
     Ok(ManifestValue::Tuple { fields })
 }
 
-fn generate_string(value: &ast::Value) -> Result<String, GeneratorError> {
+fn generate_string(value: &ast::Value) -> Result<String, GeneratorErrorKind> {
     match value {
         ast::Value::String(s) => Ok(s.into()),
         v => invalid_type!(v, ast::ValueKind::String),
     }
 }
 
-fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorError> {
+fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorErrorKind> {
     match value {
         ast::Value::Decimal(inner) => match &**inner {
             ast::Value::String(s) => {
-                Decimal::from_str(s).map_err(|_| GeneratorError::InvalidDecimal(s.into()))
+                Decimal::from_str(s).map_err(|_| GeneratorErrorKind::InvalidDecimal(s.into()))
             }
             v => invalid_type!(v, ast::ValueKind::String),
         },
@@ -857,11 +981,11 @@ fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorError> {
     }
 }
 
-fn generate_precise_decimal(value: &ast::Value) -> Result<PreciseDecimal, GeneratorError> {
+fn generate_precise_decimal(value: &ast::Value) -> Result<PreciseDecimal, GeneratorErrorKind> {
     match value {
         ast::Value::PreciseDecimal(inner) => match &**inner {
             ast::Value::String(s) => PreciseDecimal::from_str(s)
-                .map_err(|_| GeneratorError::InvalidPreciseDecimal(s.into())),
+                .map_err(|_| GeneratorErrorKind::InvalidPreciseDecimal(s.into())),
 
             v => invalid_type!(v, ast::ValueKind::String),
         },
@@ -872,7 +996,7 @@ fn generate_precise_decimal(value: &ast::Value) -> Result<PreciseDecimal, Genera
 fn generate_package_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
-) -> Result<PackageAddress, GeneratorError> {
+) -> Result<PackageAddress, GeneratorErrorKind> {
     match value {
         ast::Value::Address(inner) => match inner.borrow() {
             ast::Value::String(s) => {
@@ -881,7 +1005,7 @@ fn generate_package_address(
                         return Ok(address);
                     }
                 }
-                return Err(GeneratorError::InvalidGlobalAddress(s.into()));
+                return Err(GeneratorErrorKind::InvalidGlobalAddress(s.into()));
             }
             v => invalid_type!(v, ast::ValueKind::String),
         },
@@ -892,7 +1016,7 @@ fn generate_package_address(
 fn generate_resource_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
-) -> Result<ResourceAddress, GeneratorError> {
+) -> Result<ResourceAddress, GeneratorErrorKind> {
     match value {
         ast::Value::Address(inner) => match inner.borrow() {
             ast::Value::String(s) => {
@@ -901,7 +1025,7 @@ fn generate_resource_address(
                         return Ok(address);
                     }
                 }
-                return Err(GeneratorError::InvalidGlobalAddress(s.into()));
+                return Err(GeneratorErrorKind::InvalidGlobalAddress(s.into()));
             }
             v => invalid_type!(v, ast::ValueKind::String),
         },
@@ -913,7 +1037,7 @@ fn generate_dynamic_global_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
     resolver: &mut NameResolver,
-) -> Result<DynamicGlobalAddress, GeneratorError> {
+) -> Result<DynamicGlobalAddress, GeneratorErrorKind> {
     match value {
         ast::Value::Address(value) => match value.borrow() {
             ast::Value::String(s) => {
@@ -922,7 +1046,7 @@ fn generate_dynamic_global_address(
                         return Ok(DynamicGlobalAddress::Static(address));
                     }
                 }
-                return Err(GeneratorError::InvalidGlobalAddress(s.into()));
+                return Err(GeneratorErrorKind::InvalidGlobalAddress(s.into()));
             }
             v => return invalid_type!(v, ast::ValueKind::String),
         },
@@ -931,7 +1055,7 @@ fn generate_dynamic_global_address(
             ast::Value::String(s) => resolver
                 .resolve_named_address(&s)
                 .map(Into::into)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::U32, ast::ValueKind::String),
         },
         v => invalid_type!(
@@ -949,7 +1073,7 @@ fn generate_dynamic_package_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
     resolver: &mut NameResolver,
-) -> Result<DynamicPackageAddress, GeneratorError> {
+) -> Result<DynamicPackageAddress, GeneratorErrorKind> {
     match value {
         ast::Value::Address(value) => match value.borrow() {
             ast::Value::String(s) => {
@@ -958,7 +1082,7 @@ fn generate_dynamic_package_address(
                         return Ok(DynamicPackageAddress::Static(address));
                     }
                 }
-                return Err(GeneratorError::InvalidPackageAddress(s.into()));
+                return Err(GeneratorErrorKind::InvalidPackageAddress(s.into()));
             }
             v => return invalid_type!(v, ast::ValueKind::String),
         },
@@ -967,7 +1091,7 @@ fn generate_dynamic_package_address(
             ast::Value::String(s) => resolver
                 .resolve_named_address(&s)
                 .map(Into::into)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::U32, ast::ValueKind::String),
         },
         v => invalid_type!(
@@ -981,7 +1105,7 @@ fn generate_dynamic_package_address(
 fn generate_local_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
-) -> Result<InternalAddress, GeneratorError> {
+) -> Result<InternalAddress, GeneratorErrorKind> {
     match value {
         ast::Value::Address(value) => match value.borrow() {
             ast::Value::String(s) => {
@@ -990,7 +1114,7 @@ fn generate_local_address(
                         return Ok(address);
                     }
                 }
-                return Err(GeneratorError::InvalidInternalAddress(s.into()));
+                return Err(GeneratorErrorKind::InvalidInternalAddress(s.into()));
             }
             v => return invalid_type!(v, ast::ValueKind::String),
         },
@@ -1008,12 +1132,12 @@ fn declare_bucket(
     value: &ast::Value,
     resolver: &mut NameResolver,
     bucket_id: ManifestBucket,
-) -> Result<(), GeneratorError> {
+) -> Result<(), GeneratorErrorKind> {
     match value {
         ast::Value::Bucket(inner) => match &**inner {
             ast::Value::String(name) => resolver
                 .insert_bucket(name.to_string(), bucket_id)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::String),
         },
         v => invalid_type!(v, ast::ValueKind::Bucket),
@@ -1023,13 +1147,13 @@ fn declare_bucket(
 fn generate_bucket(
     value: &ast::Value,
     resolver: &mut NameResolver,
-) -> Result<ManifestBucket, GeneratorError> {
+) -> Result<ManifestBucket, GeneratorErrorKind> {
     match value {
         ast::Value::Bucket(inner) => match &**inner {
             ast::Value::U32(n) => Ok(ManifestBucket(*n)),
             ast::Value::String(s) => resolver
                 .resolve_bucket(&s)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::U32, ast::ValueKind::String),
         },
         v => invalid_type!(v, ast::ValueKind::Bucket),
@@ -1040,12 +1164,12 @@ fn declare_proof(
     value: &ast::Value,
     resolver: &mut NameResolver,
     proof_id: ManifestProof,
-) -> Result<(), GeneratorError> {
+) -> Result<(), GeneratorErrorKind> {
     match value {
         ast::Value::Proof(inner) => match &**inner {
             ast::Value::String(name) => resolver
                 .insert_proof(name.to_string(), proof_id)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::String),
         },
         v => invalid_type!(v, ast::ValueKind::Proof),
@@ -1056,12 +1180,12 @@ fn declare_address_reservation(
     value: &ast::Value,
     resolver: &mut NameResolver,
     address_reservation_id: ManifestAddressReservation,
-) -> Result<(), GeneratorError> {
+) -> Result<(), GeneratorErrorKind> {
     match value {
         ast::Value::AddressReservation(inner) => match &**inner {
             ast::Value::String(name) => resolver
                 .insert_address_reservation(name.to_string(), address_reservation_id)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::String),
         },
         v => invalid_type!(v, ast::ValueKind::AddressReservation),
@@ -1072,12 +1196,12 @@ fn declare_named_address(
     value: &ast::Value,
     resolver: &mut NameResolver,
     address_id: u32,
-) -> Result<(), GeneratorError> {
+) -> Result<(), GeneratorErrorKind> {
     match value {
         ast::Value::NamedAddress(inner) => match &**inner {
             ast::Value::String(name) => resolver
                 .insert_named_address(name.to_string(), address_id)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::String),
         },
         v => invalid_type!(v, ast::ValueKind::NamedAddress),
@@ -1087,13 +1211,13 @@ fn declare_named_address(
 fn generate_proof(
     value: &ast::Value,
     resolver: &mut NameResolver,
-) -> Result<ManifestProof, GeneratorError> {
+) -> Result<ManifestProof, GeneratorErrorKind> {
     match value {
         ast::Value::Proof(inner) => match &**inner {
             ast::Value::U32(n) => Ok(ManifestProof(*n)),
             ast::Value::String(s) => resolver
                 .resolve_proof(&s)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::U32, ast::ValueKind::String),
         },
         v => invalid_type!(v, ast::ValueKind::Proof),
@@ -1103,13 +1227,13 @@ fn generate_proof(
 fn generate_address_reservation(
     value: &ast::Value,
     resolver: &mut NameResolver,
-) -> Result<ManifestAddressReservation, GeneratorError> {
+) -> Result<ManifestAddressReservation, GeneratorErrorKind> {
     match value {
         ast::Value::AddressReservation(inner) => match &**inner {
             ast::Value::U32(n) => Ok(ManifestAddressReservation(*n)),
             ast::Value::String(s) => resolver
                 .resolve_address_reservation(&s)
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::U32, ast::ValueKind::String),
         },
         v => invalid_type!(v, ast::ValueKind::AddressReservation),
@@ -1119,7 +1243,7 @@ fn generate_address_reservation(
 fn generate_static_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
-) -> Result<ManifestAddress, GeneratorError> {
+) -> Result<ManifestAddress, GeneratorErrorKind> {
     match value {
         ast::Value::Address(value) => match value.borrow() {
             ast::Value::String(s) => {
@@ -1132,7 +1256,7 @@ fn generate_static_address(
                         )));
                     }
                 }
-                return Err(GeneratorError::InvalidGlobalAddress(s.into()));
+                return Err(GeneratorErrorKind::InvalidGlobalAddress(s.into()));
             }
             v => return invalid_type!(v, ast::ValueKind::String),
         },
@@ -1149,14 +1273,14 @@ fn generate_static_address(
 fn generate_named_address(
     value: &ast::Value,
     resolver: &mut NameResolver,
-) -> Result<ManifestAddress, GeneratorError> {
+) -> Result<ManifestAddress, GeneratorErrorKind> {
     match value {
         ast::Value::NamedAddress(inner) => match &**inner {
             ast::Value::U32(n) => Ok(ManifestAddress::Named(*n)),
             ast::Value::String(s) => resolver
                 .resolve_named_address(&s)
                 .map(|x| ManifestAddress::Named(x))
-                .map_err(GeneratorError::NameResolverError),
+                .map_err(GeneratorErrorKind::NameResolverError),
             v => invalid_type!(v, ast::ValueKind::U32, ast::ValueKind::String),
         },
         v => invalid_type!(v, ast::ValueKind::NamedAddress),
@@ -1165,24 +1289,24 @@ fn generate_named_address(
 
 fn generate_non_fungible_local_id(
     value: &ast::Value,
-) -> Result<NonFungibleLocalId, GeneratorError> {
+) -> Result<NonFungibleLocalId, GeneratorErrorKind> {
     match value {
         ast::Value::NonFungibleLocalId(inner) => match inner.as_ref() {
             ast::Value::String(s) => NonFungibleLocalId::from_str(s.as_str())
-                .map_err(|_| GeneratorError::InvalidNonFungibleLocalId(s.clone())),
+                .map_err(|_| GeneratorErrorKind::InvalidNonFungibleLocalId(s.clone())),
             v => invalid_type!(v, ast::ValueKind::String)?,
         },
         v => invalid_type!(v, ast::ValueKind::NonFungibleLocalId),
     }
 }
 
-fn generate_expression(value: &ast::Value) -> Result<ManifestExpression, GeneratorError> {
+fn generate_expression(value: &ast::Value) -> Result<ManifestExpression, GeneratorErrorKind> {
     match value {
         ast::Value::Expression(inner) => match &**inner {
             ast::Value::String(s) => match s.as_str() {
                 "ENTIRE_WORKTOP" => Ok(ManifestExpression::EntireWorktop),
                 "ENTIRE_AUTH_ZONE" => Ok(ManifestExpression::EntireAuthZone),
-                _ => Err(GeneratorError::InvalidExpression(s.into())),
+                _ => Err(GeneratorErrorKind::InvalidExpression(s.into())),
             },
             v => invalid_type!(v, ast::ValueKind::String),
         },
@@ -1190,7 +1314,7 @@ fn generate_expression(value: &ast::Value) -> Result<ManifestExpression, Generat
     }
 }
 
-fn generate_blob<B>(value: &ast::Value, blobs: &B) -> Result<ManifestBlobRef, GeneratorError>
+fn generate_blob<B>(value: &ast::Value, blobs: &B) -> Result<ManifestBlobRef, GeneratorErrorKind>
 where
     B: IsBlobProvider,
 {
@@ -1198,10 +1322,10 @@ where
         ast::Value::Blob(inner) => match &**inner {
             ast::Value::String(s) => {
                 let hash = Hash::from_str(s)
-                    .map_err(|_| GeneratorError::InvalidBlobHash(s.to_string()))?;
+                    .map_err(|_| GeneratorErrorKind::InvalidBlobHash(s.to_string()))?;
                 blobs
                     .get_blob(&hash)
-                    .ok_or(GeneratorError::BlobNotFound(s.clone()))?;
+                    .ok_or(GeneratorErrorKind::BlobNotFound(s.clone()))?;
                 Ok(ManifestBlobRef(hash.0))
             }
             v => invalid_type!(v, ast::ValueKind::String),
@@ -1212,11 +1336,11 @@ where
 
 fn generate_non_fungible_local_ids(
     value: &ast::Value,
-) -> Result<Vec<NonFungibleLocalId>, GeneratorError> {
+) -> Result<Vec<NonFungibleLocalId>, GeneratorErrorKind> {
     match value {
         ast::Value::Array(kind, values) => {
             if kind != &ast::ValueKind::NonFungibleLocalId {
-                return Err(GeneratorError::InvalidAstType {
+                return Err(GeneratorErrorKind::InvalidAstType {
                     expected_type: ast::ValueKind::String,
                     actual: kind.clone(),
                 });
@@ -1231,10 +1355,10 @@ fn generate_non_fungible_local_ids(
     }
 }
 
-fn generate_byte_vec_from_hex(value: &ast::Value) -> Result<Vec<u8>, GeneratorError> {
+fn generate_byte_vec_from_hex(value: &ast::Value) -> Result<Vec<u8>, GeneratorErrorKind> {
     let bytes = match value {
         ast::Value::String(s) => {
-            hex::decode(s).map_err(|_| GeneratorError::InvalidBytesHex(s.to_owned()))?
+            hex::decode(s).map_err(|_| GeneratorErrorKind::InvalidBytesHex(s.to_owned()))?
         }
         v => invalid_type!(v, ast::ValueKind::String)?,
     };
@@ -1247,13 +1371,13 @@ pub fn generate_value<B>(
     resolver: &mut NameResolver,
     address_bech32_decoder: &AddressBech32Decoder,
     blobs: &B,
-) -> Result<ManifestValue, GeneratorError>
+) -> Result<ManifestValue, GeneratorErrorKind>
 where
     B: IsBlobProvider,
 {
     if let Some(ty) = expected_type {
         if ty != value.value_kind() {
-            return Err(GeneratorError::UnexpectedValue {
+            return Err(GeneratorErrorKind::UnexpectedValue {
                 expected_type: ty,
                 actual: value.clone(),
             });
@@ -1364,7 +1488,7 @@ where
                     address_bech32_decoder,
                     s.as_str(),
                 )
-                .map_err(|_| GeneratorError::InvalidNonFungibleGlobalId),
+                .map_err(|_| GeneratorErrorKind::InvalidNonFungibleGlobalId),
                 v => invalid_type!(v, ast::ValueKind::String)?,
             }?;
             Ok(Value::Tuple {
@@ -1432,7 +1556,7 @@ fn generate_singletons<B>(
     resolver: &mut NameResolver,
     address_bech32_decoder: &AddressBech32Decoder,
     blobs: &B,
-) -> Result<Vec<ManifestValue>, GeneratorError>
+) -> Result<Vec<ManifestValue>, GeneratorErrorKind>
 where
     B: IsBlobProvider,
 {
@@ -1456,7 +1580,7 @@ fn generate_kv_entries<B>(
     resolver: &mut NameResolver,
     address_bech32_decoder: &AddressBech32Decoder,
     blobs: &B,
-) -> Result<Vec<(ManifestValue, ManifestValue)>, GeneratorError>
+) -> Result<Vec<(ManifestValue, ManifestValue)>, GeneratorErrorKind>
 where
     B: IsBlobProvider,
 {
@@ -1485,7 +1609,7 @@ where
 mod tests {
     use super::*;
     use crate::manifest::lexer::tokenize;
-    use crate::manifest::parser::{Parser, ParserError, PARSER_MAX_DEPTH};
+    use crate::manifest::parser::{Parser, ParserError, ParserErrorKind, PARSER_MAX_DEPTH};
     use crate::signing::secp256k1::Secp256k1PrivateKey;
     use radix_engine_common::constants::CONSENSUS_MANAGER;
     use radix_engine_common::manifest_args;
@@ -1641,18 +1765,18 @@ mod tests {
     fn test_failures() {
         generate_value_error!(
             r#"Address(100u32)"#,
-            GeneratorError::InvalidAstValue {
+            GeneratorErrorKind::InvalidAstValue {
                 expected_type: vec![ast::ValueKind::String],
                 actual: ast::Value::U32(100),
             }
         );
         generate_value_error!(
             r#"Address("invalid_package_address")"#,
-            GeneratorError::InvalidGlobalAddress("invalid_package_address".into())
+            GeneratorErrorKind::InvalidGlobalAddress("invalid_package_address".into())
         );
         generate_value_error!(
             r#"Decimal("invalid_decimal")"#,
-            GeneratorError::InvalidDecimal("invalid_decimal".into())
+            GeneratorErrorKind::InvalidDecimal("invalid_decimal".into())
         );
     }
 
@@ -2165,14 +2289,14 @@ mod tests {
             &NetworkDefinition::simulator(),
             BlobProvider::default(),
         );
-        let expected = CompileError::ParserError(ParserError::MaxDepthExceeded(PARSER_MAX_DEPTH));
+        let expected = ParserErrorKind::MaxDepthExceeded(PARSER_MAX_DEPTH);
 
-        match result {
-            Ok(_) => {
-                panic!("Expected {:?} but no error is thrown", expected);
+        match &result {
+            Err(CompileError::ParserError(ParserError { error_kind, .. })) => {
+                assert_eq!(error_kind, &expected);
             }
-            Err(e) => {
-                assert_eq!(e, expected);
+            _ => {
+                panic!("Expected {:?} but got {:?}", expected, result);
             }
         }
     }
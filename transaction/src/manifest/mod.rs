@@ -2,6 +2,7 @@ pub mod ast;
 pub mod blob_provider;
 pub mod compiler;
 pub mod decompiler;
+pub mod diagnostics;
 #[cfg(feature = "std")]
 pub mod dumper;
 pub mod e2e;
@@ -9,8 +10,11 @@ pub mod generator;
 pub mod lexer;
 pub mod manifest_enums;
 pub mod parser;
+pub mod static_analysis;
 
 pub use blob_provider::*;
 pub use compiler::{compile, CompileError};
 pub use decompiler::{decompile, DecompileError};
+pub use diagnostics::compile_error_diagnostics;
 pub use manifest_enums::*;
+pub use static_analysis::*;
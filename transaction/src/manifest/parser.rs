@@ -1,5 +1,5 @@
-use crate::manifest::ast::{Instruction, Value, ValueKind};
-use crate::manifest::lexer::{Token, TokenKind};
+use crate::manifest::ast::{Instruction, InstructionWithSpan, Value, ValueKind};
+use crate::manifest::lexer::{Position, Span, Token, TokenKind};
 use crate::manifest::manifest_enums::KNOWN_ENUM_DISCRIMINATORS;
 use radix_engine_interface::data::manifest::MANIFEST_SBOR_V1_MAX_DEPTH;
 
@@ -8,7 +8,7 @@ use radix_engine_interface::data::manifest::MANIFEST_SBOR_V1_MAX_DEPTH;
 pub const PARSER_MAX_DEPTH: usize = MANIFEST_SBOR_V1_MAX_DEPTH - 4;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ParserError {
+pub enum ParserErrorKind {
     UnexpectedEof,
     UnexpectedToken { expected: TokenType, actual: Token },
     InvalidNumberOfValues { expected: usize, actual: usize },
@@ -18,6 +18,24 @@ pub enum ParserError {
     MaxDepthExceeded(usize),
 }
 
+/// A parser error, together with the span of the tokens which caused it.
+///
+/// For [`ParserErrorKind::UnexpectedEof`], the span is the (empty) span at the end of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserError {
+    pub error_kind: ParserErrorKind,
+    pub span: Span,
+}
+
+impl ParserError {
+    pub fn unexpected_token(expected: TokenType, actual: Token) -> Self {
+        Self {
+            span: actual.span,
+            error_kind: ParserErrorKind::UnexpectedToken { expected, actual },
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TokenType {
     Instruction,
@@ -384,6 +402,8 @@ pub struct Parser {
     current: usize,
     max_depth: usize,
     stack_depth: usize,
+    /// The string literals parsed since the start of the current instruction.
+    string_literals: Vec<(String, Span)>,
 }
 
 #[macro_export]
@@ -399,10 +419,10 @@ macro_rules! advance_match {
     ( $self:expr, $expected:expr ) => {{
         let token = $self.advance()?;
         if token.kind != $expected {
-            return Err(ParserError::UnexpectedToken {
-                expected: TokenType::Exact($expected),
-                actual: token,
-            });
+            return Err(ParserError::unexpected_token(
+                TokenType::Exact($expected),
+                token,
+            ));
         }
     }};
 }
@@ -414,6 +434,7 @@ impl Parser {
             current: 0,
             max_depth,
             stack_depth: 0,
+            string_literals: Vec::new(),
         }
     }
 
@@ -421,7 +442,7 @@ impl Parser {
     fn track_stack_depth_increase(&mut self) -> Result<(), ParserError> {
         self.stack_depth += 1;
         if self.stack_depth > self.max_depth {
-            return Err(ParserError::MaxDepthExceeded(self.max_depth));
+            return Err(self.error_at_current(ParserErrorKind::MaxDepthExceeded(self.max_depth)));
         }
         Ok(())
     }
@@ -440,7 +461,7 @@ impl Parser {
         self.tokens
             .get(self.current)
             .cloned()
-            .ok_or(ParserError::UnexpectedEof)
+            .ok_or_else(|| self.error_at_current(ParserErrorKind::UnexpectedEof))
     }
 
     pub fn advance(&mut self) -> Result<Token, ParserError> {
@@ -449,16 +470,59 @@ impl Parser {
         Ok(token)
     }
 
-    pub fn parse_manifest(&mut self) -> Result<Vec<Instruction>, ParserError> {
-        let mut instructions = Vec::<Instruction>::new();
+    /// Returns the span of the next token, or an empty span at the end of the input if all
+    /// tokens have been consumed.
+    fn current_span(&self) -> Span {
+        match self.tokens.get(self.current) {
+            Some(token) => token.span,
+            None => {
+                let end = self.tokens.last().map(|t| t.span.end).unwrap_or(Position {
+                    full_index: 0,
+                    line_number: 1,
+                    line_char_index: 0,
+                });
+                Span { start: end, end }
+            }
+        }
+    }
+
+    /// Returns the span from `start` up to the end of the last consumed token.
+    fn span_from(&self, start: Position) -> Span {
+        let end = match self.current.checked_sub(1) {
+            Some(last) => self.tokens[last].span.end,
+            None => start,
+        };
+        Span { start, end }
+    }
+
+    fn error_at_current(&self, error_kind: ParserErrorKind) -> ParserError {
+        ParserError {
+            error_kind,
+            span: self.current_span(),
+        }
+    }
+
+    pub fn parse_manifest(&mut self) -> Result<Vec<InstructionWithSpan>, ParserError> {
+        let mut instructions = Vec::<InstructionWithSpan>::new();
 
         while !self.is_eof() {
-            instructions.push(self.parse_instruction()?);
+            instructions.push(self.parse_instruction_with_span()?);
         }
 
         Ok(instructions)
     }
 
+    pub fn parse_instruction_with_span(&mut self) -> Result<InstructionWithSpan, ParserError> {
+        let start = self.peek()?.span.start;
+        self.string_literals.clear();
+        let instruction = self.parse_instruction()?;
+        Ok(InstructionWithSpan {
+            instruction,
+            span: self.span_from(start),
+            string_literals: sbor::rust::mem::take(&mut self.string_literals),
+        })
+    }
+
     fn parse_values_till_semicolon(&mut self) -> Result<Vec<Value>, ParserError> {
         let mut values = Vec::new();
         while self.peek()?.kind != TokenKind::Semicolon {
@@ -470,17 +534,10 @@ impl Parser {
     pub fn parse_instruction(&mut self) -> Result<Instruction, ParserError> {
         let token = self.advance()?;
         let instruction_ident = match &token.kind {
-            TokenKind::Ident(ident_str) => {
-                InstructionIdent::from_ident(ident_str).ok_or(ParserError::UnexpectedToken {
-                    expected: TokenType::Instruction,
-                    actual: token,
-                })?
-            }
+            TokenKind::Ident(ident_str) => InstructionIdent::from_ident(ident_str)
+                .ok_or(ParserError::unexpected_token(TokenType::Instruction, token))?,
             _ => {
-                return Err(ParserError::UnexpectedToken {
-                    expected: TokenType::Instruction,
-                    actual: token,
-                });
+                return Err(ParserError::unexpected_token(TokenType::Instruction, token));
             }
         };
         let instruction = match instruction_ident {
@@ -754,13 +811,13 @@ impl Parser {
             TokenKind::I32Literal(value) => Value::I32(*value),
             TokenKind::I64Literal(value) => Value::I64(*value),
             TokenKind::I128Literal(value) => Value::I128(*value),
-            TokenKind::StringLiteral(value) => Value::String(value.clone()),
+            TokenKind::StringLiteral(value) => {
+                self.string_literals.push((value.clone(), token.span));
+                Value::String(value.clone())
+            }
             TokenKind::Ident(ident_str) => {
-                let value_ident =
-                    SborValueIdent::from_ident(ident_str).ok_or(ParserError::UnexpectedToken {
-                        expected: TokenType::Value,
-                        actual: token,
-                    })?;
+                let value_ident = SborValueIdent::from_ident(ident_str)
+                    .ok_or(ParserError::unexpected_token(TokenType::Value, token))?;
                 match value_ident {
                     SborValueIdent::Enum => self.parse_enum_content()?,
                     SborValueIdent::Array => self.parse_array_content()?,
@@ -805,10 +862,7 @@ impl Parser {
                 }
             }
             _ => {
-                return Err(ParserError::UnexpectedToken {
-                    expected: TokenType::Value,
-                    actual: token,
-                });
+                return Err(ParserError::unexpected_token(TokenType::Value, token));
             }
         };
         self.track_stack_depth_decrease()?;
@@ -823,12 +877,15 @@ impl Parser {
             TokenKind::Ident(discriminator) => KNOWN_ENUM_DISCRIMINATORS
                 .get(discriminator.as_str())
                 .cloned()
-                .ok_or(ParserError::UnknownEnumDiscriminator(discriminator.clone()))?,
+                .ok_or(ParserError {
+                    error_kind: ParserErrorKind::UnknownEnumDiscriminator(discriminator.clone()),
+                    span: discriminator_token.span,
+                })?,
             _ => {
-                return Err(ParserError::UnexpectedToken {
-                    expected: TokenType::EnumDiscriminator,
-                    actual: discriminator_token,
-                })
+                return Err(ParserError::unexpected_token(
+                    TokenType::EnumDiscriminator,
+                    discriminator_token,
+                ))
             }
         };
         advance_match!(self, TokenKind::GreaterThan);
@@ -890,12 +947,16 @@ impl Parser {
     }
 
     fn parse_values_one(&mut self) -> Result<Value, ParserError> {
+        let start = self.peek()?.span.start;
         let values =
             self.parse_values_any(TokenKind::OpenParenthesis, TokenKind::CloseParenthesis)?;
         if values.len() != 1 {
-            Err(ParserError::InvalidNumberOfValues {
-                actual: values.len(),
-                expected: 1,
+            Err(ParserError {
+                error_kind: ParserErrorKind::InvalidNumberOfValues {
+                    actual: values.len(),
+                    expected: 1,
+                },
+                span: self.span_from(start),
             })
         } else {
             Ok(values[0].clone())
@@ -903,6 +964,7 @@ impl Parser {
     }
 
     fn parse_generics(&mut self, n: usize) -> Result<Vec<ValueKind>, ParserError> {
+        let start = self.peek()?.span.start;
         advance_match!(self, TokenKind::LessThan);
         let mut types = Vec::new();
         while self.peek()?.kind != TokenKind::GreaterThan {
@@ -914,9 +976,12 @@ impl Parser {
         advance_match!(self, TokenKind::GreaterThan);
 
         if types.len() != n {
-            Err(ParserError::InvalidNumberOfTypes {
-                expected: n,
-                actual: types.len(),
+            Err(ParserError {
+                error_kind: ParserErrorKind::InvalidNumberOfTypes {
+                    expected: n,
+                    actual: types.len(),
+                },
+                span: self.span_from(start),
             })
         } else {
             Ok(types)
@@ -927,12 +992,8 @@ impl Parser {
         let token = self.advance()?;
         let the_type = match &token.kind {
             TokenKind::Ident(ident_str) => {
-                let value_kind_ident = SborValueKindIdent::from_ident(&ident_str).ok_or(
-                    ParserError::UnexpectedToken {
-                        expected: TokenType::ValueKind,
-                        actual: token,
-                    },
-                )?;
+                let value_kind_ident = SborValueKindIdent::from_ident(&ident_str)
+                    .ok_or(ParserError::unexpected_token(TokenType::ValueKind, token))?;
                 match value_kind_ident {
                     // ==============
                     // Simple basic value kinds
@@ -980,10 +1041,7 @@ impl Parser {
                 }
             }
             _ => {
-                return Err(ParserError::UnexpectedToken {
-                    expected: TokenType::ValueKind,
-                    actual: token,
-                });
+                return Err(ParserError::unexpected_token(TokenType::ValueKind, token));
             }
         };
         Ok(the_type)
@@ -993,7 +1051,7 @@ impl Parser {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::manifest::lexer::{tokenize, Position, Span};
+    use crate::manifest::lexer::tokenize;
 
     #[macro_export]
     macro_rules! parse_instruction_ok {
@@ -1022,7 +1080,7 @@ mod tests {
                     panic!("Expected {:?} but no error is thrown", $expected);
                 }
                 Err(e) => {
-                    assert_eq!(e, $expected);
+                    assert_eq!(e.error_kind, $expected);
                 }
             }
         }};
@@ -1132,10 +1190,10 @@ mod tests {
 
     #[test]
     fn test_failures() {
-        parse_value_error!(r#"Enum<0u8"#, ParserError::UnexpectedEof);
+        parse_value_error!(r#"Enum<0u8"#, ParserErrorKind::UnexpectedEof);
         parse_value_error!(
             r#"Enum<0u8)"#,
-            ParserError::UnexpectedToken {
+            ParserErrorKind::UnexpectedToken {
                 expected: TokenType::Exact(TokenKind::GreaterThan),
                 actual: Token {
                     kind: TokenKind::CloseParenthesis,
@@ -1156,7 +1214,7 @@ mod tests {
         );
         parse_value_error!(
             r#"Address("abc", "def")"#,
-            ParserError::InvalidNumberOfValues {
+            ParserErrorKind::InvalidNumberOfValues {
                 actual: 2,
                 expected: 1
             }
@@ -1178,7 +1236,53 @@ mod tests {
         // Should actually be an error not a panic
         parse_value_error!(
             &value_string,
-            ParserError::MaxDepthExceeded(PARSER_MAX_DEPTH)
+            ParserErrorKind::MaxDepthExceeded(PARSER_MAX_DEPTH)
+        );
+    }
+
+    #[test]
+    fn test_error_spans() {
+        let mut parser = Parser::new(tokenize(r#"Address("abc", "def")"#).unwrap(), 10);
+        let error = parser.parse_value().unwrap_err();
+        // The span should cover the whole value list, from `(` to `)`
+        assert_eq!(error.span.start.full_index, 7);
+        assert_eq!(error.span.end.full_index, 21);
+
+        let mut parser = Parser::new(tokenize("Enum<0u8>(\n  1u8,").unwrap(), 10);
+        let error = parser.parse_value().unwrap_err();
+        assert_eq!(error.error_kind, ParserErrorKind::UnexpectedEof);
+        assert_eq!(error.span.start, error.span.end);
+        assert_eq!(error.span.start.line_number, 2);
+        assert_eq!(error.span.start.line_char_index, 6);
+    }
+
+    #[test]
+    fn test_instruction_spans() {
+        let manifest = "DROP_ALL_PROOFS;\n  DROP_PROOF\n    Proof(\"proof\");\n";
+        let instructions = Parser::new(tokenize(manifest).unwrap(), PARSER_MAX_DEPTH)
+            .parse_manifest()
+            .unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(
+            instructions[1].instruction,
+            Instruction::DropProof {
+                proof: Value::Proof(Value::String("proof".into()).into())
+            }
+        );
+        assert_eq!(
+            instructions[1].span,
+            Span {
+                start: Position {
+                    full_index: 19,
+                    line_number: 2,
+                    line_char_index: 2,
+                },
+                end: Position {
+                    full_index: 49,
+                    line_number: 3,
+                    line_char_index: 19,
+                },
+            }
         );
     }
 
@@ -0,0 +1,796 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::consensus_manager::{
+    VALIDATOR_CLAIM_XRD_IDENT, VALIDATOR_STAKE_IDENT, VALIDATOR_UNSTAKE_IDENT,
+};
+use radix_engine_interface::blueprints::pool::{
+    ONE_RESOURCE_POOL_CONTRIBUTE_IDENT, ONE_RESOURCE_POOL_REDEEM_IDENT,
+};
+
+/// What a manifest does, as far as can be told without executing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ManifestClass {
+    /// Only moves resources between accounts.
+    Transfer,
+    PoolContribution,
+    PoolRedemption,
+    ValidatorStake,
+    ValidatorUnstake,
+    ValidatorClaim,
+    /// Updates the deposit rules, resource preferences or authorized depositors of accounts.
+    AccountDepositSettingsUpdate,
+    /// Anything else, including any manifest with instructions the analyzer can't reason about,
+    /// or which combines several of the other classes.
+    General,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceSpecifier {
+    Amount(ResourceAddress, Decimal),
+    Ids(ResourceAddress, IndexSet<NonFungibleLocalId>),
+}
+
+impl ResourceSpecifier {
+    pub fn resource_address(&self) -> ResourceAddress {
+        match self {
+            ResourceSpecifier::Amount(resource_address, _)
+            | ResourceSpecifier::Ids(resource_address, _) => *resource_address,
+        }
+    }
+}
+
+/// The resources held by a bucket or moved by an instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceIndicator {
+    /// Exactly these resources.
+    Specified(ResourceSpecifier),
+    /// Some quantity of this resource, which is only known once the transaction is executed.
+    Unspecified(ResourceAddress),
+    /// Resources returned by components the analyzer can't reason about.
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnrecognizedInstructionReason {
+    /// The instruction invokes a function or method the analyzer doesn't know the effects of.
+    UnknownInvocation,
+    /// The instruction invokes a method on an address allocated within the manifest.
+    NamedAddress,
+    /// The arguments don't match the signature of the invoked method.
+    InvalidArguments,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnrecognizedInstruction {
+    pub index: usize,
+    pub reason: UnrecognizedInstructionReason,
+}
+
+/// A human-readable summary of a manifest, derived from its instructions alone.
+///
+/// Anything which depends on the behaviour of arbitrary components is reported as
+/// [`ResourceIndicator::Unknown`] or in `unrecognized_instructions`, and should be
+/// confirmed by previewing the transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestSummary {
+    pub class: ManifestClass,
+    pub account_withdrawals: IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+    pub account_deposits: IndexMap<ComponentAddress, Vec<ResourceIndicator>>,
+    /// Proofs created from accounts, which are presented to components called afterwards.
+    pub presented_proofs: IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+    /// Accounts whose owner needs to sign the transaction.
+    pub accounts_requiring_auth: IndexSet<ComponentAddress>,
+    pub addresses_touched: IndexSet<GlobalAddress>,
+    pub unrecognized_instructions: Vec<UnrecognizedInstruction>,
+}
+
+pub fn summarize_manifest(instructions: &[InstructionV1]) -> ManifestSummary {
+    let mut analyzer = StaticManifestAnalyzer::default();
+    for (index, instruction) in instructions.iter().enumerate() {
+        analyzer.analyze_instruction(index, instruction);
+    }
+    analyzer.finish()
+}
+
+enum AccountInteraction {
+    LockFee,
+    Withdraw(ResourceSpecifier),
+    CreateProof(ResourceSpecifier),
+    Deposit { may_refund: bool },
+    UpdateDepositSettings,
+}
+
+#[derive(Default)]
+struct StaticManifestAnalyzer {
+    id_allocator: ManifestIdAllocator,
+    buckets: IndexMap<ManifestBucket, ResourceIndicator>,
+    worktop: StaticWorktop,
+    classes: IndexSet<ManifestClass>,
+    account_withdrawals: IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+    account_deposits: IndexMap<ComponentAddress, Vec<ResourceIndicator>>,
+    presented_proofs: IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+    accounts_requiring_auth: IndexSet<ComponentAddress>,
+    addresses_touched: IndexSet<GlobalAddress>,
+    unrecognized_instructions: Vec<UnrecognizedInstruction>,
+}
+
+impl StaticManifestAnalyzer {
+    fn analyze_instruction(&mut self, index: usize, instruction: &InstructionV1) {
+        match instruction {
+            InstructionV1::TakeAllFromWorktop { resource_address } => {
+                self.touch(*resource_address);
+                let contents = self.worktop.take_all(*resource_address);
+                self.new_bucket(contents);
+            }
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => {
+                self.touch(*resource_address);
+                let contents = self.worktop.take(*resource_address, *amount);
+                self.new_bucket(contents);
+            }
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => {
+                self.touch(*resource_address);
+                let contents = self.worktop.take_non_fungibles(*resource_address, ids);
+                self.new_bucket(contents);
+            }
+            InstructionV1::ReturnToWorktop { bucket_id } => {
+                if let Some(contents) = self.buckets.remove(bucket_id) {
+                    self.worktop.put(contents);
+                }
+            }
+            InstructionV1::BurnResource { bucket_id } => {
+                self.buckets.remove(bucket_id);
+            }
+            InstructionV1::AssertWorktopContainsAny { resource_address }
+            | InstructionV1::AssertWorktopContains {
+                resource_address, ..
+            }
+            | InstructionV1::AssertWorktopContainsNonFungibles {
+                resource_address, ..
+            }
+            | InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address, ..
+            }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address, ..
+            }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { resource_address } => {
+                self.touch(*resource_address);
+            }
+            InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. }
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropNamedProofs
+            | InstructionV1::DropAllProofs => {}
+            InstructionV1::AllocateGlobalAddress {
+                package_address, ..
+            } => {
+                self.touch(*package_address);
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                args,
+                ..
+            } => {
+                if let DynamicPackageAddress::Static(package_address) = package_address {
+                    self.touch(*package_address);
+                }
+                self.unrecognized_invocation(
+                    index,
+                    UnrecognizedInstructionReason::UnknownInvocation,
+                    args,
+                );
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                self.analyze_method_call(index, address, method_name, args);
+            }
+            InstructionV1::CallRoyaltyMethod { address, args, .. }
+            | InstructionV1::CallMetadataMethod { address, args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { address, args, .. } => {
+                if let DynamicGlobalAddress::Static(address) = address {
+                    self.touch(*address);
+                }
+                self.unrecognized_invocation(
+                    index,
+                    UnrecognizedInstructionReason::UnknownInvocation,
+                    args,
+                );
+            }
+            InstructionV1::CallDirectVaultMethod { args, .. } => {
+                self.unrecognized_invocation(
+                    index,
+                    UnrecognizedInstructionReason::UnknownInvocation,
+                    args,
+                );
+            }
+        }
+    }
+
+    fn analyze_method_call(
+        &mut self,
+        index: usize,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) {
+        let DynamicGlobalAddress::Static(address) = address else {
+            return self.unrecognized_invocation(
+                index,
+                UnrecognizedInstructionReason::NamedAddress,
+                args,
+            );
+        };
+        self.touch(*address);
+
+        match address.as_node_id().entity_type() {
+            Some(entity_type) if entity_type.is_global_account() => {
+                let account = ComponentAddress::new_or_panic(address.as_node_id().0);
+                match Self::parse_account_call(method_name, args) {
+                    Ok(interaction) => self.apply_account_interaction(account, interaction, args),
+                    Err(reason) => self.unrecognized_invocation(index, reason, args),
+                }
+            }
+            Some(EntityType::GlobalValidator) => {
+                let (class, returned) = match method_name {
+                    VALIDATOR_STAKE_IDENT => {
+                        (ManifestClass::ValidatorStake, ResourceIndicator::Unknown)
+                    }
+                    VALIDATOR_UNSTAKE_IDENT => {
+                        (ManifestClass::ValidatorUnstake, ResourceIndicator::Unknown)
+                    }
+                    VALIDATOR_CLAIM_XRD_IDENT => (
+                        ManifestClass::ValidatorClaim,
+                        ResourceIndicator::Unspecified(XRD),
+                    ),
+                    _ => {
+                        return self.unrecognized_invocation(
+                            index,
+                            UnrecognizedInstructionReason::UnknownInvocation,
+                            args,
+                        )
+                    }
+                };
+                self.consume_args(args);
+                self.worktop.put(returned);
+                self.classes.insert(class);
+            }
+            Some(
+                EntityType::GlobalOneResourcePool
+                | EntityType::GlobalTwoResourcePool
+                | EntityType::GlobalMultiResourcePool,
+            ) => {
+                // All pool blueprints share the same method names
+                let class = match method_name {
+                    ONE_RESOURCE_POOL_CONTRIBUTE_IDENT => ManifestClass::PoolContribution,
+                    ONE_RESOURCE_POOL_REDEEM_IDENT => ManifestClass::PoolRedemption,
+                    _ => {
+                        return self.unrecognized_invocation(
+                            index,
+                            UnrecognizedInstructionReason::UnknownInvocation,
+                            args,
+                        )
+                    }
+                };
+                // Pool units, and any change from multi-resource contributions
+                self.consume_args(args);
+                self.worktop.put(ResourceIndicator::Unknown);
+                self.classes.insert(class);
+            }
+            _ => self.unrecognized_invocation(
+                index,
+                UnrecognizedInstructionReason::UnknownInvocation,
+                args,
+            ),
+        }
+    }
+
+    fn parse_account_call(
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> Result<AccountInteraction, UnrecognizedInstructionReason> {
+        let interaction = match method_name {
+            ACCOUNT_LOCK_FEE_IDENT | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                AccountInteraction::LockFee
+            }
+            ACCOUNT_WITHDRAW_IDENT => {
+                let input: AccountWithdrawInput = decode_args(args)?;
+                AccountInteraction::Withdraw(ResourceSpecifier::Amount(
+                    input.resource_address,
+                    input.amount,
+                ))
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                let input: AccountWithdrawNonFungiblesInput = decode_args(args)?;
+                AccountInteraction::Withdraw(ResourceSpecifier::Ids(
+                    input.resource_address,
+                    input.ids,
+                ))
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                let input: AccountLockFeeAndWithdrawInput = decode_args(args)?;
+                AccountInteraction::Withdraw(ResourceSpecifier::Amount(
+                    input.resource_address,
+                    input.amount,
+                ))
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                let input: AccountLockFeeAndWithdrawNonFungiblesInput = decode_args(args)?;
+                AccountInteraction::Withdraw(ResourceSpecifier::Ids(
+                    input.resource_address,
+                    input.ids,
+                ))
+            }
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                let input: AccountCreateProofOfAmountInput = decode_args(args)?;
+                AccountInteraction::CreateProof(ResourceSpecifier::Amount(
+                    input.resource_address,
+                    input.amount,
+                ))
+            }
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                let input: AccountCreateProofOfNonFungiblesInput = decode_args(args)?;
+                AccountInteraction::CreateProof(ResourceSpecifier::Ids(
+                    input.resource_address,
+                    input.ids,
+                ))
+            }
+            ACCOUNT_DEPOSIT_IDENT
+            | ACCOUNT_DEPOSIT_BATCH_IDENT
+            | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+            | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => {
+                AccountInteraction::Deposit { may_refund: false }
+            }
+            ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT | ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => {
+                AccountInteraction::Deposit { may_refund: true }
+            }
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT
+            | ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT
+            | ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT
+            | ACCOUNT_ADD_AUTHORIZED_DEPOSITOR
+            | ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => AccountInteraction::UpdateDepositSettings,
+            _ => return Err(UnrecognizedInstructionReason::UnknownInvocation),
+        };
+        Ok(interaction)
+    }
+
+    fn apply_account_interaction(
+        &mut self,
+        account: ComponentAddress,
+        interaction: AccountInteraction,
+        args: &ManifestValue,
+    ) {
+        match interaction {
+            AccountInteraction::LockFee => {
+                self.accounts_requiring_auth.insert(account);
+            }
+            AccountInteraction::Withdraw(withdrawn) => {
+                self.accounts_requiring_auth.insert(account);
+                self.worktop
+                    .put(ResourceIndicator::Specified(withdrawn.clone()));
+                self.account_withdrawals
+                    .entry(account)
+                    .or_default()
+                    .push(withdrawn);
+            }
+            AccountInteraction::CreateProof(proven) => {
+                self.accounts_requiring_auth.insert(account);
+                self.presented_proofs
+                    .entry(account)
+                    .or_default()
+                    .push(proven);
+            }
+            AccountInteraction::Deposit { may_refund } => {
+                let deposited = self.consume_args(args);
+                if !deposited.is_empty() {
+                    self.account_deposits
+                        .entry(account)
+                        .or_default()
+                        .extend(deposited);
+                }
+                if may_refund {
+                    // Whether anything comes back depends on the account's deposit rules
+                    self.worktop.put(ResourceIndicator::Unknown);
+                }
+            }
+            AccountInteraction::UpdateDepositSettings => {
+                self.accounts_requiring_auth.insert(account);
+                self.classes
+                    .insert(ManifestClass::AccountDepositSettingsUpdate);
+            }
+        }
+    }
+
+    /// The invoked component receives any buckets passed to it, and may return anything.
+    fn unrecognized_invocation(
+        &mut self,
+        index: usize,
+        reason: UnrecognizedInstructionReason,
+        args: &ManifestValue,
+    ) {
+        self.consume_args(args);
+        self.worktop.put(ResourceIndicator::Unknown);
+        self.unrecognized_instructions
+            .push(UnrecognizedInstruction { index, reason });
+    }
+
+    fn new_bucket(&mut self, contents: ResourceIndicator) {
+        let bucket_id = self.id_allocator.new_bucket_id();
+        self.buckets.insert(bucket_id, contents);
+    }
+
+    fn touch(&mut self, address: impl Into<GlobalAddress>) {
+        self.addresses_touched.insert(address.into());
+    }
+
+    /// Records the addresses passed to an invocation, and removes the buckets and worktop
+    /// contents it takes, returning their contents.
+    fn consume_args(&mut self, args: &ManifestValue) -> Vec<ResourceIndicator> {
+        let mut consumed = Vec::new();
+        visit_custom_values(args, &mut |value: &ManifestCustomValue| match value {
+            ManifestCustomValue::Address(ManifestAddress::Static(node_id)) => {
+                if let Ok(address) = GlobalAddress::try_from(*node_id) {
+                    self.addresses_touched.insert(address);
+                }
+            }
+            ManifestCustomValue::Bucket(bucket_id) => {
+                if let Some(contents) = self.buckets.remove(bucket_id) {
+                    consumed.push(contents);
+                }
+            }
+            ManifestCustomValue::Expression(ManifestExpression::EntireWorktop) => {
+                consumed.extend(self.worktop.drain());
+            }
+            _ => {}
+        });
+        consumed
+    }
+
+    fn finish(self) -> ManifestSummary {
+        let class = if !self.unrecognized_instructions.is_empty() {
+            ManifestClass::General
+        } else if self.classes.len() == 1 {
+            self.classes[0]
+        } else if self.classes.is_empty()
+            && !self.account_withdrawals.is_empty()
+            && !self.account_deposits.is_empty()
+        {
+            ManifestClass::Transfer
+        } else {
+            ManifestClass::General
+        };
+
+        ManifestSummary {
+            class,
+            account_withdrawals: self.account_withdrawals,
+            account_deposits: self.account_deposits,
+            presented_proofs: self.presented_proofs,
+            accounts_requiring_auth: self.accounts_requiring_auth,
+            addresses_touched: self.addresses_touched,
+            unrecognized_instructions: self.unrecognized_instructions,
+        }
+    }
+}
+
+fn decode_args<T: ManifestDecode>(
+    args: &ManifestValue,
+) -> Result<T, UnrecognizedInstructionReason> {
+    from_manifest_value(args).map_err(|_| UnrecognizedInstructionReason::InvalidArguments)
+}
+
+fn visit_custom_values(value: &ManifestValue, visitor: &mut impl FnMut(&ManifestCustomValue)) {
+    match value {
+        ManifestValue::Enum { fields, .. } | ManifestValue::Tuple { fields } => {
+            for field in fields {
+                visit_custom_values(field, visitor);
+            }
+        }
+        ManifestValue::Array { elements, .. } => {
+            for element in elements {
+                visit_custom_values(element, visitor);
+            }
+        }
+        ManifestValue::Map { entries, .. } => {
+            for (key, value) in entries {
+                visit_custom_values(key, visitor);
+                visit_custom_values(value, visitor);
+            }
+        }
+        ManifestValue::Custom { value } => visitor(value),
+        _ => {}
+    }
+}
+
+/// Tracks what is statically known about the worktop contents.
+#[derive(Default)]
+struct StaticWorktop {
+    specified: IndexMap<ResourceAddress, ResourceSpecifier>,
+    /// Resources present in a quantity which can't be determined statically.
+    unspecified: IndexSet<ResourceAddress>,
+    /// Whether the worktop may contain resources returned by unrecognized invocations.
+    contains_unknown: bool,
+}
+
+impl StaticWorktop {
+    fn put(&mut self, contents: ResourceIndicator) {
+        match contents {
+            ResourceIndicator::Specified(specifier) => {
+                let resource_address = specifier.resource_address();
+                let merged = match (self.specified.remove(&resource_address), specifier) {
+                    (None, specifier) => Some(specifier),
+                    (
+                        Some(ResourceSpecifier::Amount(_, existing)),
+                        ResourceSpecifier::Amount(_, amount),
+                    ) => existing
+                        .checked_add(amount)
+                        .map(|total| ResourceSpecifier::Amount(resource_address, total)),
+                    (
+                        Some(ResourceSpecifier::Ids(_, mut existing)),
+                        ResourceSpecifier::Ids(_, ids),
+                    ) => {
+                        existing.extend(ids);
+                        Some(ResourceSpecifier::Ids(resource_address, existing))
+                    }
+                    _ => None,
+                };
+                match merged {
+                    Some(specifier) => {
+                        self.specified.insert(resource_address, specifier);
+                    }
+                    None => {
+                        self.unspecified.insert(resource_address);
+                    }
+                }
+            }
+            ResourceIndicator::Unspecified(resource_address) => {
+                self.unspecified.insert(resource_address);
+            }
+            ResourceIndicator::Unknown => {
+                self.contains_unknown = true;
+            }
+        }
+    }
+
+    fn take(&mut self, resource_address: ResourceAddress, amount: Decimal) -> ResourceIndicator {
+        match self.specified.get_mut(&resource_address) {
+            Some(ResourceSpecifier::Amount(_, available)) => match available.checked_sub(amount) {
+                Some(remaining) if remaining.is_positive() => *available = remaining,
+                _ => {
+                    self.specified.remove(&resource_address);
+                }
+            },
+            Some(ResourceSpecifier::Ids(..)) => {
+                // We can't tell which of the non-fungibles are taken
+                self.specified.remove(&resource_address);
+                self.unspecified.insert(resource_address);
+            }
+            None => {}
+        }
+        ResourceIndicator::Specified(ResourceSpecifier::Amount(resource_address, amount))
+    }
+
+    fn take_non_fungibles(
+        &mut self,
+        resource_address: ResourceAddress,
+        ids: &[NonFungibleLocalId],
+    ) -> ResourceIndicator {
+        match self.specified.get_mut(&resource_address) {
+            Some(ResourceSpecifier::Ids(_, available)) => {
+                for id in ids {
+                    available.remove(id);
+                }
+                if available.is_empty() {
+                    self.specified.remove(&resource_address);
+                }
+            }
+            Some(ResourceSpecifier::Amount(..)) => {
+                self.take(resource_address, Decimal::from(ids.len()));
+            }
+            None => {}
+        }
+        ResourceIndicator::Specified(ResourceSpecifier::Ids(
+            resource_address,
+            ids.iter().cloned().collect(),
+        ))
+    }
+
+    fn take_all(&mut self, resource_address: ResourceAddress) -> ResourceIndicator {
+        let specified = self.specified.remove(&resource_address);
+        if self.unspecified.remove(&resource_address) || self.contains_unknown {
+            ResourceIndicator::Unspecified(resource_address)
+        } else {
+            ResourceIndicator::Specified(
+                specified.unwrap_or(ResourceSpecifier::Amount(resource_address, Decimal::ZERO)),
+            )
+        }
+    }
+
+    fn drain(&mut self) -> Vec<ResourceIndicator> {
+        let mut contents: Vec<ResourceIndicator> = self
+            .specified
+            .drain(..)
+            .filter(|(resource_address, _)| !self.unspecified.contains(resource_address))
+            .map(|(_, specifier)| ResourceIndicator::Specified(specifier))
+            .collect();
+        contents.extend(
+            self.unspecified
+                .drain(..)
+                .map(ResourceIndicator::Unspecified),
+        );
+        if self.contains_unknown {
+            contents.push(ResourceIndicator::Unknown);
+            self.contains_unknown = false;
+        }
+        contents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(n: u64) -> ComponentAddress {
+        ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(n).unwrap().public_key(),
+        )
+    }
+
+    fn component_of_type(entity_type: EntityType) -> ComponentAddress {
+        ComponentAddress::new_or_panic([entity_type as u8; NodeId::LENGTH])
+    }
+
+    fn fungible(amount: u32) -> ResourceIndicator {
+        ResourceIndicator::Specified(ResourceSpecifier::Amount(XRD, Decimal::from(amount)))
+    }
+
+    #[test]
+    fn transfer_between_accounts_is_summarized() {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account(1), 10)
+            .withdraw_from_account(account(1), XRD, 100)
+            .take_from_worktop(XRD, 40, "bucket")
+            .deposit(account(2), "bucket")
+            .try_deposit_entire_worktop_or_abort(account(3), None)
+            .build();
+
+        let summary = summarize_manifest(&manifest.instructions);
+
+        assert_eq!(summary.class, ManifestClass::Transfer);
+        assert_eq!(
+            summary.account_withdrawals,
+            indexmap!(account(1) => vec![ResourceSpecifier::Amount(XRD, dec!("100"))])
+        );
+        assert_eq!(
+            summary.account_deposits,
+            indexmap!(
+                account(2) => vec![fungible(40)],
+                account(3) => vec![fungible(60)],
+            )
+        );
+        assert_eq!(summary.accounts_requiring_auth, indexset!(account(1)));
+        assert!(summary.unrecognized_instructions.is_empty());
+    }
+
+    #[test]
+    fn non_fungibles_taken_from_worktop_are_tracked() {
+        let resource_address = ResourceAddress::new_or_panic(
+            [EntityType::GlobalNonFungibleResourceManager as u8; NodeId::LENGTH],
+        );
+        let manifest = ManifestBuilder::new()
+            .withdraw_non_fungibles_from_account(
+                account(1),
+                resource_address,
+                [
+                    NonFungibleLocalId::integer(1),
+                    NonFungibleLocalId::integer(2),
+                ],
+            )
+            .take_non_fungibles_from_worktop(
+                resource_address,
+                [NonFungibleLocalId::integer(1)],
+                "bucket",
+            )
+            .deposit(account(2), "bucket")
+            .try_deposit_entire_worktop_or_abort(account(3), None)
+            .build();
+
+        let summary = summarize_manifest(&manifest.instructions);
+
+        assert_eq!(summary.class, ManifestClass::Transfer);
+        assert_eq!(
+            summary.account_deposits,
+            indexmap!(
+                account(2) => vec![ResourceIndicator::Specified(ResourceSpecifier::Ids(
+                    resource_address,
+                    indexset!(NonFungibleLocalId::integer(1)),
+                ))],
+                account(3) => vec![ResourceIndicator::Specified(ResourceSpecifier::Ids(
+                    resource_address,
+                    indexset!(NonFungibleLocalId::integer(2)),
+                ))],
+            )
+        );
+    }
+
+    #[test]
+    fn validator_stake_is_classified() {
+        let validator = component_of_type(EntityType::GlobalValidator);
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account(1), 10)
+            .withdraw_from_account(account(1), XRD, 100)
+            .take_all_from_worktop(XRD, "xrd")
+            .stake_validator(validator, "xrd")
+            .try_deposit_entire_worktop_or_abort(account(1), None)
+            .build();
+
+        let summary = summarize_manifest(&manifest.instructions);
+
+        assert_eq!(summary.class, ManifestClass::ValidatorStake);
+        assert_eq!(
+            summary.account_deposits,
+            indexmap!(account(1) => vec![ResourceIndicator::Unknown])
+        );
+        assert!(summary
+            .addresses_touched
+            .contains(&GlobalAddress::from(validator)));
+    }
+
+    #[test]
+    fn account_deposit_settings_update_is_classified() {
+        let manifest = ManifestBuilder::new()
+            .call_method(
+                account(1),
+                ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+                AccountSetDefaultDepositRuleInput {
+                    default: DefaultDepositRule::Reject,
+                },
+            )
+            .build();
+
+        let summary = summarize_manifest(&manifest.instructions);
+
+        assert_eq!(summary.class, ManifestClass::AccountDepositSettingsUpdate);
+        assert_eq!(summary.accounts_requiring_auth, indexset!(account(1)));
+    }
+
+    #[test]
+    fn calls_to_other_components_are_flagged() {
+        let component = component_of_type(EntityType::GlobalGenericComponent);
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(account(1), XRD, 100)
+            .take_all_from_worktop(XRD, "xrd")
+            .call_method_with_name_lookup(component, "swap", |lookup| (lookup.bucket("xrd"),))
+            .try_deposit_entire_worktop_or_abort(account(1), None)
+            .build();
+
+        let summary = summarize_manifest(&manifest.instructions);
+
+        assert_eq!(summary.class, ManifestClass::General);
+        assert_eq!(
+            summary.unrecognized_instructions,
+            vec![UnrecognizedInstruction {
+                index: 2,
+                reason: UnrecognizedInstructionReason::UnknownInvocation,
+            }]
+        );
+        assert_eq!(
+            summary.account_deposits,
+            indexmap!(account(1) => vec![ResourceIndicator::Unknown])
+        );
+    }
+}
@@ -0,0 +1,388 @@
+use super::*;
+use crate::errors::{MessageDecryptionError, MessageEncryptionError};
+use crate::prelude::*;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::Aes128Gcm;
+use aes_kw::KekAes256;
+use blake2::digest::consts::U32;
+use blake2::Blake2b;
+use hkdf::SimpleHkdf;
+use rand_core::{CryptoRng, RngCore};
+
+const AES_GCM_NONCE_LENGTH: usize = 12;
+const AES_GCM_TAG_LENGTH: usize = 16;
+
+/// The secret material used to encrypt a single message.
+///
+/// All of it must be freshly generated from a cryptographically secure source of randomness
+/// for every message, as [`EncryptedMessageV1::encrypt`] does. It is only exposed so that
+/// other implementations can be checked against fixed test vectors.
+pub struct MessageEncryptionSecrets {
+    pub aes_key: [u8; 16],
+    pub aes_gcm_nonce: [u8; AES_GCM_NONCE_LENGTH],
+    pub ed25519_dh_ephemeral_private_key: Ed25519PrivateKey,
+    pub secp256k1_dh_ephemeral_private_key: Secp256k1PrivateKey,
+}
+
+impl MessageEncryptionSecrets {
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut aes_key = [0u8; 16];
+        rng.fill_bytes(&mut aes_key);
+        let mut aes_gcm_nonce = [0u8; AES_GCM_NONCE_LENGTH];
+        rng.fill_bytes(&mut aes_gcm_nonce);
+
+        Self {
+            aes_key,
+            aes_gcm_nonce,
+            ed25519_dh_ephemeral_private_key: Self::generate_private_key(rng, |bytes| {
+                Ed25519PrivateKey::from_bytes(bytes)
+            }),
+            secp256k1_dh_ephemeral_private_key: Self::generate_private_key(rng, |bytes| {
+                Secp256k1PrivateKey::from_bytes(bytes)
+            }),
+        }
+    }
+
+    fn generate_private_key<R: RngCore + CryptoRng, K>(
+        rng: &mut R,
+        from_bytes: impl Fn(&[u8]) -> Result<K, ()>,
+    ) -> K {
+        // Not every 32 bytes are a valid Secp256k1 scalar, but the chance of hitting an
+        // invalid one is negligible.
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            if let Ok(private_key) = from_bytes(&bytes) {
+                return private_key;
+            }
+        }
+    }
+}
+
+impl EncryptedMessageV1 {
+    /// Encrypts the message such that it can be decrypted with the private key of any of the
+    /// given decryptors, as described on [`EncryptedMessageV1`].
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        message: &PlaintextMessageV1,
+        decryptors: &[PublicKey],
+        rng: &mut R,
+    ) -> Result<Self, MessageEncryptionError> {
+        Self::encrypt_with_secrets(
+            message,
+            decryptors,
+            &MessageEncryptionSecrets::generate(rng),
+        )
+    }
+
+    /// Encrypts the message with the given secrets, which must never be reused.
+    /// Prefer [`EncryptedMessageV1::encrypt`] outside of tests.
+    pub fn encrypt_with_secrets(
+        message: &PlaintextMessageV1,
+        decryptors: &[PublicKey],
+        secrets: &MessageEncryptionSecrets,
+    ) -> Result<Self, MessageEncryptionError> {
+        if decryptors.is_empty() {
+            return Err(MessageEncryptionError::NoDecryptors);
+        }
+
+        let payload = manifest_encode(message)?;
+        let ciphertext = Aes128Gcm::new(&secrets.aes_key.into())
+            .encrypt(
+                aes_gcm::Nonce::from_slice(&secrets.aes_gcm_nonce),
+                payload.as_slice(),
+            )
+            .expect("Encrypting an in-memory payload can't fail");
+        let mut encrypted = secrets.aes_gcm_nonce.to_vec();
+        encrypted.extend(ciphertext);
+
+        let mut ed25519_decryptors = index_map_new();
+        let mut secp256k1_decryptors = index_map_new();
+        for decryptor in decryptors {
+            let invalid_decryptor =
+                |_| MessageEncryptionError::InvalidDecryptorPublicKey(*decryptor);
+            match decryptor {
+                PublicKey::Ed25519(public_key) => {
+                    let shared_secret = secrets
+                        .ed25519_dh_ephemeral_private_key
+                        .diffie_hellman(public_key)
+                        .map_err(invalid_decryptor)?;
+                    ed25519_decryptors.insert(
+                        PublicKeyFingerprint::from(*decryptor),
+                        wrap_key(&secrets.aes_key, &shared_secret),
+                    );
+                }
+                PublicKey::Secp256k1(public_key) => {
+                    let shared_secret = secrets
+                        .secp256k1_dh_ephemeral_private_key
+                        .diffie_hellman(public_key)
+                        .map_err(invalid_decryptor)?;
+                    secp256k1_decryptors.insert(
+                        PublicKeyFingerprint::from(*decryptor),
+                        wrap_key(&secrets.aes_key, &shared_secret),
+                    );
+                }
+            }
+        }
+
+        let mut decryptors_by_curve = index_map_new();
+        if !ed25519_decryptors.is_empty() {
+            decryptors_by_curve.insert(
+                CurveType::Ed25519,
+                DecryptorsByCurve::Ed25519 {
+                    dh_ephemeral_public_key: secrets.ed25519_dh_ephemeral_private_key.public_key(),
+                    decryptors: ed25519_decryptors,
+                },
+            );
+        }
+        if !secp256k1_decryptors.is_empty() {
+            decryptors_by_curve.insert(
+                CurveType::Secp256k1,
+                DecryptorsByCurve::Secp256k1 {
+                    dh_ephemeral_public_key: secrets
+                        .secp256k1_dh_ephemeral_private_key
+                        .public_key(),
+                    decryptors: secp256k1_decryptors,
+                },
+            );
+        }
+
+        Ok(Self {
+            encrypted: AesGcmPayload(encrypted),
+            decryptors_by_curve,
+        })
+    }
+
+    /// Decrypts the message with the private key of one of its decryptors.
+    pub fn decrypt(
+        &self,
+        private_key: &PrivateKey,
+    ) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+        let fingerprint = PublicKeyFingerprint::from(private_key.public_key());
+        let (shared_secret, wrapped_key) = self
+            .decryptors_by_curve
+            .values()
+            .find_map(|decryptors| match (decryptors, private_key) {
+                (
+                    DecryptorsByCurve::Ed25519 {
+                        dh_ephemeral_public_key,
+                        decryptors,
+                    },
+                    PrivateKey::Ed25519(private_key),
+                ) => decryptors.get(&fingerprint).map(|wrapped_key| {
+                    (
+                        private_key.diffie_hellman(dh_ephemeral_public_key),
+                        wrapped_key,
+                    )
+                }),
+                (
+                    DecryptorsByCurve::Secp256k1 {
+                        dh_ephemeral_public_key,
+                        decryptors,
+                    },
+                    PrivateKey::Secp256k1(private_key),
+                ) => decryptors.get(&fingerprint).map(|wrapped_key| {
+                    (
+                        private_key.diffie_hellman(dh_ephemeral_public_key),
+                        wrapped_key,
+                    )
+                }),
+                _ => None,
+            })
+            .ok_or(MessageDecryptionError::NotADecryptor)?;
+        let shared_secret =
+            shared_secret.map_err(|_| MessageDecryptionError::InvalidEphemeralPublicKey)?;
+        let aes_key = unwrap_key(wrapped_key, &shared_secret)?;
+
+        let encrypted = &self.encrypted.0;
+        if encrypted.len() < AES_GCM_NONCE_LENGTH + AES_GCM_TAG_LENGTH {
+            return Err(MessageDecryptionError::PayloadDecryptionFailed);
+        }
+        let (nonce, ciphertext) = encrypted.split_at(AES_GCM_NONCE_LENGTH);
+        let payload = Aes128Gcm::new(&aes_key.into())
+            .decrypt(aes_gcm::Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| MessageDecryptionError::PayloadDecryptionFailed)?;
+
+        Ok(manifest_decode(&payload)?)
+    }
+}
+
+/// `KEK = HKDF(hash: Blake2b, secret: shared_secret, salt: [], length: 256 bits)`
+fn key_encrypting_key(shared_secret: &[u8; 32]) -> KekAes256 {
+    let mut kek = [0u8; 32];
+    SimpleHkdf::<Blake2b<U32>>::new(None, shared_secret)
+        .expand(&[], &mut kek)
+        .expect("256 bits is a valid HKDF output length");
+    KekAes256::from(kek)
+}
+
+fn wrap_key(aes_key: &[u8; 16], shared_secret: &[u8; 32]) -> AesWrapped128BitKey {
+    let mut wrapped_key = [0u8; AesWrapped128BitKey::LENGTH];
+    key_encrypting_key(shared_secret)
+        .wrap(aes_key, &mut wrapped_key)
+        .expect("A 128-bit key always wraps into 24 bytes");
+    AesWrapped128BitKey(wrapped_key)
+}
+
+fn unwrap_key(
+    wrapped_key: &AesWrapped128BitKey,
+    shared_secret: &[u8; 32],
+) -> Result<[u8; 16], MessageDecryptionError> {
+    let mut aes_key = [0u8; 16];
+    key_encrypting_key(shared_secret)
+        .unwrap(&wrapped_key.0, &mut aes_key)
+        .map_err(|_| MessageDecryptionError::KeyUnwrapFailed)?;
+    Ok(aes_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::str::FromStr;
+
+    fn test_message() -> PlaintextMessageV1 {
+        PlaintextMessageV1 {
+            mime_type: "text/plain".to_string(),
+            message: MessageContentsV1::String("Hello Radix!".to_string()),
+        }
+    }
+
+    fn test_secrets() -> MessageEncryptionSecrets {
+        MessageEncryptionSecrets {
+            aes_key: hex::decode("000102030405060708090a0b0c0d0e0f")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            aes_gcm_nonce: hex::decode("a0a1a2a3a4a5a6a7a8a9aaab")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            ed25519_dh_ephemeral_private_key: Ed25519PrivateKey::from_u64(3).unwrap(),
+            secp256k1_dh_ephemeral_private_key: Secp256k1PrivateKey::from_u64(4).unwrap(),
+        }
+    }
+
+    fn fingerprint(hex: &str) -> PublicKeyFingerprint {
+        PublicKeyFingerprint(hex::decode(hex).unwrap().try_into().unwrap())
+    }
+
+    fn wrapped_key(hex: &str) -> AesWrapped128BitKey {
+        AesWrapped128BitKey(hex::decode(hex).unwrap().try_into().unwrap())
+    }
+
+    #[test]
+    fn encryption_matches_test_vector() {
+        let ed25519_decryptor = Ed25519PrivateKey::from_u64(1).unwrap();
+        let secp256k1_decryptor = Secp256k1PrivateKey::from_u64(1).unwrap();
+
+        let encrypted = EncryptedMessageV1::encrypt_with_secrets(
+            &test_message(),
+            &[
+                ed25519_decryptor.public_key().into(),
+                secp256k1_decryptor.public_key().into(),
+            ],
+            &test_secrets(),
+        )
+        .unwrap();
+
+        // The plaintext payload is
+        // 4d21020c0a746578742f706c61696e2200010c0c48656c6c6f20526164697821
+        let expected = EncryptedMessageV1 {
+            encrypted: AesGcmPayload(
+                hex::decode(
+                    "a0a1a2a3a4a5a6a7a8a9aaabe7a73ab774fd5672fe57c56c277bde4253ef2f04cb7251c34a3023fa26e4f78955de0423f7821abb3eaac3defb34bfd4",
+                )
+                .unwrap(),
+            ),
+            decryptors_by_curve: indexmap!(
+                CurveType::Ed25519 => DecryptorsByCurve::Ed25519 {
+                    dh_ephemeral_public_key: Ed25519PublicKey::from_str(
+                        "f381626e41e7027ea431bfe3009e94bdd25a746beec468948d6c3c7c5dc9a54b",
+                    )
+                    .unwrap(),
+                    decryptors: indexmap!(
+                        fingerprint("f91fa8df2486c9ea")
+                            => wrapped_key("be4d98686a1244f4bc2d443be4f7d5ea401620eaa29deabc"),
+                    ),
+                },
+                CurveType::Secp256k1 => DecryptorsByCurve::Secp256k1 {
+                    dh_ephemeral_public_key: Secp256k1PublicKey::from_str(
+                        "02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13",
+                    )
+                    .unwrap(),
+                    decryptors: indexmap!(
+                        fingerprint("a243c1128c2fe737")
+                            => wrapped_key("30103abf47d2a11f1d444a3f21dbd547eea9f440ac4faccf"),
+                    ),
+                },
+            ),
+        };
+        assert_eq!(encrypted, expected);
+    }
+
+    #[test]
+    fn every_decryptor_can_decrypt() {
+        let decryptors: Vec<PrivateKey> = vec![
+            Ed25519PrivateKey::from_u64(1).unwrap().into(),
+            Ed25519PrivateKey::from_u64(2).unwrap().into(),
+            Secp256k1PrivateKey::from_u64(1).unwrap().into(),
+            Secp256k1PrivateKey::from_u64(2).unwrap().into(),
+        ];
+        let public_keys: Vec<PublicKey> = decryptors.iter().map(|k| k.public_key()).collect();
+
+        let encrypted = EncryptedMessageV1::encrypt_with_secrets(
+            &test_message(),
+            &public_keys,
+            &test_secrets(),
+        )
+        .unwrap();
+
+        for decryptor in decryptors.iter() {
+            assert_eq!(encrypted.decrypt(decryptor), Ok(test_message()));
+        }
+    }
+
+    #[test]
+    fn other_keys_cannot_decrypt() {
+        let encrypted = EncryptedMessageV1::encrypt_with_secrets(
+            &test_message(),
+            &[Ed25519PrivateKey::from_u64(1).unwrap().public_key().into()],
+            &test_secrets(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            encrypted.decrypt(&Ed25519PrivateKey::from_u64(2).unwrap().into()),
+            Err(MessageDecryptionError::NotADecryptor)
+        );
+        assert_eq!(
+            encrypted.decrypt(&Secp256k1PrivateKey::from_u64(1).unwrap().into()),
+            Err(MessageDecryptionError::NotADecryptor)
+        );
+    }
+
+    #[test]
+    fn tampered_payload_cannot_be_decrypted() {
+        let decryptor = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let mut encrypted = EncryptedMessageV1::encrypt_with_secrets(
+            &test_message(),
+            &[decryptor.public_key().into()],
+            &test_secrets(),
+        )
+        .unwrap();
+        let last = encrypted.encrypted.0.len() - 1;
+        encrypted.encrypted.0[last] ^= 0xff;
+
+        assert_eq!(
+            encrypted.decrypt(&decryptor.into()),
+            Err(MessageDecryptionError::PayloadDecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn encrypting_without_decryptors_fails() {
+        assert_eq!(
+            EncryptedMessageV1::encrypt_with_secrets(&test_message(), &[], &test_secrets()),
+            Err(MessageEncryptionError::NoDecryptors)
+        );
+    }
+}
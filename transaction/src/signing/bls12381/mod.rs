@@ -0,0 +1,3 @@
+mod private_key;
+
+pub use private_key::*;
@@ -0,0 +1,121 @@
+use crate::internal_prelude::*;
+use crate::validation::BLS12381_CIPHERSUITE_V1;
+use blst::min_pk::{AggregateSignature, SecretKey, Signature};
+
+/// A BLS12-381 private key, using the "minimal public key size" variant (public keys in G1,
+/// signatures in G2).
+///
+/// Unlike the Ed25519 and Secp256k1 keys, this isn't used to sign transactions - it exists so
+/// that off-ledger signatures verified by the `CryptoUtils` host functions can be produced in
+/// tests and tooling.
+pub struct Bls12381G1PrivateKey(SecretKey);
+
+impl Bls12381G1PrivateKey {
+    pub const LENGTH: usize = 32;
+
+    pub fn public_key(&self) -> Bls12381G1PublicKey {
+        Bls12381G1PublicKey(self.0.sk_to_pk().to_bytes())
+    }
+
+    pub fn sign_v1(&self, message: &[u8]) -> Bls12381G2Signature {
+        let signature = self
+            .0
+            .sign(message, BLS12381_CIPHERSUITE_V1, &[])
+            .to_bytes();
+        Bls12381G2Signature(signature)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+
+    pub fn from_bytes(slice: &[u8]) -> Result<Self, ()> {
+        if slice.len() != Bls12381G1PrivateKey::LENGTH {
+            return Err(());
+        }
+        Ok(Self(SecretKey::from_bytes(slice).map_err(|_| ())?))
+    }
+
+    pub fn from_u64(n: u64) -> Result<Self, ()> {
+        let mut bytes = [0u8; Bls12381G1PrivateKey::LENGTH];
+        (&mut bytes[Bls12381G1PrivateKey::LENGTH - 8..Bls12381G1PrivateKey::LENGTH])
+            .copy_from_slice(&n.to_be_bytes());
+
+        Ok(Self(SecretKey::from_bytes(&bytes).map_err(|_| ())?))
+    }
+}
+
+/// Aggregates the given BLS12-381 G2 signatures into a single signature.
+///
+/// Returns `None` if the list is empty or any of the signatures is not a valid G2 point.
+pub fn aggregate_bls12381_signatures(
+    signatures: &[Bls12381G2Signature],
+) -> Option<Bls12381G2Signature> {
+    let signatures = signatures
+        .iter()
+        .map(|sig| Signature::from_bytes(&sig.0).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let signatures: Vec<&Signature> = signatures.iter().collect();
+    let aggregate = AggregateSignature::aggregate(&signatures, true).ok()?;
+    Some(Bls12381G2Signature(aggregate.to_signature().to_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{verify_bls12381_v1, verify_bls12381_v1_aggregate};
+
+    #[test]
+    fn sign_and_verify() {
+        let sk = Bls12381G1PrivateKey::from_u64(1).unwrap();
+        let pk = sk.public_key();
+        let message = b"Test";
+        let sig = sk.sign_v1(message);
+
+        assert_eq!(
+            Bls12381G1PrivateKey::from_bytes(&sk.to_bytes())
+                .unwrap()
+                .public_key(),
+            pk
+        );
+        assert!(verify_bls12381_v1(message, &pk, &sig));
+        assert!(!verify_bls12381_v1(b"Other", &pk, &sig));
+
+        let other_pk = Bls12381G1PrivateKey::from_u64(2).unwrap().public_key();
+        assert!(!verify_bls12381_v1(message, &other_pk, &sig));
+    }
+
+    #[test]
+    fn aggregate_sign_and_verify() {
+        let keys: Vec<Bls12381G1PrivateKey> = (1..=3)
+            .map(|i| Bls12381G1PrivateKey::from_u64(i).unwrap())
+            .collect();
+        let messages: Vec<Vec<u8>> = (1..=3u8).map(|i| vec![i; 10]).collect();
+        let signatures: Vec<Bls12381G2Signature> = keys
+            .iter()
+            .zip(messages.iter())
+            .map(|(sk, msg)| sk.sign_v1(msg))
+            .collect();
+        let aggregate = aggregate_bls12381_signatures(&signatures).unwrap();
+
+        let pub_keys_and_msgs: Vec<(Bls12381G1PublicKey, Vec<u8>)> = keys
+            .iter()
+            .map(|sk| sk.public_key())
+            .zip(messages.iter().cloned())
+            .collect();
+        assert!(verify_bls12381_v1_aggregate(&pub_keys_and_msgs, &aggregate));
+
+        // Swapping the messages around must fail
+        let mut swapped = pub_keys_and_msgs.clone();
+        swapped[0].1 = messages[1].clone();
+        assert!(!verify_bls12381_v1_aggregate(&swapped, &aggregate));
+
+        // Omitting one of the signers must fail
+        assert!(!verify_bls12381_v1_aggregate(
+            &pub_keys_and_msgs[..2],
+            &aggregate
+        ));
+
+        assert_eq!(aggregate_bls12381_signatures(&[]), None);
+    }
+}
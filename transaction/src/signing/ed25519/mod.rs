@@ -1,5 +1,6 @@
 mod private_key;
-mod signature;
 
 pub use private_key::*;
-pub use signature::*;
+pub use radix_engine_common::crypto::{
+    Ed25519Signature, Ed25519Verifier, ParseEd25519SignatureError,
+};
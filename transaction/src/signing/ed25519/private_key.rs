@@ -1,6 +1,8 @@
 use super::Ed25519Signature;
 use crate::internal_prelude::*;
-use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::scalar::Scalar;
+use ed25519_dalek::{ExpandedSecretKey, Keypair, PublicKey, SecretKey, Signer};
 
 pub struct Ed25519PrivateKey(SecretKey);
 
@@ -22,6 +24,25 @@ impl Ed25519PrivateKey {
         Ed25519Signature(keypair.sign(msg_hash.as_ref()).to_bytes())
     }
 
+    /// Static Diffie-Hellman with the given public key, returning the `u` coordinate of the
+    /// shared point on the birationally equivalent Montgomery curve (ie X25519).
+    ///
+    /// Fails if the public key isn't a valid point, or if the shared point is the identity.
+    pub fn diffie_hellman(&self, public_key: &Ed25519PublicKey) -> Result<[u8; 32], ()> {
+        let expanded_secret_key = ExpandedSecretKey::from(&self.0).to_bytes();
+        let scalar = Scalar::from_bits(copy_u8_array(&expanded_secret_key[..32]));
+        let point = CompressedEdwardsY(public_key.0)
+            .decompress()
+            .ok_or(())?
+            .to_montgomery();
+
+        let shared_secret = (&point * &scalar).to_bytes();
+        if shared_secret == [0u8; 32] {
+            return Err(());
+        }
+        Ok(shared_secret)
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         self.0.to_bytes().to_vec()
     }
@@ -0,0 +1,331 @@
+use crate::internal_prelude::*;
+use radix_engine_common::prelude::IsHash;
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+
+/// A signer whose keys are held outside of this process, for example in an HSM, a KMS or a
+/// hardware wallet.
+///
+/// Unlike [`Signer`], signing goes through I/O and can fail, or be refused by the device.
+pub trait ExternalSigner {
+    fn public_key(&self) -> PublicKey;
+    fn sign_with_public_key(
+        &mut self,
+        message_hash: &impl IsHash,
+    ) -> Result<SignatureWithPublicKeyV1, ExternalSignerError>;
+}
+
+/// A request sent to a remote signer, encoded with `manifest_encode`.
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub enum SignerRequest {
+    GetPublicKey,
+    Sign { message_hash: Hash },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub enum SignerResponse {
+    PublicKey(PublicKey),
+    Signature(SignatureWithPublicKeyV1),
+    /// The signer refused the request, eg because the user declined it on the device.
+    Rejected(String),
+}
+
+/// Carries encoded requests to a remote signer, and brings back its encoded responses.
+pub trait SignerTransport {
+    fn exchange(&mut self, request: &[u8]) -> Result<Vec<u8>, ExternalSignerError>;
+}
+
+/// Answers signer requests with a key held by this process. This is what the remote end of a
+/// [`RemoteSigner`] runs.
+pub fn handle_signer_request<S: Signer>(signer: &S, request: SignerRequest) -> SignerResponse {
+    match request {
+        SignerRequest::GetPublicKey => SignerResponse::PublicKey(signer.public_key()),
+        SignerRequest::Sign { message_hash } => {
+            SignerResponse::Signature(signer.sign_with_public_key(&message_hash))
+        }
+    }
+}
+
+/// An [`ExternalSigner`] which talks to a signer on the other side of a [`SignerTransport`].
+pub struct RemoteSigner<T: SignerTransport> {
+    transport: T,
+    public_key: PublicKey,
+}
+
+impl<T: SignerTransport> RemoteSigner<T> {
+    /// Connects to the signer, and fetches the public key it signs for.
+    pub fn new(mut transport: T) -> Result<Self, ExternalSignerError> {
+        let public_key = match Self::request(&mut transport, SignerRequest::GetPublicKey)? {
+            SignerResponse::PublicKey(public_key) => public_key,
+            _ => return Err(ExternalSignerError::UnexpectedResponse),
+        };
+        Ok(Self {
+            transport,
+            public_key,
+        })
+    }
+
+    fn request(
+        transport: &mut T,
+        request: SignerRequest,
+    ) -> Result<SignerResponse, ExternalSignerError> {
+        let response = transport.exchange(&manifest_encode(&request)?)?;
+        match manifest_decode(&response)? {
+            SignerResponse::Rejected(reason) => Err(ExternalSignerError::Rejected(reason)),
+            response => Ok(response),
+        }
+    }
+}
+
+impl<T: SignerTransport> ExternalSigner for RemoteSigner<T> {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    fn sign_with_public_key(
+        &mut self,
+        message_hash: &impl IsHash,
+    ) -> Result<SignatureWithPublicKeyV1, ExternalSignerError> {
+        let message_hash = Hash(*message_hash.as_bytes());
+        let signature =
+            match Self::request(&mut self.transport, SignerRequest::Sign { message_hash })? {
+                SignerResponse::Signature(signature) => signature,
+                _ => return Err(ExternalSignerError::UnexpectedResponse),
+            };
+
+        // Don't trust the device to have signed the right hash with the right key
+        match recover(&message_hash, &signature) {
+            Some(public_key)
+                if public_key == self.public_key
+                    && verify(&message_hash, &public_key, &signature.signature()) =>
+            {
+                Ok(signature)
+            }
+            _ => Err(ExternalSignerError::InvalidSignature),
+        }
+    }
+}
+
+/// An in-process stand-in for a remote signer, for tests.
+///
+/// Requests still go through the same encoding as a real transport, and it can be told to
+/// reject requests, as a user declining on a hardware wallet would.
+pub struct MockSignerTransport {
+    private_key: PrivateKey,
+    pub reject_requests: bool,
+    pub requests: Vec<SignerRequest>,
+}
+
+impl MockSignerTransport {
+    pub fn new(private_key: impl Into<PrivateKey>) -> Self {
+        Self {
+            private_key: private_key.into(),
+            reject_requests: false,
+            requests: Vec::new(),
+        }
+    }
+}
+
+impl SignerTransport for MockSignerTransport {
+    fn exchange(&mut self, request: &[u8]) -> Result<Vec<u8>, ExternalSignerError> {
+        let request: SignerRequest = manifest_decode(request)?;
+        self.requests.push(request.clone());
+        let response = if self.reject_requests {
+            SignerResponse::Rejected("Rejected by mock signer".to_string())
+        } else {
+            handle_signer_request(&self.private_key, request)
+        };
+        Ok(manifest_encode(&response)?)
+    }
+}
+
+/// The maximum length of a framed message, well above the size of any request or response, so
+/// that a misbehaving peer can't make us allocate arbitrary amounts of memory.
+#[cfg(feature = "std")]
+pub const MAX_SIGNER_FRAME_LENGTH: usize = 64 * 1024;
+
+/// A transport over any byte stream, such as a `TcpStream`, a Unix socket, or the standard
+/// input and output of a child process.
+///
+/// Each message is framed as its length, as a little-endian `u32`, followed by its bytes.
+/// Messages longer than [`MAX_SIGNER_FRAME_LENGTH`] are refused.
+#[cfg(feature = "std")]
+pub struct StreamSignerTransport<R: Read, W: Write> {
+    reader: R,
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, W: Write> StreamSignerTransport<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        Self { reader, writer }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, W: Write> SignerTransport for StreamSignerTransport<R, W> {
+    fn exchange(&mut self, request: &[u8]) -> Result<Vec<u8>, ExternalSignerError> {
+        write_frame(&mut self.writer, request)?;
+        read_frame(&mut self.reader)?.ok_or_else(|| {
+            ExternalSignerError::TransportError("Connection closed by signer".to_string())
+        })
+    }
+}
+
+/// Serves signer requests from a stream until it is closed, signing with a key held by this
+/// process. Combined with [`StreamSignerTransport`], this lets a key live in another process.
+#[cfg(feature = "std")]
+pub fn serve_signer<S: Signer>(
+    signer: &S,
+    mut reader: impl Read,
+    mut writer: impl Write,
+) -> Result<(), ExternalSignerError> {
+    while let Some(request) = read_frame(&mut reader)? {
+        let response = match manifest_decode(&request) {
+            Ok(request) => handle_signer_request(signer, request),
+            Err(error) => SignerResponse::Rejected(format!("Invalid request: {:?}", error)),
+        };
+        write_frame(&mut writer, &manifest_encode(&response)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+fn write_frame(writer: &mut impl Write, message: &[u8]) -> Result<(), ExternalSignerError> {
+    if message.len() > MAX_SIGNER_FRAME_LENGTH {
+        return Err(ExternalSignerError::TransportError(
+            "Message too long".to_string(),
+        ));
+    }
+    writer.write_all(&(message.len() as u32).to_le_bytes())?;
+    writer.write_all(message)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Returns `None` if the stream ended cleanly before a new frame.
+#[cfg(feature = "std")]
+fn read_frame(reader: &mut impl Read) -> Result<Option<Vec<u8>>, ExternalSignerError> {
+    let mut length = [0u8; 4];
+    match reader.read_exact(&mut length) {
+        Ok(()) => {}
+        Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error.into()),
+    }
+    let length = u32::from_le_bytes(length) as usize;
+    if length > MAX_SIGNER_FRAME_LENGTH {
+        return Err(ExternalSignerError::TransportError(format!(
+            "Frame of {} bytes exceeds the maximum of {} bytes",
+            length, MAX_SIGNER_FRAME_LENGTH
+        )));
+    }
+    let mut message = vec![0u8; length];
+    reader.read_exact(&mut message)?;
+    Ok(Some(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::crypto::hash;
+
+    #[test]
+    fn remote_signer_signs_through_mock_transport() {
+        let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+        let mut signer = RemoteSigner::new(MockSignerTransport::new(
+            Ed25519PrivateKey::from_u64(1).unwrap(),
+        ))
+        .unwrap();
+        let message_hash = hash("message");
+
+        let signature = signer.sign_with_public_key(&message_hash).unwrap();
+
+        assert_eq!(signer.public_key(), private_key.public_key().into());
+        assert_eq!(
+            signature,
+            Signer::sign_with_public_key(&private_key, &message_hash)
+        );
+    }
+
+    #[test]
+    fn rejection_by_signer_is_reported() {
+        let mut transport = MockSignerTransport::new(Secp256k1PrivateKey::from_u64(1).unwrap());
+        transport.reject_requests = true;
+
+        assert!(matches!(
+            RemoteSigner::new(transport),
+            Err(ExternalSignerError::Rejected(_))
+        ));
+    }
+
+    #[test]
+    fn signature_by_another_key_is_rejected() {
+        struct SwappingTransport(MockSignerTransport, MockSignerTransport);
+        impl SignerTransport for SwappingTransport {
+            fn exchange(&mut self, request: &[u8]) -> Result<Vec<u8>, ExternalSignerError> {
+                // Reports one key, but signs with another
+                match manifest_decode(request)? {
+                    SignerRequest::GetPublicKey => self.0.exchange(request),
+                    SignerRequest::Sign { .. } => self.1.exchange(request),
+                }
+            }
+        }
+        let mut signer = RemoteSigner::new(SwappingTransport(
+            MockSignerTransport::new(Secp256k1PrivateKey::from_u64(1).unwrap()),
+            MockSignerTransport::new(Secp256k1PrivateKey::from_u64(2).unwrap()),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            signer.sign_with_public_key(&hash("message")),
+            Err(ExternalSignerError::InvalidSignature)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn remote_signer_signs_over_a_stream() {
+        let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let message_hash = hash("message");
+
+        // Record the responses of a served session, and replay them to the client
+        let mut requests = Vec::new();
+        write_frame(
+            &mut requests,
+            &manifest_encode(&SignerRequest::GetPublicKey).unwrap(),
+        )
+        .unwrap();
+        write_frame(
+            &mut requests,
+            &manifest_encode(&SignerRequest::Sign { message_hash }).unwrap(),
+        )
+        .unwrap();
+        let mut responses = Vec::new();
+        serve_signer(&private_key, requests.as_slice(), &mut responses).unwrap();
+
+        let mut signer =
+            RemoteSigner::new(StreamSignerTransport::new(responses.as_slice(), Vec::new()))
+                .unwrap();
+
+        assert_eq!(
+            signer.sign_with_public_key(&message_hash).unwrap(),
+            Signer::sign_with_public_key(&private_key, &message_hash)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn oversized_frames_are_refused() {
+        let mut stream = u32::MAX.to_le_bytes().to_vec();
+        stream.extend_from_slice(&[0u8; 16]);
+
+        assert!(matches!(
+            read_frame(&mut stream.as_slice()),
+            Err(ExternalSignerError::TransportError(_))
+        ));
+        assert!(matches!(
+            write_frame(&mut Vec::new(), &vec![0u8; MAX_SIGNER_FRAME_LENGTH + 1]),
+            Err(ExternalSignerError::TransportError(_))
+        ));
+    }
+}
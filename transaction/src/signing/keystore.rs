@@ -0,0 +1,315 @@
+use crate::internal_prelude::*;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::Aes256Gcm;
+use rand_core::{CryptoRng, RngCore};
+use sha2::Sha256;
+
+/// The number of PBKDF2-HMAC-SHA256 rounds used to derive the encryption key from a password,
+/// as recommended by OWASP.
+pub const DEFAULT_KEYSTORE_KDF_ITERATIONS: u32 = 600_000;
+
+/// A private key encrypted under a password, suitable for storing on disk.
+///
+/// The encryption key is derived from the password with PBKDF2-HMAC-SHA256, and the private key
+/// is encrypted with AES-256-GCM. The public key is kept in the clear, so that keys can be
+/// found without the password.
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub struct EncryptedPrivateKeyV1 {
+    pub public_key: PublicKey,
+    pub kdf_salt: [u8; 16],
+    pub kdf_iterations: u32,
+    pub nonce: [u8; 12],
+    pub ciphertext: Vec<u8>,
+}
+
+impl EncryptedPrivateKeyV1 {
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        private_key: &PrivateKey,
+        password: &str,
+        kdf_iterations: u32,
+        rng: &mut R,
+    ) -> Self {
+        let mut kdf_salt = [0u8; 16];
+        rng.fill_bytes(&mut kdf_salt);
+        let mut nonce = [0u8; 12];
+        rng.fill_bytes(&mut nonce);
+
+        let key = derive_key(password, &kdf_salt, kdf_iterations);
+        let private_key_bytes = match private_key {
+            PrivateKey::Secp256k1(key) => key.to_bytes(),
+            PrivateKey::Ed25519(key) => key.to_bytes(),
+        };
+        let ciphertext = Aes256Gcm::new(&key.into())
+            .encrypt(&nonce.into(), private_key_bytes.as_slice())
+            .expect("Encrypting a private key cannot fail");
+
+        Self {
+            public_key: private_key.public_key(),
+            kdf_salt,
+            kdf_iterations,
+            nonce,
+            ciphertext,
+        }
+    }
+
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey, KeystoreError> {
+        let key = derive_key(password, &self.kdf_salt, self.kdf_iterations);
+        let private_key_bytes = Aes256Gcm::new(&key.into())
+            .decrypt(&self.nonce.into(), self.ciphertext.as_slice())
+            .map_err(|_| KeystoreError::DecryptionFailed)?;
+
+        let private_key: PrivateKey = match self.public_key {
+            PublicKey::Secp256k1(_) => Secp256k1PrivateKey::from_bytes(&private_key_bytes)
+                .map_err(|_| KeystoreError::InvalidPrivateKey)?
+                .into(),
+            PublicKey::Ed25519(_) => Ed25519PrivateKey::from_bytes(&private_key_bytes)
+                .map_err(|_| KeystoreError::InvalidPrivateKey)?
+                .into(),
+        };
+        if private_key.public_key() != self.public_key {
+            return Err(KeystoreError::InvalidPrivateKey);
+        }
+        Ok(private_key)
+    }
+}
+
+fn derive_key(password: &str, salt: &[u8; 16], iterations: u32) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, iterations, &mut key);
+    key
+}
+
+/// A directory of password-encrypted private keys, one `<public key hex>.key` file per key.
+#[cfg(feature = "std")]
+pub struct Keystore {
+    path: std::path::PathBuf,
+    kdf_iterations: u32,
+}
+
+#[cfg(feature = "std")]
+impl Keystore {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            kdf_iterations: DEFAULT_KEYSTORE_KDF_ITERATIONS,
+        }
+    }
+
+    /// Sets the number of KDF rounds used for keys imported from now on.
+    pub fn with_kdf_iterations(mut self, kdf_iterations: u32) -> Self {
+        self.kdf_iterations = kdf_iterations;
+        self
+    }
+
+    /// Encrypts the private key under the password and stores it, replacing any existing
+    /// entry for the same key.
+    pub fn import(
+        &self,
+        private_key: &PrivateKey,
+        password: &str,
+    ) -> Result<PublicKey, KeystoreError> {
+        let encrypted = EncryptedPrivateKeyV1::encrypt(
+            private_key,
+            password,
+            self.kdf_iterations,
+            &mut rand_core::OsRng,
+        );
+        create_private_dir(&self.path)?;
+        write_private_file(
+            &self.key_path(&encrypted.public_key),
+            &manifest_encode(&encrypted)?,
+        )?;
+        Ok(encrypted.public_key)
+    }
+
+    pub fn load(
+        &self,
+        public_key: &PublicKey,
+        password: &str,
+    ) -> Result<PrivateKey, KeystoreError> {
+        let encrypted = match std::fs::read(self.key_path(public_key)) {
+            Ok(bytes) => manifest_decode::<EncryptedPrivateKeyV1>(&bytes)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Err(KeystoreError::KeyNotFound(*public_key))
+            }
+            Err(error) => return Err(error.into()),
+        };
+        encrypted.decrypt(password)
+    }
+
+    /// The public keys of all stored keys.
+    pub fn list(&self) -> Result<Vec<PublicKey>, KeystoreError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let mut public_keys = Vec::new();
+        for entry in std::fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            if path.extension().and_then(|extension| extension.to_str()) != Some("key") {
+                continue;
+            }
+            let encrypted = manifest_decode::<EncryptedPrivateKeyV1>(&std::fs::read(&path)?)?;
+            public_keys.push(encrypted.public_key);
+        }
+        Ok(public_keys)
+    }
+
+    fn key_path(&self, public_key: &PublicKey) -> std::path::PathBuf {
+        let public_key_hex = match public_key {
+            PublicKey::Secp256k1(public_key) => hex::encode(public_key.to_vec()),
+            PublicKey::Ed25519(public_key) => hex::encode(public_key.to_vec()),
+        };
+        self.path.join(format!("{}.key", public_key_hex))
+    }
+}
+
+/// Creates the keystore directory, and any missing parent, so that only its owner can list or
+/// enter it.
+#[cfg(feature = "std")]
+fn create_private_dir(path: &std::path::Path) -> std::io::Result<()> {
+    let mut builder = std::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(path)
+}
+
+/// Writes a file which only its owner can read, even though its content is encrypted, so that
+/// the password can't be brute-forced offline by other users.
+///
+/// The contents are written to a new temporary file next to `path`, which is then renamed into
+/// place, so that an existing entry is never left truncated or readable by others.
+#[cfg(feature = "std")]
+fn write_private_file(path: &std::path::Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid keystore file name",
+            )
+        })?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name,
+        hex::encode(rand_core::OsRng.next_u64().to_le_bytes())
+    ));
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let result = options.open(&temp_path).and_then(|mut file| {
+        file.write_all(contents)?;
+        file.sync_all()?;
+        std::fs::rename(&temp_path, path)
+    });
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_KDF_ITERATIONS: u32 = 10;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn encrypted_private_key_round_trips() {
+        for private_key in [
+            PrivateKey::from(Secp256k1PrivateKey::from_u64(1).unwrap()),
+            PrivateKey::from(Ed25519PrivateKey::from_u64(1).unwrap()),
+        ] {
+            let encrypted = EncryptedPrivateKeyV1::encrypt(
+                &private_key,
+                "password",
+                TEST_KDF_ITERATIONS,
+                &mut rand_core::OsRng,
+            );
+            let decoded: EncryptedPrivateKeyV1 =
+                manifest_decode(&manifest_encode(&encrypted).unwrap()).unwrap();
+
+            let decrypted = decoded.decrypt("password").unwrap();
+
+            assert_eq!(decrypted.public_key(), private_key.public_key());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn wrong_password_fails_to_decrypt() {
+        let encrypted = EncryptedPrivateKeyV1::encrypt(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().into(),
+            "password",
+            TEST_KDF_ITERATIONS,
+            &mut rand_core::OsRng,
+        );
+
+        assert_eq!(
+            encrypted.decrypt("wrong password").err(),
+            Some(KeystoreError::DecryptionFailed)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn keystore_stores_and_loads_keys() {
+        let path = std::env::temp_dir().join(format!(
+            "keystore_test_{}",
+            hex::encode(rand_core::OsRng.next_u64().to_le_bytes())
+        ));
+        let keystore = Keystore::new(&path).with_kdf_iterations(TEST_KDF_ITERATIONS);
+        let private_key: PrivateKey = Ed25519PrivateKey::from_u64(2).unwrap().into();
+        let missing_key: PublicKey = Ed25519PrivateKey::from_u64(3).unwrap().public_key().into();
+
+        let public_key = keystore.import(&private_key, "password").unwrap();
+
+        assert_eq!(keystore.list().unwrap(), vec![public_key]);
+        assert_eq!(
+            keystore.load(&public_key, "password").unwrap().public_key(),
+            private_key.public_key()
+        );
+        assert_eq!(
+            keystore.load(&missing_key, "password").err(),
+            Some(KeystoreError::KeyNotFound(missing_key))
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let key_file = std::fs::metadata(keystore.key_path(&public_key)).unwrap();
+            assert_eq!(key_file.permissions().mode() & 0o777, 0o600);
+            let keystore_dir = std::fs::metadata(&path).unwrap();
+            assert_eq!(keystore_dir.permissions().mode() & 0o777, 0o700);
+        }
+        std::fs::remove_dir_all(path).unwrap();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn importing_a_key_again_replaces_its_entry() {
+        let path = std::env::temp_dir().join(format!(
+            "keystore_test_{}",
+            hex::encode(rand_core::OsRng.next_u64().to_le_bytes())
+        ));
+        let keystore = Keystore::new(&path).with_kdf_iterations(TEST_KDF_ITERATIONS);
+        let private_key: PrivateKey = Ed25519PrivateKey::from_u64(2).unwrap().into();
+
+        keystore.import(&private_key, "old password").unwrap();
+        let public_key = keystore.import(&private_key, "new password").unwrap();
+
+        assert_eq!(keystore.list().unwrap(), vec![public_key]);
+        assert_eq!(std::fs::read_dir(&path).unwrap().count(), 1);
+        assert!(keystore.load(&public_key, "new password").is_ok());
+        assert_eq!(
+            keystore.load(&public_key, "old password").err(),
+            Some(KeystoreError::DecryptionFailed)
+        );
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
@@ -1,5 +1,10 @@
+pub mod bls12381;
 pub mod ed25519;
+mod external_signer;
+mod keystore;
 pub mod secp256k1;
 mod signer;
 
+pub use external_signer::*;
+pub use keystore::*;
 pub use signer::*;
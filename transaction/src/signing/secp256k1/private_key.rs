@@ -23,6 +23,14 @@ impl Secp256k1PrivateKey {
         Secp256k1Signature(buf)
     }
 
+    /// Static Diffie-Hellman with the given public key, returning the unhashed `x` coordinate
+    /// of the shared point (ie ANSI X9.63 ECDH, rather than the hashed libsecp256k1 variant).
+    pub fn diffie_hellman(&self, public_key: &Secp256k1PublicKey) -> Result<[u8; 32], ()> {
+        let public_key = PublicKey::from_slice(&public_key.0).map_err(|_| ())?;
+        let shared_point = ::secp256k1::ecdh::shared_secret_point(&public_key, &self.0);
+        Ok(copy_u8_array(&shared_point[..32]))
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         self.0.secret_bytes().to_vec()
     }
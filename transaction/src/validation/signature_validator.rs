@@ -73,3 +73,71 @@ pub fn verify_ed25519(
 
     false
 }
+
+/// Verifies an Ed25519 signature over an arbitrary message, rather than a transaction hash.
+pub fn verify_ed25519_message(
+    message: &[u8],
+    public_key: &Ed25519PublicKey,
+    signature: &Ed25519Signature,
+) -> bool {
+    if let Ok(sig) = ed25519_dalek::Signature::from_bytes(&signature.0) {
+        if let Ok(pk) = ed25519_dalek::PublicKey::from_bytes(&public_key.0) {
+            return pk.verify_strict(message, &sig).is_ok();
+        }
+    }
+
+    false
+}
+
+/// The domain separation tag used by the V1 BLS12-381 verification functions.
+///
+/// This is the standard ciphersuite for the proof-of-possession scheme with public keys in G1
+/// and signatures in G2 - see the IETF BLS signature draft, section 4.2.3.
+pub const BLS12381_CIPHERSUITE_V1: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Verifies a BLS12-381 G2 signature over a message, using the V1 ciphersuite.
+pub fn verify_bls12381_v1(
+    message: &[u8],
+    public_key: &Bls12381G1PublicKey,
+    signature: &Bls12381G2Signature,
+) -> bool {
+    if let Ok(sig) = blst::min_pk::Signature::from_bytes(&signature.0) {
+        if let Ok(pk) = blst::min_pk::PublicKey::from_bytes(&public_key.0) {
+            let result = sig.verify(true, message, BLS12381_CIPHERSUITE_V1, &[], &pk, true);
+            return matches!(result, blst::BLST_ERROR::BLST_SUCCESS);
+        }
+    }
+
+    false
+}
+
+/// Verifies an aggregate BLS12-381 G2 signature over a list of (public key, message) pairs,
+/// using the V1 ciphersuite.
+///
+/// An empty list is never considered valid.
+pub fn verify_bls12381_v1_aggregate(
+    pub_keys_and_msgs: &[(Bls12381G1PublicKey, Vec<u8>)],
+    signature: &Bls12381G2Signature,
+) -> bool {
+    if pub_keys_and_msgs.is_empty() {
+        return false;
+    }
+
+    let sig = match blst::min_pk::Signature::from_bytes(&signature.0) {
+        Ok(sig) => sig,
+        Err(_) => return false,
+    };
+    let mut pks = Vec::with_capacity(pub_keys_and_msgs.len());
+    let mut msgs = Vec::with_capacity(pub_keys_and_msgs.len());
+    for (public_key, message) in pub_keys_and_msgs {
+        match blst::min_pk::PublicKey::from_bytes(&public_key.0) {
+            Ok(pk) => pks.push(pk),
+            Err(_) => return false,
+        }
+        msgs.push(message.as_slice());
+    }
+    let pk_refs: Vec<&blst::min_pk::PublicKey> = pks.iter().collect();
+
+    let result = sig.aggregate_verify(true, &msgs, BLS12381_CIPHERSUITE_V1, &pk_refs, true);
+    matches!(result, blst::BLST_ERROR::BLST_SUCCESS)
+}